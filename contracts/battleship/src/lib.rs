@@ -0,0 +1,752 @@
+#![no_std]
+
+//! # Battleship
+//!
+//! A two-player hidden-board game. Each player places a fleet on an 8x8
+//! board and commits the Merkle root of it. Players then take turns firing
+//! at the opponent's board, and the defender answers hit or miss with a
+//! Merkle proof against their committed root. The first player to hit every
+//! ship cell of the opponent's fleet wins, once they reveal their own board
+//! and it holds a legal fleet.
+//!
+//! Boards stay hidden through Merkle commitments. Each of the 64 cells is a
+//! leaf `keccak256(cell || occupied || salt)`, with its own random 32-byte
+//! salt, so answering one shot reveals nothing about the other cells.
+//!
+//! **Game Hub Integration:**
+//! This game is Game Hub-aware and enforces all games to be played through the
+//! Game Hub contract. Games cannot be started or completed without points involvement.
+
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contractclient, contracterror,
+    contractimpl, contracttype, vec
+};
+
+// Import GameHub contract interface
+// This allows us to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(
+        env: Env,
+        session_id: u32,
+        player1_won: bool
+    );
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    GameNotFound = 1,
+    NotPlayer = 2,
+    AlreadyCommitted = 3,
+    WrongPhase = 4,
+    GameAlreadyEnded = 5,
+    NotYourTurn = 6,
+    InvalidCell = 7,
+    AlreadyFired = 8,
+    ShotPending = 9,
+    NoShotPending = 10,
+    InvalidProof = 11,
+    InvalidReveal = 12,
+    TimeoutNotReached = 13,
+    CannotClaimTimeout = 14,
+    SelfPlay = 15,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Phase {
+    /// Waiting for both players to commit their boards
+    Commit,
+    /// Players alternate shots, each answered by the defender
+    Firing,
+    /// A fleet is sunk; waiting for the shooter to reveal their board
+    Reveal,
+    /// Winner decided and reported to the Game Hub
+    Ended,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub phase: Phase,
+    /// Merkle roots of the players' boards
+    pub player1_root: Option<BytesN<32>>,
+    pub player2_root: Option<BytesN<32>>,
+    /// Player firing next, or whose shot is waiting for an answer
+    pub shooter: Address,
+    /// Cell fired at and not answered yet
+    pub pending_shot: Option<u32>,
+    /// Cells player 1 fired at on player 2's board (bit i = cell i)
+    pub player1_shots: u64,
+    /// Cells of player 1's shots that hit
+    pub player1_hits: u64,
+    pub player2_shots: u64,
+    pub player2_hits: u64,
+    /// Ledger after which the stalled player can be forfeited
+    pub deadline: u32,
+    pub winner: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Game(u32),
+    GameHubAddress,
+    Admin,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) ensures game data doesn't expire unexpectedly
+// Games are stored in temporary storage with a minimum 30-day retention
+
+/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Ledgers a player has to act before the opponent may claim the game
+/// 1 day = 24 * 60 * 60 / 5 = 17,280 ledgers
+const MOVE_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Width and height of a board
+pub const BOARD_SIZE: u32 = 8;
+
+/// Cells on a board, cell index = y * BOARD_SIZE + x
+pub const BOARD_CELLS: u32 = BOARD_SIZE * BOARD_SIZE;
+
+/// Levels of the board's Merkle tree (2^6 = 64 leaves), i.e. proof length
+pub const BOARD_DEPTH: u32 = 6;
+
+/// Ship lengths in a fleet
+pub const FLEET: [u32; 4] = [4, 3, 3, 2];
+
+/// Ship cells in a fleet; hitting all of them sinks it
+const FLEET_CELLS: u32 = 12;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Merkle leaf for one cell: keccak256(cell as u8 || occupied as u8 || salt)
+pub fn leaf_hash(env: &Env, cell: u32, occupied: bool, salt: &BytesN<32>) -> BytesN<32> {
+    let mut bytes = Bytes::from_array(env, &[cell as u8, occupied as u8]);
+    bytes.append(&Bytes::from(salt.clone()));
+    env.crypto().keccak256(&bytes).into()
+}
+
+/// Parent node: keccak256(left || right)
+pub fn hash_pair(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut bytes = Bytes::from(left.clone());
+    bytes.append(&Bytes::from(right.clone()));
+    env.crypto().keccak256(&bytes).into()
+}
+
+/// Root reached from a cell's leaf and its proof (siblings from the leaf up;
+/// bit i of the cell index says whether the node is the right child at level i)
+fn proof_root(env: &Env, cell: u32, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> BytesN<32> {
+    let mut node = leaf;
+    for (level, sibling) in proof.iter().enumerate() {
+        node = if (cell >> level) & 1 == 0 {
+            hash_pair(env, &node, &sibling)
+        } else {
+            hash_pair(env, &sibling, &node)
+        };
+    }
+    node
+}
+
+/// Merkle root of a whole board
+fn board_root(env: &Env, board: u64, salts: &Vec<BytesN<32>>) -> BytesN<32> {
+    let mut level = Vec::new(env);
+    for cell in 0..BOARD_CELLS {
+        let salt = salts.get_unchecked(cell);
+        level.push_back(leaf_hash(env, cell, (board >> cell) & 1 == 1, &salt));
+    }
+    while level.len() > 1 {
+        let mut parents = Vec::new(env);
+        for i in (0..level.len()).step_by(2) {
+            parents.push_back(hash_pair(env, &level.get_unchecked(i), &level.get_unchecked(i + 1)));
+        }
+        level = parents;
+    }
+    level.get_unchecked(0)
+}
+
+fn occupied(board: u64, x: u32, y: u32) -> bool {
+    x < BOARD_SIZE && y < BOARD_SIZE && (board >> (y * BOARD_SIZE + x)) & 1 == 1
+}
+
+/// Whether a board holds exactly the fleet: straight horizontal or vertical
+/// ships of the `FLEET` lengths, no two sharing a side (corners may touch)
+fn legal_fleet(board: u64) -> bool {
+    let mut counts = [0u32; BOARD_SIZE as usize + 1];
+    let mut seen = 0u64;
+
+    for y in 0..BOARD_SIZE {
+        for x in 0..BOARD_SIZE {
+            let cell = y * BOARD_SIZE + x;
+            if !occupied(board, x, y) || (seen >> cell) & 1 == 1 {
+                continue;
+            }
+
+            // Scanning row by row, this is the ship's top-left end
+            let horizontal = occupied(board, x + 1, y);
+            let (mut cx, mut cy, mut length) = (x, y, 0);
+            while occupied(board, cx, cy) {
+                let beside = if horizontal {
+                    (cy > 0 && occupied(board, cx, cy - 1)) || occupied(board, cx, cy + 1)
+                } else {
+                    (cx > 0 && occupied(board, cx - 1, cy)) || occupied(board, cx + 1, cy)
+                };
+                if beside {
+                    return false;
+                }
+                seen |= 1 << (cy * BOARD_SIZE + cx);
+                length += 1;
+                if horizontal {
+                    cx += 1;
+                } else {
+                    cy += 1;
+                }
+            }
+            counts[length as usize] += 1;
+        }
+    }
+
+    let mut expected = [0u32; BOARD_SIZE as usize + 1];
+    for length in FLEET {
+        expected[length as usize] += 1;
+    }
+    counts == expected
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct BattleshipContract;
+
+#[contractimpl]
+impl BattleshipContract {
+    /// Initialize the contract with GameHub address and admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    /// * `game_hub` - Address of the GameHub contract
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        // Store admin and GameHub address
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &game_hub);
+    }
+
+    /// Start a new game between two players with points.
+    /// This creates a session in the Game Hub and locks points before starting the game.
+    ///
+    /// **CRITICAL:** This method requires authorization from THIS contract (not players).
+    /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points amount committed by player 1
+    /// * `player2_points` - Points amount committed by player 2
+    pub fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            return Err(Error::SelfPlay);
+        }
+
+        // Require authentication from both players (they consent to committing points)
+        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Create GameHub client
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        // Create game (boards are committed next, player 1 fires first)
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            phase: Phase::Commit,
+            player1_root: None,
+            player2_root: None,
+            shooter: player1,
+            pending_shot: None,
+            player1_shots: 0,
+            player1_hits: 0,
+            player2_shots: 0,
+            player2_hits: 0,
+            deadline: env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS,
+            winner: None,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        Self::save_game(&env, session_id, &game);
+
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
+    }
+
+    /// Commit the Merkle root of the player's board.
+    /// Firing opens once both players have committed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the committing player
+    /// * `root` - Merkle root over the 64 cell leaves
+    pub fn commit_board(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        root: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Commit)?;
+
+        if player == game.player1 {
+            if game.player1_root.is_some() {
+                return Err(Error::AlreadyCommitted);
+            }
+            game.player1_root = Some(root);
+        } else if player == game.player2 {
+            if game.player2_root.is_some() {
+                return Err(Error::AlreadyCommitted);
+            }
+            game.player2_root = Some(root);
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        if game.player1_root.is_some() && game.player2_root.is_some() {
+            game.phase = Phase::Firing;
+        }
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Fire at a cell of the opponent's board.
+    /// The opponent must answer before the next shot.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the firing player (must be their turn)
+    /// * `x` - Column (0-7)
+    /// * `y` - Row (0-7)
+    pub fn fire(env: Env, session_id: u32, player: Address, x: u32, y: u32) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Firing)?;
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if game.pending_shot.is_some() {
+            return Err(Error::ShotPending);
+        }
+        if player != game.shooter {
+            return Err(Error::NotYourTurn);
+        }
+        if x >= BOARD_SIZE || y >= BOARD_SIZE {
+            return Err(Error::InvalidCell);
+        }
+
+        let cell = y * BOARD_SIZE + x;
+        let shots = if player == game.player1 {
+            game.player1_shots
+        } else {
+            game.player2_shots
+        };
+        if (shots >> cell) & 1 == 1 {
+            return Err(Error::AlreadyFired);
+        }
+
+        game.pending_shot = Some(cell);
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Answer the pending shot at the player's board, proving the answer
+    /// against their committed root. Turns alternate after every answer.
+    /// When the shot sinks the last ship cell, the shooter must reveal
+    /// their board to win.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the defending player
+    /// * `hit` - Whether the cell holds a ship
+    /// * `salt` - The cell's salt
+    /// * `proof` - Sibling hashes from the cell's leaf up to the root
+    pub fn answer_shot(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        hit: bool,
+        salt: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Firing)?;
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        let cell = game.pending_shot.ok_or(Error::NoShotPending)?;
+        if player == game.shooter {
+            return Err(Error::NotYourTurn);
+        }
+
+        let root = if player == game.player1 {
+            game.player1_root.clone()
+        } else {
+            game.player2_root.clone()
+        }
+        .ok_or(Error::WrongPhase)?;
+        if proof.len() != BOARD_DEPTH
+            || proof_root(&env, cell, leaf_hash(&env, cell, hit, &salt), &proof) != root
+        {
+            return Err(Error::InvalidProof);
+        }
+
+        let hits = if game.shooter == game.player1 {
+            game.player1_shots |= 1 << cell;
+            if hit {
+                game.player1_hits |= 1 << cell;
+            }
+            game.player1_hits
+        } else {
+            game.player2_shots |= 1 << cell;
+            if hit {
+                game.player2_hits |= 1 << cell;
+            }
+            game.player2_hits
+        };
+
+        game.pending_shot = None;
+        if hits.count_ones() == FLEET_CELLS {
+            game.phase = Phase::Reveal;
+        } else {
+            game.shooter = player;
+        }
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the full board of the player who sank the opponent's fleet.
+    /// The board must match the committed root. If it holds a legal fleet
+    /// the player wins; otherwise they forfeit to the opponent.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player who sank the fleet
+    /// * `board` - Ship cells (bit i set = cell i holds a ship)
+    /// * `salts` - The salt of every cell, in cell order
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player
+    pub fn reveal_board(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        board: u64,
+        salts: Vec<BytesN<32>>,
+    ) -> Result<Address, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Reveal)?;
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if player != game.shooter {
+            return Err(Error::NotYourTurn);
+        }
+
+        let root = if player == game.player1 {
+            game.player1_root.clone()
+        } else {
+            game.player2_root.clone()
+        }
+        .ok_or(Error::WrongPhase)?;
+        if salts.len() != BOARD_CELLS || board_root(&env, board, &salts) != root {
+            return Err(Error::InvalidReveal);
+        }
+
+        // Every answer the player gave was proven against this root, so only
+        // the placement itself is left to check
+        let winner = if legal_fleet(board) {
+            player
+        } else {
+            Self::opponent(&game, &player)
+        };
+
+        Self::finish_game(&env, session_id, &mut game, winner.clone());
+
+        Ok(winner)
+    }
+
+    /// Win the game because the opponent stopped acting.
+    /// After the deadline passes, the player who is not holding up the game
+    /// (the one who committed, is waiting on a shot or an answer, or is
+    /// waiting on the winner's reveal) can claim it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the claiming player
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player (the claimant)
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        if game.phase == Phase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        let can_claim = match game.phase {
+            Phase::Commit => {
+                let (own, opponent) = if player == game.player1 {
+                    (&game.player1_root, &game.player2_root)
+                } else {
+                    (&game.player2_root, &game.player1_root)
+                };
+                own.is_some() && opponent.is_none()
+            }
+            Phase::Firing => (game.shooter == player) == game.pending_shot.is_some(),
+            Phase::Reveal => game.shooter != player,
+            Phase::Ended => false,
+        };
+        if !can_claim {
+            return Err(Error::CannotClaimTimeout);
+        }
+
+        Self::finish_game(&env, session_id, &mut game, player.clone());
+
+        Ok(player)
+    }
+
+    /// Get game information.
+    /// Boards stay hidden; only the roots and the answered shots are public.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Game` - The game state
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        Self::load_game(&env, session_id)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    fn load_game(env: &Env, session_id: u32) -> Result<Game, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    fn save_game(env: &Env, session_id: u32, game: &Game) {
+        let key = DataKey::Game(session_id);
+        env.storage().temporary().set(&key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    fn require_phase(game: &Game, phase: Phase) -> Result<(), Error> {
+        if game.phase == Phase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.phase != phase {
+            return Err(Error::WrongPhase);
+        }
+        Ok(())
+    }
+
+    fn opponent(game: &Game, player: &Address) -> Address {
+        if *player == game.player1 {
+            game.player2.clone()
+        } else {
+            game.player1.clone()
+        }
+    }
+
+    /// Record the winner and end the session with the Game Hub
+    fn finish_game(env: &Env, session_id: u32, game: &mut Game, winner: Address) {
+        game.phase = Phase::Ended;
+        game.pending_shot = None;
+        game.winner = Some(winner.clone());
+        Self::save_game(env, session_id, game);
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Call GameHub to end the session
+        // This unlocks points and updates standings
+        // Event emitted by the Game Hub contract (GameEnded)
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        game_hub.end_game(&session_id, &(winner == game.player1));
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    pub fn get_hub(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set")
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,392 @@
+#![cfg(test)]
+extern crate std;
+
+// Unit tests for the battleship contract using a simple mock GameHub.
+// These tests verify game logic independently of the full GameHub system.
+
+use crate::{
+    hash_pair, leaf_hash, legal_fleet, BattleshipContract, BattleshipContractClient, Error, Phase, BOARD_CELLS,
+    BOARD_SIZE,
+};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+
+// ============================================================================
+// Mock GameHub for Unit Testing
+// ============================================================================
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+        // Mock implementation - does nothing
+    }
+}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (
+    Env,
+    BattleshipContractClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Set ledger info for time-based operations
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    // Deploy mock GameHub contract
+    let hub_addr = env.register(MockGameHub, ());
+
+    // Deploy battleship with admin and GameHub address
+    let admin = Address::generate(&env);
+    let contract_id = env.register(BattleshipContract, (&admin, &hub_addr));
+    let client = BattleshipContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    (env, client, player1, player2)
+}
+
+/// Assert that a Result contains a specific battleship error
+fn assert_battleship_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        _ => panic!("Expected error {:?}, got something else", expected_error),
+    }
+}
+
+/// Build a board from (x, y, length, horizontal) ships
+fn place(ships: &[(u32, u32, u32, bool)]) -> u64 {
+    let mut board = 0u64;
+    for &(x, y, length, horizontal) in ships {
+        for i in 0..length {
+            let (cx, cy) = if horizontal { (x + i, y) } else { (x, y + i) };
+            board |= 1 << (cy * BOARD_SIZE + cx);
+        }
+    }
+    board
+}
+
+/// A legal fleet: ships along the left of rows 0, 2, 4 and 6
+fn fleet() -> u64 {
+    place(&[(0, 0, 4, true), (0, 2, 3, true), (0, 4, 3, true), (0, 6, 2, true)])
+}
+
+/// A player's board with its salts and Merkle tree, as a frontend keeps it
+struct Board {
+    board: u64,
+    salts: Vec<BytesN<32>>,
+    /// Tree levels, leaves first
+    levels: std::vec::Vec<std::vec::Vec<BytesN<32>>>,
+}
+
+impl Board {
+    fn new(env: &Env, board: u64, seed: u8) -> Self {
+        let mut salts = Vec::new(env);
+        let mut leaves = std::vec::Vec::new();
+        for cell in 0..BOARD_CELLS {
+            let salt = BytesN::from_array(env, &[seed ^ cell as u8; 32]);
+            leaves.push(leaf_hash(env, cell, (board >> cell) & 1 == 1, &salt));
+            salts.push_back(salt);
+        }
+        let mut levels = std::vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let parents = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| hash_pair(env, &pair[0], &pair[1]))
+                .collect();
+            levels.push(parents);
+        }
+        Board { board, salts, levels }
+    }
+
+    fn root(&self) -> BytesN<32> {
+        self.levels.last().unwrap()[0].clone()
+    }
+
+    fn occupied(&self, cell: u32) -> bool {
+        (self.board >> cell) & 1 == 1
+    }
+
+    fn proof(&self, env: &Env, cell: u32) -> Vec<BytesN<32>> {
+        let mut proof = Vec::new(env);
+        let mut index = cell as usize;
+        for level in &self.levels[..self.levels.len() - 1] {
+            proof.push_back(level[index ^ 1].clone());
+            index /= 2;
+        }
+        proof
+    }
+
+    /// Answer a shot at a cell truthfully
+    fn answer(&self, env: &Env, client: &BattleshipContractClient, session_id: u32, player: &Address, cell: u32) {
+        let salt = self.salts.get_unchecked(cell);
+        client.answer_shot(&session_id, player, &self.occupied(cell), &salt, &self.proof(env, cell));
+    }
+}
+
+/// Start a game and commit both boards
+fn start_committed_game(
+    client: &BattleshipContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    board1: &Board,
+    board2: &Board,
+) {
+    client.start_game(&session_id, player1, player2, &100_0000000, &100_0000000);
+    client.commit_board(&session_id, player1, &board1.root());
+    client.commit_board(&session_id, player2, &board2.root());
+}
+
+/// Player 1 fires at every ship cell of player 2 while player 2 misses down
+/// the right-hand column, leaving the game in the reveal phase
+fn sink_player2(
+    env: &Env,
+    client: &BattleshipContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    board1: &Board,
+    board2: &Board,
+) {
+    let targets: std::vec::Vec<u32> = (0..BOARD_CELLS).filter(|cell| board2.occupied(*cell)).collect();
+    for (i, &cell) in targets.iter().enumerate() {
+        client.fire(&session_id, player1, &(cell % BOARD_SIZE), &(cell / BOARD_SIZE));
+        board2.answer(env, client, session_id, player2, cell);
+        if i + 1 == targets.len() {
+            break;
+        }
+
+        let x = BOARD_SIZE - 1 - (i as u32 / BOARD_SIZE);
+        let y = i as u32 % BOARD_SIZE;
+        client.fire(&session_id, player2, &x, &y);
+        board1.answer(env, client, session_id, player1, y * BOARD_SIZE + x);
+    }
+}
+
+// ============================================================================
+// Game Flow Tests
+// ============================================================================
+
+#[test]
+fn test_complete_game() {
+    let (env, client, player1, player2) = setup_test();
+    let board1 = Board::new(&env, fleet(), 1);
+    let board2 = Board::new(&env, place(&[(7, 0, 4, false), (5, 0, 3, false), (3, 7, 3, true), (0, 3, 2, false)]), 2);
+
+    start_committed_game(&client, 1, &player1, &player2, &board1, &board2);
+    assert_eq!(client.get_game(&1).phase, Phase::Firing);
+
+    sink_player2(&env, &client, 1, &player1, &player2, &board1, &board2);
+    let game = client.get_game(&1);
+    assert_eq!(game.phase, Phase::Reveal);
+    assert_eq!(game.shooter, player1);
+    assert_eq!(game.player1_hits, board2.board);
+    assert_eq!(game.player2_hits, 0);
+
+    let winner = client.reveal_board(&1, &player1, &board1.board, &board1.salts);
+    assert_eq!(winner, player1);
+    let game = client.get_game(&1);
+    assert_eq!(game.phase, Phase::Ended);
+    assert_eq!(game.winner, Some(player1));
+}
+
+#[test]
+fn test_illegal_fleet_forfeits() {
+    let (env, client, player1, player2) = setup_test();
+    // Player 1 hides a single two-cell ship, so they can barely be hit
+    let board1 = Board::new(&env, place(&[(0, 7, 2, true)]), 1);
+    let board2 = Board::new(&env, fleet(), 2);
+
+    start_committed_game(&client, 1, &player1, &player2, &board1, &board2);
+    sink_player2(&env, &client, 1, &player1, &player2, &board1, &board2);
+
+    let winner = client.reveal_board(&1, &player1, &board1.board, &board1.salts);
+    assert_eq!(winner, player2);
+    assert_eq!(client.get_game(&1).winner, Some(player2));
+}
+
+#[test]
+fn test_legal_fleet_rules() {
+    assert!(legal_fleet(fleet()));
+    // Vertical ships, corners touching
+    assert!(legal_fleet(place(&[(0, 0, 4, false), (1, 4, 3, false), (2, 0, 3, false), (7, 6, 2, false)])));
+
+    // Missing or extra ships
+    assert!(!legal_fleet(0));
+    assert!(!legal_fleet(place(&[(0, 0, 4, true), (0, 2, 3, true), (0, 4, 3, true)])));
+    assert!(!legal_fleet(fleet() | place(&[(7, 7, 1, true)])));
+    // Ships sharing a side
+    assert!(!legal_fleet(place(&[(0, 0, 4, true), (0, 1, 3, true), (0, 4, 3, true), (0, 6, 2, true)])));
+    assert!(!legal_fleet(place(&[(0, 0, 4, true), (4, 0, 3, true), (0, 4, 3, true), (0, 6, 2, true)])));
+    // A bent ship
+    assert!(!legal_fleet(place(&[(0, 0, 3, true), (3, 0, 2, false), (0, 2, 3, true), (0, 4, 3, true), (0, 6, 2, true)])));
+}
+
+// ============================================================================
+// Proof and Reveal Tests
+// ============================================================================
+
+#[test]
+fn test_answer_needs_valid_proof() {
+    let (env, client, player1, player2) = setup_test();
+    let board1 = Board::new(&env, fleet(), 1);
+    let board2 = Board::new(&env, fleet(), 2);
+    start_committed_game(&client, 1, &player1, &player2, &board1, &board2);
+
+    client.fire(&1, &player1, &0, &0);
+    let salt = board2.salts.get_unchecked(0);
+    let proof = board2.proof(&env, 0);
+
+    // Claiming a miss on a ship cell doesn't match the root
+    let result = client.try_answer_shot(&1, &player2, &false, &salt, &proof);
+    assert_battleship_error(&result, Error::InvalidProof);
+
+    // Nor does a proof for another cell
+    let result = client.try_answer_shot(&1, &player2, &true, &salt, &board2.proof(&env, 1));
+    assert_battleship_error(&result, Error::InvalidProof);
+
+    let mut short = proof.clone();
+    short.pop_back();
+    let result = client.try_answer_shot(&1, &player2, &true, &salt, &short);
+    assert_battleship_error(&result, Error::InvalidProof);
+
+    client.answer_shot(&1, &player2, &true, &salt, &proof);
+    assert_eq!(client.get_game(&1).player1_hits, 1);
+}
+
+#[test]
+fn test_reveal_must_match_root() {
+    let (env, client, player1, player2) = setup_test();
+    let board1 = Board::new(&env, fleet(), 1);
+    let board2 = Board::new(&env, fleet(), 2);
+    start_committed_game(&client, 1, &player1, &player2, &board1, &board2);
+    sink_player2(&env, &client, 1, &player1, &player2, &board1, &board2);
+
+    // Only the shooter reveals
+    let result = client.try_reveal_board(&1, &player2, &board2.board, &board2.salts);
+    assert_battleship_error(&result, Error::NotYourTurn);
+
+    // A different legal board than the one committed
+    let other = place(&[(0, 1, 4, true), (0, 3, 3, true), (0, 5, 3, true), (0, 7, 2, true)]);
+    let result = client.try_reveal_board(&1, &player1, &other, &board1.salts);
+    assert_battleship_error(&result, Error::InvalidReveal);
+    let result = client.try_reveal_board(&1, &player1, &board1.board, &board2.salts);
+    assert_battleship_error(&result, Error::InvalidReveal);
+
+    assert_eq!(client.reveal_board(&1, &player1, &board1.board, &board1.salts), player1);
+}
+
+// ============================================================================
+// Turn Tests
+// ============================================================================
+
+#[test]
+fn test_turn_enforcement() {
+    let (env, client, player1, player2) = setup_test();
+    let board1 = Board::new(&env, fleet(), 1);
+    let board2 = Board::new(&env, fleet(), 2);
+
+    client.start_game(&1, &player1, &player2, &100_0000000, &100_0000000);
+    assert_battleship_error(&client.try_fire(&1, &player1, &0, &0), Error::WrongPhase);
+    client.commit_board(&1, &player1, &board1.root());
+    assert_battleship_error(&client.try_commit_board(&1, &player1, &board1.root()), Error::AlreadyCommitted);
+    client.commit_board(&1, &player2, &board2.root());
+
+    assert_battleship_error(&client.try_fire(&1, &player2, &0, &0), Error::NotYourTurn);
+    assert_battleship_error(&client.try_fire(&1, &player1, &8, &0), Error::InvalidCell);
+    assert_battleship_error(&client.try_fire(&1, &Address::generate(&env), &0, &0), Error::NotPlayer);
+
+    client.fire(&1, &player1, &5, &5);
+    assert_battleship_error(&client.try_fire(&1, &player1, &6, &5), Error::ShotPending);
+    let salt = board2.salts.get_unchecked(45);
+    let result = client.try_answer_shot(&1, &player1, &false, &salt, &board2.proof(&env, 45));
+    assert_battleship_error(&result, Error::NotYourTurn);
+    board2.answer(&env, &client, 1, &player2, 45);
+
+    // Player 2's turn, then player 1 may not repeat a cell
+    client.fire(&1, &player2, &0, &0);
+    board1.answer(&env, &client, 1, &player1, 0);
+    assert_battleship_error(&client.try_fire(&1, &player1, &5, &5), Error::AlreadyFired);
+
+    let game = client.get_game(&1);
+    assert_eq!(game.player1_shots, 1 << 45);
+    assert_eq!(game.player2_hits, 1);
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_does_not_answer() {
+    let (env, client, player1, player2) = setup_test();
+    let board1 = Board::new(&env, fleet(), 1);
+    let board2 = Board::new(&env, fleet(), 2);
+    start_committed_game(&client, 1, &player1, &player2, &board1, &board2);
+
+    client.fire(&1, &player1, &3, &3);
+    assert_battleship_error(&client.try_claim_timeout(&1, &player1), Error::TimeoutNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    assert_battleship_error(&client.try_claim_timeout(&1, &player2), Error::CannotClaimTimeout);
+    assert_eq!(client.claim_timeout(&1, &player1), player1);
+    assert_battleship_error(&client.try_claim_timeout(&1, &player1), Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_when_winner_does_not_reveal() {
+    let (env, client, player1, player2) = setup_test();
+    let board1 = Board::new(&env, fleet(), 1);
+    let board2 = Board::new(&env, fleet(), 2);
+    start_committed_game(&client, 1, &player1, &player2, &board1, &board2);
+    sink_player2(&env, &client, 1, &player1, &player2, &board1, &board2);
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    assert_battleship_error(&client.try_claim_timeout(&1, &player1), Error::CannotClaimTimeout);
+    assert_eq!(client.claim_timeout(&1, &player2), player2);
+}
+
+#[test]
+fn test_cannot_play_against_self() {
+    let (_env, client, player1, _player2) = setup_test();
+    let result = client.try_start_game(&1, &player1, &player1, &100_0000000, &100_0000000);
+    assert_battleship_error(&result, Error::SelfPlay);
+}
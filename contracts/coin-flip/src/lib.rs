@@ -0,0 +1,660 @@
+#![no_std]
+
+//! # Coin Flip
+//!
+//! A two-player coin flip with double-or-nothing. Player 1 has heads, player 2
+//! has tails. Each round both players commit `keccak256(secret)` and then
+//! reveal their secrets; the XOR of the secrets decides the side, so neither
+//! player alone controls the result.
+//!
+//! The round winner either cashes out, ending the session in their favour,
+//! or offers double-or-nothing. Accepting doubles both stakes with the Game
+//! Hub (`add_stake`) and flips again in the same session, so a winning streak
+//! keeps doubling the pot until someone cashes out or the streak breaks.
+//!
+//! **Game Hub Integration:**
+//! This game is Game Hub-aware and enforces all games to be played through the
+//! Game Hub contract. Games cannot be started or completed without points involvement.
+
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl,
+    contracttype, vec
+};
+
+// Import GameHub contract interface
+// This allows us to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(
+        env: Env,
+        session_id: u32,
+        player1_won: bool
+    );
+
+    fn add_stake(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        amount: i128
+    );
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    GameNotFound = 1,
+    NotPlayer = 2,
+    AlreadyCommitted = 3,
+    WrongPhase = 4,
+    GameAlreadyEnded = 5,
+    AlreadyRevealed = 6,
+    InvalidReveal = 7,
+    NotRoundWinner = 8,
+    NotRoundLoser = 9,
+    TimeoutNotReached = 10,
+    CannotClaimTimeout = 11,
+    SelfPlay = 12,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Phase {
+    /// Waiting for both players to commit this round's secrets
+    Commit,
+    /// Waiting for both secrets
+    Reveal,
+    /// The round winner chooses to cash out or offer double-or-nothing
+    Decide,
+    /// Double-or-nothing offered; waiting for the round loser
+    Offer,
+    /// Winner decided and reported to the Game Hub
+    Ended,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    pub player1: Address,
+    pub player2: Address,
+    /// Each player's stake with the Game Hub, doubled by every accepted offer
+    pub player1_stake: i128,
+    pub player2_stake: i128,
+    pub phase: Phase,
+    /// Current round, starting at 1
+    pub round: u32,
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    /// Revealed secrets for the current round
+    pub player1_secret: Option<BytesN<32>>,
+    pub player2_secret: Option<BytesN<32>>,
+    /// Winner of the last flip, None before the first one lands
+    pub round_winner: Option<Address>,
+    /// Flips in a row won by `round_winner`
+    pub streak: u32,
+    /// Ledger after which the stalled player can be forfeited
+    pub deadline: u32,
+    pub winner: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Game(u32),
+    GameHubAddress,
+    Admin,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) ensures game data doesn't expire unexpectedly
+// Games are stored in temporary storage with a minimum 30-day retention
+
+/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Ledgers a player has to act before the opponent may claim the game
+/// 1 day = 24 * 60 * 60 / 5 = 17,280 ledgers
+const MOVE_TIMEOUT_LEDGERS: u32 = 17_280;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Flip the coin from both secrets: true for heads (player 1).
+/// Only the low bit of the XOR matters, so either secret alone leaves the
+/// result at 50/50.
+fn flip(secret1: &BytesN<32>, secret2: &BytesN<32>) -> bool {
+    let low1 = secret1.get(31).unwrap_or(0);
+    let low2 = secret2.get(31).unwrap_or(0);
+    (low1 ^ low2) & 1 == 0
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct CoinFlipContract;
+
+#[contractimpl]
+impl CoinFlipContract {
+    /// Initialize the contract with GameHub address and admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    /// * `game_hub` - Address of the GameHub contract
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        // Store admin and GameHub address
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &game_hub);
+    }
+
+    /// Start a new game between two players with points.
+    /// This creates a session in the Game Hub and locks points before starting the game.
+    ///
+    /// **CRITICAL:** This method requires authorization from THIS contract (not players).
+    /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player (heads)
+    /// * `player2` - Address of second player (tails)
+    /// * `player1_points` - Points amount committed by player 1
+    /// * `player2_points` - Points amount committed by player 2
+    pub fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            return Err(Error::SelfPlay);
+        }
+
+        // Require authentication from both players (they consent to committing points)
+        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        Self::game_hub(&env).start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        // Create game (secrets for the first round are committed next)
+        let game = Game {
+            player1,
+            player2,
+            player1_stake: player1_points,
+            player2_stake: player2_points,
+            phase: Phase::Commit,
+            round: 1,
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_secret: None,
+            player2_secret: None,
+            round_winner: None,
+            streak: 0,
+            deadline: env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS,
+            winner: None,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        Self::save_game(&env, session_id, &game);
+
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
+    }
+
+    /// Commit the player's secret for this round as `keccak256(secret)`.
+    /// Revealing opens once both players have committed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the committing player
+    /// * `commitment` - keccak256 hash of the player's 32-byte secret
+    pub fn commit(env: Env, session_id: u32, player: Address, commitment: BytesN<32>) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Commit)?;
+
+        if player == game.player1 {
+            if game.player1_commitment.is_some() {
+                return Err(Error::AlreadyCommitted);
+            }
+            game.player1_commitment = Some(commitment);
+        } else if player == game.player2 {
+            if game.player2_commitment.is_some() {
+                return Err(Error::AlreadyCommitted);
+            }
+            game.player2_commitment = Some(commitment);
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        if game.player1_commitment.is_some() && game.player2_commitment.is_some() {
+            game.phase = Phase::Reveal;
+        }
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the player's secret for this round.
+    /// The secret must hash to the player's commitment. The second reveal
+    /// flips the coin and hands the decision to the round winner.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `secret` - The 32-byte secret behind the player's commitment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Some(round winner) once both secrets are revealed, None otherwise
+    pub fn reveal(env: Env, session_id: u32, player: Address, secret: BytesN<32>) -> Result<Option<Address>, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Reveal)?;
+
+        let (commitment, revealed) = if player == game.player1 {
+            (&game.player1_commitment, &game.player1_secret)
+        } else if player == game.player2 {
+            (&game.player2_commitment, &game.player2_secret)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if revealed.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(secret.clone())).into();
+        if Some(hash) != *commitment {
+            return Err(Error::InvalidReveal);
+        }
+
+        if player == game.player1 {
+            game.player1_secret = Some(secret);
+        } else {
+            game.player2_secret = Some(secret);
+        }
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        let (Some(secret1), Some(secret2)) = (&game.player1_secret, &game.player2_secret) else {
+            Self::save_game(&env, session_id, &game);
+            return Ok(None);
+        };
+
+        // Both secrets are known: flip and extend or restart the streak
+        let round_winner = if flip(secret1, secret2) {
+            game.player1.clone()
+        } else {
+            game.player2.clone()
+        };
+        if game.round_winner.as_ref() == Some(&round_winner) {
+            game.streak += 1;
+        } else {
+            game.streak = 1;
+        }
+        game.round_winner = Some(round_winner.clone());
+        game.phase = Phase::Decide;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(Some(round_winner))
+    }
+
+    /// Take the pot: the round winner ends the session as the winner.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the round winner
+    pub fn cash_out(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Decide)?;
+        Self::require_round_winner(&game, &player)?;
+
+        Self::finish_game(&env, session_id, &mut game, player);
+
+        Ok(())
+    }
+
+    /// Offer double-or-nothing: the round winner doubles their stake now and
+    /// the round loser may match it for another flip.
+    ///
+    /// **Auth:** the round winner authorizes `(session_id, amount)`, the
+    /// amount added to their stake.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the round winner
+    pub fn offer_double(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Decide)?;
+        Self::require_round_winner(&game, &player)?;
+
+        Self::double_stake(&env, session_id, &mut game, &player);
+        game.phase = Phase::Offer;
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Accept double-or-nothing: the round loser doubles their stake and a
+    /// new round starts.
+    ///
+    /// **Auth:** the round loser authorizes `(session_id, amount)`, the
+    /// amount added to their stake.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the round loser
+    pub fn accept_double(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Offer)?;
+        Self::require_round_loser(&game, &player)?;
+
+        Self::double_stake(&env, session_id, &mut game, &player);
+        game.phase = Phase::Commit;
+        game.round += 1;
+        game.player1_commitment = None;
+        game.player2_commitment = None;
+        game.player1_secret = None;
+        game.player2_secret = None;
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Decline double-or-nothing: the round winner takes the pot. Their
+    /// offered extra stake goes back to them with it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the round loser
+    pub fn decline_double(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Offer)?;
+        Self::require_round_loser(&game, &player)?;
+
+        let winner = Self::opponent(&game, &player);
+        Self::finish_game(&env, session_id, &mut game, winner);
+
+        Ok(())
+    }
+
+    /// End a game the opponent has stopped acting on.
+    /// After the deadline passes, a player who committed or revealed while
+    /// the opponent has not wins. A round winner who neither cashes out nor
+    /// offers a double is cashed out, and an unanswered offer counts as
+    /// declined.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the claiming player
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        if game.phase == Phase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        let is_player1 = player == game.player1;
+        let waiting = |player1_done: bool, player2_done: bool| {
+            if is_player1 {
+                player1_done && !player2_done
+            } else {
+                player2_done && !player1_done
+            }
+        };
+        let winner = match game.phase {
+            Phase::Commit if waiting(game.player1_commitment.is_some(), game.player2_commitment.is_some()) => {
+                player
+            }
+            Phase::Reveal if waiting(game.player1_secret.is_some(), game.player2_secret.is_some()) => player,
+            Phase::Decide | Phase::Offer => game.round_winner.clone().ok_or(Error::CannotClaimTimeout)?,
+            _ => return Err(Error::CannotClaimTimeout),
+        };
+
+        Self::finish_game(&env, session_id, &mut game, winner.clone());
+
+        Ok(winner)
+    }
+
+    /// Get game information.
+    /// Secrets stay hidden until revealed; only the commitments are public before then.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Game` - The game state
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        Self::load_game(&env, session_id)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    fn load_game(env: &Env, session_id: u32) -> Result<Game, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    fn save_game(env: &Env, session_id: u32, game: &Game) {
+        let key = DataKey::Game(session_id);
+        env.storage().temporary().set(&key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    fn game_hub(env: &Env) -> GameHubClient<'_> {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        GameHubClient::new(env, &game_hub_addr)
+    }
+
+    fn require_phase(game: &Game, phase: Phase) -> Result<(), Error> {
+        if game.phase == Phase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.phase != phase {
+            return Err(Error::WrongPhase);
+        }
+        Ok(())
+    }
+
+    fn require_round_winner(game: &Game, player: &Address) -> Result<(), Error> {
+        if *player != game.player1 && *player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if game.round_winner.as_ref() != Some(player) {
+            return Err(Error::NotRoundWinner);
+        }
+        Ok(())
+    }
+
+    fn require_round_loser(game: &Game, player: &Address) -> Result<(), Error> {
+        if *player != game.player1 && *player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if game.round_winner.as_ref() == Some(player) {
+            return Err(Error::NotRoundLoser);
+        }
+        Ok(())
+    }
+
+    fn opponent(game: &Game, player: &Address) -> Address {
+        if *player == game.player1 {
+            game.player2.clone()
+        } else {
+            game.player1.clone()
+        }
+    }
+
+    /// Double the player's stake here and with the Game Hub
+    fn double_stake(env: &Env, session_id: u32, game: &mut Game, player: &Address) {
+        let stake = if *player == game.player1 {
+            &mut game.player1_stake
+        } else {
+            &mut game.player2_stake
+        };
+        let amount = *stake;
+        player.require_auth_for_args(vec![env, session_id.into_val(env), amount.into_val(env)]);
+
+        Self::game_hub(env).add_stake(&session_id, player, &amount);
+        *stake += amount;
+    }
+
+    /// Record the winner and end the session with the Game Hub
+    fn finish_game(env: &Env, session_id: u32, game: &mut Game, winner: Address) {
+        game.phase = Phase::Ended;
+        game.winner = Some(winner.clone());
+        Self::save_game(env, session_id, game);
+
+        // Call GameHub to end the session
+        // This unlocks points and updates standings
+        // Event emitted by the Game Hub contract (GameEnded)
+        Self::game_hub(env).end_game(&session_id, &(winner == game.player1));
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    pub fn get_hub(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set")
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,227 @@
+#![cfg(test)]
+
+// Tests run against the mock Game Hub crate so doubled stakes show up in the
+// settled points.
+
+use crate::{CoinFlipContract, CoinFlipContractClient, Error, Phase};
+use mock_game_hub::{MockGameHub, MockGameHubClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (
+    Env,
+    MockGameHubClient<'static>,
+    CoinFlipContractClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Set ledger info for time-based operations
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    // Deploy the mock GameHub, then coin flip with admin and GameHub address
+    let admin = Address::generate(&env);
+    let hub = MockGameHubClient::new(&env, &env.register(MockGameHub, (&admin,)));
+    let contract_id = env.register(CoinFlipContract, (&admin, &hub.address));
+    let client = CoinFlipContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    (env, hub, client, player1, player2)
+}
+
+/// Assert that a Result contains a specific coin flip error
+fn assert_coin_flip_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        _ => panic!("Expected error {:?}, got something else", expected_error),
+    }
+}
+
+fn secret(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+fn commitment(env: &Env, byte: u8) -> BytesN<32> {
+    env.crypto().keccak256(&Bytes::from(secret(env, byte))).into()
+}
+
+/// Play one round: equal low bits flip heads (player 1), different ones tails
+fn flip_round(
+    env: &Env,
+    client: &CoinFlipContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    bytes: (u8, u8),
+) -> Address {
+    client.commit(&session_id, player1, &commitment(env, bytes.0));
+    client.commit(&session_id, player2, &commitment(env, bytes.1));
+    assert_eq!(client.reveal(&session_id, player1, &secret(env, bytes.0)), None);
+    client.reveal(&session_id, player2, &secret(env, bytes.1)).unwrap()
+}
+
+const HEADS: (u8, u8) = (2, 4);
+const TAILS: (u8, u8) = (2, 5);
+
+// ============================================================================
+// Game Flow Tests
+// ============================================================================
+
+#[test]
+fn test_flip_and_cash_out() {
+    let (env, hub, client, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100, &100);
+
+    assert_eq!(flip_round(&env, &client, 1, &player1, &player2, HEADS), player1);
+    let game = client.get_game(&1);
+    assert_eq!(game.phase, Phase::Decide);
+    assert_eq!(game.streak, 1);
+
+    client.cash_out(&1, &player1);
+    let game = client.get_game(&1);
+    assert_eq!(game.phase, Phase::Ended);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(hub.get_player_stats(&player1, &client.address).net_points, 100);
+
+    // Tails goes to player 2
+    client.start_game(&2, &player1, &player2, &100, &100);
+    assert_eq!(flip_round(&env, &client, 2, &player1, &player2, TAILS), player2);
+}
+
+#[test]
+fn test_double_or_nothing_streak() {
+    let (env, hub, client, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100, &100);
+    flip_round(&env, &client, 1, &player1, &player2, HEADS);
+
+    // Both stakes double and a second round starts in the same session
+    client.offer_double(&1, &player1);
+    assert_eq!(client.get_game(&1).phase, Phase::Offer);
+    client.accept_double(&1, &player2);
+    let game = client.get_game(&1);
+    assert_eq!((game.player1_stake, game.player2_stake), (200, 200));
+    assert_eq!((game.round, game.phase), (2, Phase::Commit));
+    assert_eq!(game.player1_commitment, None);
+
+    assert_eq!(flip_round(&env, &client, 1, &player1, &player2, HEADS), player1);
+    assert_eq!(client.get_game(&1).streak, 2);
+
+    // Player 2 turns down a third flip: player 1 takes the doubled pot
+    client.offer_double(&1, &player1);
+    client.decline_double(&1, &player2);
+    assert_eq!(client.get_game(&1).player1_stake, 400);
+    assert_eq!(hub.get_player_stats(&player1, &client.address).net_points, 200);
+    assert_eq!(hub.get_player_stats(&player2, &client.address).net_points, -200);
+}
+
+#[test]
+fn test_losing_the_double_breaks_the_streak() {
+    let (env, hub, client, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100, &100);
+    flip_round(&env, &client, 1, &player1, &player2, HEADS);
+    client.offer_double(&1, &player1);
+    client.accept_double(&1, &player2);
+
+    assert_eq!(flip_round(&env, &client, 1, &player1, &player2, TAILS), player2);
+    let game = client.get_game(&1);
+    assert_eq!(game.round_winner, Some(player2.clone()));
+    assert_eq!(game.streak, 1);
+
+    client.cash_out(&1, &player2);
+    assert_eq!(hub.get_player_stats(&player2, &client.address).net_points, 200);
+}
+
+#[test]
+fn test_decision_rules() {
+    let (env, _hub, client, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100, &100);
+    assert_coin_flip_error(&client.try_reveal(&1, &player1, &secret(&env, 2)), Error::WrongPhase);
+
+    client.commit(&1, &player1, &commitment(&env, 2));
+    assert_coin_flip_error(&client.try_commit(&1, &player1, &commitment(&env, 2)), Error::AlreadyCommitted);
+    client.commit(&1, &player2, &commitment(&env, 4));
+
+    assert_coin_flip_error(&client.try_reveal(&1, &player1, &secret(&env, 3)), Error::InvalidReveal);
+    client.reveal(&1, &player1, &secret(&env, 2));
+    assert_coin_flip_error(&client.try_reveal(&1, &player1, &secret(&env, 2)), Error::AlreadyRevealed);
+    client.reveal(&1, &player2, &secret(&env, 4));
+
+    // Only the round winner decides, only the loser answers an offer
+    assert_coin_flip_error(&client.try_cash_out(&1, &player2), Error::NotRoundWinner);
+    assert_coin_flip_error(&client.try_accept_double(&1, &player2), Error::WrongPhase);
+    client.offer_double(&1, &player1);
+    assert_coin_flip_error(&client.try_accept_double(&1, &player1), Error::NotRoundLoser);
+    assert_coin_flip_error(&client.try_cash_out(&1, &player1), Error::WrongPhase);
+    assert_coin_flip_error(&client.try_decline_double(&1, &Address::generate(&env)), Error::NotPlayer);
+}
+
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+#[test]
+fn test_claim_timeout_on_unrevealed_secret() {
+    let (env, hub, client, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100, &100);
+    client.commit(&1, &player1, &commitment(&env, 2));
+    client.commit(&1, &player2, &commitment(&env, 4));
+    client.reveal(&1, &player2, &secret(&env, 4));
+
+    assert_coin_flip_error(&client.try_claim_timeout(&1, &player2), Error::TimeoutNotReached);
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+
+    assert_coin_flip_error(&client.try_claim_timeout(&1, &player1), Error::CannotClaimTimeout);
+    assert_eq!(client.claim_timeout(&1, &player2), player2);
+    assert_eq!(hub.get_player_stats(&player2, &client.address).net_points, 100);
+}
+
+#[test]
+fn test_stalled_decision_cashes_out() {
+    let (env, _hub, client, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100, &100);
+    flip_round(&env, &client, 1, &player1, &player2, HEADS);
+
+    // The loser can close the game, but the round winner still wins it
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    assert_eq!(client.claim_timeout(&1, &player2), player1);
+    assert_coin_flip_error(&client.try_claim_timeout(&1, &player2), Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_cannot_play_against_self() {
+    let (_env, _hub, client, player1, _player2) = setup_test();
+    let result = client.try_start_game(&1, &player1, &player1, &100, &100);
+    assert_coin_flip_error(&result, Error::SelfPlay);
+}
+
+#[test]
+fn test_cannot_get_nonexistent_game() {
+    let (_env, _hub, client, _player1, _player2) = setup_test();
+    assert_coin_flip_error(&client.try_get_game(&999), Error::GameNotFound);
+}
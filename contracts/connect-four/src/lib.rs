@@ -0,0 +1,474 @@
+#![no_std]
+
+//! # Connect Four
+//!
+//! A two-player turn-based game on a 7-column, 6-row board. Player 1 plays
+//! first and players strictly alternate, each dropping a disc into a column
+//! where it falls to the lowest empty row. Four discs in a row, column or
+//! diagonal win; a full board without a line is a draw. A player who stops
+//! moving can be forfeited once their turn times out.
+//!
+//! Like tic-tac-toe, the board is two bitmasks in `Game`, one per player.
+//! Each column takes 7 bits: 6 rows plus an always-empty bit on top, which
+//! keeps lines from wrapping between columns in the shift-based win check.
+//!
+//! **Game Hub Integration:**
+//! This game is Game Hub-aware and enforces all games to be played through the
+//! Game Hub contract. Games cannot be started or completed without points involvement.
+
+use soroban_sdk::{
+    Address, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl,
+    contracttype, vec
+};
+
+// Import GameHub contract interface
+// This allows us to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(
+        env: Env,
+        session_id: u32,
+        player1_won: bool
+    );
+
+    fn end_game_draw(env: Env, session_id: u32);
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    GameNotFound = 1,
+    NotPlayer = 2,
+    NotYourTurn = 3,
+    InvalidColumn = 4,
+    ColumnFull = 5,
+    GameAlreadyEnded = 6,
+    TimeoutNotReached = 7,
+    CannotClaimTimeout = 8,
+    SelfPlay = 9,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// Waiting for `next_player` to move
+    Playing,
+    /// `winner` got four in a line or won on timeout
+    Won,
+    /// Board full without a line
+    Draw,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    /// Discs of player 1, bit column * 7 + row (row 0 at the bottom)
+    pub player1_discs: u64,
+    /// Discs of player 2
+    pub player2_discs: u64,
+    pub next_player: Address,
+    /// Ledger after which `next_player` can be forfeited
+    pub deadline: u32,
+    pub status: Status,
+    pub winner: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Game(u32),
+    GameHubAddress,
+    Admin,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) ensures game data doesn't expire unexpectedly
+// Games are stored in temporary storage with a minimum 30-day retention
+
+/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Ledgers a player has to move before the opponent may claim the game
+/// 1 day = 24 * 60 * 60 / 5 = 17,280 ledgers
+const MOVE_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Columns on the board
+pub const COLUMNS: u32 = 7;
+
+/// Rows in a column
+pub const ROWS: u32 = 6;
+
+/// Bits per column: the rows plus an empty bit on top
+const COLUMN_BITS: u32 = ROWS + 1;
+
+/// Discs on a full board
+const BOARD_DISCS: u32 = COLUMNS * ROWS;
+
+/// Bit shifts between neighbouring discs: vertical, horizontal and both diagonals
+const DIRECTIONS: [u32; 4] = [1, COLUMN_BITS, COLUMN_BITS - 1, COLUMN_BITS + 1];
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Whether a player's discs hold four in a line
+fn has_line(discs: u64) -> bool {
+    DIRECTIONS.iter().any(|shift| {
+        // Bits that start a pair, then pairs that start a pair two along
+        let pairs = discs & (discs >> shift);
+        pairs & (pairs >> (2 * shift)) != 0
+    })
+}
+
+/// Discs already in a column of the board
+fn column_height(board: u64, column: u32) -> u32 {
+    ((board >> (column * COLUMN_BITS)) & ((1 << ROWS) - 1)).count_ones()
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct ConnectFourContract;
+
+#[contractimpl]
+impl ConnectFourContract {
+    /// Initialize the contract with GameHub address and admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    /// * `game_hub` - Address of the GameHub contract
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        // Store admin and GameHub address
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &game_hub);
+    }
+
+    /// Start a new game between two players with points.
+    /// This creates a session in the Game Hub and locks points before starting the game.
+    ///
+    /// **CRITICAL:** This method requires authorization from THIS contract (not players).
+    /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player (moves first)
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points amount committed by player 1
+    /// * `player2_points` - Points amount committed by player 2
+    pub fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            return Err(Error::SelfPlay);
+        }
+
+        // Require authentication from both players (they consent to committing points)
+        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Create GameHub client
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        // Create game (empty board, player 1 moves first)
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            player1_discs: 0,
+            player2_discs: 0,
+            next_player: player1,
+            deadline: env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS,
+            status: Status::Playing,
+            winner: None,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        Self::save_game(&env, session_id, &game);
+
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
+    }
+
+    /// Drop a disc into a column. Must be the player's turn.
+    /// Four in a line wins and a full board draws; either result is
+    /// reported to the Game Hub.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the moving player
+    /// * `column` - Column to drop into (0-6)
+    ///
+    /// # Returns
+    /// * `Status` - The game status after the move
+    pub fn play(env: Env, session_id: u32, player: Address, column: u32) -> Result<Status, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        if game.status != Status::Playing {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if player != game.next_player {
+            return Err(Error::NotYourTurn);
+        }
+        if column >= COLUMNS {
+            return Err(Error::InvalidColumn);
+        }
+        let board = game.player1_discs | game.player2_discs;
+        let height = column_height(board, column);
+        if height == ROWS {
+            return Err(Error::ColumnFull);
+        }
+
+        let disc = 1 << (column * COLUMN_BITS + height);
+        let discs = if player == game.player1 {
+            game.player1_discs |= disc;
+            game.player1_discs
+        } else {
+            game.player2_discs |= disc;
+            game.player2_discs
+        };
+
+        if has_line(discs) {
+            Self::finish_game(&env, session_id, &mut game, Some(player));
+        } else if (board | disc).count_ones() == BOARD_DISCS {
+            Self::finish_game(&env, session_id, &mut game, None);
+        } else {
+            game.next_player = Self::opponent(&game, &player);
+            game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+            Self::save_game(&env, session_id, &game);
+        }
+
+        Ok(game.status)
+    }
+
+    /// Win the game because the opponent did not move before the deadline.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the claiming player (the one waiting)
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player (the claimant)
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        if game.status != Status::Playing {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+        if player == game.next_player {
+            return Err(Error::CannotClaimTimeout);
+        }
+
+        Self::finish_game(&env, session_id, &mut game, Some(player.clone()));
+
+        Ok(player)
+    }
+
+    /// Get game information.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Game` - The game state
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        Self::load_game(&env, session_id)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    fn load_game(env: &Env, session_id: u32) -> Result<Game, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    fn save_game(env: &Env, session_id: u32, game: &Game) {
+        let key = DataKey::Game(session_id);
+        env.storage().temporary().set(&key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    fn opponent(game: &Game, player: &Address) -> Address {
+        if *player == game.player1 {
+            game.player2.clone()
+        } else {
+            game.player1.clone()
+        }
+    }
+
+    /// Record the result and end the session with the Game Hub
+    /// (None is a draw)
+    fn finish_game(env: &Env, session_id: u32, game: &mut Game, winner: Option<Address>) {
+        game.status = if winner.is_some() { Status::Won } else { Status::Draw };
+        game.winner = winner.clone();
+        Self::save_game(env, session_id, game);
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Call GameHub to end the session
+        // This unlocks points and updates standings
+        // Event emitted by the Game Hub contract (GameEnded)
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        match winner {
+            Some(winner) => game_hub.end_game(&session_id, &(winner == game.player1)),
+            None => game_hub.end_game_draw(&session_id),
+        }
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    pub fn get_hub(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set")
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,232 @@
+#![cfg(test)]
+extern crate std;
+
+// Unit tests for the connect-four contract using a simple mock GameHub.
+// These tests verify game logic independently of the full GameHub system.
+
+use crate::{Error, Status, ConnectFourContract, ConnectFourContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+// ============================================================================
+// Mock GameHub for Unit Testing
+// ============================================================================
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game_draw(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (
+    Env,
+    ConnectFourContractClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Set ledger info for time-based operations
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    // Deploy mock GameHub contract
+    let hub_addr = env.register(MockGameHub, ());
+
+    // Deploy connect-four with admin and GameHub address
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ConnectFourContract, (&admin, &hub_addr));
+    let client = ConnectFourContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    (env, client, player1, player2)
+}
+
+/// Assert that a Result contains a specific connect-four error
+fn assert_connect_four_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        _ => panic!("Expected error {:?}, got something else", expected_error),
+    }
+}
+
+/// Start a game and drop discs in turn, player 1 first
+fn play_columns(
+    client: &ConnectFourContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    columns: &[u32],
+) -> Status {
+    client.start_game(&session_id, player1, player2, &100_0000000, &100_0000000);
+    let mut status = Status::Playing;
+    for (i, column) in columns.iter().enumerate() {
+        let player = if i % 2 == 0 { player1 } else { player2 };
+        status = client.play(&session_id, player, column);
+    }
+    status
+}
+
+// ============================================================================
+// Game Flow Tests
+// ============================================================================
+
+#[test]
+fn test_vertical_win() {
+    let (_env, client, player1, player2) = setup_test();
+
+    let status = play_columns(&client, 1, &player1, &player2, &[3, 4, 3, 4, 3, 4, 3]);
+    assert_eq!(status, Status::Won);
+
+    let game = client.get_game(&1);
+    assert_eq!(game.winner, Some(player1));
+    // Column 3, rows 0-3
+    assert_eq!(game.player1_discs, 0b1111 << 21);
+    assert_eq!(game.player2_discs, 0b111 << 28);
+}
+
+#[test]
+fn test_horizontal_and_diagonal_wins() {
+    let (_env, client, player1, player2) = setup_test();
+
+    // Player 2 fills the bottom row from column 3 to 6
+    let status = play_columns(&client, 1, &player1, &player2, &[0, 3, 0, 4, 1, 5, 0, 6]);
+    assert_eq!(status, Status::Won);
+    assert_eq!(client.get_game(&1).winner, Some(player2.clone()));
+
+    // Player 1 climbs from (0, 0) to (3, 3)
+    let status = play_columns(&client, 2, &player1, &player2, &[0, 1, 1, 2, 2, 3, 2, 3, 3, 6, 3]);
+    assert_eq!(status, Status::Won);
+    assert_eq!(client.get_game(&2).winner, Some(player1.clone()));
+
+    // Player 1 descends from (0, 3) to (3, 0)
+    let status = play_columns(&client, 3, &player1, &player2, &[3, 2, 2, 1, 1, 0, 1, 0, 0, 6, 0]);
+    assert_eq!(status, Status::Won);
+    assert_eq!(client.get_game(&3).winner, Some(player1));
+}
+
+#[test]
+fn test_no_line_across_columns() {
+    let (_env, client, player1, player2) = setup_test();
+
+    // Player 1 holds the top two rows of column 0 and the bottom two of
+    // column 1: only the empty top bit separates them, and they are not a line
+    let status = play_columns(&client, 1, &player1, &player2, &[0, 0, 0, 0, 0, 6, 0, 6, 1, 6, 1]);
+    assert_eq!(status, Status::Playing);
+    assert_eq!(client.get_game(&1).winner, None);
+}
+
+#[test]
+fn test_full_board_is_draw() {
+    let (_env, client, player1, player2) = setup_test();
+
+    // Columns 0, 1, 4 and 5 fill X O X O X O from the bottom, columns 2, 3
+    // and 6 O X O X O X, so no line of four forms anywhere. Player 2 starts
+    // the O columns while player 1 fills an X column alongside.
+    let mut columns = std::vec::Vec::new();
+    for (x_column, o_column) in [(0, 2), (1, 3), (4, 6)] {
+        for _ in 0..3 {
+            columns.extend_from_slice(&[x_column, o_column, o_column, x_column]);
+        }
+    }
+    columns.extend_from_slice(&[5; 6]);
+    let status = play_columns(&client, 1, &player1, &player2, &columns);
+    assert_eq!(status, Status::Draw);
+    assert_eq!(client.get_game(&1).winner, None);
+}
+
+#[test]
+fn test_turn_enforcement() {
+    let (env, client, player1, player2) = setup_test();
+    play_columns(&client, 1, &player1, &player2, &[0, 0, 0, 0, 0, 0]);
+
+    assert_connect_four_error(&client.try_play(&1, &player2, &1), Error::NotYourTurn);
+    assert_connect_four_error(&client.try_play(&1, &Address::generate(&env), &1), Error::NotPlayer);
+    assert_connect_four_error(&client.try_play(&1, &player1, &7), Error::InvalidColumn);
+    assert_connect_four_error(&client.try_play(&1, &player1, &0), Error::ColumnFull);
+
+    client.play(&1, &player1, &1);
+    assert_eq!(client.get_game(&1).next_player, player2);
+}
+
+#[test]
+fn test_no_moves_after_game_ended() {
+    let (_env, client, player1, player2) = setup_test();
+    play_columns(&client, 1, &player1, &player2, &[3, 4, 3, 4, 3, 4, 3]);
+
+    assert_connect_four_error(&client.try_play(&1, &player2, &4), Error::GameAlreadyEnded);
+    assert_connect_four_error(&client.try_claim_timeout(&1, &player2), Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_does_not_move() {
+    let (env, client, player1, player2) = setup_test();
+    play_columns(&client, 1, &player1, &player2, &[3]);
+
+    assert_connect_four_error(&client.try_claim_timeout(&1, &player1), Error::TimeoutNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    assert_connect_four_error(&client.try_claim_timeout(&1, &player2), Error::CannotClaimTimeout);
+    assert_eq!(client.claim_timeout(&1, &player1), player1);
+
+    let game = client.get_game(&1);
+    assert_eq!(game.status, Status::Won);
+    assert_eq!(game.winner, Some(player1));
+}
+
+#[test]
+fn test_cannot_play_against_self() {
+    let (_env, client, player1, _player2) = setup_test();
+    let result = client.try_start_game(&1, &player1, &player1, &100_0000000, &100_0000000);
+    assert_connect_four_error(&result, Error::SelfPlay);
+}
+
+#[test]
+fn test_cannot_get_nonexistent_game() {
+    let (_env, client, _player1, _player2) = setup_test();
+    assert_connect_four_error(&client.try_get_game(&999), Error::GameNotFound);
+}
@@ -5,14 +5,33 @@
 //! A two-player dice game where each player rolls two dice.
 //! The player with the highest total wins (ties go to player 1).
 //!
+//! Sessions can also be started with custom `DiceRules`: any number of dice
+//! with any number of faces, an optional single reroll of selected dice, and
+//! highest-sum or highest-single-die scoring.
+//!
+//! Rerolls are commit-reveal: each player commits to the dice they reroll
+//! with a secret salt, and the new values are rolled from both salts once
+//! both players have revealed. Nobody sees the other's choice before locking
+//! in their own, or can work out the new values in advance. Each step has a
+//! deadline, after which a player who moved can `claim_timeout` the game.
+//!
+//! A winner whose six-sided dice all came up six on the reroll wins the Game
+//! Hub's jackpot. Only rerolled dice count: they come from both players'
+//! salts, while the first roll follows from the session ID and addresses.
+//!
+//! **Team mode** (`start_team_game`): two teams of 1..=4 players each roll
+//! under the session rules; each member's score is added to their team's
+//! total and the higher total wins (ties go to team 1).
+//!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl,
-    contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, Map, Vec, contract, contractclient, contracterror,
+    contractimpl, contracttype, vec
 };
+pub use session_keys::{SessionKey, MAX_SESSION_KEY_LEDGERS};
 
 // Import GameHub contract interface
 // This allows us to call into the GameHub contract
@@ -33,6 +52,22 @@ pub trait GameHub {
         session_id: u32,
         player1_won: bool
     );
+
+    fn start_team_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        team1: Vec<Address>,
+        team1_points: Vec<i128>,
+        team2: Vec<Address>,
+        team2_points: Vec<i128>,
+    );
+
+    fn end_team_game(env: Env, session_id: u32, team1_won: bool);
+
+    fn report_jackpot(env: Env, session_id: u32, player: Address) -> i128;
+
+    fn get_jackpot_rate(env: Env, game_id: Address) -> u32;
 }
 
 // ============================================================================
@@ -48,12 +83,56 @@ pub enum Error {
     AlreadyRolled = 3,
     BothPlayersNotRolled = 4,
     GameAlreadyEnded = 5,
+    InvalidRules = 6,
+    NotRolled = 7,
+    RerollNotAllowed = 8,
+    AlreadyRerolled = 9,
+    InvalidDieIndex = 10,
+    RerollPending = 11,
+    InvalidTeams = 12,
+    NotAllRolled = 13,
+    InvalidSessionKey = 14,
+    InvalidReveal = 15,
+    TimeoutNotReached = 16,
+    CannotClaimTimeout = 17,
+}
+
+impl From<session_keys::GrantError> for Error {
+    fn from(error: session_keys::GrantError) -> Self {
+        match error {
+            session_keys::GrantError::NotPlayer => Error::NotPlayer,
+            session_keys::GrantError::InvalidSessionKey => Error::InvalidSessionKey,
+        }
+    }
 }
 
 // ============================================================================
 // Data Types
 // ============================================================================
 
+/// How a player's dice are turned into a score
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Scoring {
+    /// Sum of all dice
+    HighestSum,
+    /// Value of the best single die
+    HighestSingle,
+}
+
+/// Per-session rule set, fixed when the game starts
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiceRules {
+    /// Dice rolled by each player (1..=MAX_DICE)
+    pub dice_count: u32,
+    /// Faces on each die (2..=MAX_FACES)
+    pub faces: u32,
+    /// Whether each player may reroll a chosen subset of their dice once
+    pub allow_reroll: bool,
+    pub scoring: Scoring,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
@@ -61,19 +140,53 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
+    pub rules: DiceRules,
     pub player1_rolled: bool,
     pub player2_rolled: bool,
-    pub player1_die1: Option<u32>,
-    pub player1_die2: Option<u32>,
-    pub player2_die1: Option<u32>,
-    pub player2_die2: Option<u32>,
+    /// Set once both players' rerolls are revealed and applied
+    pub player1_rerolled: bool,
+    pub player2_rerolled: bool,
+    /// keccak256(salt ‖ dice indices), set when the player locks in their reroll
+    pub player1_reroll_commitment: Option<BytesN<32>>,
+    pub player2_reroll_commitment: Option<BytesN<32>>,
+    /// Revealed salt, mixed into both players' rerolled dice
+    pub player1_reroll_salt: Option<BytesN<32>>,
+    pub player2_reroll_salt: Option<BytesN<32>>,
+    /// Revealed dice indices, applied once both players reveal
+    pub player1_reroll_dice: Vec<u32>,
+    pub player2_reroll_dice: Vec<u32>,
+    /// Dice values, empty until the player rolls
+    pub player1_dice: Vec<u32>,
+    pub player2_dice: Vec<u32>,
+    /// Ledger after which a stalled reroll can be claimed; 0 until both
+    /// players have rolled in a game with rerolls
+    pub reroll_deadline: u32,
     pub winner: Option<Address>,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TeamGame {
+    pub team1: Vec<Address>,
+    /// Points committed by each member of team 1, same order as `team1`
+    pub team1_points: Vec<i128>,
+    pub team2: Vec<Address>,
+    pub team2_points: Vec<i128>,
+    pub rules: DiceRules,
+    /// Each member's dice, added as they roll
+    pub dice: Map<Address, Vec<u32>>,
+    /// Team totals, set when the winner is revealed
+    pub team1_score: u32,
+    pub team2_score: u32,
+    /// None until the winner is revealed
+    pub team1_won: Option<bool>,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
+    TeamGame(u32),
     GameHubAddress,
     Admin,
 }
@@ -88,14 +201,103 @@ pub enum DataKey {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// Ledgers each reroll step (commit, then reveal) may take before the player
+/// who moved can claim the game
+/// 1 day = 24 * 60 * 60 / 5 = 17,280 ledgers
+const REROLL_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Upper bounds for custom rules, keeping per-call work small
+const MAX_DICE: u32 = 10;
+const MAX_FACES: u32 = 100;
+
+/// Most members on one side of a team game (matches the Game Hub)
+const MAX_TEAM_SIZE: u32 = 4;
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
-/// Roll a single die (1-6) using deterministic PRNG
-fn roll_die(env: &Env, seed: BytesN<32>) -> u32 {
+/// The classic rule set: two six-sided dice, no reroll, highest total wins
+fn default_rules() -> DiceRules {
+    DiceRules {
+        dice_count: 2,
+        faces: 6,
+        allow_reroll: false,
+        scoring: Scoring::HighestSum,
+    }
+}
+
+/// Roll a single die (1-faces) using deterministic PRNG
+fn roll_die(env: &Env, seed: BytesN<32>, faces: u32) -> u32 {
     env.prng().seed(seed.into());
-    env.prng().gen_range::<u64>(1..=6) as u32
+    env.prng().gen_range::<u64>(1..=faces as u64) as u32
+}
+
+/// Derive the seed for one die from the game's base seed.
+/// `player` is 1 or 2, `die` is 1-based and `pass` is 0 for the first roll
+/// and 1 for a reroll. The first pass keeps the original `[player, die]`
+/// layout so default games roll the same dice as before.
+fn die_seed(env: &Env, base_seed: &BytesN<32>, player: u8, die: u8, pass: u8) -> BytesN<32> {
+    let mut roll_seed_bytes = Bytes::new(env);
+    roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
+    roll_seed_bytes.append(&Bytes::from_array(env, &[player, die]));
+    if pass > 0 {
+        roll_seed_bytes.push_back(pass);
+    }
+    env.crypto().keccak256(&roll_seed_bytes).into()
+}
+
+/// True if every die is a six on six-sided dice (at least two of them), and
+/// each was rerolled from both players' salts
+fn jackpot_dice(rules: &DiceRules, dice: &Vec<u32>, rerolled: bool, reroll_dice: &Vec<u32>) -> bool {
+    rules.faces == 6
+        && dice.len() >= 2
+        && dice.iter().all(|die| die == 6)
+        && rerolled
+        && reroll_dice.len() == dice.len()
+}
+
+/// Commitment to a reroll: keccak256 of the salt followed by each zero-based
+/// die index as 4 big-endian bytes
+fn reroll_commitment(env: &Env, dice: &Vec<u32>, salt: &BytesN<32>) -> BytesN<32> {
+    let mut bytes = Bytes::from(salt.clone());
+    for index in dice.iter() {
+        bytes.append(&Bytes::from_array(env, &index.to_be_bytes()));
+    }
+    env.crypto().keccak256(&bytes).into()
+}
+
+/// Check rule bounds shared by every mode
+fn valid_rules(rules: &DiceRules) -> bool {
+    rules.dice_count > 0 && rules.dice_count <= MAX_DICE && rules.faces >= 2 && rules.faces <= MAX_FACES
+}
+
+/// Teams must have 1..=MAX_TEAM_SIZE members, one points amount per member,
+/// and no player twice
+fn valid_teams(team1: &Vec<Address>, team1_points: &Vec<i128>, team2: &Vec<Address>, team2_points: &Vec<i128>) -> bool {
+    let sizes_ok = [(team1, team1_points), (team2, team2_points)]
+        .iter()
+        .all(|(team, points)| (1..=MAX_TEAM_SIZE).contains(&team.len()) && points.len() == team.len());
+    if !sizes_ok {
+        return false;
+    }
+
+    let mut seen = Vec::new(team1.env());
+    for member in team1.iter().chain(team2.iter()) {
+        if seen.contains(&member) {
+            return false;
+        }
+        seen.push_back(member);
+    }
+    true
+}
+
+/// Score a set of dice under the given scoring rule
+fn score(dice: &Vec<u32>, scoring: &Scoring) -> u32 {
+    match scoring {
+        Scoring::HighestSum => dice.iter().sum(),
+        Scoring::HighestSingle => dice.iter().max().unwrap_or(0),
+    }
 }
 
 // ============================================================================
@@ -122,6 +324,7 @@ impl DiceDuelContract {
 
     /// Start a new game between two players with points.
     /// This creates a session in the Game Hub and locks points before starting the game.
+    /// The game uses the classic rules: two six-sided dice each, highest total wins.
     ///
     /// **CRITICAL:** This method requires authorization from THIS contract (not players).
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
@@ -132,6 +335,8 @@ impl DiceDuelContract {
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    /// * `session_keys` - Move keys to delegate up front, each mapped to its
+    ///   player and expiry (see `delegate_session_key`); may be empty
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -139,74 +344,106 @@ impl DiceDuelContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
+        session_keys: Map<Address, SessionKey>,
     ) -> Result<(), Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
         }
 
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+        // Both players consent to the points and to any session keys
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call the Game Hub to start the session and lock points
-        // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
-
-        // Create game (dice not rolled yet - will be generated in reveal_winner)
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
+        Self::create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
             player1_points,
             player2_points,
-            player1_rolled: false,
-            player2_rolled: false,
-            player1_die1: None,
-            player1_die2: None,
-            player2_die1: None,
-            player2_die2: None,
-            winner: None,
-        };
+            default_rules(),
+            session_keys,
+        )
+    }
 
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
+    /// Start a new game with a custom rule set.
+    /// Behaves like `start_game`, but both players also sign the rules.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points amount committed by player 1
+    /// * `player2_points` - Points amount committed by player 2
+    /// * `rules` - Dice count, faces, reroll allowance and scoring for this session
+    /// * `session_keys` - Move keys to delegate up front, each mapped to its
+    ///   player and expiry (see `delegate_session_key`); may be empty
+    pub fn start_game_with_rules(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        rules: DiceRules,
+        session_keys: Map<Address, SessionKey>,
+    ) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
+        }
 
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        if !valid_rules(&rules) {
+            return Err(Error::InvalidRules);
+        }
 
-        // Event emitted by GameHub contract (GameStarted)
+        // Both players consent to the points, the rules and any session keys
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            rules.clone().into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            rules.clone().into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
 
-        Ok(())
+        Self::create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            rules,
+            session_keys,
+        )
     }
 
-    /// Commit a roll for the current game.
+    /// Roll the player's dice for the current game.
     /// Both players must roll before the winner can be revealed.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `player` - Address of the player rolling the dice
+    /// * `player` - Address of the player rolling the dice, or their session key
     pub fn roll(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
-        player.require_auth();
+        let player = session_keys::move_player(&env, session_id, player);
 
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
@@ -222,18 +459,45 @@ impl DiceDuelContract {
         }
 
         // Update roll commitment for the appropriate player
-        if player == game.player1 {
+        let player_index = if player == game.player1 {
             if game.player1_rolled {
                 return Err(Error::AlreadyRolled);
             }
-            game.player1_rolled = true;
+            1
         } else if player == game.player2 {
             if game.player2_rolled {
                 return Err(Error::AlreadyRolled);
             }
-            game.player2_rolled = true;
+            2
         } else {
             return Err(Error::NotPlayer);
+        };
+
+        // Generate deterministic dice rolls (1-faces)
+        // Seed components (all deterministic and identical between sim/submit):
+        // 1. Session ID - unique per game
+        // 2. Player addresses - both players contribute
+        //
+        // Note: We do NOT include ledger sequence or timestamp because those differ
+        // between simulation and submission, which would cause different winners.
+        let base_seed = Self::base_seed(&env, session_id, &game);
+        let mut dice = Vec::new(&env);
+        for die in 1..=game.rules.dice_count {
+            let seed = die_seed(&env, &base_seed, player_index, die as u8, 0);
+            dice.push_back(roll_die(&env, seed, game.rules.faces));
+        }
+
+        if player_index == 1 {
+            game.player1_rolled = true;
+            game.player1_dice = dice;
+        } else {
+            game.player2_rolled = true;
+            game.player2_dice = dice;
+        }
+
+        // The reroll clock starts once both players have rolled
+        if game.rules.allow_reroll && game.player1_rolled && game.player2_rolled {
+            game.reroll_deadline = env.ledger().sequence() + REROLL_TIMEOUT_LEDGERS;
         }
 
         // Store updated game in temporary storage
@@ -242,9 +506,157 @@ impl DiceDuelContract {
         Ok(())
     }
 
+    /// Lock in the player's single reroll without showing it.
+    /// Only available when the session rules allow it. `commitment` is
+    /// keccak256 of a random 32-byte salt followed by the zero-based indices
+    /// of the dice to reroll, each as 4 big-endian bytes; committing to no
+    /// indices declines the reroll.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player rerolling, or their session key
+    /// * `commitment` - keccak256(salt ‖ dice indices)
+    pub fn reroll(env: Env, session_id: u32, player: Address, commitment: BytesN<32>) -> Result<(), Error> {
+        let player = session_keys::move_player(&env, session_id, player);
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        if !game.rules.allow_reroll {
+            return Err(Error::RerollNotAllowed);
+        }
+
+        let (rolled, committed) = if player == game.player1 {
+            (game.player1_rolled, &mut game.player1_reroll_commitment)
+        } else if player == game.player2 {
+            (game.player2_rolled, &mut game.player2_reroll_commitment)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        if !rolled {
+            return Err(Error::NotRolled);
+        }
+        if committed.is_some() {
+            return Err(Error::AlreadyRerolled);
+        }
+        *committed = Some(commitment);
+
+        // Both committed: the reveals get a fresh deadline
+        if game.player1_reroll_commitment.is_some() && game.player2_reroll_commitment.is_some() {
+            game.reroll_deadline = env.ledger().sequence() + REROLL_TIMEOUT_LEDGERS;
+        }
+
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the player's reroll once both players have committed. The
+    /// second reveal rolls the selected dice of both players, seeded with
+    /// both salts, so neither reroll is known until it is applied.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing, or their session key
+    /// * `dice` - Zero-based indices of the dice to reroll, as committed
+    /// * `salt` - The salt behind the commitment
+    pub fn reveal_reroll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        dice: Vec<u32>,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        let player = session_keys::move_player(&env, session_id, player);
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let is_player1 = if player == game.player1 {
+            true
+        } else if player == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        let (Some(commitment1), Some(commitment2)) =
+            (&game.player1_reroll_commitment, &game.player2_reroll_commitment)
+        else {
+            return Err(Error::RerollPending);
+        };
+        let (commitment, revealed) = if is_player1 {
+            (commitment1, &game.player1_reroll_salt)
+        } else {
+            (commitment2, &game.player2_reroll_salt)
+        };
+        if revealed.is_some() {
+            return Err(Error::AlreadyRerolled);
+        }
+        if reroll_commitment(&env, &dice, &salt) != *commitment {
+            return Err(Error::InvalidReveal);
+        }
+
+        let mut seen = Vec::new(&env);
+        for index in dice.iter() {
+            if index >= game.rules.dice_count || seen.contains(index) {
+                return Err(Error::InvalidDieIndex);
+            }
+            seen.push_back(index);
+        }
+
+        if is_player1 {
+            game.player1_reroll_salt = Some(salt);
+            game.player1_reroll_dice = dice;
+        } else {
+            game.player2_reroll_salt = Some(salt);
+            game.player2_reroll_dice = dice;
+        }
+
+        if let (Some(salt1), Some(salt2)) = (&game.player1_reroll_salt, &game.player2_reroll_salt) {
+            let mut seed_bytes = Bytes::from(Self::base_seed(&env, session_id, &game));
+            seed_bytes.append(&Bytes::from(salt1.clone()));
+            seed_bytes.append(&Bytes::from(salt2.clone()));
+            let reroll_seed: BytesN<32> = env.crypto().keccak256(&seed_bytes).into();
+
+            for index in game.player1_reroll_dice.iter() {
+                let seed = die_seed(&env, &reroll_seed, 1, (index + 1) as u8, 1);
+                game.player1_dice.set(index, roll_die(&env, seed, game.rules.faces));
+            }
+            for index in game.player2_reroll_dice.iter() {
+                let seed = die_seed(&env, &reroll_seed, 2, (index + 1) as u8, 1);
+                game.player2_dice.set(index, roll_die(&env, seed, game.rules.faces));
+            }
+            game.player1_rerolled = true;
+            game.player2_rerolled = true;
+        }
+
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
     /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have rolled.
-    /// This generates dice rolls for both players, determines the winner, and ends the session.
+    /// Can only be called after both players have rolled (and, when rerolls
+    /// are allowed, both have revealed their reroll).
+    /// This scores both players' dice, determines the winner, and ends the session.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
@@ -270,57 +682,355 @@ impl DiceDuelContract {
             return Err(Error::BothPlayersNotRolled);
         }
 
-        // Generate deterministic dice rolls (1-6)
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission, which would cause different winners.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&game.player1.to_string().to_bytes());
-        seed_bytes.append(&game.player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Roll dice for both players using unique seeds
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[1, 1]));
-        let player1_die1 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[1, 2]));
-        let player1_die2 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[2, 1]));
-        let player2_die1 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[2, 2]));
-        let player2_die2 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        game.player1_die1 = Some(player1_die1);
-        game.player1_die2 = Some(player1_die2);
-        game.player2_die1 = Some(player2_die1);
-        game.player2_die2 = Some(player2_die2);
+        // Give both players the chance to reroll before anyone can reveal
+        if game.rules.allow_reroll && (!game.player1_rerolled || !game.player2_rerolled) {
+            return Err(Error::RerollPending);
+        }
 
         // Determine winner (if tie, player1 wins)
-        let player1_total = player1_die1 + player1_die2;
-        let player2_total = player2_die1 + player2_die2;
-        let winner = if player1_total >= player2_total {
+        let player1_score = score(&game.player1_dice, &game.rules.scoring);
+        let player2_score = score(&game.player2_dice, &game.rules.scoring);
+        let winner = if player1_score >= player2_score {
             game.player1.clone()
         } else {
             game.player2.clone()
         };
 
+        // Rerolled double sixes win the hub's jackpot, reported while the session is open
+        let (dice, rerolled, reroll_dice) = if winner == game.player1 {
+            (&game.player1_dice, game.player1_rerolled, &game.player1_reroll_dice)
+        } else {
+            (&game.player2_dice, game.player2_rerolled, &game.player2_reroll_dice)
+        };
+        if jackpot_dice(&game.rules, dice, rerolled, reroll_dice) {
+            Self::report_jackpot(&env, session_id, &winner);
+        }
+
+        Self::finish_game(&env, session_id, &mut game, &winner);
+
+        Ok(winner)
+    }
+
+    /// Win a game whose opponent stalled the reroll: after the deadline, a
+    /// player who has committed (or, once both committed, revealed) wins if
+    /// the other has not.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the claiming player (the one waiting)
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player (the claimant)
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        // Only the reroll step can stall; rolls and reveal_winner need no one else
+        if game.reroll_deadline == 0 || (game.player1_rerolled && game.player2_rerolled) {
+            return Err(Error::CannotClaimTimeout);
+        }
+        if env.ledger().sequence() <= game.reroll_deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        // Commits are due first, then reveals
+        let both_committed =
+            game.player1_reroll_commitment.is_some() && game.player2_reroll_commitment.is_some();
+        let (player1_moved, player2_moved) = if both_committed {
+            (game.player1_reroll_salt.is_some(), game.player2_reroll_salt.is_some())
+        } else {
+            (game.player1_reroll_commitment.is_some(), game.player2_reroll_commitment.is_some())
+        };
+        let (moved, opponent_moved) = if player == game.player1 {
+            (player1_moved, player2_moved)
+        } else {
+            (player2_moved, player1_moved)
+        };
+        if !moved || opponent_moved {
+            return Err(Error::CannotClaimTimeout);
+        }
+
+        Self::finish_game(&env, session_id, &mut game, &player);
+
+        Ok(player)
+    }
+
+    /// Get game information.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Game` - The game state (includes dice after game ends)
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        let key = DataKey::Game(session_id);
+        env.storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)
+    }
+
+    // ========================================================================
+    // Team Mode
+    // ========================================================================
+
+    /// Start a team game. Every member signs the session and their own points
+    /// together with the rules. Rerolls are not available in team mode.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `team1` - Members of the first team (1..=4)
+    /// * `team1_points` - Points committed by each member of team 1, same order
+    /// * `team2` - Members of the second team (1..=4)
+    /// * `team2_points` - Points committed by each member of team 2, same order
+    /// * `rules` - Dice count, faces and scoring for every member
+    pub fn start_team_game(
+        env: Env,
+        session_id: u32,
+        team1: Vec<Address>,
+        team1_points: Vec<i128>,
+        team2: Vec<Address>,
+        team2_points: Vec<i128>,
+        rules: DiceRules,
+    ) -> Result<(), Error> {
+        if !valid_teams(&team1, &team1_points, &team2, &team2_points) {
+            return Err(Error::InvalidTeams);
+        }
+        if !valid_rules(&rules) || rules.allow_reroll {
+            return Err(Error::InvalidRules);
+        }
+
+        for (member, points) in team1.iter().zip(team1_points.iter()).chain(team2.iter().zip(team2_points.iter())) {
+            member.require_auth_for_args(vec![
+                &env,
+                session_id.into_val(&env),
+                points.into_val(&env),
+                rules.clone().into_val(&env),
+            ]);
+        }
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Call the Game Hub to start the team session and lock every stake
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.start_team_game(
+            &env.current_contract_address(),
+            &session_id,
+            &team1,
+            &team1_points,
+            &team2,
+            &team2_points,
+        );
+
+        let game = TeamGame {
+            team1,
+            team1_points,
+            team2,
+            team2_points,
+            rules,
+            dice: Map::new(&env),
+            team1_score: 0,
+            team2_score: 0,
+            team1_won: None,
+        };
+        Self::save_team_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Roll a team member's dice. Every member rolls once.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the member rolling
+    pub fn roll_team(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_team_game(&env, session_id)?;
+        if game.team1_won.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.dice.contains_key(player.clone()) {
+            return Err(Error::AlreadyRolled);
+        }
+
+        // Seats run 1..=n across team 1 and then team 2
+        let seat = match (game.team1.first_index_of(&player), game.team2.first_index_of(&player)) {
+            (Some(index), _) => index + 1,
+            (None, Some(index)) => game.team1.len() + index + 1,
+            (None, None) => return Err(Error::NotPlayer),
+        };
+
+        // Same scheme as two-player games: the seed depends only on the session
+        // and its players, so simulation and submission agree
+        let mut seed_bytes = Bytes::from_array(&env, &session_id.to_be_bytes());
+        for member in game.team1.iter().chain(game.team2.iter()) {
+            seed_bytes.append(&member.to_string().to_bytes());
+        }
+        let base_seed: BytesN<32> = env.crypto().keccak256(&seed_bytes).into();
+
+        let mut dice = Vec::new(&env);
+        for die in 1..=game.rules.dice_count {
+            let seed = die_seed(&env, &base_seed, seat as u8, die as u8, 0);
+            dice.push_back(roll_die(&env, seed, game.rules.faces));
+        }
+        game.dice.set(player, dice);
+        Self::save_team_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Add up each team's scores once every member has rolled and report the
+    /// winning team to the Game Hub, which splits the winnings among its
+    /// members. Ties go to team 1.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Vec<Address>` - Members of the winning team
+    pub fn reveal_team_winner(env: Env, session_id: u32) -> Result<Vec<Address>, Error> {
+        let mut game = Self::load_team_game(&env, session_id)?;
+        if let Some(team1_won) = game.team1_won {
+            return Ok(if team1_won { game.team1 } else { game.team2 });
+        }
+
+        let mut totals = [0u32; 2];
+        for (total, team) in totals.iter_mut().zip([&game.team1, &game.team2]) {
+            for member in team.iter() {
+                let dice = game.dice.get(member).ok_or(Error::NotAllRolled)?;
+                *total += score(&dice, &game.rules.scoring);
+            }
+        }
+
+        let team1_won = totals[0] >= totals[1];
+        game.team1_score = totals[0];
+        game.team2_score = totals[1];
+        game.team1_won = Some(team1_won);
+        Self::save_team_game(&env, session_id, &game);
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Event emitted by the Game Hub contract (TeamGameEnded)
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.end_team_game(&session_id, &team1_won);
+
+        Ok(if team1_won { game.team1 } else { game.team2 })
+    }
+
+    /// Get team game information.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `TeamGame` - The game state (includes team totals after the reveal)
+    pub fn get_team_game(env: Env, session_id: u32) -> Result<TeamGame, Error> {
+        Self::load_team_game(&env, session_id)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    /// Open the Game Hub session and store a fresh game with the given rules
+    fn create_game(
+        env: &Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        rules: DiceRules,
+        session_keys: Map<Address, SessionKey>,
+    ) -> Result<(), Error> {
+        session_keys::grant_all(env, session_id, &player1, &player2, &session_keys, GAME_TTL_LEDGERS)?;
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Create GameHub client
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        // Create game (dice not rolled yet - will be generated in roll)
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            rules,
+            player1_rolled: false,
+            player2_rolled: false,
+            player1_rerolled: false,
+            player2_rerolled: false,
+            player1_reroll_commitment: None,
+            player2_reroll_commitment: None,
+            player1_reroll_salt: None,
+            player2_reroll_salt: None,
+            player1_reroll_dice: Vec::new(env),
+            player2_reroll_dice: Vec::new(env),
+            player1_dice: Vec::new(env),
+            player2_dice: Vec::new(env),
+            reroll_deadline: 0,
+            winner: None,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        let game_key = DataKey::Game(session_id);
+        env.storage().temporary().set(&game_key, &game);
+
+        // Set TTL to ensure game is retained for at least 30 days
+        env.storage()
+            .temporary()
+            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
+    }
+
+    /// Record the winner and end the session with the Game Hub
+    fn finish_game(env: &Env, session_id: u32, game: &mut Game, winner: &Address) {
         // Update game with winner (this marks the game as ended)
         game.winner = Some(winner.clone());
-        env.storage().temporary().set(&key, &game);
+        env.storage().temporary().set(&DataKey::Game(session_id), game);
 
         // Get GameHub address
         let game_hub_addr: Address = env
@@ -330,30 +1040,97 @@ impl DiceDuelContract {
             .expect("GameHub address not set");
 
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
 
         // Call GameHub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
+        let player1_won = *winner == game.player1; // true if player1 won, false if player2 won
         game_hub.end_game(&session_id, &player1_won);
+    }
 
-        Ok(winner)
+    /// Report a jackpot win to the Game Hub while the session is open,
+    /// unless the hub funds no jackpot for this game
+    fn report_jackpot(env: &Env, session_id: u32, player: &Address) {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        if game_hub.get_jackpot_rate(&env.current_contract_address()) > 0 {
+            game_hub.report_jackpot(&session_id, player);
+        }
     }
 
-    /// Get game information.
+    /// Base seed shared by every die in a session
+    fn base_seed(env: &Env, session_id: u32, game: &Game) -> BytesN<32> {
+        let mut seed_bytes = Bytes::new(env);
+        seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        seed_bytes.append(&game.player1.to_string().to_bytes());
+        seed_bytes.append(&game.player2.to_string().to_bytes());
+        env.crypto().keccak256(&seed_bytes).into()
+    }
+
+    fn load_team_game(env: &Env, session_id: u32) -> Result<TeamGame, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::TeamGame(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    fn save_team_game(env: &Env, session_id: u32, game: &TeamGame) {
+        let key = DataKey::TeamGame(session_id);
+        env.storage().temporary().set(&key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    // ========================================================================
+    // Session Keys
+    // ========================================================================
+
+    /// Let a short-lived key sign `roll`, `reroll` and `reveal_reroll` for a
+    /// player, so the main wallet is not prompted for every move. Usually done
+    /// through `start_game`; this call is for renewing or adding a key once the
+    /// game is under way.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    ///
-    /// # Returns
-    /// * `Game` - The game state (includes dice after game ends)
-    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
-        let key = DataKey::Game(session_id);
-        env.storage()
+    /// * `player` - The player delegating
+    /// * `session_key` - The key that will sign moves; also authorizes this call
+    /// * `expiration_ledger` - Last ledger the key is accepted at, at most
+    ///   `MAX_SESSION_KEY_LEDGERS` from now
+    pub fn delegate_session_key(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        session_key: Address,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let game: Game = env
+            .storage()
             .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if game.winner.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let delegation = SessionKey { player, expiration_ledger };
+        session_keys::grant(
+            &env,
+            session_id,
+            &game.player1,
+            &game.player2,
+            session_key,
+            delegation,
+            GAME_TTL_LEDGERS,
+        )?;
+        Ok(())
     }
 
     // ========================================================================
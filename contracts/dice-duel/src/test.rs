@@ -3,9 +3,9 @@
 // Unit tests for the dice-duel contract using a simple mock GameHub.
 // These tests verify game logic independently of the full GameHub system.
 
-use crate::{DiceDuelContract, DiceDuelContractClient, Error};
+use crate::{reroll_commitment, DiceDuelContract, DiceDuelContractClient, DiceRules, Error, Scoring, SessionKey, MAX_SESSION_KEY_LEDGERS};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, map, symbol_short, vec, Address, BytesN, Env, Map, Vec};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -32,9 +32,43 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn start_team_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _team1: Vec<Address>,
+        _team1_points: Vec<i128>,
+        _team2: Vec<Address>,
+        _team2_points: Vec<i128>,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_team_game(_env: Env, _session_id: u32, _team1_won: bool) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
+
+    /// Remembers jackpot winners so tests can check the report
+    pub fn report_jackpot(env: Env, session_id: u32, player: Address) -> i128 {
+        env.storage().instance().set(&session_id, &player);
+        0
+    }
+
+    pub fn get_jackpot_winner(env: Env, session_id: u32) -> Option<Address> {
+        env.storage().instance().get(&session_id)
+    }
+
+    pub fn set_jackpot_rate(env: Env, rate_bp: u32) {
+        env.storage().instance().set(&symbol_short!("rate"), &rate_bp);
+    }
+
+    pub fn get_jackpot_rate(env: Env, _game_id: Address) -> u32 {
+        env.storage().instance().get(&symbol_short!("rate")).unwrap_or(0)
+    }
 }
 
 // ============================================================================
@@ -123,13 +157,13 @@ fn assert_dice_duel_error<T, E>(
 
 #[test]
 fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &Map::new(&env));
 
     // Verify initial state
     let game = client.get_game(&session_id);
@@ -138,12 +172,14 @@ fn test_complete_game() {
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
-    assert_eq!(game.player1_rolled, false);
-    assert_eq!(game.player2_rolled, false);
-    assert!(game.player1_die1.is_none());
-    assert!(game.player1_die2.is_none());
-    assert!(game.player2_die1.is_none());
-    assert!(game.player2_die2.is_none());
+    assert!(!game.player1_rolled);
+    assert!(!game.player2_rolled);
+    assert!(game.player1_dice.is_empty());
+    assert!(game.player2_dice.is_empty());
+    assert_eq!(game.rules.dice_count, 2);
+    assert_eq!(game.rules.faces, 6);
+    assert!(!game.rules.allow_reroll);
+    assert_eq!(game.rules.scoring, Scoring::HighestSum);
 
     // Players roll
     client.roll(&session_id, &player1);
@@ -158,10 +194,12 @@ fn test_complete_game() {
     assert!(final_game.winner.is_some());
     assert_eq!(final_game.winner.unwrap(), winner);
 
-    let p1d1 = final_game.player1_die1.unwrap();
-    let p1d2 = final_game.player1_die2.unwrap();
-    let p2d1 = final_game.player2_die1.unwrap();
-    let p2d2 = final_game.player2_die2.unwrap();
+    assert_eq!(final_game.player1_dice.len(), 2);
+    assert_eq!(final_game.player2_dice.len(), 2);
+    let p1d1 = final_game.player1_dice.get(0).unwrap();
+    let p1d2 = final_game.player1_dice.get(1).unwrap();
+    let p2d1 = final_game.player2_dice.get(0).unwrap();
+    let p2d2 = final_game.player2_dice.get(1).unwrap();
 
     assert!((1..=6).contains(&p1d1));
     assert!((1..=6).contains(&p1d2));
@@ -176,12 +214,12 @@ fn test_complete_game() {
 
 #[test]
 fn test_cannot_roll_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &Map::new(&env));
 
     client.roll(&session_id, &player1);
     let result = client.try_roll(&session_id, &player1);
@@ -190,12 +228,12 @@ fn test_cannot_roll_twice() {
 
 #[test]
 fn test_cannot_reveal_before_both_roll() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 3u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &Map::new(&env));
     client.roll(&session_id, &player1);
 
     let result = client.try_reveal_winner(&session_id);
@@ -204,26 +242,26 @@ fn test_cannot_reveal_before_both_roll() {
 
 #[test]
 fn test_non_player_cannot_roll() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 4u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &Map::new(&env));
 
-    let non_player = Address::generate(&_env);
+    let non_player = Address::generate(&env);
     let result = client.try_roll(&session_id, &non_player);
     assert_dice_duel_error(&result, Error::NotPlayer);
 }
 
 #[test]
 fn test_cannot_roll_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &Map::new(&env));
     client.roll(&session_id, &player1);
     client.roll(&session_id, &player2);
     client.reveal_winner(&session_id);
@@ -232,6 +270,464 @@ fn test_cannot_roll_after_game_ended() {
     assert_dice_duel_error(&result, Error::GameAlreadyEnded);
 }
 
+// ============================================================================
+// Custom Rules Tests
+// ============================================================================
+
+fn rules(dice_count: u32, faces: u32, allow_reroll: bool, scoring: Scoring) -> DiceRules {
+    DiceRules {
+        dice_count,
+        faces,
+        allow_reroll,
+        scoring,
+    }
+}
+
+#[test]
+fn test_custom_dice_count_and_faces() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 10u32;
+    let points = 100_0000000;
+    let custom = rules(5, 20, false, Scoring::HighestSum);
+
+    client.start_game_with_rules(&session_id, &player1, &player2, &points, &points, &custom, &Map::new(&env));
+    assert_eq!(client.get_game(&session_id).rules, custom);
+
+    client.roll(&session_id, &player1);
+    client.roll(&session_id, &player2);
+    let winner = client.reveal_winner(&session_id);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_dice.len(), 5);
+    assert_eq!(game.player2_dice.len(), 5);
+    for die in game.player1_dice.iter().chain(game.player2_dice.iter()) {
+        assert!((1..=20).contains(&die));
+    }
+
+    let total1: u32 = game.player1_dice.iter().sum();
+    let total2: u32 = game.player2_dice.iter().sum();
+    let expected = if total1 >= total2 { player1 } else { player2 };
+    assert_eq!(winner, expected);
+}
+
+#[test]
+fn test_highest_single_scoring() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 11u32;
+    let points = 100_0000000;
+    let custom = rules(3, 12, false, Scoring::HighestSingle);
+
+    client.start_game_with_rules(&session_id, &player1, &player2, &points, &points, &custom, &Map::new(&env));
+    client.roll(&session_id, &player1);
+    client.roll(&session_id, &player2);
+    let winner = client.reveal_winner(&session_id);
+
+    let game = client.get_game(&session_id);
+    let best1 = game.player1_dice.iter().max().unwrap();
+    let best2 = game.player2_dice.iter().max().unwrap();
+    let expected = if best1 >= best2 { player1 } else { player2 };
+    assert_eq!(winner, expected);
+}
+
+#[test]
+fn test_default_rules_match_classic_game() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let points = 100_0000000;
+
+    // The same session played with explicit classic rules rolls the same dice
+    client.start_game(&12, &player1, &player2, &points, &points, &Map::new(&env));
+    client.roll(&12, &player1);
+    let classic = client.get_game(&12).player1_dice;
+
+    // A fresh environment generates the same player addresses
+    let (env2, client2, _hub2, player1_2, player2_2) = setup_test();
+    let explicit = rules(2, 6, false, Scoring::HighestSum);
+    client2.start_game_with_rules(&12, &player1_2, &player2_2, &points, &points, &explicit, &Map::new(&env2));
+    client2.roll(&12, &player1_2);
+    assert_eq!(client2.get_game(&12).player1_dice, classic);
+}
+
+#[test]
+fn test_invalid_rules_rejected() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let points = 100_0000000;
+
+    for bad in [
+        rules(0, 6, false, Scoring::HighestSum),
+        rules(11, 6, false, Scoring::HighestSum),
+        rules(2, 1, false, Scoring::HighestSum),
+        rules(2, 101, false, Scoring::HighestSum),
+    ] {
+        let result = client.try_start_game_with_rules(&13, &player1, &player2, &points, &points, &bad, &Map::new(&env));
+        assert_dice_duel_error(&result, Error::InvalidRules);
+    }
+}
+
+fn salt(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+/// Lock in a reroll of `dice` with the salt filled with `salt_byte`
+fn commit_reroll(
+    env: &Env,
+    client: &DiceDuelContractClient<'static>,
+    session_id: u32,
+    player: &Address,
+    dice: &Vec<u32>,
+    salt_byte: u8,
+) {
+    client.reroll(&session_id, player, &reroll_commitment(env, dice, &salt(env, salt_byte)));
+}
+
+#[test]
+fn test_reroll_selected_dice() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 14u32;
+    let points = 100_0000000;
+    let custom = rules(4, 6, true, Scoring::HighestSum);
+
+    client.start_game_with_rules(&session_id, &player1, &player2, &points, &points, &custom, &Map::new(&env));
+    client.roll(&session_id, &player1);
+    client.roll(&session_id, &player2);
+
+    // Reveal waits until both players have revealed their reroll
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::RerollPending);
+
+    let reroll1 = vec![&env, 1u32, 3u32];
+    commit_reroll(&env, &client, session_id, &player1, &reroll1, 1);
+    let result = client.try_reroll(&session_id, &player1, &reroll_commitment(&env, &reroll1, &salt(&env, 1)));
+    assert_dice_duel_error(&result, Error::AlreadyRerolled);
+
+    // Nobody reveals until both have locked in
+    let result = client.try_reveal_reroll(&session_id, &player1, &reroll1, &salt(&env, 1));
+    assert_dice_duel_error(&result, Error::RerollPending);
+
+    // Declining is a commitment to no dice
+    commit_reroll(&env, &client, session_id, &player2, &Vec::new(&env), 2);
+
+    // The first reveal changes nothing yet
+    let before = client.get_game(&session_id);
+    client.reveal_reroll(&session_id, &player1, &reroll1, &salt(&env, 1));
+    assert_eq!(client.get_game(&session_id).player1_dice, before.player1_dice);
+    let result = client.try_reveal_reroll(&session_id, &player1, &reroll1, &salt(&env, 1));
+    assert_dice_duel_error(&result, Error::AlreadyRerolled);
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::RerollPending);
+
+    let result = client.try_reveal_reroll(&session_id, &player2, &Vec::new(&env), &salt(&env, 3));
+    assert_dice_duel_error(&result, Error::InvalidReveal);
+
+    // The second reveal applies both rerolls; untouched dice keep their values
+    client.reveal_reroll(&session_id, &player2, &Vec::new(&env), &salt(&env, 2));
+    let after = client.get_game(&session_id);
+    assert_eq!(after.player1_dice.len(), 4);
+    assert_eq!(after.player1_dice.get(0), before.player1_dice.get(0));
+    assert_eq!(after.player1_dice.get(2), before.player1_dice.get(2));
+    for die in after.player1_dice.iter() {
+        assert!((1..=6).contains(&die));
+    }
+    assert_eq!(after.player2_dice, before.player2_dice);
+
+    let winner = client.reveal_winner(&session_id);
+    assert!(winner == player1 || winner == player2);
+}
+
+#[test]
+fn test_reroll_errors() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let points = 100_0000000;
+    let commitment = reroll_commitment(&env, &vec![&env, 0u32], &salt(&env, 1));
+
+    // Classic rules have no reroll
+    client.start_game(&15, &player1, &player2, &points, &points, &Map::new(&env));
+    client.roll(&15, &player1);
+    let result = client.try_reroll(&15, &player1, &commitment);
+    assert_dice_duel_error(&result, Error::RerollNotAllowed);
+
+    let custom = rules(3, 6, true, Scoring::HighestSum);
+    client.start_game_with_rules(&16, &player1, &player2, &points, &points, &custom, &Map::new(&env));
+
+    // Must roll first
+    let result = client.try_reroll(&16, &player1, &commitment);
+    assert_dice_duel_error(&result, Error::NotRolled);
+
+    let non_player = Address::generate(&env);
+    let result = client.try_reroll(&16, &non_player, &commitment);
+    assert_dice_duel_error(&result, Error::NotPlayer);
+
+    // Out-of-range and repeated indices are rejected at the reveal
+    client.roll(&16, &player1);
+    client.roll(&16, &player2);
+    commit_reroll(&env, &client, 16, &player1, &vec![&env, 3u32], 1);
+    commit_reroll(&env, &client, 16, &player2, &vec![&env, 1u32, 1u32], 2);
+    let result = client.try_reveal_reroll(&16, &player1, &vec![&env, 3u32], &salt(&env, 1));
+    assert_dice_duel_error(&result, Error::InvalidDieIndex);
+    let result = client.try_reveal_reroll(&16, &player2, &vec![&env, 1u32, 1u32], &salt(&env, 2));
+    assert_dice_duel_error(&result, Error::InvalidDieIndex);
+    let result = client.try_reveal_reroll(&16, &non_player, &vec![&env, 0u32], &salt(&env, 1));
+    assert_dice_duel_error(&result, Error::NotPlayer);
+}
+
+#[test]
+fn test_claim_timeout_when_reroll_not_committed() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let points = 100_0000000;
+    let custom = rules(2, 6, true, Scoring::HighestSum);
+    client.start_game_with_rules(&17, &player1, &player2, &points, &points, &custom, &Map::new(&env));
+
+    // Nothing to claim before both have rolled
+    let result = client.try_claim_timeout(&17, &player1);
+    assert_dice_duel_error(&result, Error::CannotClaimTimeout);
+
+    client.roll(&17, &player1);
+    client.roll(&17, &player2);
+    commit_reroll(&env, &client, 17, &player1, &vec![&env, 0u32], 1);
+    let result = client.try_claim_timeout(&17, &player1);
+    assert_dice_duel_error(&result, Error::TimeoutNotReached);
+
+    // Player 2 never commits; only the player who did can claim
+    env.ledger().with_mut(|li| li.sequence_number = 100 + 17_281);
+    let result = client.try_claim_timeout(&17, &player2);
+    assert_dice_duel_error(&result, Error::CannotClaimTimeout);
+    assert_eq!(client.claim_timeout(&17, &player1), player1);
+    assert_eq!(client.get_game(&17).winner, Some(player1.clone()));
+    assert_eq!(client.reveal_winner(&17), player1);
+    let result = client.try_claim_timeout(&17, &player1);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_when_reveal_withheld() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let points = 100_0000000;
+    let custom = rules(2, 6, true, Scoring::HighestSum);
+    client.start_game_with_rules(&18, &player1, &player2, &points, &points, &custom, &Map::new(&env));
+    client.roll(&18, &player1);
+    client.roll(&18, &player2);
+
+    // The reveals get a fresh deadline once both have committed
+    commit_reroll(&env, &client, 18, &player1, &vec![&env, 0u32], 1);
+    env.ledger().with_mut(|li| li.sequence_number = 10_000);
+    commit_reroll(&env, &client, 18, &player2, &vec![&env, 1u32], 2);
+    assert_eq!(client.get_game(&18).reroll_deadline, 10_000 + 17_280);
+
+    // Player 2 could work out the result from player 1's reveal and withhold theirs
+    client.reveal_reroll(&18, &player1, &vec![&env, 0u32], &salt(&env, 1));
+    env.ledger().with_mut(|li| li.sequence_number = 20_000);
+    let result = client.try_claim_timeout(&18, &player1);
+    assert_dice_duel_error(&result, Error::TimeoutNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number = 10_000 + 17_281);
+    let result = client.try_claim_timeout(&18, &player2);
+    assert_dice_duel_error(&result, Error::CannotClaimTimeout);
+    assert_eq!(client.claim_timeout(&18, &player1), player1);
+    let result = client.try_reveal_reroll(&18, &player2, &vec![&env, 1u32], &salt(&env, 2));
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+// ============================================================================
+// Team Mode Tests
+// ============================================================================
+
+fn team(env: &Env, size: u32) -> (Vec<Address>, Vec<i128>) {
+    let mut members = Vec::new(env);
+    let mut points = Vec::new(env);
+    for _ in 0..size {
+        members.push_back(Address::generate(env));
+        points.push_back(50_0000000);
+    }
+    (members, points)
+}
+
+#[test]
+fn test_team_game_totals_decide_winner() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (team1, team1_points) = team(&env, 2);
+    let (team2, team2_points) = team(&env, 2);
+    let classic = rules(2, 6, false, Scoring::HighestSum);
+    client.start_team_game(&40, &team1, &team1_points, &team2, &team2_points, &classic);
+
+    for member in team1.iter().chain(team2.iter()) {
+        client.roll_team(&40, &member);
+    }
+    let winners = client.reveal_team_winner(&40);
+
+    let game = client.get_team_game(&40);
+    let total = |members: &Vec<Address>| -> u32 {
+        members.iter().map(|m| game.dice.get(m).unwrap().iter().sum::<u32>()).sum()
+    };
+    assert_eq!(game.team1_score, total(&team1));
+    assert_eq!(game.team2_score, total(&team2));
+    for member in team1.iter().chain(team2.iter()) {
+        assert_eq!(game.dice.get(member).unwrap().len(), 2);
+    }
+
+    let team1_won = game.team1_score >= game.team2_score;
+    assert_eq!(game.team1_won, Some(team1_won));
+    assert_eq!(winners, if team1_won { team1.clone() } else { team2.clone() });
+
+    // Revealing again returns the stored result
+    assert_eq!(client.reveal_team_winner(&40), winners);
+    let result = client.try_roll_team(&40, &team1.get(0).unwrap());
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_team_game_uneven_teams() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (team1, team1_points) = team(&env, 1);
+    let (team2, team2_points) = team(&env, 3);
+    let single = rules(1, 6, false, Scoring::HighestSingle);
+    client.start_team_game(&41, &team1, &team1_points, &team2, &team2_points, &single);
+
+    for member in team1.iter().chain(team2.iter()) {
+        client.roll_team(&41, &member);
+    }
+
+    // Three dice can never total less than one
+    assert_eq!(client.reveal_team_winner(&41), team2);
+    assert!(client.get_team_game(&41).team2_score >= 3);
+}
+
+#[test]
+fn test_team_game_roll_rules() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let (team1, team1_points) = team(&env, 2);
+    let (team2, team2_points) = team(&env, 2);
+    let classic = rules(2, 6, false, Scoring::HighestSum);
+    client.start_team_game(&42, &team1, &team1_points, &team2, &team2_points, &classic);
+
+    let member = team2.get(1).unwrap();
+    client.roll_team(&42, &member);
+    let result = client.try_roll_team(&42, &member);
+    assert_dice_duel_error(&result, Error::AlreadyRolled);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_roll_team(&42, &outsider);
+    assert_dice_duel_error(&result, Error::NotPlayer);
+
+    let result = client.try_reveal_team_winner(&42);
+    assert_dice_duel_error(&result, Error::NotAllRolled);
+}
+
+#[test]
+fn test_invalid_teams_rejected() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let classic = rules(2, 6, false, Scoring::HighestSum);
+    let (team1, team1_points) = team(&env, 2);
+
+    let (empty, no_points) = team(&env, 0);
+    let result = client.try_start_team_game(&43, &team1, &team1_points, &empty, &no_points, &classic);
+    assert_dice_duel_error(&result, Error::InvalidTeams);
+
+    let (big, big_points) = team(&env, 5);
+    let result = client.try_start_team_game(&43, &team1, &team1_points, &big, &big_points, &classic);
+    assert_dice_duel_error(&result, Error::InvalidTeams);
+
+    let (team2, _) = team(&env, 2);
+    let result = client.try_start_team_game(&43, &team1, &team1_points, &team2, &vec![&env, 1], &classic);
+    assert_dice_duel_error(&result, Error::InvalidTeams);
+
+    let overlapping = vec![&env, team1.get(0).unwrap(), Address::generate(&env)];
+    let result = client.try_start_team_game(&43, &team1, &team1_points, &overlapping, &team1_points, &classic);
+    assert_dice_duel_error(&result, Error::InvalidTeams);
+
+    let with_reroll = rules(2, 6, true, Scoring::HighestSum);
+    let result = client.try_start_team_game(&43, &team1, &team1_points, &team2, &team1_points, &with_reroll);
+    assert_dice_duel_error(&result, Error::InvalidRules);
+}
+
+// ============================================================================
+// Jackpot Tests
+// ============================================================================
+
+#[test]
+fn test_rerolled_double_sixes_report_jackpot() {
+    let (env, client, hub, player1, player2) = setup_test();
+    hub.set_jackpot_rate(&100);
+    let both = vec![&env, 0u32, 1u32];
+
+    // Both players reroll both dice; a winner with rerolled double sixes is reported
+    let mut jackpots = 0;
+    for session_id in 100..200u32 {
+        let custom = rules(2, 6, true, Scoring::HighestSum);
+        client.start_game_with_rules(&session_id, &player1, &player2, &100, &100, &custom, &Map::new(&env));
+        client.roll(&session_id, &player1);
+        client.roll(&session_id, &player2);
+        commit_reroll(&env, &client, session_id, &player1, &both, 1);
+        commit_reroll(&env, &client, session_id, &player2, &both, 2);
+        client.reveal_reroll(&session_id, &player1, &both, &salt(&env, 1));
+        client.reveal_reroll(&session_id, &player2, &both, &salt(&env, 2));
+        let winner = client.reveal_winner(&session_id);
+
+        let game = client.get_game(&session_id);
+        let dice = if winner == player1 { game.player1_dice } else { game.player2_dice };
+        let double_sixes = dice.iter().all(|die| die == 6);
+        let expected = if double_sixes { Some(winner) } else { None };
+        assert_eq!(hub.get_jackpot_winner(&session_id), expected);
+        jackpots += double_sixes as u32;
+    }
+    assert!(jackpots > 0);
+
+    // A first roll follows from the session ID, so its double sixes never count
+    let mut session_id = 200;
+    loop {
+        client.start_game(&session_id, &player1, &player2, &100, &100, &Map::new(&env));
+        client.roll(&session_id, &player1);
+        client.roll(&session_id, &player2);
+        let winner = client.reveal_winner(&session_id);
+        let game = client.get_game(&session_id);
+        let dice = if winner == player1 { game.player1_dice } else { game.player2_dice };
+        if dice.iter().all(|die| die == 6) {
+            assert_eq!(hub.get_jackpot_winner(&session_id), None);
+            break;
+        }
+        session_id += 1;
+    }
+}
+
+// ============================================================================
+// Session Key Tests
+// ============================================================================
+
+#[test]
+fn test_session_key_rolls_for_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let key = Address::generate(&env);
+    let session_keys = map![&env, (key.clone(), SessionKey { player: player1.clone(), expiration_ledger: 200 })];
+    client.start_game(&70u32, &player1, &player2, &100_0000000, &100_0000000, &session_keys);
+
+    let result = client.try_delegate_session_key(&70u32, &player1, &key, &(101 + MAX_SESSION_KEY_LEDGERS));
+    assert_dice_duel_error(&result, Error::InvalidSessionKey);
+
+    // The key signs the roll, and it counts as player 1's
+    client.roll(&70u32, &key);
+    assert_eq!(env.auths()[0].0, key);
+    assert!(client.get_game(&70u32).player1_rolled);
+    let result = client.try_roll(&70u32, &player1);
+    assert_dice_duel_error(&result, Error::AlreadyRolled);
+
+    // Keys end with their game
+    client.roll(&70u32, &player2);
+    client.reveal_winner(&70u32);
+    let result = client.try_delegate_session_key(&70u32, &player2, &Address::generate(&env), &200);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+
+    // Keys can also come with custom rules
+    let custom = rules(3, 8, false, Scoring::HighestSum);
+    client.start_game_with_rules(&71u32, &player1, &player2, &100_0000000, &100_0000000, &custom, &session_keys);
+    client.roll(&71u32, &key);
+    assert!(client.get_game(&71u32).player1_rolled);
+}
+
+// ============================================================================
+// Admin Function Tests
+// ============================================================================
+
 #[test]
 fn test_upgrade_function_exists() {
     let (_env, client, _hub, _player1, _player2) = setup_test();
@@ -0,0 +1,814 @@
+#![no_std]
+
+//! # League
+//!
+//! Round-robin leagues played out in any Game Hub-aware game contract.
+//!
+//! The organizer lists the participants and the league generates the full
+//! fixture list: everyone meets everyone once, one round of fixtures at a
+//! time, each with its own deadline. Results earn league points per win, draw
+//! or loss, and standings break ties on head-to-head points, then on win-loss
+//! differential.
+//!
+//! **Game Hub Integration:**
+//! Like the tournament contract, the league sits between a game and the Game
+//! Hub. Deploy an instance of the game with this contract as its `game_hub`.
+//! Players start each fixture through the game with the fixture's session ID
+//! and zero points. The game reports `start_game`/`end_game`/`end_game_draw`
+//! here, and the league forwards them to the real Game Hub before recording
+//! the result.
+//!
+//! **No-shows:**
+//! Players check in to a fixture before its deadline. A fixture not started by
+//! the deadline can be forfeited by anyone: a player who checked in wins
+//! against one who did not, two no-shows both lose, and two players who both
+//! checked in share a draw.
+
+use soroban_sdk::{
+    Address, BytesN, Env, Vec, contract, contractclient, contracterror, contractimpl, contracttype
+};
+
+// Import GameHub contract interface
+// This allows us to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(
+        env: Env,
+        session_id: u32,
+        player1_won: bool
+    );
+
+    fn end_game_draw(env: Env, session_id: u32);
+
+    fn report_jackpot(env: Env, session_id: u32, player: Address) -> i128;
+
+    fn get_jackpot_rate(env: Env, game_id: Address) -> u32;
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    LeagueNotFound = 1,
+    LeagueExists = 2,
+    InvalidConfig = 3,
+    InvalidPlayers = 4,
+    FixtureNotFound = 5,
+    UnknownSession = 6,
+    WrongGame = 7,
+    WrongPlayers = 8,
+    StakeNotAllowed = 9,
+    FixtureAlreadyStarted = 10,
+    FixtureNotStarted = 11,
+    FixtureDecided = 12,
+    DeadlinePassed = 13,
+    DeadlineNotReached = 14,
+    NotPlayer = 15,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Pending,
+    HomeWin,
+    AwayWin,
+    Draw,
+    /// The home player did not show; the away player wins
+    HomeForfeit,
+    /// The away player did not show; the home player wins
+    AwayForfeit,
+    /// Neither player showed; both lose
+    DoubleForfeit,
+}
+
+/// League rules, fixed at creation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeagueSetup {
+    /// The game contract fixtures are played in (deployed with this contract as its hub)
+    pub game_id: Address,
+    /// Participants; list order is the final tiebreaker
+    pub players: Vec<Address>,
+    /// Session ID of the first fixture; fixture `i` uses `first_session_id + i`
+    pub first_session_id: u32,
+    /// Ledgers each round has before its fixtures can be forfeited
+    pub round_ledgers: u32,
+    pub win_points: u32,
+    pub draw_points: u32,
+    /// Points for a loss on the board; a forfeit earns nothing
+    pub loss_points: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct League {
+    pub organizer: Address,
+    pub setup: LeagueSetup,
+    pub start_ledger: u32,
+    /// Fixtures without a result; the league is over at 0
+    pub fixtures_left: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Fixture {
+    pub round: u32,
+    /// Player 1 in the game session
+    pub home: Address,
+    /// Player 2 in the game session
+    pub away: Address,
+    pub session_id: u32,
+    /// Last ledger the fixture can be started or checked in to
+    pub deadline: u32,
+    pub home_checked_in: bool,
+    pub away_checked_in: bool,
+    pub started: bool,
+    pub outcome: Outcome,
+}
+
+/// Which fixture a Game Hub session belongs to
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixtureRef {
+    pub league_id: u32,
+    pub fixture_index: u32,
+}
+
+/// One row of the league table
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Standing {
+    pub player: Address,
+    pub played: u32,
+    pub wins: u32,
+    pub draws: u32,
+    /// Includes forfeits
+    pub losses: u32,
+    pub forfeits: u32,
+    pub points: u32,
+    /// Points from fixtures against players on the same points
+    pub head_to_head: u32,
+    /// Wins minus losses
+    pub differential: i32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    League(u32),
+    Fixtures(u32),
+    Session(u32),
+    GameHubAddress,
+    Admin,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) ensures league data doesn't expire unexpectedly
+// Leagues are stored in temporary storage with a minimum 30-day retention
+
+/// TTL for league storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Most players in a league (120 fixtures)
+const MAX_PLAYERS: u32 = 16;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Build the fixture list with the circle method: the first player stays put
+/// while the others rotate one seat per round. An odd field gets a bye seat,
+/// so each round one player sits out.
+fn build_fixtures(env: &Env, setup: &LeagueSetup, start_ledger: u32) -> Vec<Fixture> {
+    let count = setup.players.len();
+    let seats = count + count % 2;
+    let mut fixtures = Vec::new(env);
+
+    for round in 0..seats - 1 {
+        // Seat 0 is fixed; seat k > 0 holds player 1 + (k - 1 + round) mod (seats - 1)
+        let seat = |k: u32| if k == 0 { 0 } else { 1 + (k - 1 + round) % (seats - 1) };
+        for pair in 0..seats / 2 {
+            let (mut home, mut away) = (seat(pair), seat(seats - 1 - pair));
+            if home >= count || away >= count {
+                continue;
+            }
+            // Alternate the fixed player's side so nobody is always home
+            if pair == 0 && round % 2 == 1 {
+                core::mem::swap(&mut home, &mut away);
+            }
+            fixtures.push_back(Fixture {
+                round: round + 1,
+                home: setup.players.get_unchecked(home),
+                away: setup.players.get_unchecked(away),
+                session_id: setup.first_session_id + fixtures.len(),
+                deadline: start_ledger + (round + 1) * setup.round_ledgers,
+                home_checked_in: false,
+                away_checked_in: false,
+                started: false,
+                outcome: Outcome::Pending,
+            });
+        }
+    }
+
+    fixtures
+}
+
+/// League points for the home and away player
+fn fixture_points(setup: &LeagueSetup, outcome: Outcome) -> (u32, u32) {
+    match outcome {
+        Outcome::Pending | Outcome::DoubleForfeit => (0, 0),
+        Outcome::HomeWin => (setup.win_points, setup.loss_points),
+        Outcome::AwayWin => (setup.loss_points, setup.win_points),
+        Outcome::Draw => (setup.draw_points, setup.draw_points),
+        Outcome::HomeForfeit => (0, setup.win_points),
+        Outcome::AwayForfeit => (setup.win_points, 0),
+    }
+}
+
+/// Add one result to a player's row
+fn record(standing: &mut Standing, points: u32, won: bool, drew: bool, forfeited: bool) {
+    standing.played += 1;
+    standing.points += points;
+    if won {
+        standing.wins += 1;
+        standing.differential += 1;
+    } else if drew {
+        standing.draws += 1;
+    } else {
+        standing.losses += 1;
+        standing.differential -= 1;
+        if forfeited {
+            standing.forfeits += 1;
+        }
+    }
+}
+
+/// True if `a` ranks above `b`: points, then head-to-head, then differential
+fn ranks_above(a: &Standing, b: &Standing) -> bool {
+    (a.points, a.head_to_head, a.differential) > (b.points, b.head_to_head, b.differential)
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct LeagueContract;
+
+#[contractimpl]
+impl LeagueContract {
+    /// Initialize the contract with GameHub address and admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    /// * `game_hub` - Address of the GameHub contract
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        // Store admin and GameHub address
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &game_hub);
+    }
+
+    // ========================================================================
+    // League Setup
+    // ========================================================================
+
+    /// Create a league and generate its fixtures. Round 1 starts now; each
+    /// round's deadline is `round_ledgers` after the previous one.
+    ///
+    /// # Arguments
+    /// * `league_id` - Unique league identifier
+    /// * `organizer` - Address running the league
+    /// * `setup` - Game, participants, session IDs, round length and scoring
+    pub fn create_league(
+        env: Env,
+        league_id: u32,
+        organizer: Address,
+        setup: LeagueSetup,
+    ) -> Result<(), Error> {
+        organizer.require_auth();
+
+        let key = DataKey::League(league_id);
+        if env.storage().temporary().has(&key) {
+            return Err(Error::LeagueExists);
+        }
+        if setup.round_ledgers == 0 || setup.win_points < setup.draw_points || setup.draw_points < setup.loss_points {
+            return Err(Error::InvalidConfig);
+        }
+        let count = setup.players.len();
+        if !(2..=MAX_PLAYERS).contains(&count) {
+            return Err(Error::InvalidPlayers);
+        }
+        for (i, player) in setup.players.iter().enumerate() {
+            if setup.players.first_index_of(&player) != Some(i as u32) {
+                return Err(Error::InvalidPlayers);
+            }
+        }
+
+        let start_ledger = env.ledger().sequence();
+        let fixtures = build_fixtures(&env, &setup, start_ledger);
+        for (index, fixture) in fixtures.iter().enumerate() {
+            let key = DataKey::Session(fixture.session_id);
+            if env.storage().temporary().has(&key) {
+                return Err(Error::InvalidConfig);
+            }
+            let fixture_ref = FixtureRef {
+                league_id,
+                fixture_index: index as u32,
+            };
+            env.storage().temporary().set(&key, &fixture_ref);
+            env.storage()
+                .temporary()
+                .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+
+        let league = League {
+            organizer,
+            setup,
+            start_ledger,
+            fixtures_left: fixtures.len(),
+        };
+        Self::save_league(&env, league_id, &league);
+        Self::save_fixtures(&env, league_id, &fixtures);
+
+        Ok(())
+    }
+
+    /// Declare that a player is ready to play a fixture. Only matters if the
+    /// fixture is not played by its deadline.
+    ///
+    /// # Arguments
+    /// * `league_id` - The league
+    /// * `fixture_index` - Index into the fixture list
+    /// * `player` - One of the fixture's players
+    pub fn check_in(env: Env, league_id: u32, fixture_index: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        Self::load_league(&env, league_id)?;
+        let mut fixtures = Self::load_fixtures(&env, league_id)?;
+        let mut fixture = fixtures.get(fixture_index).ok_or(Error::FixtureNotFound)?;
+
+        if fixture.outcome != Outcome::Pending {
+            return Err(Error::FixtureDecided);
+        }
+        if env.ledger().sequence() > fixture.deadline {
+            return Err(Error::DeadlinePassed);
+        }
+        if player == fixture.home {
+            fixture.home_checked_in = true;
+        } else if player == fixture.away {
+            fixture.away_checked_in = true;
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        fixtures.set(fixture_index, fixture);
+        Self::save_fixtures(&env, league_id, &fixtures);
+
+        Ok(())
+    }
+
+    /// Settle a fixture nobody started before its deadline. Anyone can call it.
+    ///
+    /// # Arguments
+    /// * `league_id` - The league
+    /// * `fixture_index` - Index into the fixture list
+    ///
+    /// # Returns
+    /// * `Outcome` - The forfeit result, or a draw if both players checked in
+    pub fn claim_forfeit(env: Env, league_id: u32, fixture_index: u32) -> Result<Outcome, Error> {
+        let mut league = Self::load_league(&env, league_id)?;
+        let mut fixtures = Self::load_fixtures(&env, league_id)?;
+        let mut fixture = fixtures.get(fixture_index).ok_or(Error::FixtureNotFound)?;
+
+        if fixture.outcome != Outcome::Pending {
+            return Err(Error::FixtureDecided);
+        }
+        if fixture.started {
+            return Err(Error::FixtureAlreadyStarted);
+        }
+        if env.ledger().sequence() <= fixture.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let outcome = match (fixture.home_checked_in, fixture.away_checked_in) {
+            (true, true) => Outcome::Draw,
+            (true, false) => Outcome::AwayForfeit,
+            (false, true) => Outcome::HomeForfeit,
+            (false, false) => Outcome::DoubleForfeit,
+        };
+        env.storage()
+            .temporary()
+            .remove(&DataKey::Session(fixture.session_id));
+
+        fixture.outcome = outcome;
+        fixtures.set(fixture_index, fixture);
+        league.fixtures_left -= 1;
+
+        Self::save_fixtures(&env, league_id, &fixtures);
+        Self::save_league(&env, league_id, &league);
+
+        Ok(outcome)
+    }
+
+    // ========================================================================
+    // Game Hub Interface (called by the game contract)
+    // ========================================================================
+
+    /// Called by the game when a fixture starts. Checks the session belongs to
+    /// an open fixture with the home player first and no stake, then opens it
+    /// in the Game Hub.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - The fixture's session ID
+    /// * `player1` - The fixture's home player
+    /// * `player2` - The fixture's away player
+    /// * `player1_points` - Must be 0
+    /// * `player2_points` - Must be 0
+    pub fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        game_id.require_auth();
+
+        let (fixture_ref, league, mut fixtures) = Self::load_session(&env, session_id)?;
+        if game_id != league.setup.game_id {
+            return Err(Error::WrongGame);
+        }
+
+        let mut fixture = fixtures.get_unchecked(fixture_ref.fixture_index);
+        if fixture.started {
+            return Err(Error::FixtureAlreadyStarted);
+        }
+        if env.ledger().sequence() > fixture.deadline {
+            return Err(Error::DeadlinePassed);
+        }
+        if player1 != fixture.home || player2 != fixture.away {
+            return Err(Error::WrongPlayers);
+        }
+        if player1_points != 0 || player2_points != 0 {
+            return Err(Error::StakeNotAllowed);
+        }
+
+        fixture.started = true;
+        fixtures.set(fixture_ref.fixture_index, fixture);
+        Self::save_fixtures(&env, fixture_ref.league_id, &fixtures);
+
+        // The league opens the session under its own address
+        Self::game_hub(&env).start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &0,
+            &0,
+        );
+
+        Ok(())
+    }
+
+    /// Called by the game when a fixture ends with a winner.
+    ///
+    /// # Arguments
+    /// * `session_id` - The fixture's session ID
+    /// * `player1_won` - True if the home player won
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
+        let outcome = if player1_won { Outcome::HomeWin } else { Outcome::AwayWin };
+        Self::finish_fixture(&env, session_id, outcome)?;
+
+        Self::game_hub(&env).end_game(&session_id, &player1_won);
+
+        Ok(())
+    }
+
+    /// Called by the game when a fixture ends level.
+    ///
+    /// # Arguments
+    /// * `session_id` - The fixture's session ID
+    pub fn end_game_draw(env: Env, session_id: u32) -> Result<(), Error> {
+        Self::finish_fixture(&env, session_id, Outcome::Draw)?;
+
+        Self::game_hub(&env).end_game_draw(&session_id);
+
+        Ok(())
+    }
+
+    /// Called by the game before `end_game` when a fixture earns its game's
+    /// jackpot. Forwarded to the Game Hub, which pays the player.
+    ///
+    /// # Arguments
+    /// * `session_id` - The fixture's session ID
+    /// * `player` - The jackpot winner
+    pub fn report_jackpot(env: Env, session_id: u32, player: Address) -> Result<i128, Error> {
+        let (fixture_ref, league, fixtures) = Self::load_session(&env, session_id)?;
+        league.setup.game_id.require_auth();
+
+        if !fixtures.get_unchecked(fixture_ref.fixture_index).started {
+            return Err(Error::FixtureNotStarted);
+        }
+
+        Ok(Self::game_hub(&env).report_jackpot(&session_id, &player))
+    }
+
+    /// The Game Hub's jackpot rate for this league's sessions, which the hub
+    /// opens under the league's address. Games check it before reporting a
+    /// jackpot.
+    ///
+    /// # Arguments
+    /// * `_game_id` - The calling game; the league has only one
+    pub fn get_jackpot_rate(env: Env, _game_id: Address) -> u32 {
+        Self::game_hub(&env).get_jackpot_rate(&env.current_contract_address())
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// Get league information.
+    ///
+    /// # Arguments
+    /// * `league_id` - The league's ID
+    pub fn get_league(env: Env, league_id: u32) -> Result<League, Error> {
+        Self::load_league(&env, league_id)
+    }
+
+    /// Get the full fixture list, ordered by round.
+    ///
+    /// # Arguments
+    /// * `league_id` - The league's ID
+    pub fn get_fixtures(env: Env, league_id: u32) -> Result<Vec<Fixture>, Error> {
+        Self::load_league(&env, league_id)?;
+        Self::load_fixtures(&env, league_id)
+    }
+
+    /// Find the fixture a session ID belongs to.
+    ///
+    /// # Arguments
+    /// * `session_id` - A fixture session ID
+    pub fn get_session_fixture(env: Env, session_id: u32) -> Result<FixtureRef, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Session(session_id))
+            .ok_or(Error::UnknownSession)
+    }
+
+    /// Get the league table, best first. Ties on points are broken by points
+    /// won against the other players on the same points, then by win-loss
+    /// differential, then by the order players were listed in.
+    ///
+    /// # Arguments
+    /// * `league_id` - The league's ID
+    pub fn get_standings(env: Env, league_id: u32) -> Result<Vec<Standing>, Error> {
+        let league = Self::load_league(&env, league_id)?;
+        let fixtures = Self::load_fixtures(&env, league_id)?;
+        let setup = &league.setup;
+
+        let mut table = Vec::new(&env);
+        for player in setup.players.iter() {
+            table.push_back(Standing {
+                player,
+                played: 0,
+                wins: 0,
+                draws: 0,
+                losses: 0,
+                forfeits: 0,
+                points: 0,
+                head_to_head: 0,
+                differential: 0,
+            });
+        }
+        let seat = |player: &Address| setup.players.first_index_of(player).unwrap();
+
+        for fixture in fixtures.iter() {
+            let outcome = fixture.outcome;
+            if outcome == Outcome::Pending {
+                continue;
+            }
+            let (home_points, away_points) = fixture_points(setup, outcome);
+            let drew = outcome == Outcome::Draw;
+
+            let (home_seat, away_seat) = (seat(&fixture.home), seat(&fixture.away));
+            let mut home = table.get_unchecked(home_seat);
+            let home_won = matches!(outcome, Outcome::HomeWin | Outcome::AwayForfeit);
+            let home_forfeited = matches!(outcome, Outcome::HomeForfeit | Outcome::DoubleForfeit);
+            record(&mut home, home_points, home_won, drew, home_forfeited);
+            table.set(home_seat, home);
+
+            let mut away = table.get_unchecked(away_seat);
+            let away_won = matches!(outcome, Outcome::AwayWin | Outcome::HomeForfeit);
+            let away_forfeited = matches!(outcome, Outcome::AwayForfeit | Outcome::DoubleForfeit);
+            record(&mut away, away_points, away_won, drew, away_forfeited);
+            table.set(away_seat, away);
+        }
+
+        // Head-to-head: points taken off players finishing on the same points
+        for fixture in fixtures.iter() {
+            let (home_seat, away_seat) = (seat(&fixture.home), seat(&fixture.away));
+            let mut home = table.get_unchecked(home_seat);
+            let mut away = table.get_unchecked(away_seat);
+            if fixture.outcome == Outcome::Pending || home.points != away.points {
+                continue;
+            }
+            let (home_points, away_points) = fixture_points(setup, fixture.outcome);
+            home.head_to_head += home_points;
+            away.head_to_head += away_points;
+            table.set(home_seat, home);
+            table.set(away_seat, away);
+        }
+
+        // Stable insertion sort keeps list order among full ties
+        let mut standings: Vec<Standing> = Vec::new(&env);
+        for row in table.iter() {
+            let mut position = standings.len();
+            while position > 0 && ranks_above(&row, &standings.get_unchecked(position - 1)) {
+                position -= 1;
+            }
+            standings.insert(position, row);
+        }
+
+        Ok(standings)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    /// Record a reported result for a started fixture
+    fn finish_fixture(env: &Env, session_id: u32, outcome: Outcome) -> Result<(), Error> {
+        let (fixture_ref, mut league, mut fixtures) = Self::load_session(env, session_id)?;
+        league.setup.game_id.require_auth();
+
+        let mut fixture = fixtures.get_unchecked(fixture_ref.fixture_index);
+        if !fixture.started {
+            return Err(Error::FixtureNotStarted);
+        }
+        env.storage().temporary().remove(&DataKey::Session(session_id));
+
+        fixture.outcome = outcome;
+        fixtures.set(fixture_ref.fixture_index, fixture);
+        league.fixtures_left -= 1;
+
+        Self::save_fixtures(env, fixture_ref.league_id, &fixtures);
+        Self::save_league(env, fixture_ref.league_id, &league);
+
+        Ok(())
+    }
+
+    fn game_hub(env: &Env) -> GameHubClient<'_> {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        GameHubClient::new(env, &game_hub_addr)
+    }
+
+    fn load_session(env: &Env, session_id: u32) -> Result<(FixtureRef, League, Vec<Fixture>), Error> {
+        let fixture_ref: FixtureRef = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Session(session_id))
+            .ok_or(Error::UnknownSession)?;
+        let league = Self::load_league(env, fixture_ref.league_id)?;
+        let fixtures = Self::load_fixtures(env, fixture_ref.league_id)?;
+        Ok((fixture_ref, league, fixtures))
+    }
+
+    fn load_league(env: &Env, league_id: u32) -> Result<League, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::League(league_id))
+            .ok_or(Error::LeagueNotFound)
+    }
+
+    fn load_fixtures(env: &Env, league_id: u32) -> Result<Vec<Fixture>, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Fixtures(league_id))
+            .ok_or(Error::LeagueNotFound)
+    }
+
+    fn save_league(env: &Env, league_id: u32, league: &League) {
+        let key = DataKey::League(league_id);
+        env.storage().temporary().set(&key, league);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    fn save_fixtures(env: &Env, league_id: u32, fixtures: &Vec<Fixture>) {
+        let key = DataKey::Fixtures(league_id);
+        env.storage().temporary().set(&key, fixtures);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    pub fn get_hub(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set")
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
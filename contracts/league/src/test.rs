@@ -0,0 +1,428 @@
+#![cfg(test)]
+
+// Unit tests for the league contract using a simple mock GameHub.
+// The tests call the league's Game Hub interface directly, standing in for
+// the game contract the fixtures are played in.
+
+use crate::{Error, Fixture, LeagueContract, LeagueContractClient, LeagueSetup, Outcome};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, Vec};
+
+// ============================================================================
+// Mock GameHub for Unit Testing
+// ============================================================================
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game_draw(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn report_jackpot(_env: Env, _session_id: u32, _player: Address) -> i128 {
+        // Mock implementation - the jackpot is always 25
+        25
+    }
+
+    pub fn get_jackpot_rate(_env: Env, _game_id: Address) -> u32 {
+        // Mock implementation - the rate is always 1%
+        100
+    }
+}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+const LEAGUE_ID: u32 = 1;
+const FIRST_SESSION_ID: u32 = 100;
+const ROUND_LEDGERS: u32 = 1_000;
+
+fn setup_test() -> (Env, LeagueContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Set ledger info for time-based operations
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    // Deploy mock GameHub contract
+    let hub_addr = env.register(MockGameHub, ());
+
+    // Create admin address
+    let admin = Address::generate(&env);
+
+    // Deploy league with admin and GameHub address
+    let contract_id = env.register(LeagueContract, (&admin, &hub_addr));
+    let client = LeagueContractClient::new(&env, &contract_id);
+
+    // Stand-in for the game contract the fixtures are played in
+    let game = Address::generate(&env);
+    let organizer = Address::generate(&env);
+
+    (env, client, game, organizer)
+}
+
+fn league_setup(game: &Address, players: &Vec<Address>, points: (u32, u32, u32)) -> LeagueSetup {
+    LeagueSetup {
+        game_id: game.clone(),
+        players: players.clone(),
+        first_session_id: FIRST_SESSION_ID,
+        round_ledgers: ROUND_LEDGERS,
+        win_points: points.0,
+        draw_points: points.1,
+        loss_points: points.2,
+    }
+}
+
+fn generate_players(env: &Env, count: u32) -> Vec<Address> {
+    let mut players = Vec::new(env);
+    for _ in 0..count {
+        players.push_back(Address::generate(env));
+    }
+    players
+}
+
+/// Find the fixture between two players, in either order
+fn find_fixture(client: &LeagueContractClient, a: &Address, b: &Address) -> Fixture {
+    client
+        .get_fixtures(&LEAGUE_ID)
+        .iter()
+        .find(|f| (f.home == *a && f.away == *b) || (f.home == *b && f.away == *a))
+        .expect("fixture not found")
+}
+
+/// Play the fixture between `a` and `b`; `winner` None means a draw
+fn play(client: &LeagueContractClient, game: &Address, a: &Address, b: &Address, winner: Option<&Address>) {
+    let fixture = find_fixture(client, a, b);
+    client.start_game(game, &fixture.session_id, &fixture.home, &fixture.away, &0, &0);
+    match winner {
+        Some(winner) => client.end_game(&fixture.session_id, &(*winner == fixture.home)),
+        None => client.end_game_draw(&fixture.session_id),
+    }
+}
+
+/// Assert that a Result contains a specific league error
+fn assert_league_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        Err(Err(_invoke_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got invocation error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Err(_conv_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got conversion error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Ok(_)) => {
+            panic!(
+                "Expected error {:?} (code {}), but operation succeeded",
+                expected_error, expected_error as u32
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Fixture List Tests
+// ============================================================================
+
+#[test]
+fn test_fixture_list() {
+    let (env, client, game, organizer) = setup_test();
+
+    for count in [4u32, 5] {
+        let league_id = count;
+        let players = generate_players(&env, count);
+        let mut setup = league_setup(&game, &players, (3, 1, 0));
+        setup.first_session_id = count * 100;
+        client.create_league(&league_id, &organizer, &setup);
+
+        // Everyone meets everyone exactly once
+        let fixtures = client.get_fixtures(&league_id);
+        assert_eq!(fixtures.len(), count * (count - 1) / 2);
+        for (i, a) in players.iter().enumerate() {
+            for b in players.iter().skip(i + 1) {
+                let meetings = fixtures
+                    .iter()
+                    .filter(|f| (f.home == a && f.away == b) || (f.home == b && f.away == a))
+                    .count();
+                assert_eq!(meetings, 1);
+            }
+        }
+
+        // Nobody plays twice in a round; rounds have staggered deadlines
+        let rounds = count + count % 2 - 1;
+        for round in 1..=rounds {
+            for player in players.iter() {
+                let games = fixtures
+                    .iter()
+                    .filter(|f| f.round == round && (f.home == player || f.away == player))
+                    .count();
+                assert!(games <= 1);
+            }
+        }
+        for (i, fixture) in fixtures.iter().enumerate() {
+            assert_eq!(fixture.session_id, setup.first_session_id + i as u32);
+            assert_eq!(fixture.deadline, 100 + fixture.round * ROUND_LEDGERS);
+            assert_eq!(fixture.outcome, Outcome::Pending);
+        }
+        assert_eq!(fixtures.last().unwrap().round, rounds);
+    }
+}
+
+// ============================================================================
+// Standings Tests
+// ============================================================================
+
+#[test]
+fn test_head_to_head_tiebreak() {
+    let (env, client, game, organizer) = setup_test();
+    let players = generate_players(&env, 4);
+    let (b, a, c, d) = (
+        players.get(0).unwrap(),
+        players.get(1).unwrap(),
+        players.get(2).unwrap(),
+        players.get(3).unwrap(),
+    );
+    client.create_league(&LEAGUE_ID, &organizer, &league_setup(&game, &players, (3, 1, 0)));
+
+    play(&client, &game, &a, &b, Some(&a));
+    play(&client, &game, &a, &c, Some(&c));
+    play(&client, &game, &a, &d, Some(&a));
+    play(&client, &game, &b, &c, Some(&b));
+    play(&client, &game, &b, &d, Some(&b));
+    play(&client, &game, &c, &d, None);
+
+    assert_eq!(client.get_league(&LEAGUE_ID).fixtures_left, 0);
+
+    // A and B both have 6 points; A beat B
+    let standings = client.get_standings(&LEAGUE_ID);
+    assert_eq!(standings.get(0).unwrap().player, a);
+    assert_eq!(standings.get(1).unwrap().player, b);
+    assert_eq!(standings.get(2).unwrap().player, c);
+    assert_eq!(standings.get(3).unwrap().player, d);
+
+    let top = standings.get(0).unwrap();
+    assert_eq!((top.played, top.wins, top.draws, top.losses), (3, 2, 0, 1));
+    assert_eq!((top.points, top.head_to_head, top.differential), (6, 3, 1));
+    let third = standings.get(2).unwrap();
+    assert_eq!((third.points, third.draws), (4, 1));
+}
+
+#[test]
+fn test_differential_tiebreak() {
+    let (env, client, game, organizer) = setup_test();
+    let players = generate_players(&env, 4);
+    let (x, y, z, w) = (
+        players.get(0).unwrap(),
+        players.get(1).unwrap(),
+        players.get(2).unwrap(),
+        players.get(3).unwrap(),
+    );
+    // A loss earns as much as a draw, so points alone hide the difference
+    client.create_league(&LEAGUE_ID, &organizer, &league_setup(&game, &players, (2, 1, 1)));
+
+    play(&client, &game, &x, &y, None);
+    play(&client, &game, &x, &z, Some(&x));
+    play(&client, &game, &w, &x, Some(&w));
+    play(&client, &game, &y, &z, Some(&y));
+    play(&client, &game, &y, &w, None);
+    play(&client, &game, &z, &w, None);
+
+    // X, Y and W have 4 points. W leads on head-to-head; X and Y are level
+    // there, and Y never lost
+    let standings = client.get_standings(&LEAGUE_ID);
+    assert_eq!(standings.get(0).unwrap().player, w);
+    assert_eq!(standings.get(1).unwrap().player, y);
+    assert_eq!(standings.get(2).unwrap().player, x);
+    assert_eq!(standings.get(3).unwrap().player, z);
+
+    assert_eq!(standings.get(0).unwrap().head_to_head, 3);
+    assert_eq!(standings.get(1).unwrap().head_to_head, 2);
+    assert_eq!(standings.get(1).unwrap().differential, 1);
+    assert_eq!(standings.get(2).unwrap().head_to_head, 2);
+    assert_eq!(standings.get(2).unwrap().differential, 0);
+}
+
+// ============================================================================
+// Deadline and Forfeit Tests
+// ============================================================================
+
+#[test]
+fn test_forfeits() {
+    let (env, client, game, organizer) = setup_test();
+    let players = generate_players(&env, 4);
+    client.create_league(&LEAGUE_ID, &organizer, &league_setup(&game, &players, (3, 1, 1)));
+
+    // Round 1: fixture 0 has one check-in, fixture 1 none
+    let fixtures = client.get_fixtures(&LEAGUE_ID);
+    let shown = fixtures.get(0).unwrap();
+    let absent = fixtures.get(1).unwrap();
+    client.check_in(&LEAGUE_ID, &0, &shown.home);
+
+    let result = client.try_claim_forfeit(&LEAGUE_ID, &0);
+    assert_league_error(&result, Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number = shown.deadline + 1);
+
+    assert_eq!(client.claim_forfeit(&LEAGUE_ID, &0), Outcome::AwayForfeit);
+    assert_eq!(client.claim_forfeit(&LEAGUE_ID, &1), Outcome::DoubleForfeit);
+    assert_league_error(&client.try_claim_forfeit(&LEAGUE_ID, &0), Error::FixtureDecided);
+
+    // A forfeited fixture's session can no longer be started
+    let result = client.try_start_game(&game, &shown.session_id, &shown.home, &shown.away, &0, &0);
+    assert_league_error(&result, Error::UnknownSession);
+
+    // Round 2: both players check in but never play
+    let both = client.get_fixtures(&LEAGUE_ID).get(2).unwrap();
+    client.check_in(&LEAGUE_ID, &2, &both.home);
+    client.check_in(&LEAGUE_ID, &2, &both.away);
+    env.ledger().with_mut(|li| li.sequence_number = both.deadline + 1);
+    assert_eq!(client.claim_forfeit(&LEAGUE_ID, &2), Outcome::Draw);
+
+    // Forfeits count as losses worth nothing
+    let standings = client.get_standings(&LEAGUE_ID);
+    let row = |player: &Address| standings.iter().find(|s| s.player == *player).unwrap();
+    let winner = row(&shown.home);
+    assert_eq!(winner.wins, 1);
+    assert_eq!(winner.points, 3 + winner.draws);
+    let loser = row(&shown.away);
+    assert_eq!((loser.losses, loser.forfeits, loser.points, loser.differential), (1, 1, 0, -1));
+    let double = row(&absent.home);
+    assert_eq!(double.forfeits, 1);
+    assert_eq!(double.points, double.draws);
+    assert_eq!(client.get_league(&LEAGUE_ID).fixtures_left, 3);
+}
+
+#[test]
+fn test_fixture_checks() {
+    let (env, client, game, organizer) = setup_test();
+    let players = generate_players(&env, 3);
+    client.create_league(&LEAGUE_ID, &organizer, &league_setup(&game, &players, (3, 1, 0)));
+
+    let fixture = client.get_fixtures(&LEAGUE_ID).get(0).unwrap();
+    let session_id = fixture.session_id;
+    let (home, away) = (fixture.home.clone(), fixture.away.clone());
+
+    assert_league_error(&client.try_end_game(&session_id, &true), Error::FixtureNotStarted);
+    let result = client.try_report_jackpot(&session_id, &home);
+    assert_league_error(&result, Error::FixtureNotStarted);
+
+    let other_game = Address::generate(&env);
+    let result = client.try_start_game(&other_game, &session_id, &home, &away, &0, &0);
+    assert_league_error(&result, Error::WrongGame);
+
+    let result = client.try_start_game(&game, &session_id, &away, &home, &0, &0);
+    assert_league_error(&result, Error::WrongPlayers);
+
+    let result = client.try_start_game(&game, &session_id, &home, &away, &5, &5);
+    assert_league_error(&result, Error::StakeNotAllowed);
+
+    let outsider = Address::generate(&env);
+    assert_league_error(&client.try_check_in(&LEAGUE_ID, &0, &outsider), Error::NotPlayer);
+    assert_league_error(&client.try_check_in(&LEAGUE_ID, &9, &home), Error::FixtureNotFound);
+
+    client.start_game(&game, &session_id, &home, &away, &0, &0);
+    let result = client.try_start_game(&game, &session_id, &home, &away, &0, &0);
+    assert_league_error(&result, Error::FixtureAlreadyStarted);
+
+    // A started fixture cannot be forfeited, even past its deadline
+    env.ledger().with_mut(|li| li.sequence_number = fixture.deadline + 1);
+    assert_league_error(&client.try_claim_forfeit(&LEAGUE_ID, &0), Error::FixtureAlreadyStarted);
+
+    // Jackpots are forwarded to the hub while the fixture is live
+    assert_eq!(client.get_jackpot_rate(&game), 100);
+    assert_eq!(client.report_jackpot(&session_id, &away), 25);
+
+    // ...and its result still counts once the game ends
+    client.end_game(&session_id, &false);
+    assert_eq!(client.get_fixtures(&LEAGUE_ID).get(0).unwrap().outcome, Outcome::AwayWin);
+    assert_league_error(&client.try_end_game(&session_id, &false), Error::UnknownSession);
+    assert_league_error(&client.try_check_in(&LEAGUE_ID, &0, &home), Error::FixtureDecided);
+
+    // Later rounds close at their own deadlines
+    let late = client.get_fixtures(&LEAGUE_ID).get(2).unwrap();
+    env.ledger().with_mut(|li| li.sequence_number = late.deadline + 1);
+    let result = client.try_start_game(&game, &late.session_id, &late.home, &late.away, &0, &0);
+    assert_league_error(&result, Error::DeadlinePassed);
+    assert_league_error(&client.try_check_in(&LEAGUE_ID, &2, &late.home), Error::DeadlinePassed);
+}
+
+// ============================================================================
+// League Setup Tests
+// ============================================================================
+
+#[test]
+fn test_create_checks() {
+    let (env, client, game, organizer) = setup_test();
+    let players = generate_players(&env, 3);
+
+    let single = vec![&env, players.get(0).unwrap()];
+    let result = client.try_create_league(&LEAGUE_ID, &organizer, &league_setup(&game, &single, (3, 1, 0)));
+    assert_league_error(&result, Error::InvalidPlayers);
+
+    let mut duplicate = players.clone();
+    duplicate.push_back(players.get(1).unwrap());
+    let result = client.try_create_league(&LEAGUE_ID, &organizer, &league_setup(&game, &duplicate, (3, 1, 0)));
+    assert_league_error(&result, Error::InvalidPlayers);
+
+    let result = client.try_create_league(&LEAGUE_ID, &organizer, &league_setup(&game, &players, (1, 3, 0)));
+    assert_league_error(&result, Error::InvalidConfig);
+
+    let mut no_rounds = league_setup(&game, &players, (3, 1, 0));
+    no_rounds.round_ledgers = 0;
+    let result = client.try_create_league(&LEAGUE_ID, &organizer, &no_rounds);
+    assert_league_error(&result, Error::InvalidConfig);
+
+    client.create_league(&LEAGUE_ID, &organizer, &league_setup(&game, &players, (3, 1, 0)));
+    let result = client.try_create_league(&LEAGUE_ID, &organizer, &league_setup(&game, &players, (3, 1, 0)));
+    assert_league_error(&result, Error::LeagueExists);
+
+    // A second league cannot reuse the first league's session IDs
+    let result = client.try_create_league(&2, &organizer, &league_setup(&game, &players, (3, 1, 0)));
+    assert_league_error(&result, Error::InvalidConfig);
+
+    assert_league_error(&client.try_get_league(&2), Error::LeagueNotFound);
+    let fixture_ref = client.get_session_fixture(&(FIRST_SESSION_ID + 1));
+    assert_eq!((fixture_ref.league_id, fixture_ref.fixture_index), (LEAGUE_ID, 1));
+}
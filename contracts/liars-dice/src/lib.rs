@@ -0,0 +1,787 @@
+#![no_std]
+
+//! # Liar's Dice
+//!
+//! A two-player bluffing game. Each player holds five hidden six-sided dice.
+//! Players take turns bidding that at least `quantity` dice on the table show
+//! `face`, each bid raising the last one, until a player calls "liar" on the
+//! opponent's bid. Both hands are then revealed: if the bid holds the bidder
+//! wins, otherwise the challenger wins.
+//!
+//! Dice stay hidden through hash commitments. Each player commits
+//! `keccak256(secret)` and `keccak256(cut)` for two random 32-byte values.
+//! Once both have committed, each reveals their cut. A player's dice are
+//! derived from their secret, both cuts and the session ID, so they can be
+//! computed off-chain by the owner but not by the opponent, and nobody can
+//! pick values for the roll they want: everything is committed before any
+//! cut is known. The secrets are revealed (and checked against the
+//! commitments) once "liar" is called.
+//!
+//! If neither player commits in time, either can end the session as a draw.
+//!
+//! **Game Hub Integration:**
+//! This game is Game Hub-aware and enforces all games to be played through the
+//! Game Hub contract. Games cannot be started or completed without points involvement.
+
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contractclient, contracterror,
+    contractimpl, contracttype, vec
+};
+
+// Import GameHub contract interface
+// This allows us to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(
+        env: Env,
+        session_id: u32,
+        player1_won: bool
+    );
+
+    fn end_game_draw(env: Env, session_id: u32);
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    GameNotFound = 1,
+    NotPlayer = 2,
+    AlreadyCommitted = 3,
+    WrongPhase = 4,
+    GameAlreadyEnded = 5,
+    NotYourTurn = 6,
+    InvalidBid = 7,
+    NoBidToChallenge = 8,
+    AlreadyRevealed = 9,
+    InvalidReveal = 10,
+    TimeoutNotReached = 11,
+    CannotClaimTimeout = 12,
+    SelfPlay = 13,
+    AlreadyCut = 14,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Phase {
+    /// Waiting for both players to commit their dice
+    Commit,
+    /// Waiting for both players to reveal their cuts
+    Cut,
+    /// Players alternate bids until one calls "liar"
+    Bidding,
+    /// "Liar" was called; waiting for both secrets
+    Reveal,
+    /// Winner decided and reported to the Game Hub, or nobody committed
+    Ended,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub phase: Phase,
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    pub player1_cut_commitment: Option<BytesN<32>>,
+    pub player2_cut_commitment: Option<BytesN<32>>,
+    /// Revealed cuts, mixed into both players' dice
+    pub player1_cut: Option<BytesN<32>>,
+    pub player2_cut: Option<BytesN<32>>,
+    /// Revealed dice, empty until the player reveals their secret
+    pub player1_dice: Vec<u32>,
+    pub player2_dice: Vec<u32>,
+    /// Player who made the current bid, None until the first bid
+    pub bidder: Option<Address>,
+    /// Current bid: at least `bid_quantity` dice across both hands show `bid_face`
+    pub bid_quantity: u32,
+    pub bid_face: u32,
+    /// Player expected to bid or call "liar" next
+    pub next_bidder: Address,
+    pub challenger: Option<Address>,
+    /// Ledger after which the stalled player can be forfeited
+    pub deadline: u32,
+    /// None while playing, and after a session nobody committed to
+    pub winner: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Game(u32),
+    GameHubAddress,
+    Admin,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) ensures game data doesn't expire unexpectedly
+// Games are stored in temporary storage with a minimum 30-day retention
+
+/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Ledgers a player has to act before the opponent may claim the game
+/// 1 day = 24 * 60 * 60 / 5 = 17,280 ledgers
+const MOVE_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Dice held by each player
+const DICE_PER_PLAYER: u32 = 5;
+
+/// Faces on each die
+const DIE_FACES: u32 = 6;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Roll a single die (1-6) using deterministic PRNG
+fn roll_die(env: &Env, seed: BytesN<32>) -> u32 {
+    env.prng().seed(seed.into());
+    env.prng().gen_range::<u64>(1..=DIE_FACES as u64) as u32
+}
+
+/// Derive a player's hidden dice.
+/// Seed components: the player's secret, both players' cuts (revealed only
+/// after every secret and cut is committed), the session ID and the die index.
+fn derive_dice(
+    env: &Env,
+    session_id: u32,
+    secret: &BytesN<32>,
+    player1_cut: &BytesN<32>,
+    player2_cut: &BytesN<32>,
+) -> Vec<u32> {
+    let mut base_bytes = Bytes::from(secret.clone());
+    base_bytes.append(&Bytes::from(player1_cut.clone()));
+    base_bytes.append(&Bytes::from(player2_cut.clone()));
+    base_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    let base_seed = env.crypto().keccak256(&base_bytes);
+
+    let mut dice = Vec::new(env);
+    for die in 0..DICE_PER_PLAYER {
+        let mut roll_seed_bytes = Bytes::from(base_seed.clone());
+        roll_seed_bytes.push_back(die as u8);
+        dice.push_back(roll_die(env, env.crypto().keccak256(&roll_seed_bytes).into()));
+    }
+    dice
+}
+
+/// Count dice showing `face` in a hand
+fn count_face(dice: &Vec<u32>, face: u32) -> u32 {
+    dice.iter().filter(|die| *die == face).count() as u32
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct LiarsDiceContract;
+
+#[contractimpl]
+impl LiarsDiceContract {
+    /// Initialize the contract with GameHub address and admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    /// * `game_hub` - Address of the GameHub contract
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        // Store admin and GameHub address
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &game_hub);
+    }
+
+    /// Start a new game between two players with points.
+    /// This creates a session in the Game Hub and locks points before starting the game.
+    ///
+    /// **CRITICAL:** This method requires authorization from THIS contract (not players).
+    /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points amount committed by player 1
+    /// * `player2_points` - Points amount committed by player 2
+    pub fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            return Err(Error::SelfPlay);
+        }
+
+        // Require authentication from both players (they consent to committing points)
+        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Create GameHub client
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        // Create game (dice are committed next, player 1 bids first)
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            phase: Phase::Commit,
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_cut_commitment: None,
+            player2_cut_commitment: None,
+            player1_cut: None,
+            player2_cut: None,
+            player1_dice: Vec::new(&env),
+            player2_dice: Vec::new(&env),
+            bidder: None,
+            bid_quantity: 0,
+            bid_face: 0,
+            next_bidder: player1,
+            challenger: None,
+            deadline: env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS,
+            winner: None,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        Self::save_game(&env, session_id, &game);
+
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
+    }
+
+    /// Commit the player's hidden dice as `keccak256(secret)`, together with
+    /// `keccak256(cut)` for the cut mixed into both hands.
+    /// Cuts are revealed once both players have committed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the committing player
+    /// * `commitment` - keccak256 hash of the player's 32-byte secret
+    /// * `cut_commitment` - keccak256 hash of the player's 32-byte cut
+    pub fn commit_dice(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+        cut_commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Commit)?;
+
+        if player == game.player1 {
+            if game.player1_commitment.is_some() {
+                return Err(Error::AlreadyCommitted);
+            }
+            game.player1_commitment = Some(commitment);
+            game.player1_cut_commitment = Some(cut_commitment);
+        } else if player == game.player2 {
+            if game.player2_commitment.is_some() {
+                return Err(Error::AlreadyCommitted);
+            }
+            game.player2_commitment = Some(commitment);
+            game.player2_cut_commitment = Some(cut_commitment);
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        if game.player1_commitment.is_some() && game.player2_commitment.is_some() {
+            game.phase = Phase::Cut;
+        }
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the player's cut; it must hash to their cut commitment. Once
+    /// both cuts are revealed, each player can work out their own dice and
+    /// bidding opens.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the cutting player
+    /// * `cut` - The 32-byte value behind the player's cut commitment
+    pub fn cut_dice(env: Env, session_id: u32, player: Address, cut: BytesN<32>) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Cut)?;
+
+        let (cut_commitment, revealed) = if player == game.player1 {
+            (&game.player1_cut_commitment, &mut game.player1_cut)
+        } else if player == game.player2 {
+            (&game.player2_cut_commitment, &mut game.player2_cut)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if revealed.is_some() {
+            return Err(Error::AlreadyCut);
+        }
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(cut.clone())).into();
+        if Some(hash) != *cut_commitment {
+            return Err(Error::InvalidReveal);
+        }
+        *revealed = Some(cut);
+
+        if game.player1_cut.is_some() && game.player2_cut.is_some() {
+            game.phase = Phase::Bidding;
+        }
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Bid that at least `quantity` dice across both hands show `face`.
+    /// A bid must raise the current one: a higher quantity, or the same
+    /// quantity with a higher face.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the bidding player (must be their turn)
+    /// * `quantity` - Claimed number of dice (1-10)
+    /// * `face` - Claimed face value (1-6)
+    pub fn bid(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        quantity: u32,
+        face: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Bidding)?;
+        Self::require_turn(&game, &player)?;
+
+        if quantity == 0 || quantity > DICE_PER_PLAYER * 2 || face == 0 || face > DIE_FACES {
+            return Err(Error::InvalidBid);
+        }
+        let raises = quantity > game.bid_quantity
+            || (quantity == game.bid_quantity && face > game.bid_face);
+        if !raises {
+            return Err(Error::InvalidBid);
+        }
+
+        game.bidder = Some(player.clone());
+        game.bid_quantity = quantity;
+        game.bid_face = face;
+        game.next_bidder = Self::opponent(&game, &player);
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Call "liar" on the opponent's current bid.
+    /// Moves the game to the reveal phase, where both players must reveal
+    /// their secrets.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the challenging player (must be their turn)
+    pub fn call_liar(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Bidding)?;
+        Self::require_turn(&game, &player)?;
+
+        if game.bidder.is_none() {
+            return Err(Error::NoBidToChallenge);
+        }
+
+        game.challenger = Some(player);
+        game.phase = Phase::Reveal;
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the player's secret after "liar" was called.
+    /// The secret must hash to the player's commitment. Once both players have
+    /// revealed, the bid is checked and the result is reported to the Game Hub.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `secret` - The 32-byte secret behind the player's commitment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Some(winner) once both hands are revealed, None otherwise
+    pub fn reveal_dice(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        secret: BytesN<32>,
+    ) -> Result<Option<Address>, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Reveal)?;
+
+        let own = if player == game.player1 {
+            game.player1_commitment.clone()
+        } else if player == game.player2 {
+            game.player2_commitment.clone()
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        let player1_cut = game.player1_cut.clone().ok_or(Error::WrongPhase)?;
+        let player2_cut = game.player2_cut.clone().ok_or(Error::WrongPhase)?;
+
+        let already_revealed = if player == game.player1 {
+            !game.player1_dice.is_empty()
+        } else {
+            !game.player2_dice.is_empty()
+        };
+        if already_revealed {
+            return Err(Error::AlreadyRevealed);
+        }
+
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(secret.clone())).into();
+        if Some(hash) != own {
+            return Err(Error::InvalidReveal);
+        }
+
+        let dice = derive_dice(&env, session_id, &secret, &player1_cut, &player2_cut);
+        if player == game.player1 {
+            game.player1_dice = dice;
+        } else {
+            game.player2_dice = dice;
+        }
+
+        if game.player1_dice.is_empty() || game.player2_dice.is_empty() {
+            game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+            Self::save_game(&env, session_id, &game);
+            return Ok(None);
+        }
+
+        // Both hands are known: the bid holds if enough dice show its face
+        let bidder = game.bidder.clone().ok_or(Error::NoBidToChallenge)?;
+        let total = count_face(&game.player1_dice, game.bid_face)
+            + count_face(&game.player2_dice, game.bid_face);
+        let winner = if total >= game.bid_quantity {
+            bidder
+        } else {
+            game.challenger.clone().ok_or(Error::NoBidToChallenge)?
+        };
+
+        Self::finish_game(&env, session_id, &mut game, winner.clone());
+
+        Ok(Some(winner))
+    }
+
+    /// Win the game because the opponent stopped acting.
+    /// After the deadline passes, the player who is not holding up the game
+    /// (the one who committed, cut, is waiting on a bid, or has revealed) can
+    /// claim it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the claiming player
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player (the claimant)
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        if game.phase == Phase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        let is_player1 = player == game.player1;
+        let can_claim = match game.phase {
+            Phase::Commit => {
+                let (own, opponent) = if is_player1 {
+                    (&game.player1_commitment, &game.player2_commitment)
+                } else {
+                    (&game.player2_commitment, &game.player1_commitment)
+                };
+                own.is_some() && opponent.is_none()
+            }
+            Phase::Cut => {
+                let (own, opponent) = if is_player1 {
+                    (&game.player1_cut, &game.player2_cut)
+                } else {
+                    (&game.player2_cut, &game.player1_cut)
+                };
+                own.is_some() && opponent.is_none()
+            }
+            Phase::Bidding => game.next_bidder != player,
+            Phase::Reveal => {
+                let (own, opponent) = if is_player1 {
+                    (&game.player1_dice, &game.player2_dice)
+                } else {
+                    (&game.player2_dice, &game.player1_dice)
+                };
+                !own.is_empty() && opponent.is_empty()
+            }
+            Phase::Ended => false,
+        };
+        if !can_claim {
+            return Err(Error::CannotClaimTimeout);
+        }
+
+        Self::finish_game(&env, session_id, &mut game, player.clone());
+
+        Ok(player)
+    }
+
+    /// End a session neither player committed to before the deadline as a
+    /// draw, so the Game Hub returns both stakes. Either player can call it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the calling player
+    pub fn abandon_game(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Commit)?;
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+        // A player who committed wins by `claim_timeout` instead
+        if game.player1_commitment.is_some() || game.player2_commitment.is_some() {
+            return Err(Error::AlreadyCommitted);
+        }
+
+        game.phase = Phase::Ended;
+        Self::save_game(&env, session_id, &game);
+
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        GameHubClient::new(&env, &game_hub_addr).end_game_draw(&session_id);
+
+        Ok(())
+    }
+
+    /// Get game information.
+    /// Dice stay empty until revealed; only the commitments are public before then.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Game` - The game state
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        Self::load_game(&env, session_id)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    fn load_game(env: &Env, session_id: u32) -> Result<Game, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    fn save_game(env: &Env, session_id: u32, game: &Game) {
+        let key = DataKey::Game(session_id);
+        env.storage().temporary().set(&key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    fn require_phase(game: &Game, phase: Phase) -> Result<(), Error> {
+        if game.phase == Phase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.phase != phase {
+            return Err(Error::WrongPhase);
+        }
+        Ok(())
+    }
+
+    fn require_turn(game: &Game, player: &Address) -> Result<(), Error> {
+        if *player != game.player1 && *player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if *player != game.next_bidder {
+            return Err(Error::NotYourTurn);
+        }
+        Ok(())
+    }
+
+    fn opponent(game: &Game, player: &Address) -> Address {
+        if *player == game.player1 {
+            game.player2.clone()
+        } else {
+            game.player1.clone()
+        }
+    }
+
+    /// Record the winner and end the session with the Game Hub
+    fn finish_game(env: &Env, session_id: u32, game: &mut Game, winner: Address) {
+        game.phase = Phase::Ended;
+        game.winner = Some(winner.clone());
+        Self::save_game(env, session_id, game);
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Call GameHub to end the session
+        // This unlocks points and updates standings
+        // Event emitted by the Game Hub contract (GameEnded)
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        game_hub.end_game(&session_id, &(winner == game.player1));
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    pub fn get_hub(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set")
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
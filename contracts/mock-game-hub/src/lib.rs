@@ -1,14 +1,298 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractevent, contractimpl, contracttype, token, Address, Env, Vec};
 
-/// Mock Game Hub contract for game studio development
-///
-/// This contract provides the same external interface that games expect
-/// (start_game, end_game) but does nothing internally. It exists purely
-/// for game contracts to compile and integrate during development.
-#[contract]
-pub struct MockGameHub;
+/// Fewest participants in a multi-player session
+pub const MIN_PLAYERS: u32 = 2;
+
+/// Most participants in a multi-player session
+pub const MAX_PLAYERS: u32 = 8;
+
+/// Most members on one side of a team session
+pub const MAX_TEAM_SIZE: u32 = 4;
+
+/// TTL for open sessions, kept until they end (30 days)
+const SESSION_TTL_LEDGERS: u32 = 518_400;
+
+/// Ledgers after which anyone can refund a token session that never ended (~7 days)
+pub const SESSION_TIMEOUT_LEDGERS: u32 = 120_960;
+
+/// Basis points in 100%, the largest rake
+pub const MAX_RAKE_BP: u32 = 10_000;
+
+/// Odds of 1:1 in basis points, the shortest `FixedOdds` allowed
+pub const EVEN_ODDS_BP: u32 = 10_000;
+
+/// TTL for standings, bumped whenever they change (~180 days)
+const STATS_TTL_LEDGERS: u32 = 3_110_400;
+
+/// Entries kept on each season leaderboard
+pub const LEADERBOARD_SIZE: u32 = 10;
+
+/// Rating every player starts with in every game (and every season)
+pub const INITIAL_RATING: u32 = 1500;
+
+/// Largest rating change from one session (ELO K-factor)
+pub const K_FACTOR: i64 = 32;
+
+/// Expected score of the higher-rated side in basis points for rating
+/// differences 0, 25, ..., 800: 10000 / (1 + 10^(-diff / 400))
+const EXPECTED_SCORE_BP: [i64; 33] = [
+    5000, 5359, 5715, 6063, 6401, 6725, 7034, 7325, 7597, 7850, 8083, 8296, 8490, 8666, 8823,
+    8965, 9091, 9203, 9302, 9390, 9468, 9536, 9595, 9648, 9693, 9733, 9768, 9799, 9825, 9848,
+    9868, 9886, 9901,
+];
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+/// An open session, kept so results can be settled and recorded at the end
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    /// Points staked by each player, same order as `players`
+    pub points: Vec<i128>,
+    /// For team sessions, the first `team1_size` players are team 1; 0 otherwise
+    pub team1_size: u32,
+    /// Token escrowing the stakes, None for plain points
+    pub token: Option<Address>,
+    /// Ledger the session started at, for `refund_expired`
+    pub start_ledger: u32,
+    /// How the losing stakes are paid to the winners
+    pub policy: PayoutPolicy,
+}
+
+/// What the winners of a session receive when the stakes are uneven. The
+/// losers' stakes are the pot; any part of it the winners do not win is
+/// returned to the losers pro rata.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PayoutPolicy {
+    /// The winners take the whole pot, however little they staked
+    WinnerTakesAll,
+    /// Winnings are capped at the winners' own stake, so each side wins in
+    /// proportion to what it risked
+    Proportional,
+    /// The side with the smaller stake (the underdog) is matched at these
+    /// odds, in basis points (30000 = 3:1): the underdog wins up to its stake
+    /// times the odds, the favourite up to its stake divided by the odds.
+    /// Even stakes settle 1:1.
+    FixedOdds(u32),
+}
+
+/// A player's record, either for one game or across all games
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub games_played: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    /// Points won minus points lost
+    pub net_points: i128,
+    /// Positive for consecutive wins, negative for consecutive losses, 0 after a draw
+    pub current_streak: i32,
+}
+
+/// Aggregate activity, either for one game or for the whole hub
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameTotals {
+    pub sessions_started: u32,
+    pub sessions_ended: u32,
+    pub draws: u32,
+    /// Sum of all stakes in started sessions
+    pub points_wagered: i128,
+    /// Sum of all house fees taken at settlement
+    pub fees_collected: i128,
+}
+
+/// House rake on a game's settlements. The fee comes out of the pot the
+/// winners split (the losers' stakes they win), so draws pay nothing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Share of the pot taken, in basis points (100 = 1%)
+    pub rake_bp: u32,
+    /// Smallest fee on a non-empty pot (never more than the pot itself)
+    pub min_fee: i128,
+    /// Largest fee on any pot
+    pub max_fee: i128,
+}
+
+/// A competitive season. Results are recorded for the season that is active
+/// when a session ends.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Season {
+    pub start_ledger: u32,
+    /// None while the season is running
+    pub end_ledger: Option<u32>,
+    /// Games that finished at least one session this season
+    pub games: Vec<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub player: Address,
+    pub rating: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    /// Id of the running season, absent between seasons
+    CurrentSeason,
+    /// Number of seasons ever started; season ids are 1..=SeasonCount
+    SeasonCount,
+    Season(u32),
+    /// Record of a player in one game during a season: (season, player, game_id)
+    SeasonStats(u32, Address, Address),
+    /// Season rating of a player in one game: (season, player, game_id)
+    SeasonRating(u32, Address, Address),
+    /// Top `LEADERBOARD_SIZE` season ratings in one game: (season, game_id)
+    Leaderboard(u32, Address),
+    /// Everyone rated in one game during a season, in the order they were
+    /// first rated: (season, game_id)
+    SeasonPlayers(u32, Address),
+    Session(u32),
+    /// Token session refunded by `refund_expired` that its game has not
+    /// ended yet: session_id -> game_id
+    Refunded(u32),
+    /// Token a game's sessions are staked in: game_id -> token
+    GameToken(Address),
+    /// Rake on a game's settlements: game_id -> FeeConfig
+    FeeConfig(Address),
+    /// Where withdrawn fees go (the admin if unset)
+    Treasury,
+    /// Fees held in escrow for withdrawal, per token
+    AccruedFees(Address),
+    /// Fees taken from plain-points sessions
+    AccruedPoints,
+    /// Share of each pot diverted to a game's jackpot, in basis points
+    JackpotRate(Address),
+    /// A game's jackpot, in the game's token (or points)
+    Jackpot(Address),
+    /// Payout policy new sessions of a game start with: game_id -> PayoutPolicy
+    PayoutPolicy(Address),
+    /// Record of a player in one game: (player, game_id)
+    PlayerStats(Address, Address),
+    /// Record of a player across all games
+    PlayerTotals(Address),
+    /// Skill rating of a player in one game: (player, game_id)
+    Rating(Address, Address),
+    GameTotals(Address),
+    HubTotals,
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Split `pot` between stakes pro rata. Integer division rounds down; the
+/// remainder goes to the first member so the shares always add up to `pot`.
+pub fn split_pro_rata(env: &Env, stakes: &Vec<i128>, pot: i128) -> Vec<i128> {
+    let total: i128 = stakes.iter().sum();
+    let mut shares = Vec::new(env);
+    if total == 0 {
+        // Nobody staked anything: share equally
+        let count = stakes.len() as i128;
+        for _ in 0..stakes.len() {
+            shares.push_back(pot / count);
+        }
+    } else {
+        for stake in stakes.iter() {
+            shares.push_back(pot * stake / total);
+        }
+    }
+    let paid: i128 = shares.iter().sum();
+    if let Some(first) = shares.first() {
+        shares.set(0, first + pot - paid);
+    }
+    shares
+}
+
+/// Expected score (basis points) of a player rated `diff` points above their
+/// opponent. Interpolates linearly between table entries; differences beyond
+/// 800 points are clamped.
+pub fn expected_score_bp(diff: i64) -> i64 {
+    let distance = diff.abs().min(800);
+    let index = (distance / 25) as usize;
+    let low = EXPECTED_SCORE_BP[index];
+    let high = EXPECTED_SCORE_BP.get(index + 1).copied().unwrap_or(low);
+    let expected = low + (high - low) * (distance % 25) / 25;
+    if diff >= 0 {
+        expected
+    } else {
+        10_000 - expected
+    }
+}
+
+/// Fee on a pot under `config`: the rake clamped to the min/max caps, and
+/// never more than the pot
+pub fn rake_fee(config: &FeeConfig, pot: i128) -> i128 {
+    if pot <= 0 {
+        return 0;
+    }
+    let rake = pot * config.rake_bp as i128 / MAX_RAKE_BP as i128;
+    rake.clamp(config.min_fee, config.max_fee).min(pot)
+}
+
+/// Part of `pot` (the losers' stakes) won by winners who staked `staked`
+/// between them under `policy`
+pub fn winnings(policy: &PayoutPolicy, staked: i128, pot: i128) -> i128 {
+    let matched = match policy {
+        PayoutPolicy::WinnerTakesAll => pot,
+        PayoutPolicy::Proportional => staked,
+        PayoutPolicy::FixedOdds(odds_bp) => {
+            let odds_bp = *odds_bp as i128;
+            if staked < pot {
+                staked * odds_bp / EVEN_ODDS_BP as i128
+            } else if staked > pot {
+                staked * EVEN_ODDS_BP as i128 / odds_bp
+            } else {
+                pot
+            }
+        }
+    };
+    matched.clamp(0, pot.max(0))
+}
+
+/// Divide by 10,000, rounding half away from zero
+fn round_bp(value: i64) -> i64 {
+    if value >= 0 {
+        (value + 5_000) / 10_000
+    } else {
+        (value - 5_000) / 10_000
+    }
+}
+
+impl PlayerStats {
+    /// Add one finished session. `net` is negative for a loss, 0 for a draw.
+    fn record(&mut self, won: bool, draw: bool, net: i128) {
+        self.games_played += 1;
+        self.net_points += net;
+        if draw {
+            self.draws += 1;
+            self.current_streak = 0;
+        } else if won {
+            self.wins += 1;
+            self.current_streak = self.current_streak.max(0) + 1;
+        } else {
+            self.losses += 1;
+            self.current_streak = self.current_streak.min(0) - 1;
+        }
+    }
+}
+
+// ============================================================================
+// Events
+// ============================================================================
 
 #[contractevent]
 pub struct GameStarted {
@@ -26,8 +310,130 @@ pub struct GameEnded {
     pub player1_won: bool,
 }
 
+#[contractevent]
+pub struct GameDrawn {
+    pub session_id: u32,
+}
+
+#[contractevent]
+pub struct MultiGameStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub points: Vec<i128>,
+}
+
+#[contractevent]
+pub struct MultiGameEnded {
+    pub session_id: u32,
+    /// Place of each player, in the order they were passed to `start_multi_game`
+    pub places: Vec<u32>,
+}
+
+#[contractevent]
+pub struct TeamGameStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub team1: Vec<Address>,
+    pub team2: Vec<Address>,
+}
+
+#[contractevent]
+pub struct TeamGameEnded {
+    pub session_id: u32,
+    pub team1_won: bool,
+    /// Members of the winning team
+    pub winners: Vec<Address>,
+    /// What each winner receives: their stake plus a pro rata share of the
+    /// losing team's stakes, same order as `winners`
+    pub payouts: Vec<i128>,
+}
+
+#[contractevent]
+pub struct StakeAdded {
+    pub session_id: u32,
+    pub player: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct SessionRefunded {
+    pub session_id: u32,
+    /// True if refunded by `refund_expired`, false if cancelled by the game
+    pub expired: bool,
+}
+
+#[contractevent]
+pub struct FeeCollected {
+    #[topic]
+    pub game_id: Address,
+    pub session_id: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct FeesWithdrawn {
+    pub treasury: Address,
+    /// None for plain points
+    pub token: Option<Address>,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct JackpotWon {
+    #[topic]
+    pub game_id: Address,
+    pub session_id: u32,
+    pub player: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct SeasonStarted {
+    pub season_id: u32,
+}
+
+#[contractevent]
+pub struct SeasonEnded {
+    pub season_id: u32,
+}
+
+#[contractevent]
+pub struct RatingChanged {
+    #[topic]
+    pub game_id: Address,
+    #[topic]
+    pub player: Address,
+    pub old_rating: u32,
+    pub new_rating: u32,
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+/// Mock Game Hub contract for game studio development
+///
+/// This contract provides the same external interface that games expect
+/// (start_game, end_game, and the multi-player and team variants). Points are
+/// abstract unless the admin gives a game a token, in which case stakes are
+/// escrowed in that token and paid out when the session ends. It remembers
+/// open sessions so it can report how winnings are split and keep per-player
+/// standings. It exists for game contracts to compile and integrate during
+/// development.
+#[contract]
+pub struct MockGameHub;
+
 #[contractimpl]
 impl MockGameHub {
+    /// Initialize the hub with its admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (starts and ends seasons)
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
     /// Start a game session
     ///
     /// # Arguments
@@ -35,8 +441,8 @@ impl MockGameHub {
     /// * `session_id` - Unique identifier for this game session
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
-    /// * `player1_points` - Points amount for player 1 (ignored in mock)
-    /// * `player2_points` - Points amount for player 2 (ignored in mock)
+    /// * `player1_points` - Points amount for player 1
+    /// * `player2_points` - Points amount for player 2
     pub fn start_game(
         env: Env,
         game_id: Address,
@@ -46,18 +452,26 @@ impl MockGameHub {
         player1_points: i128,
         player2_points: i128,
     ) {
-        // No auth required for mock
+        game_id.require_auth();
+
         GameStarted {
             session_id,
-            game_id,
-            player1,
-            player2,
+            game_id: game_id.clone(),
+            player1: player1.clone(),
+            player2: player2.clone(),
             player1_points,
             player2_points,
         }
         .publish(&env);
-        // bump instance ttl if required
-        env.storage().instance().extend_ttl(17_280, 518_400);
+
+        Self::open_session(
+            &env,
+            session_id,
+            game_id,
+            Vec::from_array(&env, [player1, player2]),
+            Vec::from_array(&env, [player1_points, player2_points]),
+            0,
+        );
     }
 
     /// End a game session and declare winner
@@ -70,28 +484,1165 @@ impl MockGameHub {
         session_id: u32,
         player1_won: bool,
     ) {
-        // No auth required for mock
+        let Some(session) = Self::take_ended_session(&env, session_id) else {
+            return;
+        };
+        if session.players.len() != 2 || session.team1_size != 0 {
+            panic!("Not a two-player session");
+        }
+        let places = if player1_won { [1, 2] } else { [2, 1] };
+        Self::settle(&env, session_id, &session, &Vec::from_array(&env, places));
+
         GameEnded {
             session_id,
             player1_won,
         }
         .publish(&env);
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
-    #[test]
-    fn test_start_and_end_game() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
-        let client = MockGameHubClient::new(&env, &contract_id);
-        let game_id = Address::generate(&env);
-        let player1 = Address::generate(&env);
-        let player2 = Address::generate(&env);
-        client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
-        client.end_game(&1, &true);
+    /// End a game session as a draw (every player gets their points back)
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    pub fn end_game_draw(env: Env, session_id: u32) {
+        let Some(session) = Self::take_ended_session(&env, session_id) else {
+            return;
+        };
+        let mut all_first = Vec::new(&env);
+        for _ in session.players.iter() {
+            all_first.push_back(1);
+        }
+        Self::settle(&env, session_id, &session, &all_first);
+
+        GameDrawn { session_id }.publish(&env);
+    }
+
+    /// Start a session with 2..=8 participants, each with their own stake
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - Unique identifier for this game session
+    /// * `players` - Participant addresses, all different
+    /// * `points` - Points amount for each player, same order as `players`
+    pub fn start_multi_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+    ) {
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players.len()) {
+            panic!("A multi-player session needs between 2 and 8 players");
+        }
+        if points.len() != players.len() {
+            panic!("Each player needs exactly one points amount");
+        }
+
+        game_id.require_auth();
+
+        MultiGameStarted {
+            session_id,
+            game_id: game_id.clone(),
+            players: players.clone(),
+            points: points.clone(),
+        }
+        .publish(&env);
+
+        Self::open_session(&env, session_id, game_id, players, points, 0);
+    }
+
+    /// End a multi-player session with a ranking
+    ///
+    /// Players sharing place 1 are the winners and split the other players'
+    /// stakes pro rata; if every player has place 1 the session is a draw.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `places` - Place of each player (1 = first, ties share a place), same
+    ///   order as `start_multi_game`'s `players`
+    pub fn end_multi_game(env: Env, session_id: u32, places: Vec<u32>) {
+        if !places.contains(1) {
+            panic!("At least one player must finish first");
+        }
+        let Some(session) = Self::take_ended_session(&env, session_id) else {
+            return;
+        };
+        if places.len() != session.players.len() {
+            panic!("Each player needs exactly one place");
+        }
+        if places.iter().any(|place| place == 0 || place > session.players.len()) {
+            panic!("Places run from 1 to the number of players");
+        }
+
+        Self::settle(&env, session_id, &session, &places);
+
+        MultiGameEnded { session_id, places }.publish(&env);
+    }
+
+    /// Start a team session: two sides of 1..=4 members, each with their own stake
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - Unique identifier for this game session
+    /// * `team1` - Members of the first team
+    /// * `team1_points` - Points amount for each member of team 1, same order
+    /// * `team2` - Members of the second team
+    /// * `team2_points` - Points amount for each member of team 2, same order
+    pub fn start_team_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        team1: Vec<Address>,
+        team1_points: Vec<i128>,
+        team2: Vec<Address>,
+        team2_points: Vec<i128>,
+    ) {
+        for (team, points) in [(&team1, &team1_points), (&team2, &team2_points)] {
+            if !(1..=MAX_TEAM_SIZE).contains(&team.len()) {
+                panic!("A team needs between 1 and 4 members");
+            }
+            if points.len() != team.len() {
+                panic!("Each team member needs exactly one points amount");
+            }
+        }
+        for member in team1.iter() {
+            if team2.contains(&member) {
+                panic!("A player cannot be on both teams");
+            }
+        }
+
+        game_id.require_auth();
+
+        TeamGameStarted {
+            session_id,
+            game_id: game_id.clone(),
+            team1: team1.clone(),
+            team2: team2.clone(),
+        }
+        .publish(&env);
+
+        let team1_size = team1.len();
+        let mut players = team1;
+        players.append(&team2);
+        let mut points = team1_points;
+        points.append(&team2_points);
+
+        Self::open_session(&env, session_id, game_id, players, points, team1_size);
+    }
+
+    /// End a team session and declare the winning team.
+    /// The losing team's stakes are split among the winners pro rata to what
+    /// each winner staked.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `team1_won` - True if team 1 won, false if team 2 won
+    pub fn end_team_game(env: Env, session_id: u32, team1_won: bool) {
+        let Some(session) = Self::take_ended_session(&env, session_id) else {
+            return;
+        };
+        if session.team1_size == 0 {
+            panic!("Not a team session");
+        }
+
+        let mut places = Vec::new(&env);
+        for index in 0..session.players.len() {
+            places.push_back(if (index < session.team1_size) == team1_won { 1 } else { 2 });
+        }
+        let payouts = Self::settle(&env, session_id, &session, &places);
+
+        let mut winners = Vec::new(&env);
+        let mut winner_payouts = Vec::new(&env);
+        for ((player, place), payout) in session.players.iter().zip(places.iter()).zip(payouts.iter()) {
+            if place == 1 {
+                winners.push_back(player);
+                winner_payouts.push_back(payout);
+            }
+        }
+
+        TeamGameEnded {
+            session_id,
+            team1_won,
+            winners,
+            payouts: winner_payouts,
+        }
+        .publish(&env);
+    }
+
+    /// Cancel an open session without a result. Every stake is refunded and
+    /// nothing is recorded in the standings.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being cancelled
+    pub fn cancel_session(env: Env, session_id: u32) {
+        let Some(session) = Self::take_ended_session(&env, session_id) else {
+            return;
+        };
+        Self::refund(&env, &session);
+
+        SessionRefunded {
+            session_id,
+            expired: false,
+        }
+        .publish(&env);
+    }
+
+    /// Refund a token session that has not ended `SESSION_TIMEOUT_LEDGERS`
+    /// after it started, so stakes cannot be stuck behind a stalled game.
+    /// Anyone can call. Plain-points sessions escrow nothing, so they are left
+    /// for the game to end. If the game ends the session later, its result is
+    /// dropped.
+    ///
+    /// # Arguments
+    /// * `session_id` - The stalled game session
+    pub fn refund_expired(env: Env, session_id: u32) {
+        let session = Self::load_session(&env, session_id);
+        if session.token.is_none() {
+            panic!("Only token sessions can be refunded");
+        }
+        if env.ledger().sequence() <= session.start_ledger + SESSION_TIMEOUT_LEDGERS {
+            panic!("Session has not timed out");
+        }
+        Self::take_session(&env, session_id);
+        Self::refund(&env, &session);
+
+        let key = DataKey::Refunded(session_id);
+        env.storage().temporary().set(&key, &session.game_id);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+
+        SessionRefunded {
+            session_id,
+            expired: true,
+        }
+        .publish(&env);
+    }
+
+    /// Raise a player's stake in an open session, e.g. a bet or raise in a
+    /// betting game. In token mode the extra amount moves into escrow now, so
+    /// the player authorizes that transfer along with the game call.
+    ///
+    /// # Arguments
+    /// * `session_id` - The open game session
+    /// * `player` - The player adding to their stake
+    /// * `amount` - Amount added to the player's stake
+    pub fn add_stake(env: Env, session_id: u32, player: Address, amount: i128) {
+        let mut session = Self::load_session(&env, session_id);
+        session.game_id.require_auth();
+        if amount <= 0 {
+            panic!("Stake increase must be positive");
+        }
+        let index = session
+            .players
+            .first_index_of(&player)
+            .expect("Player not in session");
+
+        if let Some(token) = &session.token {
+            token::Client::new(&env, token).transfer(&player, env.current_contract_address(), &amount);
+        }
+        session
+            .points
+            .set(index, session.points.get_unchecked(index) + amount);
+        Self::store_session(&env, session_id, &session);
+
+        for key in [DataKey::GameTotals(session.game_id.clone()), DataKey::HubTotals] {
+            let mut totals: GameTotals = Self::load_or_default(&env, &key);
+            totals.points_wagered += amount;
+            Self::save(&env, &key, &totals);
+        }
+
+        StakeAdded {
+            session_id,
+            player,
+            amount,
+        }
+        .publish(&env);
+    }
+
+    // ========================================================================
+    // Token Escrow
+    // ========================================================================
+
+    /// Stake a game's sessions in a token (a Stellar Asset Contract or any
+    /// SEP-41 token), or go back to plain points with None. Sessions already
+    /// open keep the mode they started with.
+    ///
+    /// In token mode, points are token amounts. Each player's stake moves into
+    /// the hub when the session starts, so players authorize that transfer
+    /// along with the game call. Payouts are sent when the session ends.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `token` - The token contract, or None for plain points
+    pub fn set_game_token(env: Env, game_id: Address, token: Option<Address>) {
+        Self::get_admin(env.clone()).require_auth();
+        if Self::get_jackpot(env.clone(), game_id.clone()) > 0 {
+            panic!("Jackpot must be won before changing token");
+        }
+        let key = DataKey::GameToken(game_id);
+        match token {
+            Some(token) => Self::save(&env, &key, &token),
+            None => env.storage().persistent().remove(&key),
+        }
+    }
+
+    /// Get the token a game's sessions are staked in, None for plain points
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn get_game_token(env: Env, game_id: Address) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::GameToken(game_id))
+    }
+
+    // ========================================================================
+    // House Fees
+    // ========================================================================
+
+    /// Set a game's rake, or remove it with None. Applies to sessions that
+    /// end from now on.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `config` - Rake in basis points with min/max fee caps
+    pub fn set_fee_config(env: Env, game_id: Address, config: Option<FeeConfig>) {
+        Self::get_admin(env.clone()).require_auth();
+        let key = DataKey::FeeConfig(game_id);
+        match config {
+            Some(config) => {
+                if config.rake_bp > MAX_RAKE_BP || config.min_fee < 0 || config.min_fee > config.max_fee {
+                    panic!("Invalid fee config");
+                }
+                Self::save(&env, &key, &config);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+    }
+
+    /// Get a game's rake, None if it plays fee-free
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn get_fee_config(env: Env, game_id: Address) -> Option<FeeConfig> {
+        env.storage().persistent().get(&DataKey::FeeConfig(game_id))
+    }
+
+    /// Fee the hub would take from a pot in a game, so frontends can show the
+    /// net payout before players sign. For two players under winner-takes-all
+    /// the pot is the loser's stake; the winner receives their own stake plus
+    /// `pot - fee`. Other payout policies shrink the pot to what is won.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `pot` - The losing stakes the winners win
+    pub fn quote_fee(env: Env, game_id: Address, pot: i128) -> i128 {
+        Self::get_fee_config(env, game_id).map_or(0, |config| rake_fee(&config, pot))
+    }
+
+    /// Set where withdrawn fees go
+    ///
+    /// # Arguments
+    /// * `treasury` - The treasury address
+    pub fn set_treasury(env: Env, treasury: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+    }
+
+    /// Get where withdrawn fees go (the admin until a treasury is set)
+    pub fn get_treasury(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .unwrap_or_else(|| Self::get_admin(env.clone()))
+    }
+
+    /// Get fees collected and not yet withdrawn
+    ///
+    /// # Arguments
+    /// * `token` - The token, or None for fees from plain-points sessions
+    pub fn get_accrued_fees(env: Env, token: Option<Address>) -> i128 {
+        Self::load_or_default(&env, &Self::fees_key(token))
+    }
+
+    /// Withdraw every accrued fee in one token to the treasury. For plain
+    /// points this only clears the balance.
+    ///
+    /// # Arguments
+    /// * `token` - The token, or None for fees from plain-points sessions
+    ///
+    /// # Returns
+    /// * `i128` - The amount withdrawn
+    pub fn withdraw_fees(env: Env, token: Option<Address>) -> i128 {
+        Self::get_admin(env.clone()).require_auth();
+        let key = Self::fees_key(token.clone());
+        let amount: i128 = Self::load_or_default(&env, &key);
+        env.storage().persistent().remove(&key);
+
+        let treasury = Self::get_treasury(env.clone());
+        if let Some(token) = &token {
+            if amount > 0 {
+                token::Client::new(&env, token).transfer(&env.current_contract_address(), &treasury, &amount);
+            }
+        }
+
+        FeesWithdrawn {
+            treasury,
+            token,
+            amount,
+        }
+        .publish(&env);
+
+        amount
+    }
+
+    // ========================================================================
+    // Jackpots
+    // ========================================================================
+
+    /// Divert a share of every pot in a game to its progressive jackpot
+    /// (0 stops funding; the jackpot already built up stays to be won).
+    /// The share is taken after the house fee.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `rate_bp` - Share of the pot in basis points (100 = 1%)
+    pub fn set_jackpot_rate(env: Env, game_id: Address, rate_bp: u32) {
+        Self::get_admin(env.clone()).require_auth();
+        if rate_bp > MAX_RAKE_BP {
+            panic!("Invalid jackpot rate");
+        }
+        Self::save(&env, &DataKey::JackpotRate(game_id), &rate_bp);
+    }
+
+    /// Get the share of each pot diverted to a game's jackpot, in basis points
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn get_jackpot_rate(env: Env, game_id: Address) -> u32 {
+        Self::load_or_default(&env, &DataKey::JackpotRate(game_id))
+    }
+
+    /// Get a game's current jackpot
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn get_jackpot(env: Env, game_id: Address) -> i128 {
+        Self::load_or_default(&env, &DataKey::Jackpot(game_id))
+    }
+
+    /// Award a game's whole jackpot to a player for a jackpot-eligible result
+    /// (an exact guess, double sixes, a five-card 21...). The game calls this
+    /// while the session is still open, just before ending it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The open game session
+    /// * `player` - The player who hit the jackpot
+    ///
+    /// # Returns
+    /// * `i128` - The amount won (0 if the jackpot was empty or the session
+    ///   was refunded)
+    pub fn report_jackpot(env: Env, session_id: u32, player: Address) -> i128 {
+        // A refunded session can still finish, but wins nothing
+        if let Some(game_id) = env.storage().temporary().get::<_, Address>(&DataKey::Refunded(session_id)) {
+            game_id.require_auth();
+            return 0;
+        }
+        let session = Self::load_session(&env, session_id);
+        session.game_id.require_auth();
+        let index = session
+            .players
+            .first_index_of(&player)
+            .expect("Player not in session");
+
+        let key = DataKey::Jackpot(session.game_id.clone());
+        let amount: i128 = Self::load_or_default(&env, &key);
+        if amount == 0 {
+            return 0;
+        }
+        env.storage().persistent().remove(&key);
+
+        let mut amounts = Vec::new(&env);
+        for i in 0..session.players.len() {
+            amounts.push_back(if i == index { amount } else { 0 });
+        }
+        Self::pay_out(&env, &session, &amounts);
+
+        for key in [
+            DataKey::PlayerStats(player.clone(), session.game_id.clone()),
+            DataKey::PlayerTotals(player.clone()),
+        ] {
+            let mut stats: PlayerStats = Self::load_or_default(&env, &key);
+            stats.net_points += amount;
+            Self::save(&env, &key, &stats);
+        }
+
+        JackpotWon {
+            game_id: session.game_id,
+            session_id,
+            player,
+            amount,
+        }
+        .publish(&env);
+
+        amount
+    }
+
+    // ========================================================================
+    // Payout Policies
+    // ========================================================================
+
+    /// Set the payout policy a game's sessions start with (winner-takes-all
+    /// until set). Sessions already open keep the policy they started with.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `policy` - How uneven stakes are paid out
+    pub fn set_payout_policy(env: Env, game_id: Address, policy: PayoutPolicy) {
+        Self::get_admin(env.clone()).require_auth();
+        Self::check_policy(&policy);
+        Self::save(&env, &DataKey::PayoutPolicy(game_id), &policy);
+    }
+
+    /// Get the payout policy a game's sessions start with
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn get_payout_policy(env: Env, game_id: Address) -> PayoutPolicy {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutPolicy(game_id))
+            .unwrap_or(PayoutPolicy::WinnerTakesAll)
+    }
+
+    /// Change the payout policy of one open session, e.g. to declare odds for
+    /// a match. The game calls this before it ends the session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The open game session
+    /// * `policy` - How this session's stakes are paid out
+    pub fn set_session_policy(env: Env, session_id: u32, policy: PayoutPolicy) {
+        let mut session = Self::load_session(&env, session_id);
+        session.game_id.require_auth();
+        Self::check_policy(&policy);
+        session.policy = policy;
+        Self::store_session(&env, session_id, &session);
+    }
+
+    /// Get the payout policy of an open session
+    ///
+    /// # Arguments
+    /// * `session_id` - The open game session
+    pub fn get_session_policy(env: Env, session_id: u32) -> PayoutPolicy {
+        Self::load_session(&env, session_id).policy
+    }
+
+    // ========================================================================
+    // Seasons
+    // ========================================================================
+
+    /// Start a new season. Standings, ratings and the leaderboard start fresh
+    /// for it. Only one season can run at a time.
+    ///
+    /// # Returns
+    /// * `u32` - The new season's id
+    pub fn start_season(env: Env) -> u32 {
+        Self::get_admin(env.clone()).require_auth();
+        if env.storage().instance().has(&DataKey::CurrentSeason) {
+            panic!("A season is already running");
+        }
+
+        let season_id = env
+            .storage()
+            .instance()
+            .get::<_, u32>(&DataKey::SeasonCount)
+            .unwrap_or(0)
+            + 1;
+        env.storage().instance().set(&DataKey::SeasonCount, &season_id);
+        env.storage().instance().set(&DataKey::CurrentSeason, &season_id);
+
+        let season = Season {
+            start_ledger: env.ledger().sequence(),
+            end_ledger: None,
+            games: Vec::new(&env),
+        };
+        Self::save(&env, &DataKey::Season(season_id), &season);
+
+        SeasonStarted { season_id }.publish(&env);
+        season_id
+    }
+
+    /// End the running season. Its standings and leaderboards stop changing
+    /// and stay queryable as the season's final snapshot.
+    ///
+    /// # Returns
+    /// * `u32` - The id of the season that ended
+    pub fn end_season(env: Env) -> u32 {
+        Self::get_admin(env.clone()).require_auth();
+        let season_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentSeason)
+            .expect("No season is running");
+        env.storage().instance().remove(&DataKey::CurrentSeason);
+
+        let key = DataKey::Season(season_id);
+        let mut season = Self::get_season(env.clone(), season_id);
+        season.end_ledger = Some(env.ledger().sequence());
+        Self::save(&env, &key, &season);
+
+        SeasonEnded { season_id }.publish(&env);
+        season_id
+    }
+
+    /// Get the running season's id, if any
+    pub fn get_current_season(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::CurrentSeason)
+    }
+
+    /// Get a season, running or archived
+    ///
+    /// # Arguments
+    /// * `season_id` - The season's id
+    pub fn get_season(env: Env, season_id: u32) -> Season {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Season(season_id))
+            .expect("Season not found")
+    }
+
+    /// Get a player's record in one game during a season
+    ///
+    /// # Arguments
+    /// * `season_id` - The season's id
+    /// * `player` - The player's address
+    /// * `game_id` - Address of the game contract
+    pub fn get_season_stats(env: Env, season_id: u32, player: Address, game_id: Address) -> PlayerStats {
+        Self::load_or_default(&env, &DataKey::SeasonStats(season_id, player, game_id))
+    }
+
+    /// Get a player's season rating in one game
+    ///
+    /// # Arguments
+    /// * `season_id` - The season's id
+    /// * `player` - The player's address
+    /// * `game_id` - Address of the game contract
+    pub fn get_season_rating(env: Env, season_id: u32, player: Address, game_id: Address) -> u32 {
+        Self::load_rating(&env, &DataKey::SeasonRating(season_id, player, game_id))
+    }
+
+    /// Get the top season ratings in one game, best first
+    ///
+    /// # Arguments
+    /// * `season_id` - The season's id
+    /// * `game_id` - Address of the game contract
+    pub fn get_leaderboard(env: Env, season_id: u32, game_id: Address) -> Vec<LeaderboardEntry> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Leaderboard(season_id, game_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // ========================================================================
+    // Standings
+    // ========================================================================
+
+    /// Get a player's record in one game
+    ///
+    /// # Arguments
+    /// * `player` - The player's address
+    /// * `game_id` - Address of the game contract
+    pub fn get_player_stats(env: Env, player: Address, game_id: Address) -> PlayerStats {
+        Self::load_or_default(&env, &DataKey::PlayerStats(player, game_id))
+    }
+
+    /// Get a player's record across every game
+    ///
+    /// # Arguments
+    /// * `player` - The player's address
+    pub fn get_player_totals(env: Env, player: Address) -> PlayerStats {
+        Self::load_or_default(&env, &DataKey::PlayerTotals(player))
+    }
+
+    /// Get a player's skill rating in one game
+    /// (`INITIAL_RATING` until they finish a session)
+    ///
+    /// # Arguments
+    /// * `player` - The player's address
+    /// * `game_id` - Address of the game contract
+    pub fn get_rating(env: Env, player: Address, game_id: Address) -> u32 {
+        Self::load_rating(&env, &DataKey::Rating(player, game_id))
+    }
+
+    /// Get aggregate activity for one game
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn get_game_totals(env: Env, game_id: Address) -> GameTotals {
+        Self::load_or_default(&env, &DataKey::GameTotals(game_id))
+    }
+
+    /// Get aggregate activity across every game
+    pub fn get_hub_totals(env: Env) -> GameTotals {
+        Self::load_or_default(&env, &DataKey::HubTotals)
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    /// Store a new session, escrow its stakes if the game uses a token, and
+    /// count it as started. The session keeps the game's token and payout
+    /// policy as they are now.
+    fn open_session(
+        env: &Env,
+        session_id: u32,
+        game_id: Address,
+        players: Vec<Address>,
+        points: Vec<i128>,
+        team1_size: u32,
+    ) {
+        let key = DataKey::Session(session_id);
+        let refunded = DataKey::Refunded(session_id);
+        if env.storage().temporary().has(&key)
+            || env.storage().persistent().has(&key)
+            || env.storage().temporary().has(&refunded)
+        {
+            panic!("Session already exists");
+        }
+        if points.iter().any(|stake| stake < 0) {
+            panic!("Stakes cannot be negative");
+        }
+
+        let session = Session {
+            token: Self::get_game_token(env.clone(), game_id.clone()),
+            policy: Self::get_payout_policy(env.clone(), game_id.clone()),
+            game_id,
+            players,
+            points,
+            team1_size,
+            start_ledger: env.ledger().sequence(),
+        };
+
+        if let Some(token) = &session.token {
+            let client = token::Client::new(env, token);
+            let hub = env.current_contract_address();
+            for (player, stake) in session.players.iter().zip(session.points.iter()) {
+                if stake > 0 {
+                    client.transfer(&player, &hub, &stake);
+                }
+            }
+        }
+        Self::store_session(env, session_id, &session);
+
+        let wagered: i128 = session.points.iter().sum();
+        for key in [DataKey::GameTotals(session.game_id.clone()), DataKey::HubTotals] {
+            let mut totals: GameTotals = Self::load_or_default(env, &key);
+            totals.sessions_started += 1;
+            totals.points_wagered += wagered;
+            Self::save(env, &key, &totals);
+        }
+
+        // bump instance ttl if required
+        env.storage().instance().extend_ttl(17_280, 518_400);
+    }
+
+    /// Get an open session from temporary (points) or persistent (token) storage
+    fn load_session(env: &Env, session_id: u32) -> Session {
+        let key = DataKey::Session(session_id);
+        env.storage()
+            .temporary()
+            .get(&key)
+            .or_else(|| env.storage().persistent().get(&key))
+            .expect("Session not found")
+    }
+
+    /// Save an open session: temporary storage for points, persistent for
+    /// tokens so the record of escrowed funds cannot expire
+    fn store_session(env: &Env, session_id: u32, session: &Session) {
+        let key = DataKey::Session(session_id);
+        if session.token.is_some() {
+            Self::save(env, &key, session);
+        } else {
+            env.storage().temporary().set(&key, session);
+            env.storage()
+                .temporary()
+                .extend_ttl(&key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+        }
+    }
+
+    /// Remove and return an open session; each session ends exactly once
+    fn take_session(env: &Env, session_id: u32) -> Session {
+        let session = Self::load_session(env, session_id);
+        let key = DataKey::Session(session_id);
+        env.storage().temporary().remove(&key);
+        env.storage().persistent().remove(&key);
+        session
+    }
+
+    /// Remove and return a session its game is ending, after checking the
+    /// game's auth. None if `refund_expired` already refunded it, so a
+    /// stalled game can still finish.
+    fn take_ended_session(env: &Env, session_id: u32) -> Option<Session> {
+        let key = DataKey::Refunded(session_id);
+        if let Some(game_id) = env.storage().temporary().get::<_, Address>(&key) {
+            game_id.require_auth();
+            env.storage().temporary().remove(&key);
+            return None;
+        }
+        let session = Self::take_session(env, session_id);
+        session.game_id.require_auth();
+        Some(session)
+    }
+
+    /// Odds shorter than 1:1 would make the underdog the favourite
+    fn check_policy(policy: &PayoutPolicy) {
+        if let PayoutPolicy::FixedOdds(odds_bp) = policy {
+            if *odds_bp < EVEN_ODDS_BP {
+                panic!("Invalid odds");
+            }
+        }
+    }
+
+    /// Move the game's jackpot share of a pot (after fees) into its jackpot
+    fn fund_jackpot(env: &Env, session: &Session, pot: i128) -> i128 {
+        let rate = Self::get_jackpot_rate(env.clone(), session.game_id.clone());
+        let share = pot * rate as i128 / MAX_RAKE_BP as i128;
+        if share > 0 {
+            let key = DataKey::Jackpot(session.game_id.clone());
+            let jackpot: i128 = Self::load_or_default(env, &key);
+            Self::save(env, &key, &(jackpot + share));
+        }
+        share
+    }
+
+    /// Take the game's rake from a pot, keep it for withdrawal and return it
+    fn collect_fee(env: &Env, session_id: u32, session: &Session, pot: i128) -> i128 {
+        let fee = Self::quote_fee(env.clone(), session.game_id.clone(), pot);
+        if fee == 0 {
+            return 0;
+        }
+
+        let key = Self::fees_key(session.token.clone());
+        let accrued: i128 = Self::load_or_default(env, &key);
+        Self::save(env, &key, &(accrued + fee));
+
+        for key in [DataKey::GameTotals(session.game_id.clone()), DataKey::HubTotals] {
+            let mut totals: GameTotals = Self::load_or_default(env, &key);
+            totals.fees_collected += fee;
+            Self::save(env, &key, &totals);
+        }
+
+        FeeCollected {
+            game_id: session.game_id.clone(),
+            session_id,
+            amount: fee,
+        }
+        .publish(env);
+
+        fee
+    }
+
+    fn fees_key(token: Option<Address>) -> DataKey {
+        match token {
+            Some(token) => DataKey::AccruedFees(token),
+            None => DataKey::AccruedPoints,
+        }
+    }
+
+    /// Send token amounts out of escrow, same order as `session.players`.
+    /// Does nothing for plain-points sessions.
+    fn pay_out(env: &Env, session: &Session, amounts: &Vec<i128>) {
+        let Some(token) = &session.token else {
+            return;
+        };
+        let client = token::Client::new(env, token);
+        let hub = env.current_contract_address();
+        for (player, amount) in session.players.iter().zip(amounts.iter()) {
+            if amount > 0 {
+                client.transfer(&hub, &player, &amount);
+            }
+        }
+    }
+
+    /// Return every stake of a session that ends without a result
+    fn refund(env: &Env, session: &Session) {
+        Self::pay_out(env, session, &session.points);
+    }
+
+    /// Pay out a finished session and record it in the standings and ratings,
+    /// both all-time and for the running season.
+    /// `places` follows `session.players` (1 = first). Players in first place
+    /// win the part of the others' stakes the session's payout policy gives
+    /// them, and split it pro rata less the game's rake and jackpot share.
+    /// The rest goes back to the losers pro rata. If everyone is first it's a
+    /// draw and every stake is returned. Returns what each player receives.
+    fn settle(env: &Env, session_id: u32, session: &Session, places: &Vec<u32>) -> Vec<i128> {
+        let season_id: Option<u32> = env.storage().instance().get(&DataKey::CurrentSeason);
+
+        let mut is_winner = Vec::new(env);
+        for place in places.iter() {
+            is_winner.push_back(place == 1);
+        }
+        let draw = is_winner.iter().all(|won| won);
+
+        let mut winner_stakes = Vec::new(env);
+        let mut loser_stakes = Vec::new(env);
+        for (stake, won) in session.points.iter().zip(is_winner.iter()) {
+            if won {
+                winner_stakes.push_back(stake);
+            } else {
+                loser_stakes.push_back(stake);
+            }
+        }
+        let pot: i128 = loser_stakes.iter().sum();
+        let won = winnings(&session.policy, winner_stakes.iter().sum(), pot);
+        let fee = Self::collect_fee(env, session_id, session, won);
+        let jackpot = Self::fund_jackpot(env, session, won - fee);
+        let mut shares = split_pro_rata(env, &winner_stakes, won - fee - jackpot).into_iter();
+        let mut refunds = split_pro_rata(env, &loser_stakes, pot - won).into_iter();
+
+        let mut payouts = Vec::new(env);
+        for ((player, stake), won) in session.players.iter().zip(session.points.iter()).zip(is_winner.iter()) {
+            let net = if won {
+                shares.next().unwrap_or(0)
+            } else {
+                refunds.next().unwrap_or(0) - stake
+            };
+            payouts.push_back(stake + net);
+
+            let mut keys = Vec::from_array(
+                env,
+                [
+                    DataKey::PlayerStats(player.clone(), session.game_id.clone()),
+                    DataKey::PlayerTotals(player.clone()),
+                ],
+            );
+            if let Some(season_id) = season_id {
+                keys.push_back(DataKey::SeasonStats(season_id, player.clone(), session.game_id.clone()));
+            }
+            for key in keys.iter() {
+                let mut stats: PlayerStats = Self::load_or_default(env, &key);
+                stats.record(won, draw, net);
+                Self::save(env, &key, &stats);
+            }
+        }
+
+        for key in [DataKey::GameTotals(session.game_id.clone()), DataKey::HubTotals] {
+            let mut totals: GameTotals = Self::load_or_default(env, &key);
+            totals.sessions_ended += 1;
+            if draw {
+                totals.draws += 1;
+            }
+            Self::save(env, &key, &totals);
+        }
+
+        Self::update_ratings(env, session, places, None);
+
+        if let Some(season_id) = season_id {
+            let key = DataKey::Season(season_id);
+            let mut season: Season = env.storage().persistent().get(&key).expect("Season not found");
+            if !season.games.contains(&session.game_id) {
+                season.games.push_back(session.game_id.clone());
+                Self::save(env, &key, &season);
+            }
+            Self::update_ratings(env, session, places, Some(season_id));
+        }
+
+        Self::pay_out(env, session, &payouts);
+
+        payouts
+    }
+
+    /// Apply ELO to every player in a finished session.
+    ///
+    /// Each pair of players counts as a game: the better place scores 1, equal
+    /// places 0.5. With more than two players the change is averaged over the
+    /// opponents. In team sessions each member is rated with their team's
+    /// average rating against the other team's average.
+    ///
+    /// With `season_id` the season ratings and leaderboard are updated instead
+    /// of the all-time ratings.
+    fn update_ratings(env: &Env, session: &Session, places: &Vec<u32>, season_id: Option<u32>) {
+        let rating_key = |player: Address| match season_id {
+            Some(season_id) => DataKey::SeasonRating(season_id, player, session.game_id.clone()),
+            None => DataKey::Rating(player, session.game_id.clone()),
+        };
+
+        let mut ratings = Vec::new(env);
+        for player in session.players.iter() {
+            ratings.push_back(Self::load_rating(env, &rating_key(player)) as i64);
+        }
+
+        // In team sessions a player's rating is replaced by their team's average
+        let mut effective = ratings.clone();
+        if session.team1_size > 0 {
+            let team1_size = session.team1_size as i64;
+            let team2_size = ratings.len() as i64 - team1_size;
+            let team1_total: i64 = ratings.iter().take(session.team1_size as usize).sum();
+            let team2_total: i64 = ratings.iter().skip(session.team1_size as usize).sum();
+            for index in 0..ratings.len() {
+                let average = if index < session.team1_size {
+                    team1_total / team1_size
+                } else {
+                    team2_total / team2_size
+                };
+                effective.set(index, average);
+            }
+        }
+
+        for (i, (player, place)) in session.players.iter().zip(places.iter()).enumerate() {
+            let i = i as u32;
+            let mut score_bp = 0i64;
+            let mut opponents = 0i64;
+            for (j, other_place) in places.iter().enumerate() {
+                let j = j as u32;
+                let teammates = session.team1_size > 0 && (i < session.team1_size) == (j < session.team1_size);
+                if i == j || teammates {
+                    continue;
+                }
+                let actual = match place.cmp(&other_place) {
+                    core::cmp::Ordering::Less => 10_000,
+                    core::cmp::Ordering::Equal => 5_000,
+                    core::cmp::Ordering::Greater => 0,
+                };
+                let diff = effective.get_unchecked(i) - effective.get_unchecked(j);
+                score_bp += actual - expected_score_bp(diff);
+                opponents += 1;
+            }
+
+            let old_rating = ratings.get_unchecked(i);
+            let change = round_bp(K_FACTOR * score_bp / opponents.max(1));
+            let new_rating = (old_rating + change).max(0) as u32;
+
+            let key = rating_key(player.clone());
+            if let Some(season_id) = season_id {
+                if !env.storage().persistent().has(&key) {
+                    let players_key = DataKey::SeasonPlayers(season_id, session.game_id.clone());
+                    let mut players: Vec<Address> = env.storage().persistent().get(&players_key).unwrap_or(Vec::new(env));
+                    players.push_back(player.clone());
+                    Self::save(env, &players_key, &players);
+                }
+            }
+            Self::save(env, &key, &new_rating);
+
+            match season_id {
+                Some(season_id) => Self::update_leaderboard(env, season_id, &session.game_id, player, new_rating),
+                None => RatingChanged {
+                    game_id: session.game_id.clone(),
+                    player,
+                    old_rating: old_rating as u32,
+                    new_rating,
+                }
+                .publish(env),
+            }
+        }
+    }
+
+    /// Move a player to their place on a season leaderboard, keeping only the
+    /// top `LEADERBOARD_SIZE`. Equal ratings keep the earlier entry first.
+    ///
+    /// Everyone off the board is rated at or below its cutoff, so a new or
+    /// rising rating only needs comparing with the board. A board member who
+    /// drops may fall below someone evicted earlier; then the board is ranked
+    /// again from every player rated this season.
+    fn update_leaderboard(env: &Env, season_id: u32, game_id: &Address, player: Address, rating: u32) {
+        let key = DataKey::Leaderboard(season_id, game_id.clone());
+        let mut board: Vec<LeaderboardEntry> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+
+        let previous = board.iter().position(|entry| entry.player == player);
+        if let Some(index) = previous {
+            let dropped = board.get_unchecked(index as u32).rating > rating;
+            if dropped && board.len() == LEADERBOARD_SIZE {
+                Self::rank_leaderboard(env, season_id, game_id);
+                return;
+            }
+            board.remove(index as u32);
+        }
+        Self::insert_ranked(&mut board, player, rating);
+
+        Self::save(env, &key, &board);
+    }
+
+    /// Rebuild a season leaderboard from every player rated in the game
+    fn rank_leaderboard(env: &Env, season_id: u32, game_id: &Address) {
+        let players: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SeasonPlayers(season_id, game_id.clone()))
+            .unwrap_or(Vec::new(env));
+        let mut board = Vec::new(env);
+        for player in players.iter() {
+            let rating = Self::load_rating(env, &DataKey::SeasonRating(season_id, player.clone(), game_id.clone()));
+            Self::insert_ranked(&mut board, player, rating);
+        }
+
+        Self::save(env, &DataKey::Leaderboard(season_id, game_id.clone()), &board);
+    }
+
+    /// Insert an entry after every entry rated at least as high, keeping only
+    /// the top `LEADERBOARD_SIZE`
+    fn insert_ranked(board: &mut Vec<LeaderboardEntry>, player: Address, rating: u32) {
+        let index = board
+            .iter()
+            .position(|entry| entry.rating < rating)
+            .map_or(board.len(), |index| index as u32);
+        if index < LEADERBOARD_SIZE {
+            board.insert(index, LeaderboardEntry { player, rating });
+            if board.len() > LEADERBOARD_SIZE {
+                board.pop_back();
+            }
+        }
+    }
+
+    fn load_rating(env: &Env, key: &DataKey) -> u32 {
+        env.storage().persistent().get(key).unwrap_or(INITIAL_RATING)
+    }
+
+    fn load_or_default<T>(env: &Env, key: &DataKey) -> T
+    where
+        T: Default + soroban_sdk::TryFromVal<Env, soroban_sdk::Val>,
+    {
+        env.storage().persistent().get(key).unwrap_or_default()
+    }
+
+    fn save<T>(env: &Env, key: &DataKey, value: &T)
+    where
+        T: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+    {
+        env.storage().persistent().set(key, value);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, STATS_TTL_LEDGERS, STATS_TTL_LEDGERS);
     }
 }
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,1010 @@
+#![cfg(test)]
+
+use crate::{
+    expected_score_bp, rake_fee, split_pro_rata, winnings, FeeConfig, GameTotals, LeaderboardEntry, MockGameHub, MockGameHubClient,
+    PayoutPolicy, PlayerStats, INITIAL_RATING, LEADERBOARD_SIZE, SESSION_TIMEOUT_LEDGERS,
+};
+use soroban_sdk::testutils::{Address as _, Ledger as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, Vec};
+
+fn setup_test() -> (Env, MockGameHubClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(MockGameHub, (&admin,));
+    let client = MockGameHubClient::new(&env, &contract_id);
+    let game_id = Address::generate(&env);
+    (env, client, game_id)
+}
+
+#[test]
+fn test_start_and_end_game() {
+    let (env, client, game_id) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
+    client.end_game(&1, &true);
+}
+
+#[test]
+fn test_start_and_draw_game() {
+    let (env, client, game_id) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.start_game(&game_id, &2, &player1, &player2, &1000, &1000);
+    client.end_game_draw(&2);
+}
+
+#[test]
+fn test_start_and_end_multi_game() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    client.start_multi_game(&game_id, &3, &players, &vec![&env, 100, 200, 300]);
+    client.end_multi_game(&3, &vec![&env, 2, 1, 1]);
+}
+
+#[test]
+#[should_panic(expected = "between 2 and 8 players")]
+fn test_multi_game_rejects_single_player() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![&env, Address::generate(&env)];
+    client.start_multi_game(&game_id, &4, &players, &vec![&env, 100]);
+}
+
+#[test]
+fn test_split_pro_rata() {
+    let env = Env::default();
+    // 1:3 stakes split 100 as 25/75
+    assert_eq!(split_pro_rata(&env, &vec![&env, 10, 30], 100), vec![&env, 25, 75]);
+    // Rounding dust goes to the first member
+    assert_eq!(split_pro_rata(&env, &vec![&env, 1, 1, 1], 100), vec![&env, 34, 33, 33]);
+    // No stakes: equal shares
+    assert_eq!(split_pro_rata(&env, &vec![&env, 0, 0], 5), vec![&env, 3, 2]);
+}
+
+#[test]
+fn test_start_and_end_team_game() {
+    let (env, client, game_id) = setup_test();
+    let team1 = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let team2 = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.start_team_game(&game_id, &5, &team1, &vec![&env, 100, 300], &team2, &vec![&env, 200, 200]);
+    assert!(client.try_end_game(&5, &true).is_err());
+    client.end_team_game(&5, &true);
+
+    // Team 1 splits team 2's 400 points 1:3
+    let first = client.get_player_stats(&team1.get(0).unwrap(), &game_id);
+    let second = client.get_player_stats(&team1.get(1).unwrap(), &game_id);
+    assert_eq!((first.net_points, second.net_points), (100, 300));
+    assert_eq!(client.get_player_stats(&team2.get(0).unwrap(), &game_id).net_points, -200);
+
+    // The session is consumed
+    assert!(client.try_end_team_game(&5, &true).is_err());
+}
+
+#[test]
+fn test_multi_game_rejects_out_of_range_places() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    client.start_multi_game(&game_id, &7, &players, &vec![&env, 100, 100, 100]);
+    assert!(client.try_end_multi_game(&7, &vec![&env, 1, 0, 2]).is_err());
+    assert!(client.try_end_multi_game(&7, &vec![&env, 1, 2, 4]).is_err());
+
+    // `end_game` only settles two-player sessions
+    assert!(client.try_end_game(&7, &true).is_err());
+    client.end_multi_game(&7, &vec![&env, 1, 3, 2]);
+}
+
+#[test]
+#[should_panic(expected = "both teams")]
+fn test_team_game_rejects_shared_member() {
+    let (env, client, game_id) = setup_test();
+    let shared = Address::generate(&env);
+    let team1 = vec![&env, shared.clone(), Address::generate(&env)];
+    let team2 = vec![&env, shared, Address::generate(&env)];
+    client.start_team_game(&game_id, &6, &team1, &vec![&env, 1, 1], &team2, &vec![&env, 1, 1]);
+}
+
+// ============================================================================
+// Standings Tests
+// ============================================================================
+
+#[test]
+fn test_standings_track_wins_losses_and_streaks() {
+    let (env, client, game_id) = setup_test();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    // Alice wins twice, then draws, then loses
+    client.start_game(&game_id, &10, &alice, &bob, &100, &50);
+    client.end_game(&10, &true);
+    client.start_game(&game_id, &11, &bob, &alice, &70, &100);
+    client.end_game(&11, &false);
+
+    let alice_stats = client.get_player_stats(&alice, &game_id);
+    assert_eq!(
+        alice_stats,
+        PlayerStats {
+            games_played: 2,
+            wins: 2,
+            losses: 0,
+            draws: 0,
+            net_points: 120,
+            current_streak: 2,
+        }
+    );
+    assert_eq!(client.get_player_stats(&bob, &game_id).current_streak, -2);
+
+    client.start_game(&game_id, &12, &alice, &bob, &100, &100);
+    client.end_game_draw(&12);
+    assert_eq!(client.get_player_stats(&alice, &game_id).current_streak, 0);
+
+    client.start_game(&game_id, &13, &alice, &bob, &100, &100);
+    client.end_game(&13, &false);
+
+    let alice_stats = client.get_player_stats(&alice, &game_id);
+    assert_eq!((alice_stats.games_played, alice_stats.wins, alice_stats.losses, alice_stats.draws), (4, 2, 1, 1));
+    assert_eq!(alice_stats.net_points, 20);
+    assert_eq!(alice_stats.current_streak, -1);
+
+    let bob_stats = client.get_player_stats(&bob, &game_id);
+    assert_eq!(bob_stats.net_points, -20);
+    assert_eq!(bob_stats.current_streak, 1);
+}
+
+#[test]
+fn test_standings_are_per_game_with_totals() {
+    let (env, client, game_a) = setup_test();
+    let game_b = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.start_game(&game_a, &20, &alice, &bob, &10, &10);
+    client.end_game(&20, &true);
+    client.start_game(&game_b, &21, &alice, &bob, &30, &30);
+    client.end_game(&21, &false);
+    client.start_game(&game_b, &22, &alice, &bob, &5, &5);
+
+    assert_eq!(client.get_player_stats(&alice, &game_a).wins, 1);
+    assert_eq!(client.get_player_stats(&alice, &game_b).losses, 1);
+
+    let totals = client.get_player_totals(&alice);
+    assert_eq!((totals.games_played, totals.wins, totals.losses), (2, 1, 1));
+    assert_eq!(totals.net_points, -20);
+
+    assert_eq!(
+        client.get_game_totals(&game_b),
+        GameTotals {
+            sessions_started: 2,
+            sessions_ended: 1,
+            draws: 0,
+            points_wagered: 70,
+            fees_collected: 0,
+        }
+    );
+    let hub = client.get_hub_totals();
+    assert_eq!((hub.sessions_started, hub.sessions_ended, hub.points_wagered), (3, 2, 90));
+
+    // Unknown players and games start empty
+    let carol = Address::generate(&env);
+    assert_eq!(client.get_player_stats(&carol, &game_a), PlayerStats::default());
+}
+
+#[test]
+fn test_multi_game_standings() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    client.start_multi_game(&game_id, &30, &players, &vec![&env, 100, 300, 200, 200]);
+    client.end_multi_game(&30, &vec![&env, 1, 1, 3, 4]);
+
+    // The two winners split 400 points 1:3
+    let net: [i128; 4] = core::array::from_fn(|i| client.get_player_stats(&players.get(i as u32).unwrap(), &game_id).net_points);
+    assert_eq!(net, [100, 300, -200, -200]);
+
+    // Everyone first is a draw
+    client.start_multi_game(&game_id, &31, &players, &vec![&env, 100, 100, 100, 100]);
+    client.end_multi_game(&31, &vec![&env, 1, 1, 1, 1]);
+    let stats = client.get_player_stats(&players.get(3).unwrap(), &game_id);
+    assert_eq!((stats.draws, stats.net_points, stats.current_streak), (1, -200, 0));
+    assert_eq!(client.get_game_totals(&game_id).draws, 1);
+}
+
+#[test]
+fn test_session_ends_once() {
+    let (env, client, game_id) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.start_game(&game_id, &40, &player1, &player2, &10, &10);
+    client.end_game(&40, &true);
+
+    assert!(client.try_end_game(&40, &true).is_err());
+    assert!(client.try_end_game_draw(&40).is_err());
+    assert!(client.try_end_game(&41, &true).is_err());
+    assert_eq!(client.get_player_stats(&player1, &game_id).games_played, 1);
+}
+
+#[test]
+fn test_sessions_require_game_auth() {
+    let (env, client, game_id) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let impostor = Address::generate(&env);
+
+    // Anyone else naming the game as `game_id` cannot open a session for it
+    let start = MockAuthInvoke {
+        contract: &client.address,
+        fn_name: "start_game",
+        args: (&game_id, 50u32, &player1, &player2, 10i128, 10i128).into_val(&env),
+        sub_invokes: &[],
+    };
+    let result = client
+        .mock_auths(&[MockAuth { address: &impostor, invoke: &start }])
+        .try_start_game(&game_id, &50, &player1, &player2, &10, &10);
+    assert!(result.is_err());
+
+    env.mock_all_auths();
+    client.start_game(&game_id, &50, &player1, &player2, &10, &10);
+    assert_eq!(env.auths()[0].0, game_id);
+
+    // Nor settle it
+    let end = MockAuthInvoke {
+        contract: &client.address,
+        fn_name: "end_game",
+        args: (50u32, true).into_val(&env),
+        sub_invokes: &[],
+    };
+    let result = client
+        .mock_auths(&[MockAuth { address: &impostor, invoke: &end }])
+        .try_end_game(&50, &true);
+    assert!(result.is_err());
+    assert_eq!(client.get_player_stats(&player1, &game_id).games_played, 0);
+
+    env.mock_all_auths();
+    client.end_game(&50, &true);
+    assert_eq!(env.auths()[0].0, game_id);
+    assert_eq!(client.get_player_stats(&player1, &game_id).games_played, 1);
+}
+
+#[test]
+fn test_standings_only_record_game_sessions() {
+    let (env, client, game_id) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.start_game(&game_id, &60, &player1, &player2, &10, &10);
+
+    // Without the game's signature nothing is started or recorded
+    env.set_auths(&[]);
+    assert!(client.try_end_game(&60, &true).is_err());
+    assert!(client.try_start_game(&game_id, &61, &player1, &player2, &10, &10).is_err());
+    assert_eq!(client.get_player_stats(&player1, &game_id), PlayerStats::default());
+    assert_eq!(client.get_player_totals(&player1), PlayerStats::default());
+    let totals = client.get_game_totals(&game_id);
+    assert_eq!((totals.sessions_started, totals.sessions_ended), (1, 0));
+}
+
+// ============================================================================
+// Rating Tests
+// ============================================================================
+
+#[test]
+fn test_expected_score_table() {
+    assert_eq!(expected_score_bp(0), 5000);
+    assert_eq!(expected_score_bp(400), 9091);
+    assert_eq!(expected_score_bp(-400), 909);
+    // Interpolated between 100 (6401) and 125 (6725)
+    assert_eq!(expected_score_bp(110), 6530);
+    // Clamped beyond 800
+    assert_eq!(expected_score_bp(5000), 9901);
+    for diff in -900..=900 {
+        assert_eq!(expected_score_bp(diff) + expected_score_bp(-diff), 10_000);
+    }
+}
+
+#[test]
+fn test_ratings_follow_elo() {
+    let (env, client, game_id) = setup_test();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    assert_eq!(client.get_rating(&alice, &game_id), INITIAL_RATING);
+
+    // Equal ratings: the winner gains K/2
+    client.start_game(&game_id, &50, &alice, &bob, &10, &10);
+    client.end_game(&50, &true);
+    assert_eq!(client.get_rating(&alice, &game_id), 1516);
+    assert_eq!(client.get_rating(&bob, &game_id), 1484);
+
+    // 32 points apart: a draw moves the favourite down slightly
+    client.start_game(&game_id, &51, &alice, &bob, &10, &10);
+    client.end_game_draw(&51);
+    assert_eq!(client.get_rating(&alice, &game_id), 1515);
+    assert_eq!(client.get_rating(&bob, &game_id), 1485);
+
+    // The underdog winning gains more than K/2
+    client.start_game(&game_id, &52, &alice, &bob, &10, &10);
+    client.end_game(&52, &false);
+    assert_eq!(client.get_rating(&bob, &game_id), 1502);
+    assert_eq!(client.get_rating(&alice, &game_id), 1498);
+
+    // Ratings are per game
+    let other_game = Address::generate(&env);
+    assert_eq!(client.get_rating(&alice, &other_game), INITIAL_RATING);
+}
+
+#[test]
+fn test_multi_and_team_ratings() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    // First beats both (+16), second beats third (0 net), third loses twice (-16)
+    client.start_multi_game(&game_id, &60, &players, &vec![&env, 1, 1, 1]);
+    client.end_multi_game(&60, &vec![&env, 1, 2, 3]);
+    let ratings: [u32; 3] = core::array::from_fn(|i| client.get_rating(&players.get(i as u32).unwrap(), &game_id));
+    assert_eq!(ratings, [1516, 1500, 1484]);
+
+    // Team averages: 1508 vs 1492, so team 1 gains a little under K/2
+    let team1 = vec![&env, players.get(0).unwrap(), players.get(1).unwrap()];
+    let team2 = vec![&env, players.get(2).unwrap(), Address::generate(&env)];
+    client.start_team_game(&game_id, &61, &team1, &vec![&env, 1, 1], &team2, &vec![&env, 1, 1]);
+    client.end_team_game(&61, &true);
+    assert_eq!(client.get_rating(&team1.get(0).unwrap(), &game_id), 1531);
+    assert_eq!(client.get_rating(&team1.get(1).unwrap(), &game_id), 1515);
+    assert_eq!(client.get_rating(&team2.get(0).unwrap(), &game_id), 1469);
+    assert_eq!(client.get_rating(&team2.get(1).unwrap(), &game_id), 1485);
+}
+
+#[test]
+fn test_ratings_need_game_auth() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![&env, Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    client.start_multi_game(&game_id, &68, &players, &vec![&env, 10, 10, 10]);
+    client.start_game(&game_id, &69, &players.get(0).unwrap(), &players.get(1).unwrap(), &10, &10);
+
+    // Nobody but the game can settle a session and move ratings
+    env.set_auths(&[]);
+    assert!(client.try_end_multi_game(&68, &vec![&env, 1, 2, 3]).is_err());
+    assert!(client.try_end_game_draw(&69).is_err());
+    for player in players.iter() {
+        assert_eq!(client.get_rating(&player, &game_id), INITIAL_RATING);
+    }
+}
+
+// ============================================================================
+// Season Tests
+// ============================================================================
+
+#[test]
+fn test_season_lifecycle() {
+    let (env, client, game_id) = setup_test();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    assert_eq!(client.get_current_season(), None);
+
+    // Results before a season only count all-time
+    client.start_game(&game_id, &70, &alice, &bob, &10, &10);
+    client.end_game(&70, &true);
+
+    env.ledger().set_sequence_number(1_000);
+    assert_eq!(client.start_season(), 1);
+    assert_eq!(client.get_current_season(), Some(1));
+    assert!(client.try_start_season().is_err());
+
+    client.start_game(&game_id, &71, &alice, &bob, &10, &10);
+    client.end_game(&71, &false);
+
+    // Season standings and ratings start fresh
+    assert_eq!(client.get_season_stats(&1, &bob, &game_id).wins, 1);
+    assert_eq!(client.get_season_stats(&1, &alice, &game_id).games_played, 1);
+    assert_eq!(client.get_player_stats(&alice, &game_id).games_played, 2);
+    assert_eq!(client.get_season_rating(&1, &bob, &game_id), INITIAL_RATING + 16);
+    assert_ne!(client.get_rating(&bob, &game_id), INITIAL_RATING + 16);
+
+    env.ledger().set_sequence_number(2_000);
+    assert_eq!(client.end_season(), 1);
+    assert_eq!(client.get_current_season(), None);
+    assert!(client.try_end_season().is_err());
+
+    let season = client.get_season(&1);
+    assert_eq!(season.start_ledger, 1_000);
+    assert_eq!(season.end_ledger, Some(2_000));
+    assert_eq!(season.games, vec![&env, game_id.clone()]);
+
+    // The archive is frozen once the season ends
+    let leaderboard = client.get_leaderboard(&1, &game_id);
+    client.start_game(&game_id, &72, &alice, &bob, &10, &10);
+    client.end_game(&72, &true);
+    assert_eq!(client.get_leaderboard(&1, &game_id), leaderboard);
+    assert_eq!(client.get_season_stats(&1, &alice, &game_id).games_played, 1);
+
+    // The next season gets a new id and fresh records
+    assert_eq!(client.start_season(), 2);
+    assert_eq!(client.get_season_stats(&2, &alice, &game_id), PlayerStats::default());
+    assert!(client.get_leaderboard(&2, &game_id).is_empty());
+}
+
+#[test]
+fn test_leaderboard_is_sorted_and_bounded() {
+    let (env, client, game_id) = setup_test();
+    client.start_season();
+
+    // Twelve winners against twelve different losers
+    let mut winners = soroban_sdk::Vec::new(&env);
+    for session_id in 0..12u32 {
+        let winner = Address::generate(&env);
+        let loser = Address::generate(&env);
+        client.start_game(&game_id, &(100 + session_id), &winner, &loser, &10, &10);
+        client.end_game(&(100 + session_id), &true);
+        winners.push_back(winner);
+    }
+
+    // The first winner wins again and moves to the top
+    let top = winners.get(0).unwrap();
+    let challenger = Address::generate(&env);
+    client.start_game(&game_id, &200, &top, &challenger, &10, &10);
+    client.end_game(&200, &true);
+
+    let board = client.get_leaderboard(&1, &game_id);
+    assert_eq!(board.len(), LEADERBOARD_SIZE);
+    assert_eq!(board.get(0).unwrap().player, top);
+    for (previous, entry) in board.iter().zip(board.iter().skip(1)) {
+        assert!(previous.rating >= entry.rating);
+    }
+    assert_eq!(
+        board.get(1).unwrap(),
+        LeaderboardEntry {
+            player: winners.get(1).unwrap(),
+            rating: INITIAL_RATING + 16,
+        }
+    );
+    // Nobody below the starting rating made the cut
+    assert!(board.iter().all(|entry| entry.rating > INITIAL_RATING));
+}
+
+#[test]
+fn test_leaderboard_reranks_when_a_member_drops() {
+    let (env, client, game_id) = setup_test();
+    client.start_season();
+
+    // Eleven winners at the same rating; the last one misses the cut
+    let mut winners = soroban_sdk::Vec::new(&env);
+    for session_id in 0..=LEADERBOARD_SIZE {
+        let winner = Address::generate(&env);
+        let loser = Address::generate(&env);
+        client.start_game(&game_id, &(100 + session_id), &winner, &loser, &10, &10);
+        client.end_game(&(100 + session_id), &true);
+        winners.push_back(winner);
+    }
+    let first = winners.get(0).unwrap();
+    let evicted = winners.get(LEADERBOARD_SIZE).unwrap();
+    let board = client.get_leaderboard(&1, &game_id);
+    assert!(board.iter().all(|entry| entry.player != evicted));
+
+    // A draw against a newcomer drops the first winner below the evicted
+    // player, who moves back up
+    let newcomer = Address::generate(&env);
+    client.start_game(&game_id, &200, &first, &newcomer, &10, &10);
+    client.end_game_draw(&200);
+
+    let board = client.get_leaderboard(&1, &game_id);
+    assert_eq!(board.len(), LEADERBOARD_SIZE);
+    assert!(board.iter().all(|entry| entry.player != first));
+    assert_eq!(
+        board.get(LEADERBOARD_SIZE - 1).unwrap(),
+        LeaderboardEntry {
+            player: evicted.clone(),
+            rating: client.get_season_rating(&1, &evicted, &game_id),
+        }
+    );
+    for (previous, entry) in board.iter().zip(board.iter().skip(1)) {
+        assert!(previous.rating >= entry.rating);
+    }
+}
+
+#[test]
+fn test_leaderboard_only_ranks_game_sessions() {
+    let (env, client, game_id) = setup_test();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.start_season();
+    client.start_game(&game_id, &80, &alice, &bob, &10, &10);
+
+    // A settlement without the game's signature cannot put anyone on the board
+    env.set_auths(&[]);
+    assert!(client.try_end_game(&80, &true).is_err());
+    assert!(client.get_leaderboard(&1, &game_id).is_empty());
+    assert_eq!(client.get_season_stats(&1, &alice, &game_id), PlayerStats::default());
+
+    env.mock_all_auths();
+    client.end_game(&80, &true);
+    assert_eq!(client.get_leaderboard(&1, &game_id).get(0).unwrap().player, alice);
+}
+
+#[test]
+fn test_season_admin_required() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(MockGameHub, (&admin,));
+    let client = MockGameHubClient::new(&env, &contract_id);
+
+    // No auths mocked
+    assert!(client.try_start_season().is_err());
+    assert_eq!(client.get_admin(), admin);
+}
+
+// ============================================================================
+// Token Escrow Tests
+// ============================================================================
+
+/// Create a Stellar Asset Contract, stake `game_id` in it and mint
+/// `balance` to each player
+fn setup_token(env: &Env, client: &MockGameHubClient, game_id: &Address, players: &Vec<Address>, balance: i128) -> token::Client<'static> {
+    // Players authorize the escrow transfer inside the hub call, not at the root
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let issuer = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    let minter = token::StellarAssetClient::new(env, &sac.address());
+    for player in players.iter() {
+        minter.mint(&player, &balance);
+    }
+    client.set_game_token(game_id, &Some(sac.address()));
+    token::Client::new(env, &sac.address())
+}
+
+#[test]
+fn test_token_escrow_and_payout() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let token = setup_token(&env, &client, &game_id, &players, 1000);
+    let (player1, player2) = (players.get(0).unwrap(), players.get(1).unwrap());
+
+    client.start_game(&game_id, &60, &player1, &player2, &300, &100);
+    assert_eq!(token.balance(&player1), 700);
+    assert_eq!(token.balance(&player2), 900);
+    assert_eq!(token.balance(&client.address), 400);
+
+    client.end_game(&60, &false);
+    assert_eq!(token.balance(&player1), 700);
+    assert_eq!(token.balance(&player2), 1300);
+    assert_eq!(token.balance(&client.address), 0);
+
+    // A draw returns both stakes
+    client.start_game(&game_id, &61, &player1, &player2, &200, &200);
+    client.end_game_draw(&61);
+    assert_eq!(token.balance(&player1), 700);
+    assert_eq!(token.balance(&player2), 1300);
+
+    // Standings still count the stakes as points
+    assert_eq!(client.get_player_stats(&player2, &game_id).net_points, 300);
+}
+
+#[test]
+fn test_token_multi_and_team_payouts() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let token = setup_token(&env, &client, &game_id, &players, 1000);
+
+    // Players 1 and 2 split the others' 400 pro rata to their 100:300 stakes
+    client.start_multi_game(&game_id, &62, &players, &vec![&env, 100, 300, 200, 200]);
+    client.end_multi_game(&62, &vec![&env, 1, 1, 2, 3]);
+    let balances: Vec<i128> = Vec::from_iter(&env, players.iter().map(|player| token.balance(&player)));
+    assert_eq!(balances, vec![&env, 1100, 1300, 800, 800]);
+
+    let team1 = vec![&env, players.get(0).unwrap(), players.get(2).unwrap()];
+    let team2 = vec![&env, players.get(1).unwrap(), players.get(3).unwrap()];
+    client.start_team_game(&game_id, &63, &team1, &vec![&env, 50, 50], &team2, &vec![&env, 50, 50]);
+    client.end_team_game(&63, &true);
+    assert_eq!(token.balance(&players.get(0).unwrap()), 1150);
+    assert_eq!(token.balance(&players.get(3).unwrap()), 750);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_token_refunds() {
+    let (env, client, game_id) = setup_test();
+    env.ledger().set_sequence_number(100);
+    let players = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let token = setup_token(&env, &client, &game_id, &players, 1000);
+    let (player1, player2) = (players.get(0).unwrap(), players.get(1).unwrap());
+
+    // The game cancels: stakes come back and nothing is recorded
+    client.start_game(&game_id, &64, &player1, &player2, &500, &250);
+    client.cancel_session(&64);
+    assert_eq!(token.balance(&player1), 1000);
+    assert_eq!(token.balance(&player2), 1000);
+    assert_eq!(client.get_player_stats(&player1, &game_id).games_played, 0);
+    assert!(client.try_end_game(&64, &true).is_err());
+
+    // A stalled session can be refunded by anyone once it times out
+    client.start_game(&game_id, &65, &player1, &player2, &500, &250);
+    assert!(client.try_refund_expired(&65).is_err());
+    env.ledger().set_sequence_number(100 + SESSION_TIMEOUT_LEDGERS + 1);
+    client.refund_expired(&65);
+    assert_eq!(token.balance(&player1), 1000);
+    assert_eq!(token.balance(&player2), 1000);
+    assert_eq!(token.balance(&client.address), 0);
+
+    // The stalled game can still finish; its result and any jackpot are dropped
+    assert_eq!(client.report_jackpot(&65, &player1), 0);
+    client.end_game(&65, &true);
+    assert_eq!(token.balance(&player1), 1000);
+    assert_eq!(client.get_player_stats(&player1, &game_id).games_played, 0);
+    assert!(client.try_end_game(&65, &true).is_err());
+
+    // Plain-points sessions escrow nothing and are left to the game
+    let points_game = Address::generate(&env);
+    client.start_game(&points_game, &66, &player1, &player2, &500, &250);
+    env.ledger().set_sequence_number(100 + 2 * SESSION_TIMEOUT_LEDGERS + 2);
+    assert!(client.try_refund_expired(&66).is_err());
+    client.end_game(&66, &true);
+    assert_eq!(client.get_player_stats(&player1, &points_game).wins, 1);
+}
+
+#[test]
+fn test_negative_stakes_rejected() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let (player1, player2) = (players.get(0).unwrap(), players.get(1).unwrap());
+
+    assert!(client.try_start_game(&game_id, &68, &player1, &player2, &-100, &100).is_err());
+    assert!(client.try_start_multi_game(&game_id, &68, &players, &vec![&env, 100, -1]).is_err());
+    let (team1, team2) = (vec![&env, player1.clone()], vec![&env, player2.clone()]);
+    assert!(client.try_start_team_game(&game_id, &68, &team1, &vec![&env, 0], &team2, &vec![&env, -5]).is_err());
+    client.start_game(&game_id, &68, &player1, &player2, &0, &100);
+}
+
+#[test]
+fn test_add_stake() {
+    let (env, client, game_id) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    // Player 1 raises by 150; losing costs them the raised stake
+    client.start_game(&game_id, &69, &player1, &player2, &100, &100);
+    client.add_stake(&69, &player1, &150);
+    client.add_stake(&69, &player2, &150);
+    assert!(client.try_add_stake(&69, &player1, &0).is_err());
+    assert!(client.try_add_stake(&69, &Address::generate(&env), &10).is_err());
+    client.end_game(&69, &false);
+    assert_eq!(client.get_player_stats(&player2, &game_id).net_points, 250);
+    assert_eq!(client.get_game_totals(&game_id).points_wagered, 500);
+
+    // In token mode the raise is escrowed straight away
+    let players = vec![&env, player1.clone(), player2.clone()];
+    let token = setup_token(&env, &client, &game_id, &players, 1000);
+    client.start_game(&game_id, &70, &player1, &player2, &100, &100);
+    client.add_stake(&70, &player2, &300);
+    assert_eq!(token.balance(&player2), 600);
+    assert_eq!(token.balance(&client.address), 500);
+    client.end_game(&70, &false);
+    assert_eq!(token.balance(&player2), 1100);
+    assert_eq!(token.balance(&player1), 900);
+}
+
+#[test]
+fn test_token_mode_is_per_game() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let token = setup_token(&env, &client, &game_id, &players, 1000);
+    let (player1, player2) = (players.get(0).unwrap(), players.get(1).unwrap());
+    assert_eq!(client.get_game_token(&game_id), Some(token.address.clone()));
+
+    // Another game still plays for plain points
+    let points_game = Address::generate(&env);
+    assert_eq!(client.get_game_token(&points_game), None);
+    client.start_game(&points_game, &66, &player1, &player2, &5000, &5000);
+    client.end_game(&66, &true);
+    assert_eq!(token.balance(&player1), 1000);
+
+    // Stakes beyond a player's balance cannot start a token session
+    assert!(client.try_start_game(&game_id, &67, &player1, &player2, &5000, &10).is_err());
+
+    // Switching back to points leaves balances alone
+    client.set_game_token(&game_id, &None);
+    client.start_game(&game_id, &68, &player1, &player2, &5000, &10);
+    client.end_game(&68, &false);
+    assert_eq!(token.balance(&player2), 1000);
+}
+
+// ============================================================================
+// House Fee Tests
+// ============================================================================
+
+fn five_percent() -> FeeConfig {
+    FeeConfig {
+        rake_bp: 500,
+        min_fee: 2,
+        max_fee: 100,
+    }
+}
+
+#[test]
+fn test_rake_fee_caps() {
+    let config = five_percent();
+    assert_eq!(rake_fee(&config, 1000), 50);
+    // Caps apply, and the fee never exceeds the pot
+    assert_eq!(rake_fee(&config, 10_000), 100);
+    assert_eq!(rake_fee(&config, 20), 2);
+    assert_eq!(rake_fee(&config, 1), 1);
+    assert_eq!(rake_fee(&config, 0), 0);
+}
+
+#[test]
+fn test_rake_on_points() {
+    let (env, client, game_id) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    assert_eq!(client.get_fee_config(&game_id), None);
+    assert_eq!(client.quote_fee(&game_id, &1000), 0);
+
+    client.set_fee_config(&game_id, &Some(five_percent()));
+    assert_eq!(client.get_fee_config(&game_id), Some(five_percent()));
+    assert_eq!(client.quote_fee(&game_id, &1000), 50);
+
+    // The winner gets the loser's 1000 less the 50 fee
+    client.start_game(&game_id, &70, &player1, &player2, &1000, &1000);
+    client.end_game(&70, &true);
+    assert_eq!(client.get_player_stats(&player1, &game_id).net_points, 950);
+    assert_eq!(client.get_player_stats(&player2, &game_id).net_points, -1000);
+
+    // Draws have no pot, so no fee
+    client.start_game(&game_id, &71, &player1, &player2, &1000, &1000);
+    client.end_game_draw(&71);
+
+    assert_eq!(client.get_game_totals(&game_id).fees_collected, 50);
+    assert_eq!(client.get_hub_totals().fees_collected, 50);
+    assert_eq!(client.get_accrued_fees(&None), 50);
+    assert_eq!(client.withdraw_fees(&None), 50);
+    assert_eq!(client.get_accrued_fees(&None), 0);
+
+    // Removing the config makes the game fee-free again
+    client.set_fee_config(&game_id, &None);
+    client.start_game(&game_id, &72, &player1, &player2, &1000, &1000);
+    client.end_game(&72, &true);
+    assert_eq!(client.get_game_totals(&game_id).fees_collected, 50);
+}
+
+#[test]
+fn test_rake_on_tokens_goes_to_treasury() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![&env, Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    let token = setup_token(&env, &client, &game_id, &players, 1000);
+    client.set_fee_config(&game_id, &Some(five_percent()));
+
+    let treasury = Address::generate(&env);
+    assert_eq!(client.get_treasury(), client.get_admin());
+    client.set_treasury(&treasury);
+    assert_eq!(client.get_treasury(), treasury);
+
+    // Two winners split the 600 pot less a 30 fee, 1:1
+    client.start_multi_game(&game_id, &73, &players, &vec![&env, 200, 200, 600]);
+    client.end_multi_game(&73, &vec![&env, 1, 1, 2]);
+    assert_eq!(token.balance(&players.get(0).unwrap()), 1285);
+    assert_eq!(token.balance(&players.get(1).unwrap()), 1285);
+    assert_eq!(token.balance(&client.address), 30);
+    assert_eq!(client.get_accrued_fees(&Some(token.address.clone())), 30);
+
+    assert_eq!(client.withdraw_fees(&Some(token.address.clone())), 30);
+    assert_eq!(token.balance(&treasury), 30);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid fee config")]
+fn test_fee_config_rejects_inverted_caps() {
+    let (_env, client, game_id) = setup_test();
+    let config = FeeConfig {
+        rake_bp: 500,
+        min_fee: 100,
+        max_fee: 10,
+    };
+    client.set_fee_config(&game_id, &Some(config));
+}
+
+// ============================================================================
+// Jackpot Tests
+// ============================================================================
+
+#[test]
+fn test_jackpot_builds_and_pays_out() {
+    let (env, client, game_id) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.set_fee_config(&game_id, &Some(five_percent()));
+    client.set_jackpot_rate(&game_id, &1000);
+    assert_eq!(client.get_jackpot_rate(&game_id), 1000);
+
+    // Pot 1000: fee 50, then 10% of the remaining 950 to the jackpot
+    client.start_game(&game_id, &80, &player1, &player2, &1000, &1000);
+    client.end_game(&80, &true);
+    assert_eq!(client.get_jackpot(&game_id), 95);
+    assert_eq!(client.get_player_stats(&player1, &game_id).net_points, 855);
+
+    // The game reports a jackpot result before ending the session
+    client.start_game(&game_id, &81, &player1, &player2, &1000, &1000);
+    assert_eq!(client.report_jackpot(&81, &player2), 95);
+    assert_eq!(client.get_jackpot(&game_id), 0);
+    assert_eq!(client.report_jackpot(&81, &player2), 0);
+    client.end_game_draw(&81);
+    assert_eq!(client.get_player_stats(&player2, &game_id).net_points, -905);
+}
+
+#[test]
+fn test_jackpot_in_tokens() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let token = setup_token(&env, &client, &game_id, &players, 1000);
+    let (player1, player2) = (players.get(0).unwrap(), players.get(1).unwrap());
+    client.set_jackpot_rate(&game_id, &5000);
+
+    client.start_game(&game_id, &82, &player1, &player2, &400, &400);
+    client.end_game(&82, &true);
+    assert_eq!(token.balance(&player1), 1200);
+    assert_eq!(token.balance(&client.address), 200);
+
+    // The token cannot change while the jackpot holds escrowed funds
+    assert!(client.try_set_game_token(&game_id, &None).is_err());
+
+    client.start_game(&game_id, &83, &player1, &player2, &100, &100);
+    client.report_jackpot(&83, &player2);
+    client.end_game(&83, &true);
+    assert_eq!(token.balance(&player2), 600 - 100 + 200);
+
+    // Half of the losing 100 starts the next jackpot
+    assert_eq!(client.get_jackpot(&game_id), 50);
+    assert_eq!(token.balance(&client.address), 50);
+}
+
+#[test]
+#[should_panic(expected = "Player not in session")]
+fn test_jackpot_requires_session_player() {
+    let (env, client, game_id) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.start_game(&game_id, &84, &player1, &player2, &10, &10);
+    client.report_jackpot(&84, &Address::generate(&env));
+}
+
+// ============================================================================
+// Payout Policy Tests
+// ============================================================================
+
+// The games' asymmetric test stakes
+const FAVOURITE_STAKE: i128 = 200_0000000;
+const UNDERDOG_STAKE: i128 = 50_0000000;
+
+#[test]
+fn test_winnings_by_policy() {
+    let (big, small) = (FAVOURITE_STAKE, UNDERDOG_STAKE);
+
+    assert_eq!(winnings(&PayoutPolicy::WinnerTakesAll, small, big), big);
+    assert_eq!(winnings(&PayoutPolicy::WinnerTakesAll, big, small), small);
+
+    // Each side wins at most what it staked
+    assert_eq!(winnings(&PayoutPolicy::Proportional, small, big), small);
+    assert_eq!(winnings(&PayoutPolicy::Proportional, big, small), small);
+
+    // 3:1 odds: the underdog wins three times its stake...
+    let odds = PayoutPolicy::FixedOdds(30_000);
+    assert_eq!(winnings(&odds, small, big), 150_0000000);
+    // ...the favourite a third of its stake, capped at the underdog's stake
+    assert_eq!(winnings(&odds, big, small), small);
+    assert_eq!(winnings(&PayoutPolicy::FixedOdds(50_000), big, small), 40_0000000);
+    // Even stakes settle 1:1
+    assert_eq!(winnings(&odds, small, small), small);
+}
+
+#[test]
+fn test_payout_policies_on_points() {
+    let (env, client, game_id) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    assert_eq!(client.get_payout_policy(&game_id), PayoutPolicy::WinnerTakesAll);
+
+    // Winner-takes-all: the underdog takes the favourite's whole stake
+    client.start_game(&game_id, &90, &player1, &player2, &FAVOURITE_STAKE, &UNDERDOG_STAKE);
+    client.end_game(&90, &false);
+    assert_eq!(client.get_player_stats(&player2, &game_id).net_points, FAVOURITE_STAKE);
+
+    // Proportional: the underdog only wins as much as it risked
+    client.set_payout_policy(&game_id, &PayoutPolicy::Proportional);
+    client.start_game(&game_id, &91, &player1, &player2, &FAVOURITE_STAKE, &UNDERDOG_STAKE);
+    assert_eq!(client.get_session_policy(&91), PayoutPolicy::Proportional);
+    client.end_game(&91, &false);
+    assert_eq!(client.get_player_stats(&player2, &game_id).net_points, FAVOURITE_STAKE + UNDERDOG_STAKE);
+    assert_eq!(client.get_player_stats(&player1, &game_id).net_points, -FAVOURITE_STAKE - UNDERDOG_STAKE);
+
+    // ...and the favourite wins the underdog's whole stake
+    client.start_game(&game_id, &92, &player1, &player2, &FAVOURITE_STAKE, &UNDERDOG_STAKE);
+    client.end_game(&92, &true);
+    assert_eq!(client.get_player_stats(&player1, &game_id).net_points, -FAVOURITE_STAKE);
+}
+
+#[test]
+fn test_fixed_odds_in_tokens() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let token = setup_token(&env, &client, &game_id, &players, 1000_0000000);
+    let (player1, player2) = (players.get(0).unwrap(), players.get(1).unwrap());
+    client.set_payout_policy(&game_id, &PayoutPolicy::FixedOdds(30_000));
+
+    // The underdog wins 3 x 50; the favourite's unmatched 50 comes back
+    client.start_game(&game_id, &93, &player1, &player2, &FAVOURITE_STAKE, &UNDERDOG_STAKE);
+    client.end_game(&93, &false);
+    assert_eq!(token.balance(&player1), 850_0000000);
+    assert_eq!(token.balance(&player2), 1150_0000000);
+    assert_eq!(token.balance(&client.address), 0);
+
+    // The favourite wins the underdog's stake
+    client.start_game(&game_id, &94, &player1, &player2, &FAVOURITE_STAKE, &UNDERDOG_STAKE);
+    client.end_game(&94, &true);
+    assert_eq!(token.balance(&player1), 900_0000000);
+    assert_eq!(token.balance(&player2), 1100_0000000);
+
+    // Fees come out of what the winner actually wins
+    client.set_fee_config(&game_id, &Some(five_percent()));
+    client.start_game(&game_id, &95, &player1, &player2, &300, &100);
+    client.end_game(&95, &false);
+    assert_eq!(token.balance(&player1), 900_0000000 - 300);
+    assert_eq!(token.balance(&player2), 1100_0000000 + 300 - 15);
+    assert_eq!(token.balance(&client.address), 15);
+}
+
+#[test]
+fn test_session_policy_override() {
+    let (env, client, game_id) = setup_test();
+    let players = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    // Sessions keep the policy they started with
+    client.start_multi_game(&game_id, &96, &players, &vec![&env, 100, 300, 600]);
+    client.set_payout_policy(&game_id, &PayoutPolicy::Proportional);
+    assert_eq!(client.get_session_policy(&96), PayoutPolicy::WinnerTakesAll);
+
+    // The game declares odds for this session only: the two underdogs staked
+    // 400 against 600 and win 400 x 1.25 = 500, split 1:3
+    client.set_session_policy(&96, &PayoutPolicy::FixedOdds(12_500));
+    client.end_multi_game(&96, &vec![&env, 1, 1, 2]);
+    let stats: Vec<i128> = Vec::from_iter(&env, players.iter().map(|player| client.get_player_stats(&player, &game_id).net_points));
+    assert_eq!(stats, vec![&env, 125, 375, -500]);
+
+    assert!(client.try_set_session_policy(&96, &PayoutPolicy::Proportional).is_err());
+    assert_eq!(client.get_payout_policy(&game_id), PayoutPolicy::Proportional);
+}
+
+#[test]
+#[should_panic(expected = "Invalid odds")]
+fn test_fixed_odds_reject_odds_on() {
+    let (_env, client, game_id) = setup_test();
+    client.set_payout_policy(&game_id, &PayoutPolicy::FixedOdds(5_000));
+}
@@ -0,0 +1,459 @@
+#![no_std]
+
+//! # Nim
+//!
+//! A two-player take-away game with no randomness. The game starts with a few
+//! heaps of objects, chosen at `start_game`. Player 1 moves first and players
+//! strictly alternate, each taking one or more objects from a single heap.
+//! Under normal play whoever takes the last object wins; under misère play
+//! whoever takes the last object loses. A player who stops moving can be
+//! forfeited once their turn times out.
+//!
+//! **Game Hub Integration:**
+//! This game is Game Hub-aware and enforces all games to be played through the
+//! Game Hub contract. Games cannot be started or completed without points involvement.
+
+use soroban_sdk::{
+    Address, BytesN, Env, IntoVal, Vec, contract, contractclient, contracterror, contractimpl,
+    contracttype, vec
+};
+
+// Import GameHub contract interface
+// This allows us to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(
+        env: Env,
+        session_id: u32,
+        player1_won: bool
+    );
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    GameNotFound = 1,
+    NotPlayer = 2,
+    NotYourTurn = 3,
+    InvalidHeap = 4,
+    InvalidCount = 5,
+    GameAlreadyEnded = 6,
+    TimeoutNotReached = 7,
+    CannotClaimTimeout = 8,
+    SelfPlay = 9,
+    InvalidRules = 10,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// Waiting for `next_player` to move
+    Playing,
+    /// The heaps ran out or `winner` won on timeout
+    Won,
+}
+
+/// Starting position and play convention, agreed by both players
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rules {
+    /// Starting heap sizes, 1..=MAX_HEAPS heaps of 1..=MAX_HEAP_SIZE objects
+    pub heaps: Vec<u32>,
+    /// Misère play: whoever takes the last object loses
+    pub misere: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    /// Objects left in each heap
+    pub heaps: Vec<u32>,
+    pub misere: bool,
+    pub next_player: Address,
+    /// Ledger after which `next_player` can be forfeited
+    pub deadline: u32,
+    pub status: Status,
+    pub winner: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Game(u32),
+    GameHubAddress,
+    Admin,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) ensures game data doesn't expire unexpectedly
+// Games are stored in temporary storage with a minimum 30-day retention
+
+/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Ledgers a player has to move before the opponent may claim the game
+/// 1 day = 24 * 60 * 60 / 5 = 17,280 ledgers
+const MOVE_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Most heaps a game can start with
+pub const MAX_HEAPS: u32 = 8;
+
+/// Most objects a heap can start with
+pub const MAX_HEAP_SIZE: u32 = 100;
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct NimContract;
+
+#[contractimpl]
+impl NimContract {
+    /// Initialize the contract with GameHub address and admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    /// * `game_hub` - Address of the GameHub contract
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        // Store admin and GameHub address
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &game_hub);
+    }
+
+    /// Start a new game between two players with points.
+    /// This creates a session in the Game Hub and locks points before starting the game.
+    ///
+    /// **CRITICAL:** This method requires authorization from THIS contract (not players).
+    /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+    ///
+    /// **Auth:** each player authorizes `(session_id, points, rules)`, so both
+    /// agree on the starting position.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player (moves first)
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points amount committed by player 1
+    /// * `player2_points` - Points amount committed by player 2
+    /// * `rules` - Starting heaps and play convention
+    pub fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        rules: Rules,
+    ) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            return Err(Error::SelfPlay);
+        }
+
+        if rules.heaps.is_empty()
+            || rules.heaps.len() > MAX_HEAPS
+            || rules.heaps.iter().any(|size| size == 0 || size > MAX_HEAP_SIZE)
+        {
+            return Err(Error::InvalidRules);
+        }
+
+        // Require authentication from both players (they consent to committing points)
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            rules.clone().into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            rules.clone().into_val(&env),
+        ]);
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Create GameHub client
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        // Create game (full heaps, player 1 moves first)
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            heaps: rules.heaps,
+            misere: rules.misere,
+            next_player: player1,
+            deadline: env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS,
+            status: Status::Playing,
+            winner: None,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        Self::save_game(&env, session_id, &game);
+
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
+    }
+
+    /// Take `count` objects from one heap. Must be the player's turn.
+    /// Taking the last object ends the game and the result is reported to
+    /// the Game Hub.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the moving player
+    /// * `heap` - Heap index
+    /// * `count` - Objects to take, from 1 to the heap's size
+    ///
+    /// # Returns
+    /// * `Status` - The game status after the move
+    pub fn take(env: Env, session_id: u32, player: Address, heap: u32, count: u32) -> Result<Status, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        if game.status != Status::Playing {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if player != game.next_player {
+            return Err(Error::NotYourTurn);
+        }
+
+        let size = game.heaps.get(heap).ok_or(Error::InvalidHeap)?;
+        if count == 0 || count > size {
+            return Err(Error::InvalidCount);
+        }
+        game.heaps.set(heap, size - count);
+
+        let opponent = Self::opponent(&game, &player);
+        if game.heaps.iter().all(|size| size == 0) {
+            // The player took the last object
+            let winner = if game.misere { opponent } else { player };
+            Self::finish_game(&env, session_id, &mut game, winner);
+        } else {
+            game.next_player = opponent;
+            game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+            Self::save_game(&env, session_id, &game);
+        }
+
+        Ok(game.status)
+    }
+
+    /// Win the game because the opponent did not move before the deadline.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the claiming player (the one waiting)
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player (the claimant)
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        if game.status != Status::Playing {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+        if player == game.next_player {
+            return Err(Error::CannotClaimTimeout);
+        }
+
+        Self::finish_game(&env, session_id, &mut game, player.clone());
+
+        Ok(player)
+    }
+
+    /// Get game information.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Game` - The game state
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        Self::load_game(&env, session_id)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    fn load_game(env: &Env, session_id: u32) -> Result<Game, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    fn save_game(env: &Env, session_id: u32, game: &Game) {
+        let key = DataKey::Game(session_id);
+        env.storage().temporary().set(&key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    fn opponent(game: &Game, player: &Address) -> Address {
+        if *player == game.player1 {
+            game.player2.clone()
+        } else {
+            game.player1.clone()
+        }
+    }
+
+    /// Record the winner and end the session with the Game Hub
+    fn finish_game(env: &Env, session_id: u32, game: &mut Game, winner: Address) {
+        game.status = Status::Won;
+        game.winner = Some(winner.clone());
+        Self::save_game(env, session_id, game);
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Call GameHub to end the session
+        // This unlocks points and updates standings
+        // Event emitted by the Game Hub contract (GameEnded)
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        game_hub.end_game(&session_id, &(winner == game.player1));
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    pub fn get_hub(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set")
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,232 @@
+#![cfg(test)]
+extern crate std;
+
+// Unit tests for the Nim contract using a simple mock GameHub.
+// Nim has no randomness, so small games can be played out exhaustively.
+
+use crate::{Error, NimContract, NimContractClient, Rules, Status, MAX_HEAPS, MAX_HEAP_SIZE};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use std::vec::Vec as StdVec;
+
+// ============================================================================
+// Mock GameHub for Unit Testing
+// ============================================================================
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+        // Mock implementation - does nothing
+    }
+}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (
+    Env,
+    NimContractClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Set ledger info for time-based operations
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    // Deploy mock GameHub contract
+    let hub_addr = env.register(MockGameHub, ());
+
+    // Deploy Nim with admin and GameHub address
+    let admin = Address::generate(&env);
+    let contract_id = env.register(NimContract, (&admin, &hub_addr));
+    let client = NimContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    (env, client, player1, player2)
+}
+
+/// Assert that a Result contains a specific Nim error
+fn assert_nim_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        _ => panic!("Expected error {:?}, got something else", expected_error),
+    }
+}
+
+fn rules(env: &Env, heaps: &[u32], misere: bool) -> Rules {
+    Rules {
+        heaps: Vec::from_slice(env, heaps),
+        misere,
+    }
+}
+
+/// Every complete game from `heaps`, as (heap, count) moves
+fn all_games(heaps: &mut StdVec<u32>) -> StdVec<StdVec<(u32, u32)>> {
+    let mut games = StdVec::new();
+    for heap in 0..heaps.len() {
+        for count in 1..=heaps[heap] {
+            heaps[heap] -= count;
+            let rest = if heaps.iter().all(|size| *size == 0) {
+                std::vec![StdVec::new()]
+            } else {
+                all_games(heaps)
+            };
+            for mut game in rest {
+                game.insert(0, (heap as u32, count));
+                games.push(game);
+            }
+            heaps[heap] += count;
+        }
+    }
+    games
+}
+
+// ============================================================================
+// Game Flow Tests
+// ============================================================================
+
+#[test]
+fn test_every_small_game() {
+    let (env, client, player1, player2) = setup_test();
+    let start = [1, 2, 2];
+    let games = all_games(&mut start.to_vec());
+    assert!(games.len() > 20);
+
+    let mut session_id = 0;
+    for misere in [false, true] {
+        for moves in &games {
+            session_id += 1;
+            client.start_game(&session_id, &player1, &player2, &100, &100, &rules(&env, &start, misere));
+
+            // Only the player to move may take, and they alternate strictly
+            let mut status = Status::Playing;
+            for (i, (heap, count)) in moves.iter().enumerate() {
+                let (mover, waiting) = if i % 2 == 0 { (&player1, &player2) } else { (&player2, &player1) };
+                assert_nim_error(&client.try_take(&session_id, waiting, heap, count), Error::NotYourTurn);
+                assert_eq!(client.get_game(&session_id).next_player, *mover);
+                status = client.take(&session_id, mover, heap, count);
+            }
+            assert_eq!(status, Status::Won);
+
+            // The last mover wins normal play and loses misère play
+            let last_mover = if moves.len() % 2 == 1 { &player1 } else { &player2 };
+            let game = client.get_game(&session_id);
+            assert_eq!(game.winner.as_ref() == Some(last_mover), !misere);
+            assert_nim_error(&client.try_take(&session_id, &player1, &0, &1), Error::GameAlreadyEnded);
+        }
+    }
+}
+
+#[test]
+fn test_winning_strategy() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100, &100, &rules(&env, &[3, 4, 5], false));
+
+    // Player 1 always leaves a zero nim-sum; player 2 takes one from the first heap it can
+    loop {
+        let heaps = client.get_game(&1).heaps;
+        let nim_sum = heaps.iter().fold(0, |sum, size| sum ^ size);
+        let heap = heaps.iter().position(|size| size ^ nim_sum < size).unwrap() as u32;
+        let size = heaps.get_unchecked(heap);
+        if client.take(&1, &player1, &heap, &(size - (size ^ nim_sum))) == Status::Won {
+            break;
+        }
+
+        let heap = client.get_game(&1).heaps.iter().position(|size| size > 0).unwrap() as u32;
+        assert_eq!(client.take(&1, &player2, &heap, &1), Status::Playing);
+    }
+    assert_eq!(client.get_game(&1).winner, Some(player1));
+}
+
+#[test]
+fn test_invalid_moves() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100, &100, &rules(&env, &[3, 4], false));
+
+    assert_nim_error(&client.try_take(&1, &player1, &2, &1), Error::InvalidHeap);
+    assert_nim_error(&client.try_take(&1, &player1, &0, &0), Error::InvalidCount);
+    assert_nim_error(&client.try_take(&1, &player1, &0, &4), Error::InvalidCount);
+    assert_nim_error(&client.try_take(&1, &Address::generate(&env), &0, &1), Error::NotPlayer);
+
+    client.take(&1, &player1, &1, &4);
+    assert_nim_error(&client.try_take(&1, &player2, &1, &1), Error::InvalidCount);
+    assert_eq!(client.get_game(&1).heaps, Vec::from_array(&env, [3, 0]));
+}
+
+#[test]
+fn test_invalid_rules() {
+    let (env, client, player1, player2) = setup_test();
+    let too_many = [1; MAX_HEAPS as usize + 1];
+    for heaps in [&[][..], &too_many[..], &[3, 0][..], &[MAX_HEAP_SIZE + 1][..]] {
+        let result = client.try_start_game(&1, &player1, &player2, &100, &100, &rules(&env, heaps, false));
+        assert_nim_error(&result, Error::InvalidRules);
+    }
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_does_not_move() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100, &100, &rules(&env, &[5], true));
+    client.take(&1, &player1, &0, &2);
+
+    assert_nim_error(&client.try_claim_timeout(&1, &player1), Error::TimeoutNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    assert_nim_error(&client.try_claim_timeout(&1, &player2), Error::CannotClaimTimeout);
+    assert_eq!(client.claim_timeout(&1, &player1), player1);
+
+    let game = client.get_game(&1);
+    assert_eq!(game.status, Status::Won);
+    assert_eq!(game.winner, Some(player1));
+}
+
+#[test]
+fn test_cannot_play_against_self() {
+    let (env, client, player1, _player2) = setup_test();
+    let result = client.try_start_game(&1, &player1, &player1, &100, &100, &rules(&env, &[3], false));
+    assert_nim_error(&result, Error::SelfPlay);
+}
+
+#[test]
+fn test_cannot_get_nonexistent_game() {
+    let (_env, client, _player1, _player2) = setup_test();
+    assert_nim_error(&client.try_get_game(&999), Error::GameNotFound);
+}
@@ -5,13 +5,30 @@
 //! A simple two-player guessing game where players guess a number between 1 and 10.
 //! The player whose guess is closest to the randomly generated number wins.
 //!
+//! Sessions started with `start_game_with_config` choose their own range
+//! (anywhere in `u32`) and scoring mode: closest wins, exact match or draw,
+//! or closest without going over.
+//!
+//! **Hinted mode** (`start_hinted_game`): a host commits to a secret number up
+//! front. Players alternate guesses and the host answers each one with
+//! Higher/Lower/Correct. The first correct guess wins; after `max_turns`
+//! guesses the closest guess wins. The host reveals the number at the end and
+//! the contract settles from the revealed number, so a lying host cannot
+//! change the result.
+//!
+//! **Multi-player mode** (`start_multi_game`): 2..=8 players each guess once
+//! and are ranked by the session's scoring mode. Everyone sharing first place
+//! wins; the ranking is reported to the Game Hub with `end_multi_game`.
+//!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl, contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, Map, Vec, contract, contractclient, contracterror, contractimpl,
+    contracttype, vec
 };
+pub use session_keys::{SessionKey, MAX_SESSION_KEY_LEDGERS};
 
 // Import GameHub contract interface
 // This allows us to call into the GameHub contract
@@ -32,6 +49,18 @@ pub trait GameHub {
         session_id: u32,
         player1_won: bool
     );
+
+    fn end_game_draw(env: Env, session_id: u32);
+
+    fn start_multi_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+    );
+
+    fn end_multi_game(env: Env, session_id: u32, places: Vec<u32>);
 }
 
 // ============================================================================
@@ -47,12 +76,57 @@ pub enum Error {
     AlreadyGuessed = 3,
     BothPlayersNotGuessed = 4,
     GameAlreadyEnded = 5,
+    GuessOutOfRange = 6,
+    InvalidConfig = 7,
+    NotYourTurn = 8,
+    HintPending = 9,
+    NoPendingGuess = 10,
+    AwaitingReveal = 11,
+    NotReadyToReveal = 12,
+    InvalidReveal = 13,
+    TimeoutNotReached = 14,
+    InvalidPlayers = 15,
+    NotAllGuessed = 16,
+    InvalidSessionKey = 17,
+}
+
+impl From<session_keys::GrantError> for Error {
+    fn from(error: session_keys::GrantError) -> Self {
+        match error {
+            session_keys::GrantError::NotPlayer => Error::NotPlayer,
+            session_keys::GrantError::InvalidSessionKey => Error::InvalidSessionKey,
+        }
+    }
 }
 
 // ============================================================================
 // Data Types
 // ============================================================================
 
+/// How guesses are compared against the winning number
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ScoringMode {
+    /// Closest guess wins; equal distances go to player 1
+    Closest,
+    /// Only an exact guess wins; no exact guess (or two) is a draw
+    ExactOrDraw,
+    /// Closest guess that is not above the number wins ("price is right");
+    /// both over, or equal guesses, is a draw
+    ClosestWithoutOver,
+}
+
+/// Per-session range and scoring, fixed when the game starts
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuessConfig {
+    /// Lowest allowed guess (inclusive)
+    pub min: u32,
+    /// Highest allowed guess (inclusive), must be above `min`
+    pub max: u32,
+    pub scoring: ScoringMode,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
@@ -60,16 +134,83 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
+    pub config: GuessConfig,
     pub player1_guess: Option<u32>,
     pub player2_guess: Option<u32>,
     pub winning_number: Option<u32>,
     pub winner: Option<Address>,
+    /// Set when the game ended without a winner
+    pub draw: bool,
+}
+
+/// Host's answer to a guess in hinted mode, relative to the guess
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Hint {
+    /// The secret number is higher than the guess
+    Higher,
+    /// The secret number is lower than the guess
+    Lower,
+    Correct,
+}
+
+/// Setup for a hinted game, signed by both players
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HintedSetup {
+    /// Address that commits the secret and answers guesses
+    pub host: Address,
+    pub min: u32,
+    pub max: u32,
+    /// Total guesses (both players) before the closest guess wins
+    pub max_turns: u32,
+    /// keccak256(secret number as 4 big-endian bytes || 32-byte salt)
+    pub commitment: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HintedGame {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub setup: HintedSetup,
+    /// All guesses in order; even indices are player 1's
+    pub guesses: Vec<u32>,
+    /// Host answers, one per answered guess
+    pub hints: Vec<Hint>,
+    /// Ledger after which whoever is holding up the game can be timed out
+    pub deadline: u32,
+    /// Revealed secret, None until the host reveals it
+    pub secret_number: Option<u32>,
+    /// False if the reveal showed a hint was wrong
+    pub host_honest: bool,
+    pub winner: Option<Address>,
+    pub draw: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiGame {
+    pub players: Vec<Address>,
+    /// Points committed by each player, same order as `players`
+    pub points: Vec<i128>,
+    pub config: GuessConfig,
+    pub guesses: Map<Address, u32>,
+    pub winning_number: Option<u32>,
+    /// Place of each player (1 = first, ties share a place); empty until revealed
+    pub places: Vec<u32>,
+    /// Players in first place; all of them if the game is a draw
+    pub winners: Vec<Address>,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
+    HintedGame(u32),
+    MultiGame(u32),
     GameHubAddress,
     Admin,
 }
@@ -84,6 +225,101 @@ pub enum DataKey {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// Ledgers a player or the host has to act in hinted mode
+/// 1 day = 24 * 60 * 60 / 5 = 17,280 ledgers
+const MOVE_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Player count limits for multi-player sessions (matches the Game Hub)
+const MIN_PLAYERS: u32 = 2;
+const MAX_PLAYERS: u32 = 8;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// The classic rules: guess 1-10, closest wins
+fn default_config() -> GuessConfig {
+    GuessConfig {
+        min: 1,
+        max: 10,
+        scoring: ScoringMode::Closest,
+    }
+}
+
+/// Decide the outcome of a reveal.
+/// Returns Some(true) if player 1 wins, Some(false) if player 2 wins, None for a draw.
+fn score(scoring: &ScoringMode, guess1: u32, guess2: u32, winning_number: u32) -> Option<bool> {
+    let distance1 = guess1.abs_diff(winning_number);
+    let distance2 = guess2.abs_diff(winning_number);
+
+    match scoring {
+        ScoringMode::Closest => Some(distance1 <= distance2),
+        ScoringMode::ExactOrDraw => match (guess1 == winning_number, guess2 == winning_number) {
+            (true, false) => Some(true),
+            (false, true) => Some(false),
+            _ => None,
+        },
+        ScoringMode::ClosestWithoutOver => {
+            match (guess1 <= winning_number, guess2 <= winning_number) {
+                (true, false) => Some(true),
+                (false, true) => Some(false),
+                (false, false) => None,
+                (true, true) if guess1 == guess2 => None,
+                (true, true) => Some(guess1 > guess2),
+            }
+        }
+    }
+}
+
+/// Sort key for a guess in multi-player mode; lower is better.
+/// Mirrors `score`: guesses the two-player rules would call a draw get equal keys.
+fn rank_key(scoring: &ScoringMode, guess: u32, winning_number: u32) -> (u32, u32) {
+    match scoring {
+        ScoringMode::Closest => (0, guess.abs_diff(winning_number)),
+        ScoringMode::ExactOrDraw => ((guess != winning_number) as u32, 0),
+        ScoringMode::ClosestWithoutOver if guess > winning_number => (1, 0),
+        ScoringMode::ClosestWithoutOver => (0, winning_number - guess),
+    }
+}
+
+/// Competition ranking ("1224"): each place is one more than the number of
+/// strictly better keys
+fn places_for(env: &Env, keys: &Vec<(u32, u32)>) -> Vec<u32> {
+    let mut places = Vec::new(env);
+    for key in keys.iter() {
+        let better = keys.iter().filter(|other| *other < key).count() as u32;
+        places.push_back(better + 1);
+    }
+    places
+}
+
+/// Truthful hint for a guess against the secret number
+fn hint_for(guess: u32, secret_number: u32) -> Hint {
+    if secret_number > guess {
+        Hint::Higher
+    } else if secret_number < guess {
+        Hint::Lower
+    } else {
+        Hint::Correct
+    }
+}
+
+/// Index of the winning guess in hinted mode: the first exact guess, otherwise
+/// the closest guess (earliest wins ties). None if there were no guesses.
+fn hinted_winning_index(guesses: &Vec<u32>, secret_number: u32) -> Option<u32> {
+    let mut best: Option<(u32, u32)> = None;
+    for (index, guess) in guesses.iter().enumerate() {
+        let distance = guess.abs_diff(secret_number);
+        if best.is_none_or(|(_, best_distance)| distance < best_distance) {
+            best = Some((index as u32, distance));
+        }
+        if distance == 0 {
+            break;
+        }
+    }
+    best.map(|(index, _)| index)
+}
+
 // ============================================================================
 // Contract Definition
 // ============================================================================
@@ -118,6 +354,8 @@ impl NumberGuessContract {
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    /// * `session_keys` - Move keys to delegate up front, each mapped to its
+    ///   player and expiry (see `delegate_session_key`); may be empty
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -125,77 +363,107 @@ impl NumberGuessContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
+        session_keys: Map<Address, SessionKey>,
     ) -> Result<(), Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
         }
 
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
-
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+        // Both players consent to the points and to any session keys
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
 
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call Game Hub to start the session and lock points
-        // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
-
-        // Create game (winning_number not set yet - will be generated in reveal_winner)
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
+        Self::create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
             player1_points,
             player2_points,
-            player1_guess: None,
-            player2_guess: None,
-            winning_number: None,
-            winner: None,
-        };
+            default_config(),
+            session_keys,
+        )
+    }
 
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
+    /// Start a new game with a custom range and scoring mode.
+    /// Behaves like `start_game`, but both players also sign the config.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points amount committed by player 1
+    /// * `player2_points` - Points amount committed by player 2
+    /// * `config` - Guess range and scoring mode for this session
+    /// * `session_keys` - Move keys to delegate up front, each mapped to its
+    ///   player and expiry (see `delegate_session_key`); may be empty
+    pub fn start_game_with_config(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        config: GuessConfig,
+        session_keys: Map<Address, SessionKey>,
+    ) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
+        }
 
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        if config.min >= config.max {
+            return Err(Error::InvalidConfig);
+        }
 
-        // Event emitted by the Game Hub contract (GameStarted)
+        // Both players consent to the points, the config and any session keys
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            config.clone().into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            config.clone().into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
 
-        Ok(())
+        Self::create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            config,
+            session_keys,
+        )
     }
 
     /// Make a guess for the current game.
-    /// Players can guess a number between 1 and 10.
+    /// Players can guess a number in the session's range (1-10 by default).
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `player` - Address of the player making the guess
-    /// * `guess` - The guessed number (1-10)
+    /// * `player` - Address of the player making the guess, or their session key
+    /// * `guess` - The guessed number, within the session's range
     pub fn make_guess(env: Env, session_id: u32, player: Address, guess: u32) -> Result<(), Error> {
-        player.require_auth();
-
-        // Validate guess is in range
-        if guess < 1 || guess > 10 {
-            panic!("Guess must be between 1 and 10");
-        }
+        let player = session_keys::move_player(&env, session_id, player);
 
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
@@ -205,11 +473,16 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner or draw yet)
+        if game.winner.is_some() || game.draw {
             return Err(Error::GameAlreadyEnded);
         }
 
+        // Validate guess is in range
+        if !(game.config.min..=game.config.max).contains(&guess) {
+            return Err(Error::GuessOutOfRange);
+        }
+
         // Update guess for the appropriate player
         if player == game.player1 {
             if game.player1_guess.is_some() {
@@ -235,14 +508,15 @@ impl NumberGuessContract {
 
     /// Reveal the winner of the game and submit outcome to GameHub.
     /// Can only be called after both players have made their guesses.
-    /// This generates the winning number, determines the winner, and ends the session.
+    /// This generates the winning number, scores the guesses with the session's
+    /// scoring mode, and ends the session (as a draw if nobody wins).
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - Some(winner), or None if the game ended in a draw
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -253,14 +527,17 @@ impl NumberGuessContract {
 
         // Check if game already ended (has a winner)
         if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+            return Ok(Some(winner.clone()));
+        }
+        if game.draw {
+            return Ok(None);
         }
 
         // Check both players have guessed
         let guess1 = game.player1_guess.ok_or(Error::BothPlayersNotGuessed)?;
         let guess2 = game.player2_guess.ok_or(Error::BothPlayersNotGuessed)?;
 
-        // Generate random winning number between 1 and 10 using seeded PRNG
+        // Generate random winning number in the session's range using seeded PRNG
         // This is done AFTER both players have committed their guesses
         //
         // Seed components (all deterministic and identical between sim/submit):
@@ -289,24 +566,35 @@ impl NumberGuessContract {
 
         let seed = env.crypto().keccak256(&seed_bytes);
         env.prng().seed(seed.into());
-        let winning_number = env.prng().gen_range::<u64>(1..=10) as u32;
+        let winning_number =
+            env.prng().gen_range::<u64>(game.config.min as u64..=game.config.max as u64) as u32;
         game.winning_number = Some(winning_number);
 
-        // Calculate distances
-        let distance1 = if guess1 > winning_number {
-            guess1 - winning_number
-        } else {
-            winning_number - guess1
-        };
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
 
-        let distance2 = if guess2 > winning_number {
-            guess2 - winning_number
-        } else {
-            winning_number - guess2
+        // Create GameHub client
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+
+        // Determine winner with the session's scoring mode
+        let player1_won = match score(&game.config.scoring, guess1, guess2, winning_number) {
+            Some(player1_won) => player1_won,
+            None => {
+                // Nobody wins: mark the draw and return both stakes
+                // Event emitted by the Game Hub contract (GameDrawn)
+                game.draw = true;
+                env.storage().temporary().set(&key, &game);
+                game_hub.end_game_draw(&session_id);
+
+                return Ok(None);
+            }
         };
 
-        // Determine winner (if equal distance, player1 wins)
-        let winner = if distance1 <= distance2 {
+        let winner = if player1_won {
             game.player1.clone()
         } else {
             game.player2.clone()
@@ -316,23 +604,12 @@ impl NumberGuessContract {
         game.winner = Some(winner.clone());
         env.storage().temporary().set(&key, &game);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
         // Call GameHub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
         game_hub.end_game(&session_id, &player1_won);
 
-        Ok(winner)
+        Ok(Some(winner))
     }
 
     /// Get game information.
@@ -350,6 +627,623 @@ impl NumberGuessContract {
             .ok_or(Error::GameNotFound)
     }
 
+    // ========================================================================
+    // Hinted Mode
+    // ========================================================================
+
+    /// Start a hinted game: the host commits a secret number and answers each
+    /// guess with Higher/Lower/Correct. Player 1 guesses first. The host
+    /// cannot be one of the players.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points amount committed by player 1
+    /// * `player2_points` - Points amount committed by player 2
+    /// * `setup` - Host, range, turn limit and the host's commitment
+    pub fn start_hinted_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        setup: HintedSetup,
+    ) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
+        }
+
+        if setup.min >= setup.max || setup.max_turns == 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        // The host knows the number, so they cannot also play
+        if setup.host == player1 || setup.host == player2 {
+            return Err(Error::InvalidPlayers);
+        }
+
+        // Both players consent to the points and to the host and rules
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            setup.clone().into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            setup.clone().into_val(&env),
+        ]);
+        // The host vouches for the commitment
+        setup
+            .host
+            .require_auth_for_args(vec![&env, session_id.into_val(&env), setup.commitment.into_val(&env)]);
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Call Game Hub to start the session and lock points
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        let game = HintedGame {
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            setup,
+            guesses: Vec::new(&env),
+            hints: Vec::new(&env),
+            deadline: env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS,
+            secret_number: None,
+            host_honest: true,
+            winner: None,
+            draw: false,
+        };
+        Self::save_hinted_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Guess in a hinted game. Players alternate, starting with player 1, and
+    /// each guess must be answered by the host before the next one.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the guessing player (must be their turn)
+    /// * `guess` - The guessed number, within the game's range
+    pub fn submit_guess(env: Env, session_id: u32, player: Address, guess: u32) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_hinted_game(&env, session_id)?;
+        if game.winner.is_some() || game.draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if game.guesses.len() > game.hints.len() {
+            return Err(Error::HintPending);
+        }
+        if Self::awaiting_reveal(&game) {
+            return Err(Error::AwaitingReveal);
+        }
+
+        let player1_turn = game.guesses.len() % 2 == 0;
+        if (player == game.player1) != player1_turn {
+            return Err(Error::NotYourTurn);
+        }
+        if !(game.setup.min..=game.setup.max).contains(&guess) {
+            return Err(Error::GuessOutOfRange);
+        }
+
+        game.guesses.push_back(guess);
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+        Self::save_hinted_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Host answers the latest guess.
+    /// After a `Correct` answer, or once the turn limit is reached, the game
+    /// waits for the host to reveal the secret.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `hint` - Whether the secret is higher than, lower than, or equal to the guess
+    pub fn answer_guess(env: Env, session_id: u32, hint: Hint) -> Result<(), Error> {
+        let mut game = Self::load_hinted_game(&env, session_id)?;
+        game.setup.host.require_auth();
+
+        if game.winner.is_some() || game.draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.guesses.len() == game.hints.len() {
+            return Err(Error::NoPendingGuess);
+        }
+
+        game.hints.push_back(hint);
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+        Self::save_hinted_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Host reveals the secret and the game is settled with the Game Hub.
+    /// The result comes from the revealed number, not from the hints: the
+    /// first exact guess wins, otherwise the closest guess (earliest on ties).
+    /// Any wrong hint is recorded by clearing `host_honest`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `secret_number` - The committed number
+    /// * `salt` - The 32-byte salt used in the commitment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Some(winner), or None if the game ended in a draw
+    pub fn reveal_number(
+        env: Env,
+        session_id: u32,
+        secret_number: u32,
+        salt: BytesN<32>,
+    ) -> Result<Option<Address>, Error> {
+        let mut game = Self::load_hinted_game(&env, session_id)?;
+        game.setup.host.require_auth();
+
+        if game.winner.is_some() || game.draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !Self::awaiting_reveal(&game) {
+            return Err(Error::NotReadyToReveal);
+        }
+
+        let mut preimage = Bytes::from_array(&env, &secret_number.to_be_bytes());
+        preimage.append(&Bytes::from(salt));
+        let commitment: BytesN<32> = env.crypto().keccak256(&preimage).into();
+        if commitment != game.setup.commitment {
+            return Err(Error::InvalidReveal);
+        }
+
+        game.secret_number = Some(secret_number);
+        for (guess, hint) in game.guesses.iter().zip(game.hints.iter()) {
+            if hint_for(guess, secret_number) != hint {
+                game.host_honest = false;
+            }
+        }
+
+        // A secret outside the agreed range can't be scored fairly
+        let in_range = (game.setup.min..=game.setup.max).contains(&secret_number);
+        let winning_index = hinted_winning_index(&game.guesses, secret_number).filter(|_| in_range);
+        if !in_range {
+            game.host_honest = false;
+        }
+
+        Ok(Self::settle_hinted_game(&env, session_id, &mut game, winning_index))
+    }
+
+    /// End a stalled hinted game after its deadline.
+    /// If a player failed to guess, the other player wins. If the host failed
+    /// to answer or reveal, the game ends as a draw.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player claiming the timeout
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Some(winner), or None if the game ended in a draw
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<Option<Address>, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_hinted_game(&env, session_id)?;
+        if game.winner.is_some() || game.draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        let host_stalled = game.guesses.len() > game.hints.len() || Self::awaiting_reveal(&game);
+        let winning_index = if host_stalled {
+            None
+        } else {
+            // The player whose turn it is stalled; the claimant must be the other one
+            let player1_turn = game.guesses.len() % 2 == 0;
+            if (player == game.player1) == player1_turn {
+                return Err(Error::NotYourTurn);
+            }
+            // Index of a guess by the claimant marks them as the winner
+            Some(if player1_turn { 1 } else { 0 })
+        };
+
+        Ok(Self::settle_hinted_game(&env, session_id, &mut game, winning_index))
+    }
+
+    /// Get hinted game information.
+    /// The secret number stays hidden (None) until the host reveals it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `HintedGame` - The game state
+    pub fn get_hinted_game(env: Env, session_id: u32) -> Result<HintedGame, Error> {
+        Self::load_hinted_game(&env, session_id)
+    }
+
+    // ========================================================================
+    // Multi-player Mode
+    // ========================================================================
+
+    /// Start a game for 2..=8 players, each with their own stake.
+    /// Every player signs the session, their own points and the config.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `players` - Participant addresses, all different
+    /// * `points` - Points committed by each player, same order as `players`
+    /// * `config` - Guess range and scoring mode for this session
+    pub fn start_multi_game(
+        env: Env,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+        config: GuessConfig,
+    ) -> Result<(), Error> {
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players.len()) || points.len() != players.len() {
+            return Err(Error::InvalidPlayers);
+        }
+        for (index, player) in players.iter().enumerate() {
+            if players.first_index_of(&player) != Some(index as u32) {
+                return Err(Error::InvalidPlayers);
+            }
+        }
+        if config.min >= config.max {
+            return Err(Error::InvalidConfig);
+        }
+
+        for (player, player_points) in players.iter().zip(points.iter()) {
+            player.require_auth_for_args(vec![
+                &env,
+                session_id.into_val(&env),
+                player_points.into_val(&env),
+                config.clone().into_val(&env),
+            ]);
+        }
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Call Game Hub to start the session and lock everyone's points
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.start_multi_game(&env.current_contract_address(), &session_id, &players, &points);
+
+        let game = MultiGame {
+            players,
+            points,
+            config,
+            guesses: Map::new(&env),
+            winning_number: None,
+            places: Vec::new(&env),
+            winners: Vec::new(&env),
+        };
+        Self::save_multi_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Make a guess in a multi-player game. Each player guesses once.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player making the guess
+    /// * `guess` - The guessed number, within the session's range
+    pub fn make_multi_guess(env: Env, session_id: u32, player: Address, guess: u32) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_multi_game(&env, session_id)?;
+        if game.winning_number.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !game.players.contains(&player) {
+            return Err(Error::NotPlayer);
+        }
+        if game.guesses.contains_key(player.clone()) {
+            return Err(Error::AlreadyGuessed);
+        }
+        if !(game.config.min..=game.config.max).contains(&guess) {
+            return Err(Error::GuessOutOfRange);
+        }
+
+        game.guesses.set(player, guess);
+        Self::save_multi_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Rank a multi-player game once everyone has guessed and report the
+    /// ranking to the Game Hub. Unlike the two-player game, equal results
+    /// share a place, so several players can win.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Vec<Address>` - The players in first place (all of them on a draw)
+    pub fn reveal_multi_winners(env: Env, session_id: u32) -> Result<Vec<Address>, Error> {
+        let mut game = Self::load_multi_game(&env, session_id)?;
+        if game.winning_number.is_some() {
+            return Ok(game.winners);
+        }
+
+        // Guesses in player order; the seed follows the same scheme as reveal_winner
+        let mut guesses = Vec::new(&env);
+        let mut seed_bytes = Bytes::from_array(&env, &session_id.to_be_bytes());
+        for player in game.players.iter() {
+            let guess = game.guesses.get(player.clone()).ok_or(Error::NotAllGuessed)?;
+            guesses.push_back(guess);
+            seed_bytes.extend_from_array(&guess.to_be_bytes());
+            seed_bytes.append(&player.to_string().to_bytes());
+        }
+
+        let seed = env.crypto().keccak256(&seed_bytes);
+        env.prng().seed(seed.into());
+        let winning_number =
+            env.prng().gen_range::<u64>(game.config.min as u64..=game.config.max as u64) as u32;
+
+        let mut keys = Vec::new(&env);
+        for guess in guesses.iter() {
+            keys.push_back(rank_key(&game.config.scoring, guess, winning_number));
+        }
+        let places = places_for(&env, &keys);
+
+        let mut winners = Vec::new(&env);
+        for (player, place) in game.players.iter().zip(places.iter()) {
+            if place == 1 {
+                winners.push_back(player);
+            }
+        }
+
+        game.winning_number = Some(winning_number);
+        game.places = places.clone();
+        game.winners = winners.clone();
+        Self::save_multi_game(&env, session_id, &game);
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Event emitted by the Game Hub contract (MultiGameEnded)
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.end_multi_game(&session_id, &places);
+
+        Ok(winners)
+    }
+
+    /// Get multi-player game information.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `MultiGame` - The game state (includes places after the reveal)
+    pub fn get_multi_game(env: Env, session_id: u32) -> Result<MultiGame, Error> {
+        Self::load_multi_game(&env, session_id)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    /// Open the Game Hub session and store a fresh game with the given config
+    fn create_game(
+        env: &Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        config: GuessConfig,
+        session_keys: Map<Address, SessionKey>,
+    ) -> Result<(), Error> {
+        session_keys::grant_all(env, session_id, &player1, &player2, &session_keys, GAME_TTL_LEDGERS)?;
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Create GameHub client
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+
+        // Call Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        // Create game (winning_number not set yet - will be generated in reveal_winner)
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            config,
+            player1_guess: None,
+            player2_guess: None,
+            winning_number: None,
+            winner: None,
+            draw: false,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        let game_key = DataKey::Game(session_id);
+        env.storage().temporary().set(&game_key, &game);
+
+        // Set TTL to ensure game is retained for at least 30 days
+        env.storage()
+            .temporary()
+            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        // Event emitted by the Game Hub contract (GameStarted)
+
+        Ok(())
+    }
+
+    fn load_hinted_game(env: &Env, session_id: u32) -> Result<HintedGame, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::HintedGame(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    fn save_hinted_game(env: &Env, session_id: u32, game: &HintedGame) {
+        let key = DataKey::HintedGame(session_id);
+        env.storage().temporary().set(&key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    fn load_multi_game(env: &Env, session_id: u32) -> Result<MultiGame, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::MultiGame(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    fn save_multi_game(env: &Env, session_id: u32, game: &MultiGame) {
+        let key = DataKey::MultiGame(session_id);
+        env.storage().temporary().set(&key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// True once a guess was answered `Correct` or the turn limit is used up
+    /// (and every guess has been answered)
+    fn awaiting_reveal(game: &HintedGame) -> bool {
+        game.guesses.len() == game.hints.len()
+            && (game.hints.last() == Some(Hint::Correct) || game.guesses.len() >= game.setup.max_turns)
+    }
+
+    /// End a hinted game: the player who made guess `winning_index` wins, or a
+    /// draw if there is none
+    fn settle_hinted_game(
+        env: &Env,
+        session_id: u32,
+        game: &mut HintedGame,
+        winning_index: Option<u32>,
+    ) -> Option<Address> {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+
+        match winning_index {
+            Some(index) => {
+                let player1_won = index % 2 == 0;
+                let winner = if player1_won {
+                    game.player1.clone()
+                } else {
+                    game.player2.clone()
+                };
+                game.winner = Some(winner.clone());
+                Self::save_hinted_game(env, session_id, game);
+
+                // Event emitted by the Game Hub contract (GameEnded)
+                game_hub.end_game(&session_id, &player1_won);
+                Some(winner)
+            }
+            None => {
+                game.draw = true;
+                Self::save_hinted_game(env, session_id, game);
+
+                // Event emitted by the Game Hub contract (GameDrawn)
+                game_hub.end_game_draw(&session_id);
+                None
+            }
+        }
+    }
+
+    // ========================================================================
+    // Session Keys
+    // ========================================================================
+
+    /// Let a short-lived key sign `make_guess` for a player, so the main wallet is
+    /// not prompted for every move. Usually done through `start_game`; this call is
+    /// for renewing or adding a key once the game is under way.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The player delegating
+    /// * `session_key` - The key that will sign moves; also authorizes this call
+    /// * `expiration_ledger` - Last ledger the key is accepted at, at most
+    ///   `MAX_SESSION_KEY_LEDGERS` from now
+    pub fn delegate_session_key(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        session_key: Address,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if game.winner.is_some() || game.draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let delegation = SessionKey { player, expiration_ledger };
+        session_keys::grant(
+            &env,
+            session_id,
+            &game.player1,
+            &game.player2,
+            session_key,
+            delegation,
+            GAME_TTL_LEDGERS,
+        )?;
+        Ok(())
+    }
+
     // ========================================================================
     // Admin Functions
     // ========================================================================
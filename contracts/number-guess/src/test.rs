@@ -6,9 +6,12 @@
 // Note: These tests use a minimal mock for isolation and speed.
 // For full integration tests with the real Game Hub contract, see the platform repo.
 
-use crate::{Error, NumberGuessContract, NumberGuessContractClient};
+use crate::{
+    Error, GuessConfig, Hint, HintedSetup, NumberGuessContract, NumberGuessContractClient, ScoringMode, SessionKey,
+    MAX_SESSION_KEY_LEDGERS,
+};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, map, vec, Address, Bytes, BytesN, Env, Map, Vec};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -35,6 +38,24 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn end_game_draw(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn start_multi_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _players: Vec<Address>,
+        _points: Vec<i128>,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_multi_game(_env: Env, _session_id: u32, _places: Vec<u32>) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
@@ -145,13 +166,13 @@ fn assert_number_guess_error<T, E>(
 
 #[test]
 fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &Map::new(&env));
 
     // Get game to verify state
     let game = client.get_game(&session_id);
@@ -167,7 +188,7 @@ fn test_complete_game() {
     client.make_guess(&session_id, &player2, &7);
 
     // Reveal winner
-    let winner = client.reveal_winner(&session_id);
+    let winner = client.reveal_winner(&session_id).unwrap();
     assert!(winner == player1 || winner == player2);
 
     // Verify game is ended and winning number is now set
@@ -176,15 +197,15 @@ fn test_complete_game() {
     assert_eq!(final_game.winner.unwrap(), winner);
     assert!(final_game.winning_number.is_some());
     let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
+    assert!((1..=10).contains(&winning_number));
 }
 
 #[test]
 fn test_winning_number_in_range() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Make guesses and reveal winner to generate winning number
     client.make_guess(&session_id, &player1, &5);
@@ -196,7 +217,7 @@ fn test_winning_number_in_range() {
         .winning_number
         .expect("Winning number should be set after reveal");
     assert!(
-        winning_number >= 1 && winning_number <= 10,
+        (1..=10).contains(&winning_number),
         "Winning number should be between 1 and 10"
     );
 }
@@ -210,8 +231,8 @@ fn test_multiple_sessions() {
     let session1 = 3u32;
     let session2 = 4u32;
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000, &Map::new(&env));
 
     // Verify both games exist and are independent
     let game1 = client.get_game(&session1);
@@ -227,33 +248,25 @@ fn test_multiple_sessions() {
 
 #[test]
 fn test_closest_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Player1 guesses closer (1 away from any number between 1-10)
     // Player2 guesses further (at least 2 away)
     client.make_guess(&session_id, &player1, &5);
     client.make_guess(&session_id, &player2, &10);
 
-    let winner = client.reveal_winner(&session_id);
+    let winner = client.reveal_winner(&session_id).unwrap();
 
     // Get the final game state to check the winning number
     let game = client.get_game(&session_id);
     let winning_number = game.winning_number.unwrap();
 
     // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = 5u32.abs_diff(winning_number);
+    let distance2 = 10u32.abs_diff(winning_number);
 
     let expected_winner = if distance1 <= distance2 {
         player1.clone()
@@ -268,46 +281,38 @@ fn test_closest_guess_wins() {
 
 #[test]
 fn test_tie_game_player1_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Both players guess the same number (guaranteed tie)
     client.make_guess(&session_id, &player1, &5);
     client.make_guess(&session_id, &player2, &5);
 
-    let winner = client.reveal_winner(&session_id);
+    let winner = client.reveal_winner(&session_id).unwrap();
     assert_eq!(winner, player1, "Player1 should win in a tie");
 }
 
 #[test]
 fn test_exact_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Player1 guesses 5 (middle), player2 guesses 10 (edge)
     // Player1 is more likely to be closer to the winning number
     client.make_guess(&session_id, &player1, &5);
     client.make_guess(&session_id, &player2, &10);
 
-    let winner = client.reveal_winner(&session_id);
+    let winner = client.reveal_winner(&session_id).unwrap();
     let game = client.get_game(&session_id);
     let winning_number = game.winning_number.unwrap();
 
     // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = 5u32.abs_diff(winning_number);
+    let distance2 = 10u32.abs_diff(winning_number);
     let expected_winner = if distance1 <= distance2 {
         player1.clone()
     } else {
@@ -322,10 +327,10 @@ fn test_exact_guess_wins() {
 
 #[test]
 fn test_cannot_guess_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Make first guess
     client.make_guess(&session_id, &player1, &5);
@@ -337,10 +342,10 @@ fn test_cannot_guess_twice() {
 
 #[test]
 fn test_cannot_reveal_before_both_guesses() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Only player1 guesses
     client.make_guess(&session_id, &player1, &5);
@@ -351,7 +356,6 @@ fn test_cannot_reveal_before_both_guesses() {
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
 fn test_cannot_guess_below_range() {
     let (env, client, _hub, player1, _player2) = setup_test();
 
@@ -362,14 +366,15 @@ fn test_cannot_guess_below_range() {
         &Address::generate(&env),
         &100_0000000,
         &100_0000000,
+        &Map::new(&env),
     );
 
-    // Try to guess 0 (below range) - should panic
-    client.make_guess(&session_id, &player1, &0);
+    // Try to guess 0 (below range) - should fail
+    let result = client.try_make_guess(&session_id, &player1, &0);
+    assert_number_guess_error(&result, Error::GuessOutOfRange);
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
 fn test_cannot_guess_above_range() {
     let (env, client, _hub, player1, _player2) = setup_test();
 
@@ -380,10 +385,12 @@ fn test_cannot_guess_above_range() {
         &Address::generate(&env),
         &100_0000000,
         &100_0000000,
+        &Map::new(&env),
     );
 
-    // Try to guess 11 (above range) - should panic
-    client.make_guess(&session_id, &player1, &11);
+    // Try to guess 11 (above range) - should fail
+    let result = client.try_make_guess(&session_id, &player1, &11);
+    assert_number_guess_error(&result, Error::GuessOutOfRange);
 }
 
 #[test]
@@ -392,7 +399,7 @@ fn test_non_player_cannot_guess() {
     let non_player = Address::generate(&env);
 
     let session_id = 11u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Non-player tries to guess
     let result = client.try_make_guess(&session_id, &non_player, &5);
@@ -409,10 +416,10 @@ fn test_cannot_reveal_nonexistent_game() {
 
 #[test]
 fn test_cannot_guess_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Both players make guesses
     client.make_guess(&session_id, &player1, &5);
@@ -428,20 +435,20 @@ fn test_cannot_guess_after_game_ended() {
 
 #[test]
 fn test_cannot_reveal_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     client.make_guess(&session_id, &player1, &5);
     client.make_guess(&session_id, &player2, &7);
 
     // First reveal succeeds
-    let winner = client.reveal_winner(&session_id);
+    let winner = client.reveal_winner(&session_id).unwrap();
     assert!(winner == player1 || winner == player2);
 
     // Second reveal should return same winner (idempotent)
-    let winner2 = client.reveal_winner(&session_id);
+    let winner2 = client.reveal_winner(&session_id).unwrap();
     assert_eq!(winner, winner2);
 }
 
@@ -459,8 +466,8 @@ fn test_multiple_games_independent() {
     let session2 = 21u32;
 
     // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000, &Map::new(&env));
 
     // Play both games independently
     client.make_guess(&session1, &player1, &3);
@@ -469,8 +476,8 @@ fn test_multiple_games_independent() {
     client.make_guess(&session2, &player4, &2);
 
     // Reveal both winners
-    let winner1 = client.reveal_winner(&session1);
-    let winner2 = client.reveal_winner(&session2);
+    let winner1 = client.reveal_winner(&session1).unwrap();
+    let winner2 = client.reveal_winner(&session2).unwrap();
 
     assert!(winner1 == player1 || winner1 == player2);
     assert!(winner2 == player3 || winner2 == player4);
@@ -489,13 +496,13 @@ fn test_multiple_games_independent() {
 
 #[test]
 fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 15u32;
     let points1 = 200_0000000;
     let points2 = 50_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
+    client.start_game(&session_id, &player1, &player2, &points1, &points2, &Map::new(&env));
 
     let game = client.get_game(&session_id);
     assert_eq!(game.player1_points, points1);
@@ -510,6 +517,498 @@ fn test_asymmetric_points() {
     assert!(final_game.winner.is_some()); // Game has ended
 }
 
+// ============================================================================
+// Config Tests
+// ============================================================================
+
+fn config(min: u32, max: u32, scoring: ScoringMode) -> GuessConfig {
+    GuessConfig { min, max, scoring }
+}
+
+/// Start a configured game, make both guesses and reveal.
+/// Returns the reveal result and the winning number.
+fn play_configured(
+    client: &NumberGuessContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    config: &GuessConfig,
+    guess1: u32,
+    guess2: u32,
+) -> (Option<Address>, u32) {
+    client.start_game_with_config(&session_id, player1, player2, &100_0000000, &100_0000000, config, &Map::new(&client.env));
+    client.make_guess(&session_id, player1, &guess1);
+    client.make_guess(&session_id, player2, &guess2);
+    let winner = client.reveal_winner(&session_id);
+    let game = client.get_game(&session_id);
+    (winner, game.winning_number.unwrap())
+}
+
+#[test]
+fn test_default_config() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&30, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    let game = client.get_game(&30);
+    assert_eq!(game.config, config(1, 10, ScoringMode::Closest));
+    assert!(!game.draw);
+}
+
+#[test]
+fn test_custom_range() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let wide = config(1_000_000, u32::MAX, ScoringMode::Closest);
+    client.start_game_with_config(&31, &player1, &player2, &100_0000000, &100_0000000, &wide, &Map::new(&env));
+
+    let result = client.try_make_guess(&31, &player1, &999_999);
+    assert_number_guess_error(&result, Error::GuessOutOfRange);
+
+    client.make_guess(&31, &player1, &1_000_000);
+    client.make_guess(&31, &player2, &u32::MAX);
+    let winner = client.reveal_winner(&31).unwrap();
+
+    let winning_number = client.get_game(&31).winning_number.unwrap();
+    assert!(winning_number >= 1_000_000);
+    let expected = if 1_000_000u32.abs_diff(winning_number) <= u32::MAX.abs_diff(winning_number) {
+        player1
+    } else {
+        player2
+    };
+    assert_eq!(winner, expected);
+}
+
+#[test]
+fn test_invalid_config_rejected() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    for bad in [config(5, 5, ScoringMode::Closest), config(10, 1, ScoringMode::Closest)] {
+        let result =
+            client.try_start_game_with_config(&32, &player1, &player2, &100_0000000, &100_0000000, &bad, &Map::new(&env));
+        assert_number_guess_error(&result, Error::InvalidConfig);
+    }
+}
+
+#[test]
+fn test_exact_or_draw_scoring() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    // A two-number range: one player is always exact, the other never
+    let coin = config(1, 2, ScoringMode::ExactOrDraw);
+    let (winner, winning_number) = play_configured(&client, 33, &player1, &player2, &coin, 1, 2);
+    let expected = if winning_number == 1 { player1.clone() } else { player2.clone() };
+    assert_eq!(winner, Some(expected));
+
+    // Equal guesses can never single out a winner
+    let (winner, _) = play_configured(&client, 34, &player1, &player2, &coin, 2, 2);
+    assert_eq!(winner, None);
+
+    let game = client.get_game(&34);
+    assert!(game.draw);
+    assert!(game.winner.is_none());
+
+    // A drawn game is over and reveal stays idempotent
+    assert_eq!(client.reveal_winner(&34), None);
+    let result = client.try_make_guess(&34, &player1, &1);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_closest_without_over_scoring() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let price = config(1, 100, ScoringMode::ClosestWithoutOver);
+    for (session_id, guess1, guess2) in [(35u32, 10u32, 60u32), (36, 90, 40), (37, 50, 50)] {
+        let (winner, n) = play_configured(&client, session_id, &player1, &player2, &price, guess1, guess2);
+
+        let expected = match (guess1 <= n, guess2 <= n) {
+            (true, false) => Some(player1.clone()),
+            (false, true) => Some(player2.clone()),
+            (true, true) if guess1 > guess2 => Some(player1.clone()),
+            (true, true) if guess2 > guess1 => Some(player2.clone()),
+            _ => None,
+        };
+        assert_eq!(winner, expected, "guesses {} and {} against {}", guess1, guess2, n);
+        assert_eq!(client.get_game(&session_id).draw, expected.is_none());
+    }
+}
+
+#[test]
+fn test_closest_ties_still_go_to_player1() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let wide = config(0, 1_000, ScoringMode::Closest);
+    let (winner, _) = play_configured(&client, 38, &player1, &player2, &wide, 500, 500);
+    assert_eq!(winner, Some(player1));
+}
+
+// ============================================================================
+// Hinted Mode Tests
+// ============================================================================
+
+/// Build a hinted setup whose commitment hides `secret` under `salt`
+fn hinted_setup(env: &Env, host: &Address, max_turns: u32, secret: u32, salt: &BytesN<32>) -> HintedSetup {
+    let mut preimage = Bytes::from_array(env, &secret.to_be_bytes());
+    preimage.append(&Bytes::from(salt.clone()));
+    HintedSetup {
+        host: host.clone(),
+        min: 1,
+        max: 100,
+        max_turns,
+        commitment: env.crypto().keccak256(&preimage).into(),
+    }
+}
+
+fn start_hinted(
+    env: &Env,
+    client: &NumberGuessContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    max_turns: u32,
+    secret: u32,
+) -> (Address, BytesN<32>) {
+    let host = Address::generate(env);
+    let salt = BytesN::from_array(env, &[7u8; 32]);
+    let setup = hinted_setup(env, &host, max_turns, secret, &salt);
+    client.start_hinted_game(&session_id, player1, player2, &100_0000000, &100_0000000, &setup);
+    (host, salt)
+}
+
+#[test]
+fn test_hinted_game_exact_guess_wins() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (_host, salt) = start_hinted(&env, &client, 40, &player1, &player2, 6, 42);
+
+    client.submit_guess(&40, &player1, &50);
+    client.answer_guess(&40, &Hint::Lower);
+    client.submit_guess(&40, &player2, &30);
+    client.answer_guess(&40, &Hint::Higher);
+    client.submit_guess(&40, &player1, &42);
+    client.answer_guess(&40, &Hint::Correct);
+
+    // Solved: no more guessing until the host reveals
+    let result = client.try_submit_guess(&40, &player2, &42);
+    assert_number_guess_error(&result, Error::AwaitingReveal);
+
+    let winner = client.reveal_number(&40, &42, &salt);
+    assert_eq!(winner, Some(player1.clone()));
+
+    let game = client.get_hinted_game(&40);
+    assert_eq!(game.secret_number, Some(42));
+    assert_eq!(game.winner, Some(player1));
+    assert!(game.host_honest);
+    assert_eq!(game.guesses.len(), 3);
+}
+
+#[test]
+fn test_hinted_turn_order() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    start_hinted(&env, &client, 41, &player1, &player2, 6, 42);
+
+    let result = client.try_answer_guess(&41, &Hint::Higher);
+    assert_number_guess_error(&result, Error::NoPendingGuess);
+
+    let result = client.try_submit_guess(&41, &player2, &10);
+    assert_number_guess_error(&result, Error::NotYourTurn);
+
+    client.submit_guess(&41, &player1, &10);
+    let result = client.try_submit_guess(&41, &player2, &20);
+    assert_number_guess_error(&result, Error::HintPending);
+
+    client.answer_guess(&41, &Hint::Higher);
+    let result = client.try_submit_guess(&41, &player1, &20);
+    assert_number_guess_error(&result, Error::NotYourTurn);
+
+    let result = client.try_submit_guess(&41, &player2, &101);
+    assert_number_guess_error(&result, Error::GuessOutOfRange);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_submit_guess(&41, &outsider, &20);
+    assert_number_guess_error(&result, Error::NotPlayer);
+}
+
+#[test]
+fn test_hinted_max_turns_closest_wins() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (_host, salt) = start_hinted(&env, &client, 42, &player1, &player2, 2, 42);
+
+    client.submit_guess(&42, &player1, &10);
+    let result = client.try_reveal_number(&42, &42, &salt);
+    assert_number_guess_error(&result, Error::NotReadyToReveal);
+
+    client.answer_guess(&42, &Hint::Higher);
+    client.submit_guess(&42, &player2, &40);
+    client.answer_guess(&42, &Hint::Higher);
+
+    let result = client.try_submit_guess(&42, &player1, &41);
+    assert_number_guess_error(&result, Error::AwaitingReveal);
+
+    assert_eq!(client.reveal_number(&42, &42, &salt), Some(player2));
+}
+
+#[test]
+fn test_hinted_lying_host_is_recorded() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (_host, salt) = start_hinted(&env, &client, 43, &player1, &player2, 6, 42);
+
+    // The host falsely ends the game on player 1's first guess
+    client.submit_guess(&43, &player1, &20);
+    client.answer_guess(&43, &Hint::Correct);
+
+    // The result follows the revealed number, not the hint
+    assert_eq!(client.reveal_number(&43, &42, &salt), Some(player1));
+    assert!(!client.get_hinted_game(&43).host_honest);
+}
+
+#[test]
+fn test_hinted_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (_host, salt) = start_hinted(&env, &client, 44, &player1, &player2, 1, 42);
+
+    client.submit_guess(&44, &player1, &42);
+    client.answer_guess(&44, &Hint::Correct);
+
+    let result = client.try_reveal_number(&44, &41, &salt);
+    assert_number_guess_error(&result, Error::InvalidReveal);
+
+    let wrong_salt = BytesN::from_array(&env, &[8u8; 32]);
+    let result = client.try_reveal_number(&44, &42, &wrong_salt);
+    assert_number_guess_error(&result, Error::InvalidReveal);
+
+    client.reveal_number(&44, &42, &salt);
+    let result = client.try_reveal_number(&44, &42, &salt);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_hinted_invalid_setup_rejected() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let host = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+
+    let mut no_turns = hinted_setup(&env, &host, 0, 42, &salt);
+    let result = client.try_start_hinted_game(&45, &player1, &player2, &100_0000000, &100_0000000, &no_turns);
+    assert_number_guess_error(&result, Error::InvalidConfig);
+
+    no_turns.max_turns = 4;
+    no_turns.min = 100;
+    let result = client.try_start_hinted_game(&45, &player1, &player2, &100_0000000, &100_0000000, &no_turns);
+    assert_number_guess_error(&result, Error::InvalidConfig);
+
+    // A host who plays would answer their own guesses
+    for host in [&player1, &player2] {
+        let setup = hinted_setup(&env, host, 4, 42, &salt);
+        let result = client.try_start_hinted_game(&45, &player1, &player2, &100_0000000, &100_0000000, &setup);
+        assert_number_guess_error(&result, Error::InvalidPlayers);
+    }
+}
+
+#[test]
+fn test_hinted_stalled_player_forfeits() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    start_hinted(&env, &client, 46, &player1, &player2, 6, 42);
+
+    client.submit_guess(&46, &player1, &10);
+    client.answer_guess(&46, &Hint::Higher);
+
+    let result = client.try_claim_timeout(&46, &player1);
+    assert_number_guess_error(&result, Error::TimeoutNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number += crate::MOVE_TIMEOUT_LEDGERS + 1);
+
+    // Player 2 is the one who stalled
+    let result = client.try_claim_timeout(&46, &player2);
+    assert_number_guess_error(&result, Error::NotYourTurn);
+
+    assert_eq!(client.claim_timeout(&46, &player1), Some(player1.clone()));
+    assert_eq!(client.get_hinted_game(&46).winner, Some(player1));
+}
+
+#[test]
+fn test_hinted_stalled_host_draws() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    start_hinted(&env, &client, 47, &player1, &player2, 6, 42);
+
+    client.submit_guess(&47, &player1, &10);
+    env.ledger().with_mut(|li| li.sequence_number += crate::MOVE_TIMEOUT_LEDGERS + 1);
+
+    assert_eq!(client.claim_timeout(&47, &player2), None);
+    let game = client.get_hinted_game(&47);
+    assert!(game.draw);
+    assert!(game.winner.is_none());
+}
+
+// ============================================================================
+// Multi-player Mode Tests
+// ============================================================================
+
+fn generate_players(env: &Env, count: u32) -> Vec<Address> {
+    let mut players = Vec::new(env);
+    for _ in 0..count {
+        players.push_back(Address::generate(env));
+    }
+    players
+}
+
+fn equal_points(env: &Env, count: u32) -> Vec<i128> {
+    let mut points = Vec::new(env);
+    for _ in 0..count {
+        points.push_back(100_0000000);
+    }
+    points
+}
+
+#[test]
+fn test_multi_game_closest_of_four() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let players = generate_players(&env, 4);
+    let points = vec![&env, 10_0000000, 20_0000000, 30_0000000, 40_0000000];
+    let wide = config(1, 100, ScoringMode::Closest);
+    client.start_multi_game(&50, &players, &points, &wide);
+
+    let guesses = [5u32, 35, 65, 95];
+    for (player, guess) in players.iter().zip(guesses) {
+        client.make_multi_guess(&50, &player, &guess);
+    }
+    let winners = client.reveal_multi_winners(&50);
+
+    let game = client.get_multi_game(&50);
+    let n = game.winning_number.unwrap();
+    assert_eq!(game.places.len(), 4);
+    for (i, guess) in guesses.iter().enumerate() {
+        let distance = guess.abs_diff(n);
+        let better = guesses.iter().filter(|other| other.abs_diff(n) < distance).count() as u32;
+        assert_eq!(game.places.get(i as u32).unwrap(), better + 1);
+        let player = players.get(i as u32).unwrap();
+        assert_eq!(winners.contains(&player), better == 0);
+    }
+    assert_eq!(game.winners, winners);
+
+    // Revealing again returns the stored result
+    assert_eq!(client.reveal_multi_winners(&50), winners);
+    let result = client.try_make_multi_guess(&50, &players.get(0).unwrap(), &5);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_multi_game_equal_guesses_share_first_place() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let players = generate_players(&env, 8);
+    client.start_multi_game(&51, &players, &equal_points(&env, 8), &config(1, 10, ScoringMode::Closest));
+
+    for player in players.iter() {
+        client.make_multi_guess(&51, &player, &7);
+    }
+
+    assert_eq!(client.reveal_multi_winners(&51), players);
+    for place in client.get_multi_game(&51).places.iter() {
+        assert_eq!(place, 1);
+    }
+}
+
+#[test]
+fn test_multi_game_exact_or_draw() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let players = generate_players(&env, 3);
+    client.start_multi_game(&52, &players, &equal_points(&env, 3), &config(1, 2, ScoringMode::ExactOrDraw));
+
+    client.make_multi_guess(&52, &players.get(0).unwrap(), &1);
+    client.make_multi_guess(&52, &players.get(1).unwrap(), &2);
+    client.make_multi_guess(&52, &players.get(2).unwrap(), &1);
+    let winners = client.reveal_multi_winners(&52);
+
+    let game = client.get_multi_game(&52);
+    if game.winning_number == Some(1) {
+        assert_eq!(winners, vec![&env, players.get(0).unwrap(), players.get(2).unwrap()]);
+        assert_eq!(game.places, vec![&env, 1, 3, 1]);
+    } else {
+        assert_eq!(winners, vec![&env, players.get(1).unwrap()]);
+        assert_eq!(game.places, vec![&env, 2, 1, 2]);
+    }
+}
+
+#[test]
+fn test_multi_game_invalid_players_rejected() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let range = config(1, 10, ScoringMode::Closest);
+
+    let result = client.try_start_multi_game(&53, &generate_players(&env, 1), &equal_points(&env, 1), &range);
+    assert_number_guess_error(&result, Error::InvalidPlayers);
+
+    let result = client.try_start_multi_game(&53, &generate_players(&env, 9), &equal_points(&env, 9), &range);
+    assert_number_guess_error(&result, Error::InvalidPlayers);
+
+    let result = client.try_start_multi_game(&53, &generate_players(&env, 3), &equal_points(&env, 2), &range);
+    assert_number_guess_error(&result, Error::InvalidPlayers);
+
+    let mut duplicated = generate_players(&env, 2);
+    duplicated.push_back(duplicated.get(0).unwrap());
+    let result = client.try_start_multi_game(&53, &duplicated, &equal_points(&env, 3), &range);
+    assert_number_guess_error(&result, Error::InvalidPlayers);
+
+    let bad_range = config(10, 1, ScoringMode::Closest);
+    let result = client.try_start_multi_game(&53, &generate_players(&env, 3), &equal_points(&env, 3), &bad_range);
+    assert_number_guess_error(&result, Error::InvalidConfig);
+}
+
+#[test]
+fn test_multi_game_guess_rules() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let players = generate_players(&env, 3);
+    client.start_multi_game(&54, &players, &equal_points(&env, 3), &config(1, 10, ScoringMode::Closest));
+
+    let first = players.get(0).unwrap();
+    client.make_multi_guess(&54, &first, &3);
+    let result = client.try_make_multi_guess(&54, &first, &4);
+    assert_number_guess_error(&result, Error::AlreadyGuessed);
+
+    let result = client.try_make_multi_guess(&54, &players.get(1).unwrap(), &11);
+    assert_number_guess_error(&result, Error::GuessOutOfRange);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_make_multi_guess(&54, &outsider, &4);
+    assert_number_guess_error(&result, Error::NotPlayer);
+
+    let result = client.try_reveal_multi_winners(&54);
+    assert_number_guess_error(&result, Error::NotAllGuessed);
+}
+
+// ============================================================================
+// Session Key Tests
+// ============================================================================
+
+#[test]
+fn test_session_key_guesses_for_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let key = Address::generate(&env);
+    let session_keys = map![&env, (key.clone(), SessionKey { player: player2.clone(), expiration_ledger: (100 + MAX_SESSION_KEY_LEDGERS) })];
+    client.start_game(&60u32, &player1, &player2, &100_0000000, &100_0000000, &session_keys);
+    let result = client.try_delegate_session_key(&60u32, &player1, &key, &200);
+    assert_number_guess_error(&result, Error::InvalidSessionKey);
+
+    // The key signs the guess, and it counts as player 2's
+    client.make_guess(&60u32, &key, &7);
+    assert_eq!(env.auths()[0].0, key);
+    assert_eq!(client.get_game(&60u32).player2_guess, Some(7));
+    let result = client.try_make_guess(&60u32, &player2, &3);
+    assert_number_guess_error(&result, Error::AlreadyGuessed);
+
+    // An expired key is just a stranger
+    client.start_game(&61u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    client.delegate_session_key(&61u32, &player1, &key, &150);
+    env.ledger().with_mut(|li| li.sequence_number = 151);
+    let result = client.try_make_guess(&61u32, &key, &7);
+    assert_number_guess_error(&result, Error::NotPlayer);
+
+    // Keys can also come with a custom config
+    let session_keys = map![&env, (key.clone(), SessionKey { player: player1.clone(), expiration_ledger: 300 })];
+    let wide = config(1, 100, ScoringMode::Closest);
+    client.start_game_with_config(&62u32, &player1, &player2, &100_0000000, &100_0000000, &wide, &session_keys);
+    client.make_guess(&62u32, &key, &50);
+    assert_eq!(client.get_game(&62u32).player1_guess, Some(50));
+}
+
 // ============================================================================
 // Admin Function Tests
 // ============================================================================
@@ -0,0 +1,293 @@
+#![no_std]
+
+//! # Player Account
+//!
+//! A smart wallet for game players, built on Soroban's custom account
+//! interface (`__check_auth`).
+//!
+//! The account is the player's address in every game. Its owner key (an
+//! ed25519 public key) signs authorization entries off-chain. Anyone can then
+//! submit the transaction, so a relayer can pay the fees and play is gasless
+//! for the player.
+//!
+//! The account only authorizes:
+//! - calls to allow-listed contracts (game contracts and the Game Hub)
+//! - token transfers to an allow-listed contract, covered by stakes authorized
+//!   in the same call (token-mode escrow)
+//! - calls to itself (managing the account)
+//!
+//! Stakes count against a per-day limit. A game authorizes stakes with the
+//! amount as the second argument (`start_game` and its variants authorize
+//! `(session_id, points, ...)`), which is how the account recognizes them.
+
+use soroban_sdk::{
+    auth::{Context, CustomAccountInterface},
+    contract, contracterror, contractimpl, contracttype, crypto::Hash, symbol_short, Address, BytesN, Env,
+    Symbol, TryFromVal, Val, Vec,
+};
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotAllowed = 1,
+    DailyLimitExceeded = 2,
+    InvalidStake = 3,
+    UnbackedTransfer = 4,
+    InvalidLimit = 5,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// ed25519 public key that signs for the account
+    Owner,
+    /// Most the account can stake per day
+    DailyLimit,
+    /// Contracts the account may authorize calls to: contract -> ()
+    Allowed(Address),
+    /// Stakes authorized on a day: day number -> amount
+    Spent(u64),
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+
+/// Seconds in a stake-limit day (days follow ledger time, starting at 00:00 UTC)
+const DAY_SECONDS: u64 = 86_400;
+
+/// TTL for a day's spending record (2 days in ledgers, ~5 seconds per ledger)
+const SPENT_TTL_LEDGERS: u32 = 34_560;
+
+/// TTL for allow-list entries (~180 days), renewed by allowing the contract again
+const ALLOWED_TTL_LEDGERS: u32 = 3_110_400;
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct PlayerAccount;
+
+#[contractimpl]
+impl PlayerAccount {
+    /// Create the account
+    ///
+    /// # Arguments
+    /// * `owner` - ed25519 public key that signs for the account
+    /// * `daily_limit` - Most the account can stake per day
+    /// * `allowed` - Game contracts and Game Hub the account may call
+    pub fn __constructor(env: Env, owner: BytesN<32>, daily_limit: i128, allowed: Vec<Address>) {
+        if daily_limit < 0 {
+            panic!("Daily limit cannot be negative");
+        }
+        env.storage().instance().set(&DataKey::Owner, &owner);
+        env.storage().instance().set(&DataKey::DailyLimit, &daily_limit);
+        for contract in allowed.iter() {
+            Self::save_allowed(&env, contract);
+        }
+    }
+
+    // ========================================================================
+    // Account Management
+    // ========================================================================
+    // Every change is authorized by the account itself, i.e. signed by the
+    // owner key through `__check_auth`.
+
+    /// Allow the account to authorize calls to a contract
+    ///
+    /// # Arguments
+    /// * `contract` - A game contract or Game Hub
+    pub fn allow(env: Env, contract: Address) {
+        env.current_contract_address().require_auth();
+        Self::save_allowed(&env, contract);
+    }
+
+    /// Stop authorizing calls to a contract
+    ///
+    /// # Arguments
+    /// * `contract` - A previously allowed contract
+    pub fn disallow(env: Env, contract: Address) {
+        env.current_contract_address().require_auth();
+        env.storage().persistent().remove(&DataKey::Allowed(contract));
+    }
+
+    /// Change the most the account can stake per day
+    ///
+    /// # Arguments
+    /// * `daily_limit` - The new limit
+    pub fn set_daily_limit(env: Env, daily_limit: i128) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+        if daily_limit < 0 {
+            return Err(Error::InvalidLimit);
+        }
+        env.storage().instance().set(&DataKey::DailyLimit, &daily_limit);
+        Ok(())
+    }
+
+    /// Hand the account to a new owner key, e.g. after a key rotation
+    ///
+    /// # Arguments
+    /// * `owner` - The new ed25519 public key
+    pub fn set_owner(env: Env, owner: BytesN<32>) {
+        env.current_contract_address().require_auth();
+        env.storage().instance().set(&DataKey::Owner, &owner);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        env.current_contract_address().require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// Get the ed25519 public key that signs for the account
+    pub fn get_owner(env: Env) -> BytesN<32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Owner)
+            .expect("Owner not set")
+    }
+
+    /// Get the most the account can stake per day
+    pub fn get_daily_limit(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::DailyLimit)
+            .expect("Daily limit not set")
+    }
+
+    /// Check whether the account authorizes calls to a contract
+    ///
+    /// # Arguments
+    /// * `contract` - The contract to check
+    pub fn is_allowed(env: Env, contract: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Allowed(contract))
+    }
+
+    /// Get the stakes authorized today
+    pub fn get_spent_today(env: Env) -> i128 {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Spent(Self::today(&env)))
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    fn save_allowed(env: &Env, contract: Address) {
+        let key = DataKey::Allowed(contract);
+        env.storage().persistent().set(&key, &());
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, ALLOWED_TTL_LEDGERS, ALLOWED_TTL_LEDGERS);
+    }
+
+    fn today(env: &Env) -> u64 {
+        env.ledger().timestamp() / DAY_SECONDS
+    }
+
+    /// Amount of a token `transfer` from this account to an allowed contract,
+    /// None for any other call
+    fn escrow_transfer(env: &Env, fn_name: &Symbol, args: &Vec<Val>) -> Option<i128> {
+        if *fn_name != symbol_short!("transfer") || args.len() != 3 {
+            return None;
+        }
+        let from = Address::try_from_val(env, &args.get_unchecked(0)).ok()?;
+        let to = Address::try_from_val(env, &args.get_unchecked(1)).ok()?;
+        if from != env.current_contract_address() || !Self::is_allowed(env.clone(), to) {
+            return None;
+        }
+        i128::try_from_val(env, &args.get_unchecked(2)).ok()
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for PlayerAccount {
+    type Signature = BytesN<64>;
+    type Error = Error;
+
+    /// Verify the owner's signature, then check every call being authorized:
+    /// allowed contracts only, escrow transfers covered by stakes, and stakes
+    /// within today's limit.
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signature: BytesN<64>,
+        auth_contexts: Vec<Context>,
+    ) -> Result<(), Error> {
+        let owner = Self::get_owner(env.clone());
+        env.crypto()
+            .ed25519_verify(&owner, &signature_payload.into(), &signature);
+
+        let this = env.current_contract_address();
+        let mut stakes = 0i128;
+        let mut transfers = 0i128;
+        for context in auth_contexts.iter() {
+            let Context::Contract(call) = context else {
+                return Err(Error::NotAllowed);
+            };
+            if call.contract == this {
+                continue;
+            }
+            if Self::is_allowed(env.clone(), call.contract.clone()) {
+                let stake = call
+                    .args
+                    .get(1)
+                    .and_then(|arg| i128::try_from_val(&env, &arg).ok());
+                if let Some(stake) = stake {
+                    if stake < 0 {
+                        return Err(Error::InvalidStake);
+                    }
+                    stakes += stake;
+                }
+                continue;
+            }
+            match Self::escrow_transfer(&env, &call.fn_name, &call.args) {
+                Some(amount) => transfers += amount,
+                None => return Err(Error::NotAllowed),
+            }
+        }
+
+        if transfers > stakes {
+            return Err(Error::UnbackedTransfer);
+        }
+        if stakes > 0 {
+            let key = DataKey::Spent(Self::today(&env));
+            let spent: i128 = env.storage().temporary().get(&key).unwrap_or(0) + stakes;
+            if spent > Self::get_daily_limit(env.clone()) {
+                return Err(Error::DailyLimitExceeded);
+            }
+            env.storage().temporary().set(&key, &spent);
+            env.storage()
+                .temporary()
+                .extend_ttl(&key, SPENT_TTL_LEDGERS, SPENT_TTL_LEDGERS);
+        }
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,293 @@
+#![cfg(test)]
+extern crate std;
+
+// Tests drive the account through real `__check_auth` calls: the owner key
+// signs each authorization entry, as a wallet would before handing the
+// transaction to a relayer.
+
+use crate::{Error, PlayerAccount, PlayerAccountClient};
+use dice_duel::{DiceDuelContract, DiceDuelContractClient};
+use ed25519_dalek::{Signer as _, SigningKey};
+use mock_game_hub::{MockGameHub, MockGameHubClient};
+use rps::{PlayerMove, RpsContract, RpsContractClient};
+use soroban_sdk::auth::{Context, ContractContext};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::xdr::{
+    HashIdPreimage, HashIdPreimageSorobanAuthorization, InvokeContractArgs, Limits, ScAddress, ScVal,
+    SorobanAddressCredentials, SorobanAuthorizationEntry, SorobanAuthorizedFunction, SorobanAuthorizedInvocation,
+    SorobanCredentials, VecM, WriteXdr,
+};
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec};
+use std::cell::Cell;
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+const DAILY_LIMIT: i128 = 1000;
+
+/// A player: their account contract and the owner key that signs for it
+struct Player {
+    account: Address,
+    key: SigningKey,
+    nonce: Cell<i64>,
+}
+
+impl Player {
+    fn new(env: &Env, seed: u8, allowed: &Vec<Address>) -> Self {
+        let key = SigningKey::from_bytes(&[seed; 32]);
+        let owner = BytesN::from_array(env, &key.verifying_key().to_bytes());
+        let account = env.register(PlayerAccount, (owner, DAILY_LIMIT, allowed.clone()));
+        Player {
+            account,
+            key,
+            nonce: Cell::new(0),
+        }
+    }
+
+    fn client<'a>(&self, env: &Env) -> PlayerAccountClient<'a> {
+        PlayerAccountClient::new(env, &self.account)
+    }
+
+    /// Sign an authorization entry for one call, made with `args` as its
+    /// authorized arguments (the `require_auth_for_args` list, or all of
+    /// them for `require_auth`)
+    fn authorize(&self, env: &Env, contract: &Address, fn_name: &str, args: Vec<Val>) -> SorobanAuthorizationEntry {
+        let nonce = self.nonce.get() + 1;
+        self.nonce.set(nonce);
+        let signature_expiration_ledger = env.ledger().sequence() + 100;
+
+        let args: std::vec::Vec<ScVal> = args
+            .iter()
+            .map(|arg| ScVal::try_from_val(env, &arg).unwrap())
+            .collect();
+        let invocation = SorobanAuthorizedInvocation {
+            function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+                contract_address: ScAddress::from(contract),
+                function_name: fn_name.try_into().unwrap(),
+                args: args.try_into().unwrap(),
+            }),
+            sub_invocations: VecM::default(),
+        };
+
+        let preimage = HashIdPreimage::SorobanAuthorization(HashIdPreimageSorobanAuthorization {
+            network_id: env.ledger().network_id().to_array().into(),
+            nonce,
+            signature_expiration_ledger,
+            invocation: invocation.clone(),
+        });
+        let payload = env
+            .crypto()
+            .sha256(&Bytes::from_slice(env, &preimage.to_xdr(Limits::none()).unwrap()));
+        let signature = self.key.sign(&payload.to_array()).to_bytes();
+
+        SorobanAuthorizationEntry {
+            credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+                address: ScAddress::from(&self.account),
+                nonce,
+                signature_expiration_ledger,
+                signature: ScVal::from(BytesN::from_array(env, &signature)),
+            }),
+            root_invocation: invocation,
+        }
+    }
+
+    /// Run `__check_auth` directly for a set of calls
+    fn check(&self, env: &Env, contexts: Vec<Context>) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+        let payload = BytesN::from_array(env, &[7; 32]);
+        let signature = BytesN::from_array(env, &self.key.sign(&payload.to_array()).to_bytes());
+        env.try_invoke_contract_check_auth::<Error>(&self.account, &payload, signature.into_val(env), &contexts)
+    }
+}
+
+struct Setup {
+    env: Env,
+    hub: MockGameHubClient<'static>,
+    rps: RpsContractClient<'static>,
+    dice: DiceDuelContractClient<'static>,
+    player1: Player,
+    player2: Player,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let admin = Address::generate(&env);
+    let hub = MockGameHubClient::new(&env, &env.register(MockGameHub, (&admin,)));
+    let rps = RpsContractClient::new(&env, &env.register(RpsContract, (&admin, &hub.address)));
+    let dice = DiceDuelContractClient::new(&env, &env.register(DiceDuelContract, (&admin, &hub.address)));
+
+    let allowed = vec![&env, rps.address.clone(), dice.address.clone(), hub.address.clone()];
+    let player1 = Player::new(&env, 1, &allowed);
+    let player2 = Player::new(&env, 2, &allowed);
+
+    Setup {
+        env,
+        hub,
+        rps,
+        dice,
+        player1,
+        player2,
+    }
+}
+
+/// Both players sign their stake for a game's `start_game`
+fn authorize_start(t: &Setup, game: &Address, session_id: u32, points: i128) {
+    // No session keys
+    let session_keys: Map<Address, Val> = Map::new(&t.env);
+    let args = vec![&t.env, session_id.into_val(&t.env), points.into_val(&t.env), session_keys.into_val(&t.env)];
+    t.env.set_auths(&[
+        t.player1.authorize(&t.env, game, "start_game", args.clone()),
+        t.player2.authorize(&t.env, game, "start_game", args),
+    ]);
+}
+
+fn call_context(env: &Env, contract: &Address, fn_name: &str, args: Vec<Val>) -> Context {
+    Context::Contract(ContractContext {
+        contract: contract.clone(),
+        fn_name: Symbol::new(env, fn_name),
+        args,
+    })
+}
+
+fn assert_account_error(result: &Result<(), Result<Error, soroban_sdk::InvokeError>>, expected_error: Error) {
+    match result {
+        Err(Ok(actual_error)) => assert_eq!(*actual_error, expected_error),
+        _ => panic!("Expected error {:?}, got something else", expected_error),
+    }
+}
+
+// ============================================================================
+// Full Sessions
+// ============================================================================
+
+#[test]
+fn test_rps_session_through_accounts() {
+    let t = setup_test();
+    let (p1, p2) = (&t.player1.account, &t.player2.account);
+
+    authorize_start(&t, &t.rps.address, 1, 300);
+    t.rps.start_game(&1, p1, p2, &300, &300, &Map::new(&t.env));
+
+    for (player, game_move) in [(&t.player1, PlayerMove::Rock), (&t.player2, PlayerMove::Scissors)] {
+        let args = vec![&t.env, 1u32.into_val(&t.env), player.account.into_val(&t.env), game_move.into_val(&t.env)];
+        t.env.set_auths(&[player.authorize(&t.env, &t.rps.address, "submit_move", args)]);
+        t.rps.submit_move(&1, &player.account, &game_move);
+    }
+
+    assert_eq!(t.rps.reveal_winner(&1), Some(p1.clone()));
+    assert_eq!(t.hub.get_player_stats(p1, &t.rps.address).net_points, 300);
+    assert_eq!(t.player1.client(&t.env).get_spent_today(), 300);
+}
+
+#[test]
+fn test_dice_duel_session_through_accounts() {
+    let t = setup_test();
+    let (p1, p2) = (&t.player1.account, &t.player2.account);
+
+    authorize_start(&t, &t.dice.address, 2, 250);
+    t.dice.start_game(&2, p1, p2, &250, &250, &Map::new(&t.env));
+
+    for player in [&t.player1, &t.player2] {
+        let args = vec![&t.env, 2u32.into_val(&t.env), player.account.into_val(&t.env)];
+        t.env.set_auths(&[player.authorize(&t.env, &t.dice.address, "roll", args)]);
+        t.dice.roll(&2, &player.account);
+    }
+
+    let winner = t.dice.reveal_winner(&2);
+    assert_eq!(t.hub.get_player_stats(&winner, &t.dice.address).wins, 1);
+    assert_eq!(t.player2.client(&t.env).get_spent_today(), 250);
+}
+
+#[test]
+fn test_daily_stake_limit() {
+    let t = setup_test();
+    let (p1, p2) = (&t.player1.account, &t.player2.account);
+
+    authorize_start(&t, &t.rps.address, 3, 700);
+    t.rps.start_game(&3, p1, p2, &700, &700, &Map::new(&t.env));
+
+    // 700 + 400 is over the 1000 limit
+    authorize_start(&t, &t.rps.address, 4, 400);
+    assert!(t.rps.try_start_game(&4, p1, p2, &400, &400, &Map::new(&t.env)).is_err());
+    assert_eq!(t.player1.client(&t.env).get_spent_today(), 700);
+
+    // The limit resets the next day
+    t.env.ledger().with_mut(|li| li.timestamp += 86_400);
+    assert_eq!(t.player1.client(&t.env).get_spent_today(), 0);
+    authorize_start(&t, &t.rps.address, 4, 400);
+    t.rps.start_game(&4, p1, p2, &400, &400, &Map::new(&t.env));
+}
+
+// ============================================================================
+// Authorization Rules
+// ============================================================================
+
+#[test]
+fn test_only_allowed_contracts() {
+    let t = setup_test();
+    let env = &t.env;
+    let stake = |points: i128| vec![env, 5u32.into_val(env), points.into_val(env)];
+
+    let result = t.player1.check(env, vec![env, call_context(env, &Address::generate(env), "start_game", stake(10))]);
+    assert_account_error(&result, Error::NotAllowed);
+
+    let result = t.player1.check(env, vec![env, call_context(env, &t.rps.address, "start_game", stake(-10))]);
+    assert_account_error(&result, Error::InvalidStake);
+
+    // Token escrow to the hub is fine when the same call stakes as much...
+    let token = Address::generate(env);
+    let to_hub = |amount: i128| {
+        let args = vec![env, t.player1.account.into_val(env), t.hub.address.into_val(env), amount.into_val(env)];
+        call_context(env, &token, "transfer", args)
+    };
+    let start = call_context(env, &t.rps.address, "start_game", stake(100));
+    assert!(t.player1.check(env, vec![env, start.clone(), to_hub(100)]).is_ok());
+
+    // ...but not on its own, and never to anyone else
+    assert_account_error(&t.player1.check(env, vec![env, start.clone(), to_hub(101)]), Error::UnbackedTransfer);
+    assert_account_error(&t.player1.check(env, vec![env, to_hub(1)]), Error::UnbackedTransfer);
+    let args = vec![env, t.player1.account.into_val(env), Address::generate(env).into_val(env), 1i128.into_val(env)];
+    let drain = call_context(env, &token, "transfer", args);
+    assert_account_error(&t.player1.check(env, vec![env, start, drain]), Error::NotAllowed);
+
+    // A signature from another key is rejected
+    let payload = BytesN::from_array(env, &[7; 32]);
+    let forged = BytesN::from_array(env, &t.player2.key.sign(&payload.to_array()).to_bytes());
+    let contexts = vec![env, call_context(env, &t.rps.address, "start_game", stake(1))];
+    let result = env.try_invoke_contract_check_auth::<Error>(&t.player1.account, &payload, forged.into_val(env), &contexts);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_account_manages_itself() {
+    let t = setup_test();
+    let env = &t.env;
+    let account = t.player1.client(env);
+
+    let args = vec![env, t.dice.address.into_val(env)];
+    env.set_auths(&[t.player1.authorize(env, &account.address, "disallow", args)]);
+    account.disallow(&t.dice.address);
+    assert!(!account.is_allowed(&t.dice.address));
+
+    // Dice-duel can no longer take this player's stake
+    authorize_start(&t, &t.dice.address, 6, 10);
+    let result = t.dice.try_start_game(&6, &t.player1.account, &t.player2.account, &10, &10, &Map::new(&t.env));
+    assert!(result.is_err());
+
+    // The owner signs limit changes like any other call
+    let args = vec![env, 5000i128.into_val(env)];
+    env.set_auths(&[t.player1.authorize(env, &account.address, "set_daily_limit", args)]);
+    account.set_daily_limit(&5000);
+    assert_eq!(account.get_daily_limit(), 5000);
+}
@@ -0,0 +1,945 @@
+#![no_std]
+
+//! # Poker
+//!
+//! Heads-up five-card draw. Each player antes, is dealt five hidden cards,
+//! bets, may replace any of their cards once, bets again and, unless someone
+//! folds, shows down: the best poker hand wins, equal hands split the pot.
+//!
+//! Cards stay hidden through commit-reveal dealing. Each player commits
+//! `keccak256(seed)` and `keccak256(cut)`, then reveals the cut. Both cuts and
+//! the session ID shuffle one shared deck: player 1 deals from its top half,
+//! player 2 from its bottom half, so the hands never share a card. Within
+//! their half a player's ten cards are picked by their secret seed, so the
+//! owner can deal their cards off-chain while the opponent cannot. The cuts
+//! are committed with the seeds, so nobody can steer the deck once they know
+//! their own seed. Seeds are revealed (and checked against the commitments)
+//! at the showdown, and the contract deals both hands again, draws included,
+//! to rank them.
+//!
+//! Bets raise the players' stakes with the Game Hub as they are made
+//! (`add_stake`), so a fold or a showdown settles the whole pot.
+//!
+//! **Game Hub Integration:**
+//! This game is Game Hub-aware and enforces all games to be played through the
+//! Game Hub contract. Games cannot be started or completed without points involvement.
+
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contractclient, contracterror,
+    contractimpl, contracttype, vec
+};
+
+// Import GameHub contract interface
+// This allows us to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(
+        env: Env,
+        session_id: u32,
+        player1_won: bool
+    );
+
+    fn end_game_draw(
+        env: Env,
+        session_id: u32
+    );
+
+    fn add_stake(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        amount: i128
+    );
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    GameNotFound = 1,
+    NotPlayer = 2,
+    AlreadyCommitted = 3,
+    AlreadyCut = 4,
+    WrongPhase = 5,
+    GameAlreadyEnded = 6,
+    NotYourTurn = 7,
+    InvalidAmount = 8,
+    RaiseLimit = 9,
+    AlreadyDrawn = 10,
+    InvalidDiscards = 11,
+    AlreadyRevealed = 12,
+    InvalidReveal = 13,
+    TimeoutNotReached = 14,
+    CannotClaimTimeout = 15,
+    SelfPlay = 16,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Phase {
+    /// Waiting for both players to commit their seeds and cuts
+    Commit,
+    /// Waiting for both players to reveal their cuts
+    Cut,
+    /// Betting on the dealt hands
+    FirstBetting,
+    /// Waiting for both players to choose their discards
+    Draw,
+    /// Betting on the final hands
+    SecondBetting,
+    /// Waiting for both seeds
+    Showdown,
+    /// Result reported to the Game Hub
+    Ended,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    pub player1: Address,
+    pub player2: Address,
+    /// Each player's stake with the Game Hub: the ante plus every bet
+    pub player1_stake: i128,
+    pub player2_stake: i128,
+    pub phase: Phase,
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    /// keccak256 of each player's cut, committed with the seed
+    pub player1_cut_commitment: Option<BytesN<32>>,
+    pub player2_cut_commitment: Option<BytesN<32>>,
+    /// Each player's revealed cut of the shared deck
+    pub player1_cut: Option<BytesN<32>>,
+    pub player2_cut: Option<BytesN<32>>,
+    /// Player expected to act in the current betting round
+    pub next_player: Address,
+    /// Actions and raises made in the current betting round
+    pub actions: u32,
+    pub raises: u32,
+    /// Hand positions each player replaced (bit i = card i), None until they draw
+    pub player1_discards: Option<u32>,
+    pub player2_discards: Option<u32>,
+    /// Final hands, empty until the player reveals their seed
+    pub player1_hand: Vec<u32>,
+    pub player2_hand: Vec<u32>,
+    /// Ledger after which the stalled player can be forfeited
+    pub deadline: u32,
+    /// Winner, None while playing or after a split pot
+    pub winner: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Game(u32),
+    GameHubAddress,
+    Admin,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) ensures game data doesn't expire unexpectedly
+// Games are stored in temporary storage with a minimum 30-day retention
+
+/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Ledgers a player has to act before the opponent may claim the game
+/// 1 day = 24 * 60 * 60 / 5 = 17,280 ledgers
+const MOVE_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Raises allowed per betting round
+pub const MAX_RAISES: u32 = 4;
+
+/// Cards in a hand
+const HAND_SIZE: u32 = 5;
+
+/// Cards are 0-51: rank = card % 13 (0 = two .. 12 = ace), suit = card / 13
+const RANKS: u32 = 13;
+const DECK_SIZE: u32 = 52;
+
+/// Cards each player deals from: player 1 the top half of the shared deck,
+/// player 2 the bottom half
+const HALF_DECK: u32 = DECK_SIZE / 2;
+
+// Hand categories, the top bits of a hand rank
+pub const HIGH_CARD: u32 = 0;
+pub const ONE_PAIR: u32 = 1;
+pub const TWO_PAIR: u32 = 2;
+pub const THREE_OF_A_KIND: u32 = 3;
+pub const STRAIGHT: u32 = 4;
+pub const FLUSH: u32 = 5;
+pub const FULL_HOUSE: u32 = 6;
+pub const FOUR_OF_A_KIND: u32 = 7;
+pub const STRAIGHT_FLUSH: u32 = 8;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Shuffle the shared deck from both revealed cuts and the session ID
+fn shuffle_deck(env: &Env, session_id: u32, player1_cut: &BytesN<32>, player2_cut: &BytesN<32>) -> Vec<u32> {
+    let mut seed_bytes = Bytes::from(player1_cut.clone());
+    seed_bytes.append(&Bytes::from(player2_cut.clone()));
+    seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    env.prng().seed(env.crypto().keccak256(&seed_bytes).into());
+
+    let mut deck = Vec::new(env);
+    for card in 0..DECK_SIZE {
+        deck.push_back(card);
+    }
+    env.prng().shuffle(&mut deck);
+    deck
+}
+
+/// The half of the shared deck a player deals from
+fn half_deck(deck: &Vec<u32>, is_player1: bool) -> Vec<u32> {
+    if is_player1 {
+        deck.slice(0..HALF_DECK)
+    } else {
+        deck.slice(HALF_DECK..DECK_SIZE)
+    }
+}
+
+/// Deal the top of a player's hidden deck: the five cards of the hand followed
+/// by the five replacements a draw can take, picked from the player's half
+/// of the shared deck by their seed and the session ID.
+fn deal_deck(env: &Env, session_id: u32, seed: &BytesN<32>, half: &Vec<u32>) -> Vec<u32> {
+    let mut seed_bytes = Bytes::from(seed.clone());
+    seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    env.prng().seed(env.crypto().keccak256(&seed_bytes).into());
+
+    let mut cards = half.clone();
+    env.prng().shuffle(&mut cards);
+    cards.slice(0..HAND_SIZE * 2)
+}
+
+/// The hand after the draw: each discarded card is replaced by the next card
+/// of the deck, in hand order
+fn final_hand(env: &Env, deck: &Vec<u32>, discards: u32) -> Vec<u32> {
+    let mut hand = Vec::new(env);
+    let mut next = HAND_SIZE;
+    for position in 0..HAND_SIZE {
+        if discards & (1 << position) != 0 {
+            hand.push_back(deck.get_unchecked(next));
+            next += 1;
+        } else {
+            hand.push_back(deck.get_unchecked(position));
+        }
+    }
+    hand
+}
+
+/// Rank a five-card hand; a higher rank wins and equal ranks split.
+/// The category (`HIGH_CARD`..`STRAIGHT_FLUSH`) sits above bit 20. Below it
+/// the ranks that break ties are packed four bits each, grouped cards first
+/// (the pair before the kickers), or just the top card of a straight.
+pub fn hand_rank(hand: &Vec<u32>) -> u32 {
+    let mut counts = [0u32; RANKS as usize];
+    let suit = hand.get_unchecked(0) / RANKS;
+    let mut flush = true;
+    for card in hand.iter() {
+        counts[(card % RANKS) as usize] += 1;
+        flush &= card / RANKS == suit;
+    }
+
+    // Ranks ordered by how often they appear, then by rank, highest first
+    let mut ordered = [0u32; HAND_SIZE as usize];
+    let mut filled = 0;
+    for count in (1..=4).rev() {
+        for rank in (0..RANKS).rev() {
+            if counts[rank as usize] == count {
+                for _ in 0..count {
+                    ordered[filled] = rank;
+                    filled += 1;
+                }
+            }
+        }
+    }
+    let most = counts[ordered[0] as usize];
+    let distinct = counts.iter().filter(|count| **count > 0).count();
+
+    // The wheel (A-2-3-4-5) is a five-high straight
+    let straight_high = if distinct == 5 && ordered[0] - ordered[4] == 4 {
+        Some(ordered[0])
+    } else if ordered == [12, 3, 2, 1, 0] {
+        Some(3)
+    } else {
+        None
+    };
+
+    let category = match (straight_high.is_some(), flush, most, distinct) {
+        (true, true, _, _) => STRAIGHT_FLUSH,
+        (_, _, 4, _) => FOUR_OF_A_KIND,
+        (_, _, 3, 2) => FULL_HOUSE,
+        (_, true, _, _) => FLUSH,
+        (true, _, _, _) => STRAIGHT,
+        (_, _, 3, _) => THREE_OF_A_KIND,
+        (_, _, 2, 3) => TWO_PAIR,
+        (_, _, 2, _) => ONE_PAIR,
+        _ => HIGH_CARD,
+    };
+    let tiebreak = match straight_high {
+        Some(high) => high,
+        None => ordered.iter().fold(0, |packed, rank| packed << 4 | rank),
+    };
+    category << 20 | tiebreak
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct PokerContract;
+
+#[contractimpl]
+impl PokerContract {
+    /// Initialize the contract with GameHub address and admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    /// * `game_hub` - Address of the GameHub contract
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        // Store admin and GameHub address
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &game_hub);
+    }
+
+    /// Start a new game between two players; the points are their antes.
+    /// This creates a session in the Game Hub and locks points before starting the game.
+    ///
+    /// **CRITICAL:** This method requires authorization from THIS contract (not players).
+    /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Ante committed by player 1
+    /// * `player2_points` - Ante committed by player 2
+    pub fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            return Err(Error::SelfPlay);
+        }
+
+        // Require authentication from both players (they consent to committing points)
+        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        Self::game_hub(&env).start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        // Create game (seeds are committed next, player 1 acts first)
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_stake: player1_points,
+            player2_stake: player2_points,
+            phase: Phase::Commit,
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_cut_commitment: None,
+            player2_cut_commitment: None,
+            player1_cut: None,
+            player2_cut: None,
+            next_player: player1,
+            actions: 0,
+            raises: 0,
+            player1_discards: None,
+            player2_discards: None,
+            player1_hand: Vec::new(&env),
+            player2_hand: Vec::new(&env),
+            deadline: env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS,
+            winner: None,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        Self::save_game(&env, session_id, &game);
+
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
+    }
+
+    /// Commit the player's seed as `keccak256(seed)` and their cut of the
+    /// shared deck as `keccak256(cut)`. Cuts are revealed once both players
+    /// have committed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the committing player
+    /// * `commitment` - keccak256 hash of the player's 32-byte seed
+    /// * `cut_commitment` - keccak256 hash of the player's 32-byte cut
+    pub fn commit_seed(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+        cut_commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Commit)?;
+
+        if player == game.player1 {
+            if game.player1_commitment.is_some() {
+                return Err(Error::AlreadyCommitted);
+            }
+            game.player1_commitment = Some(commitment);
+            game.player1_cut_commitment = Some(cut_commitment);
+        } else if player == game.player2 {
+            if game.player2_commitment.is_some() {
+                return Err(Error::AlreadyCommitted);
+            }
+            game.player2_commitment = Some(commitment);
+            game.player2_cut_commitment = Some(cut_commitment);
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        if game.player1_commitment.is_some() && game.player2_commitment.is_some() {
+            game.phase = Phase::Cut;
+        }
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the player's cut of the shared deck; it must hash to their cut
+    /// commitment. Once both cuts are revealed, each player can deal their
+    /// own hand and the first betting round opens.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the cutting player
+    /// * `cut` - The 32-byte value behind the player's cut commitment
+    pub fn cut_deck(env: Env, session_id: u32, player: Address, cut: BytesN<32>) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Cut)?;
+
+        let (cut_commitment, revealed) = if player == game.player1 {
+            (&game.player1_cut_commitment, &mut game.player1_cut)
+        } else if player == game.player2 {
+            (&game.player2_cut_commitment, &mut game.player2_cut)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if revealed.is_some() {
+            return Err(Error::AlreadyCut);
+        }
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(cut.clone())).into();
+        if Some(hash) != *cut_commitment {
+            return Err(Error::InvalidReveal);
+        }
+        *revealed = Some(cut);
+
+        if game.player1_cut.is_some() && game.player2_cut.is_some() {
+            game.phase = Phase::FirstBetting;
+        }
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Match the opponent's stake, or check when the stakes are level.
+    /// The betting round closes once both players have acted and the stakes
+    /// are level.
+    ///
+    /// **Auth:** the player authorizes `(session_id, amount)`, the amount
+    /// added to their stake (0 for a check).
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the calling player (must be their turn)
+    pub fn call(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_betting(&game)?;
+        Self::require_turn(&game, &player)?;
+
+        let amount = Self::to_call(&game, &player);
+        player.require_auth_for_args(vec![&env, session_id.into_val(&env), amount.into_val(&env)]);
+
+        Self::add_stake(&env, session_id, &mut game, &player, amount);
+        Self::next_action(&env, &mut game);
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Bet, or raise the opponent's bet, by `amount` over the opponent's stake.
+    ///
+    /// **Auth:** the player authorizes `(session_id, total)`, where `total`
+    /// is the amount to call plus the raise.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the raising player (must be their turn)
+    /// * `amount` - How far the player's stake goes above the opponent's
+    pub fn raise(env: Env, session_id: u32, player: Address, amount: i128) -> Result<(), Error> {
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_betting(&game)?;
+        Self::require_turn(&game, &player)?;
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if game.raises >= MAX_RAISES {
+            return Err(Error::RaiseLimit);
+        }
+
+        let total = Self::to_call(&game, &player) + amount;
+        player.require_auth_for_args(vec![&env, session_id.into_val(&env), total.into_val(&env)]);
+
+        Self::add_stake(&env, session_id, &mut game, &player, total);
+        game.raises += 1;
+        Self::next_action(&env, &mut game);
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Fold: the opponent wins the pot without a showdown.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the folding player (must be their turn)
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player (the opponent)
+    pub fn fold(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_betting(&game)?;
+        Self::require_turn(&game, &player)?;
+
+        let winner = Self::opponent(&game, &player);
+        Self::finish_game(&env, session_id, &mut game, Some(winner.clone()));
+
+        Ok(winner)
+    }
+
+    /// Replace cards from the dealt hand with the next cards of the deck.
+    /// Only the number of replaced cards is public until the showdown.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the drawing player
+    /// * `discards` - Hand positions to replace, bit i for card i (0 stands pat)
+    pub fn draw(env: Env, session_id: u32, player: Address, discards: u32) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Draw)?;
+
+        if discards >= 1 << HAND_SIZE {
+            return Err(Error::InvalidDiscards);
+        }
+
+        if player == game.player1 {
+            if game.player1_discards.is_some() {
+                return Err(Error::AlreadyDrawn);
+            }
+            game.player1_discards = Some(discards);
+        } else if player == game.player2 {
+            if game.player2_discards.is_some() {
+                return Err(Error::AlreadyDrawn);
+            }
+            game.player2_discards = Some(discards);
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        if game.player1_discards.is_some() && game.player2_discards.is_some() {
+            game.phase = Phase::SecondBetting;
+        }
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+
+        Self::save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the player's seed at the showdown.
+    /// The seed must hash to the player's commitment. Once both players have
+    /// revealed, the hands are ranked and the result is reported to the
+    /// Game Hub; equal hands end the session as a draw.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `seed` - The 32-byte seed behind the player's commitment
+    ///
+    /// # Returns
+    /// * `Phase` - `Ended` once both hands are revealed, `Showdown` otherwise
+    pub fn reveal_seed(env: Env, session_id: u32, player: Address, seed: BytesN<32>) -> Result<Phase, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        Self::require_phase(&game, Phase::Showdown)?;
+
+        let is_player1 = player == game.player1;
+        let (commitment, discards, hand) = if is_player1 {
+            (&game.player1_commitment, game.player1_discards, &game.player1_hand)
+        } else if player == game.player2 {
+            (&game.player2_commitment, game.player2_discards, &game.player2_hand)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if !hand.is_empty() {
+            return Err(Error::AlreadyRevealed);
+        }
+
+        let commitment = commitment.clone().ok_or(Error::WrongPhase)?;
+        let player1_cut = game.player1_cut.clone().ok_or(Error::WrongPhase)?;
+        let player2_cut = game.player2_cut.clone().ok_or(Error::WrongPhase)?;
+        let discards = discards.ok_or(Error::WrongPhase)?;
+
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(seed.clone())).into();
+        if hash != commitment {
+            return Err(Error::InvalidReveal);
+        }
+
+        let shared = shuffle_deck(&env, session_id, &player1_cut, &player2_cut);
+        let deck = deal_deck(&env, session_id, &seed, &half_deck(&shared, is_player1));
+        let hand = final_hand(&env, &deck, discards);
+        if is_player1 {
+            game.player1_hand = hand;
+        } else {
+            game.player2_hand = hand;
+        }
+
+        if game.player1_hand.is_empty() || game.player2_hand.is_empty() {
+            game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+            Self::save_game(&env, session_id, &game);
+            return Ok(Phase::Showdown);
+        }
+
+        // Both hands are known: the better one takes the pot
+        let player1_rank = hand_rank(&game.player1_hand);
+        let player2_rank = hand_rank(&game.player2_hand);
+        let winner = if player1_rank > player2_rank {
+            Some(game.player1.clone())
+        } else if player2_rank > player1_rank {
+            Some(game.player2.clone())
+        } else {
+            None
+        };
+
+        Self::finish_game(&env, session_id, &mut game, winner);
+
+        Ok(Phase::Ended)
+    }
+
+    /// Win the game because the opponent stopped acting.
+    /// After the deadline passes, the player who is not holding up the game
+    /// (the one who committed, cut, drew or revealed, or is waiting on a
+    /// betting action) can claim it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the claiming player
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player (the claimant)
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id)?;
+        if game.phase == Phase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        let is_player1 = player == game.player1;
+        let waiting = |player1_done: bool, player2_done: bool| {
+            if is_player1 {
+                player1_done && !player2_done
+            } else {
+                player2_done && !player1_done
+            }
+        };
+        let can_claim = match game.phase {
+            Phase::Commit => waiting(game.player1_commitment.is_some(), game.player2_commitment.is_some()),
+            Phase::Cut => waiting(game.player1_cut.is_some(), game.player2_cut.is_some()),
+            Phase::FirstBetting | Phase::SecondBetting => game.next_player != player,
+            Phase::Draw => waiting(game.player1_discards.is_some(), game.player2_discards.is_some()),
+            Phase::Showdown => waiting(!game.player1_hand.is_empty(), !game.player2_hand.is_empty()),
+            Phase::Ended => false,
+        };
+        if !can_claim {
+            return Err(Error::CannotClaimTimeout);
+        }
+
+        Self::finish_game(&env, session_id, &mut game, Some(player.clone()));
+
+        Ok(player)
+    }
+
+    /// Get game information.
+    /// Hands stay empty until the showdown; only commitments, cuts and the
+    /// number of cards drawn are public before then.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Game` - The game state
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        Self::load_game(&env, session_id)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    fn load_game(env: &Env, session_id: u32) -> Result<Game, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    fn save_game(env: &Env, session_id: u32, game: &Game) {
+        let key = DataKey::Game(session_id);
+        env.storage().temporary().set(&key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    fn game_hub(env: &Env) -> GameHubClient<'_> {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        GameHubClient::new(env, &game_hub_addr)
+    }
+
+    fn require_phase(game: &Game, phase: Phase) -> Result<(), Error> {
+        if game.phase == Phase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.phase != phase {
+            return Err(Error::WrongPhase);
+        }
+        Ok(())
+    }
+
+    fn require_betting(game: &Game) -> Result<(), Error> {
+        match game.phase {
+            Phase::FirstBetting | Phase::SecondBetting => Ok(()),
+            Phase::Ended => Err(Error::GameAlreadyEnded),
+            _ => Err(Error::WrongPhase),
+        }
+    }
+
+    fn require_turn(game: &Game, player: &Address) -> Result<(), Error> {
+        if *player != game.player1 && *player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if *player != game.next_player {
+            return Err(Error::NotYourTurn);
+        }
+        Ok(())
+    }
+
+    fn opponent(game: &Game, player: &Address) -> Address {
+        if *player == game.player1 {
+            game.player2.clone()
+        } else {
+            game.player1.clone()
+        }
+    }
+
+    /// What the player must add to match the opponent's stake
+    fn to_call(game: &Game, player: &Address) -> i128 {
+        let (own, opponent) = if *player == game.player1 {
+            (game.player1_stake, game.player2_stake)
+        } else {
+            (game.player2_stake, game.player1_stake)
+        };
+        (opponent - own).max(0)
+    }
+
+    /// Raise the player's stake here and with the Game Hub
+    fn add_stake(env: &Env, session_id: u32, game: &mut Game, player: &Address, amount: i128) {
+        if amount == 0 {
+            return;
+        }
+        Self::game_hub(env).add_stake(&session_id, player, &amount);
+        if *player == game.player1 {
+            game.player1_stake += amount;
+        } else {
+            game.player2_stake += amount;
+        }
+    }
+
+    /// Pass the action to the opponent, or close the betting round once both
+    /// players have acted and the stakes are level
+    fn next_action(env: &Env, game: &mut Game) {
+        game.actions += 1;
+        if game.actions >= 2 && game.player1_stake == game.player2_stake {
+            game.phase = if game.phase == Phase::FirstBetting {
+                Phase::Draw
+            } else {
+                Phase::Showdown
+            };
+            game.actions = 0;
+            game.raises = 0;
+            game.next_player = game.player1.clone();
+        } else {
+            game.next_player = Self::opponent(game, &game.next_player);
+        }
+        game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+    }
+
+    /// Record the result and end the session with the Game Hub
+    fn finish_game(env: &Env, session_id: u32, game: &mut Game, winner: Option<Address>) {
+        game.phase = Phase::Ended;
+        game.winner = winner.clone();
+        Self::save_game(env, session_id, game);
+
+        // Call GameHub to end the session
+        // This unlocks points and updates standings
+        // Event emitted by the Game Hub contract (GameEnded)
+        let game_hub = Self::game_hub(env);
+        match winner {
+            Some(winner) => game_hub.end_game(&session_id, &(winner == game.player1)),
+            None => game_hub.end_game_draw(&session_id),
+        }
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    pub fn get_hub(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set")
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,395 @@
+#![cfg(test)]
+
+// Tests run against the mock Game Hub crate so stake changes from betting
+// show up in the settled points.
+
+use crate::{
+    deal_deck, final_hand, half_deck, hand_rank, shuffle_deck, Error, Phase, PokerContract, PokerContractClient, FLUSH, FOUR_OF_A_KIND,
+    FULL_HOUSE, HIGH_CARD, MAX_RAISES, ONE_PAIR, STRAIGHT, STRAIGHT_FLUSH, THREE_OF_A_KIND, TWO_PAIR,
+};
+use mock_game_hub::{MockGameHub, MockGameHubClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (
+    Env,
+    MockGameHubClient<'static>,
+    PokerContractClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Set ledger info for time-based operations
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    // Deploy the mock GameHub, then poker with admin and GameHub address
+    let admin = Address::generate(&env);
+    let hub = MockGameHubClient::new(&env, &env.register(MockGameHub, (&admin,)));
+    let contract_id = env.register(PokerContract, (&admin, &hub.address));
+    let client = PokerContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    (env, hub, client, player1, player2)
+}
+
+/// Assert that a Result contains a specific poker error
+fn assert_poker_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        _ => panic!("Expected error {:?}, got something else", expected_error),
+    }
+}
+
+/// Card from a rank (0 = two .. 12 = ace) and a suit (0-3)
+fn card(rank: u32, suit: u32) -> u32 {
+    suit * 13 + rank
+}
+
+fn hand(env: &Env, cards: [(u32, u32); 5]) -> Vec<u32> {
+    let mut hand = Vec::new(env);
+    for (rank, suit) in cards {
+        hand.push_back(card(rank, suit));
+    }
+    hand
+}
+
+fn seed(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+fn commitment(env: &Env, seed: &BytesN<32>) -> BytesN<32> {
+    env.crypto().keccak256(&Bytes::from(seed.clone())).into()
+}
+
+/// Start a game with 100 antes; commit seeds 1 and 2 and cut with 3 and 4
+fn deal(
+    env: &Env,
+    client: &PokerContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) {
+    client.start_game(&session_id, player1, player2, &100, &100);
+    client.commit_seed(&session_id, player1, &commitment(env, &seed(env, 1)), &commitment(env, &seed(env, 3)));
+    client.commit_seed(&session_id, player2, &commitment(env, &seed(env, 2)), &commitment(env, &seed(env, 4)));
+    client.cut_deck(&session_id, player1, &seed(env, 3));
+    client.cut_deck(&session_id, player2, &seed(env, 4));
+}
+
+/// The hand a player holds after the draw, dealt the way the owner would
+/// from the deck `deal` cuts
+fn expected_hand(
+    env: &Env,
+    client: &PokerContractClient<'static>,
+    session_id: u32,
+    seed_byte: u8,
+    is_player1: bool,
+    discards: u32,
+) -> Vec<u32> {
+    env.as_contract(&client.address, || {
+        let shared = shuffle_deck(env, session_id, &seed(env, 3), &seed(env, 4));
+        let deck = deal_deck(env, session_id, &seed(env, seed_byte), &half_deck(&shared, is_player1));
+        final_hand(env, &deck, discards)
+    })
+}
+
+// ============================================================================
+// Hand Evaluation Tests
+// ============================================================================
+
+#[test]
+fn test_hand_categories() {
+    let env = Env::default();
+    let category = |cards| hand_rank(&hand(&env, cards)) >> 20;
+
+    assert_eq!(category([(8, 0), (9, 0), (10, 0), (11, 0), (12, 0)]), STRAIGHT_FLUSH);
+    assert_eq!(category([(5, 0), (5, 1), (5, 2), (5, 3), (0, 0)]), FOUR_OF_A_KIND);
+    assert_eq!(category([(5, 0), (5, 1), (5, 2), (0, 3), (0, 0)]), FULL_HOUSE);
+    assert_eq!(category([(1, 2), (4, 2), (7, 2), (9, 2), (12, 2)]), FLUSH);
+    assert_eq!(category([(12, 0), (0, 1), (1, 2), (2, 3), (3, 0)]), STRAIGHT);
+    assert_eq!(category([(5, 0), (5, 1), (5, 2), (0, 3), (1, 0)]), THREE_OF_A_KIND);
+    assert_eq!(category([(5, 0), (5, 1), (0, 2), (0, 3), (1, 0)]), TWO_PAIR);
+    assert_eq!(category([(5, 0), (5, 1), (0, 2), (2, 3), (1, 0)]), ONE_PAIR);
+    assert_eq!(category([(12, 0), (4, 1), (0, 2), (2, 3), (1, 0)]), HIGH_CARD);
+}
+
+#[test]
+fn test_hand_tiebreaks() {
+    let env = Env::default();
+    let rank = |cards| hand_rank(&hand(&env, cards));
+
+    // The wheel is the lowest straight
+    let wheel = rank([(12, 0), (0, 1), (1, 2), (2, 3), (3, 0)]);
+    let six_high = rank([(0, 0), (1, 1), (2, 2), (3, 3), (4, 0)]);
+    assert!(six_high > wheel);
+
+    // Pairs compare before kickers, kickers in order
+    let aces_king = rank([(12, 0), (12, 1), (11, 2), (2, 3), (1, 0)]);
+    let aces_queen = rank([(12, 2), (12, 3), (10, 2), (9, 3), (8, 0)]);
+    let kings = rank([(11, 0), (11, 1), (12, 2), (10, 3), (9, 0)]);
+    assert!(aces_king > aces_queen);
+    assert!(aces_queen > kings);
+
+    // Full houses compare on the three of a kind first
+    let threes_full = rank([(1, 0), (1, 1), (1, 2), (0, 0), (0, 1)]);
+    let twos_full = rank([(0, 0), (0, 1), (0, 2), (12, 0), (12, 1)]);
+    assert!(threes_full > twos_full);
+
+    // Suits never break ties
+    let spades = rank([(12, 0), (10, 0), (8, 0), (6, 0), (4, 1)]);
+    let hearts = rank([(12, 2), (10, 2), (8, 2), (6, 2), (4, 3)]);
+    assert_eq!(spades, hearts);
+}
+
+// ============================================================================
+// Game Flow Tests
+// ============================================================================
+
+#[test]
+fn test_fold_wins_the_pot() {
+    let (env, hub, client, player1, player2) = setup_test();
+    deal(&env, &client, 1, &player1, &player2);
+    assert_eq!(client.get_game(&1).phase, Phase::FirstBetting);
+
+    // Player 1 bets 50, player 2 raises 100 more, player 1 folds
+    client.raise(&1, &player1, &50);
+    client.raise(&1, &player2, &100);
+    let game = client.get_game(&1);
+    assert_eq!((game.player1_stake, game.player2_stake), (150, 250));
+
+    assert_eq!(client.fold(&1, &player1), player2);
+    let game = client.get_game(&1);
+    assert_eq!(game.phase, Phase::Ended);
+    assert_eq!(game.winner, Some(player2.clone()));
+
+    // Player 2 takes everything player 1 put in
+    assert_eq!(hub.get_player_stats(&player2, &client.address).net_points, 150);
+    assert_eq!(hub.get_player_stats(&player1, &client.address).net_points, -150);
+}
+
+#[test]
+fn test_showdown_after_draw() {
+    let (env, hub, client, player1, player2) = setup_test();
+    deal(&env, &client, 1, &player1, &player2);
+
+    // Check, bet 40, call: the first round closes
+    client.call(&1, &player1);
+    client.raise(&1, &player2, &40);
+    client.call(&1, &player1);
+    assert_eq!(client.get_game(&1).phase, Phase::Draw);
+
+    // Player 1 replaces the first and last cards, player 2 stands pat
+    client.draw(&1, &player1, &0b10001);
+    client.draw(&1, &player2, &0);
+    assert_eq!(client.get_game(&1).phase, Phase::SecondBetting);
+
+    // Check, check: on to the showdown
+    client.call(&1, &player1);
+    client.call(&1, &player2);
+    assert_eq!(client.get_game(&1).phase, Phase::Showdown);
+
+    assert_eq!(client.reveal_seed(&1, &player2, &seed(&env, 2)), Phase::Showdown);
+    assert_poker_error(&client.try_reveal_seed(&1, &player2, &seed(&env, 2)), Error::AlreadyRevealed);
+    assert_eq!(client.reveal_seed(&1, &player1, &seed(&env, 1)), Phase::Ended);
+
+    // Each hand was dealt from the player's half of the shared deck by their seed
+    let hand1 = expected_hand(&env, &client, 1, 1, true, 0b10001);
+    let hand2 = expected_hand(&env, &client, 1, 2, false, 0);
+    let game = client.get_game(&1);
+    assert_eq!(game.player1_hand, hand1);
+    assert_eq!(game.player2_hand, hand2);
+
+    let (rank1, rank2) = (hand_rank(&hand1), hand_rank(&hand2));
+    let (winner, net_points) = if rank1 > rank2 {
+        (Some(player1.clone()), 140)
+    } else if rank2 > rank1 {
+        (Some(player2.clone()), -140)
+    } else {
+        (None, 0)
+    };
+    assert_eq!(game.winner, winner);
+    assert_eq!(hub.get_player_stats(&player1, &client.address).net_points, net_points);
+}
+
+#[test]
+fn test_drawn_cards_come_off_the_deck() {
+    let env = Env::default();
+    let contract_id = env.register(PokerContract, (Address::generate(&env), Address::generate(&env)));
+
+    env.as_contract(&contract_id, || {
+        let shared = shuffle_deck(&env, 7, &seed(&env, 8), &seed(&env, 9));
+        let half = half_deck(&shared, false);
+        let deck = deal_deck(&env, 7, &seed(&env, 10), &half);
+        assert_eq!(deck.len(), 10);
+        for card in deck.iter() {
+            assert!(half.contains(card));
+            assert_eq!(deck.iter().filter(|other| *other == card).count(), 1);
+        }
+
+        // Standing pat keeps the dealt cards; discards take the next ones in order
+        assert_eq!(final_hand(&env, &deck, 0), deck.slice(0..5));
+        let drawn = final_hand(&env, &deck, 0b01010);
+        let expected = [0, 5, 2, 6, 4].map(|i| deck.get_unchecked(i));
+        assert_eq!(drawn, Vec::from_array(&env, expected));
+    });
+}
+
+#[test]
+fn test_hands_never_share_a_card() {
+    let env = Env::default();
+    let contract_id = env.register(PokerContract, (Address::generate(&env), Address::generate(&env)));
+
+    env.as_contract(&contract_id, || {
+        for session_id in 0..20u32 {
+            // The halves split the whole deck
+            let shared = shuffle_deck(&env, session_id, &seed(&env, 3), &seed(&env, 4));
+            let (top, bottom) = (half_deck(&shared, true), half_deck(&shared, false));
+            assert_eq!(top.len() + bottom.len(), 52);
+            for card in 0..52u32 {
+                assert!(top.contains(card) != bottom.contains(card));
+            }
+
+            // Both hands, replacements included, come from disjoint halves
+            let deck1 = deal_deck(&env, session_id, &seed(&env, 1), &top);
+            let deck2 = deal_deck(&env, session_id, &seed(&env, 2), &bottom);
+            assert!(deck1.iter().all(|card| !deck2.contains(card)));
+        }
+    });
+}
+
+// ============================================================================
+// Betting Tests
+// ============================================================================
+
+#[test]
+fn test_betting_rules() {
+    let (env, _hub, client, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100, &100);
+    assert_poker_error(&client.try_call(&1, &player1), Error::WrongPhase);
+
+    client.commit_seed(&1, &player1, &commitment(&env, &seed(&env, 1)), &commitment(&env, &seed(&env, 3)));
+    client.commit_seed(&1, &player2, &commitment(&env, &seed(&env, 2)), &commitment(&env, &seed(&env, 4)));
+    assert_poker_error(&client.try_cut_deck(&1, &player1, &seed(&env, 4)), Error::InvalidReveal);
+    client.cut_deck(&1, &player1, &seed(&env, 3));
+    assert_poker_error(&client.try_cut_deck(&1, &player1, &seed(&env, 3)), Error::AlreadyCut);
+    client.cut_deck(&1, &player2, &seed(&env, 4));
+
+    // Player 1 acts first, and raises must be positive
+    assert_poker_error(&client.try_call(&1, &player2), Error::NotYourTurn);
+    assert_poker_error(&client.try_raise(&1, &player1, &0), Error::InvalidAmount);
+    assert_poker_error(&client.try_fold(&1, &Address::generate(&env)), Error::NotPlayer);
+
+    // Raises are capped per round
+    for i in 0..MAX_RAISES {
+        let player = if i % 2 == 0 { &player1 } else { &player2 };
+        client.raise(&1, player, &10);
+    }
+    assert_poker_error(&client.try_raise(&1, &player1, &10), Error::RaiseLimit);
+
+    // Calling matches the stake and closes the round
+    client.call(&1, &player1);
+    let game = client.get_game(&1);
+    assert_eq!((game.player1_stake, game.player2_stake), (140, 140));
+    assert_eq!(game.phase, Phase::Draw);
+    assert_eq!(game.raises, 0);
+
+    assert_poker_error(&client.try_draw(&1, &player1, &0b100000), Error::InvalidDiscards);
+    client.draw(&1, &player1, &0b11111);
+    assert_poker_error(&client.try_draw(&1, &player1, &0), Error::AlreadyDrawn);
+}
+
+#[test]
+fn test_invalid_reveal() {
+    let (env, _hub, client, player1, player2) = setup_test();
+    deal(&env, &client, 1, &player1, &player2);
+    for _ in 0..2 {
+        client.call(&1, &player1);
+        client.call(&1, &player2);
+        if client.get_game(&1).phase == Phase::Draw {
+            client.draw(&1, &player1, &0);
+            client.draw(&1, &player2, &0);
+        }
+    }
+
+    assert_poker_error(&client.try_reveal_seed(&1, &player1, &seed(&env, 2)), Error::InvalidReveal);
+    assert!(client.get_game(&1).player1_hand.is_empty());
+}
+
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+#[test]
+fn test_claim_timeout_on_betting_action() {
+    let (env, hub, client, player1, player2) = setup_test();
+    deal(&env, &client, 1, &player1, &player2);
+    client.raise(&1, &player1, &25);
+
+    assert_poker_error(&client.try_claim_timeout(&1, &player1), Error::TimeoutNotReached);
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+
+    // Player 2 is the one holding up the game
+    assert_poker_error(&client.try_claim_timeout(&1, &player2), Error::CannotClaimTimeout);
+    assert_eq!(client.claim_timeout(&1, &player1), player1);
+    assert_eq!(hub.get_player_stats(&player1, &client.address).net_points, 100);
+    assert_poker_error(&client.try_raise(&1, &player2, &25), Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_hides_their_hand() {
+    let (env, _hub, client, player1, player2) = setup_test();
+    deal(&env, &client, 1, &player1, &player2);
+    for _ in 0..2 {
+        client.call(&1, &player1);
+        client.call(&1, &player2);
+        if client.get_game(&1).phase == Phase::Draw {
+            client.draw(&1, &player1, &0);
+            client.draw(&1, &player2, &0);
+        }
+    }
+    client.reveal_seed(&1, &player1, &seed(&env, 1));
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    assert_poker_error(&client.try_claim_timeout(&1, &player2), Error::CannotClaimTimeout);
+    assert_eq!(client.claim_timeout(&1, &player1), player1);
+    assert_eq!(client.get_game(&1).winner, Some(player1));
+}
+
+#[test]
+fn test_cannot_play_against_self() {
+    let (_env, _hub, client, player1, _player2) = setup_test();
+    let result = client.try_start_game(&1, &player1, &player1, &100, &100);
+    assert_poker_error(&result, Error::SelfPlay);
+}
+
+#[test]
+fn test_cannot_get_nonexistent_game() {
+    let (_env, _hub, client, _player1, _player2) = setup_test();
+    assert_poker_error(&client.try_get_game(&999), Error::GameNotFound);
+}
@@ -0,0 +1,597 @@
+#![no_std]
+
+//! # Raffle
+//!
+//! Raffles with any number of ticket holders. An organizer opens a raffle
+//! with a ticket price, a closing ledger and a commitment to a secret. Players
+//! buy tickets, in points or in a token, until the closing ledger. Then the
+//! organizer reveals the secret and the winning ticket is drawn. The winner
+//! takes the pot, less the organizer's fee.
+//!
+//! Game Hub sessions hold at most eight players, so a raffle keeps its own
+//! pot and settles it the way the hub settles a session: token pots are held
+//! in escrow and paid out on the draw, and points pots are settled into each
+//! player's net points. The organizer posts a bond with the raffle. It comes
+//! back on the draw; if the secret is never revealed, every ticket is
+//! refunded and the bond is shared among the buyers.
+//!
+//! Each purchase is stored once, as a range of consecutive ticket numbers.
+//! Buying is a single write and the draw finds the winning range with a
+//! binary search, so no call iterates over all tickets or buyers.
+//!
+//! The draw mixes the organizer's secret with a running hash of every
+//! purchase. Buyers cannot predict the result without the secret, and the
+//! organizer cannot fix the secret after seeing the purchases. The organizer
+//! knows the secret, so they cannot buy tickets, and withholding it costs
+//! them the bond.
+
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, IntoVal, contract, contracterror, contractevent, contractimpl,
+    contracttype, token, vec, xdr::ToXdr
+};
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    RaffleNotFound = 1,
+    RaffleExists = 2,
+    InvalidConfig = 3,
+    SalesClosed = 4,
+    SalesOpen = 5,
+    InvalidCount = 6,
+    NoTickets = 7,
+    InvalidReveal = 8,
+    AlreadyDrawn = 9,
+    RevealExpired = 10,
+    NotCancelled = 11,
+    PurchaseNotFound = 12,
+    AlreadyRefunded = 13,
+    OrganizerCannotBuy = 14,
+    TicketsSold = 15,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// Selling tickets until `close_ledger`, then waiting for the draw
+    Open,
+    /// Winner drawn and paid
+    Drawn,
+    /// The secret was not revealed in time; tickets are refundable
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RaffleSetup {
+    /// Token tickets are paid in, or None for points
+    pub token: Option<Address>,
+    pub ticket_price: i128,
+    /// First ledger at which tickets can no longer be bought
+    pub close_ledger: u32,
+    /// keccak256 of the organizer's 32-byte secret
+    pub commitment: BytesN<32>,
+    /// Organizer's cut of the pot in basis points
+    pub fee_bp: u32,
+    /// Posted by the organizer and returned on the draw; shared among the
+    /// buyers if the secret is not revealed in time
+    pub bond: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Raffle {
+    pub organizer: Address,
+    pub setup: RaffleSetup,
+    pub status: Status,
+    pub tickets_sold: u32,
+    pub purchases: u32,
+    /// Running hash of every purchase, mixed into the draw
+    pub entropy: BytesN<32>,
+    pub winner: Option<Address>,
+    pub winning_ticket: Option<u32>,
+    /// Pot paid to the winner, after the fee
+    pub prize: i128,
+    /// Purchases refunded after a missed reveal
+    pub refunds: u32,
+    /// Share of the bond paid out with those refunds
+    pub bond_refunded: i128,
+}
+
+/// Tickets `first_ticket..first_ticket + count`, bought in one call
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Purchase {
+    pub buyer: Address,
+    pub first_ticket: u32,
+    pub count: u32,
+    pub refunded: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Raffle(u32),
+    Purchase(u32, u32),
+    /// A player's points won minus points spent, across points raffles
+    Points(Address),
+    Admin,
+}
+
+#[contractevent]
+pub struct TicketsBought {
+    pub raffle_id: u32,
+    pub buyer: Address,
+    pub first_ticket: u32,
+    pub count: u32,
+}
+
+#[contractevent]
+pub struct RaffleDrawn {
+    pub raffle_id: u32,
+    pub winner: Address,
+    pub ticket: u32,
+    pub prize: i128,
+}
+
+#[contractevent]
+pub struct TicketsRefunded {
+    pub raffle_id: u32,
+    pub buyer: Address,
+    pub amount: i128,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// Raffles can hold escrowed tokens, so they live in persistent storage and
+// outlast the longest sales window plus the reveal window
+
+/// TTL for raffles and purchases, bumped whenever they change (~180 days)
+const RAFFLE_TTL_LEDGERS: u32 = 3_110_400;
+
+/// Longest sales window (30 days)
+pub const MAX_SALE_LEDGERS: u32 = 518_400;
+
+/// Ledgers after closing the organizer has to reveal the secret (1 day)
+pub const REVEAL_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// A fee of the whole pot
+pub const MAX_FEE_BP: u32 = 10_000;
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct RaffleContract;
+
+#[contractimpl]
+impl RaffleContract {
+    /// Initialize the contract with an admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    // ========================================================================
+    // Raffle Lifecycle
+    // ========================================================================
+
+    /// Create a raffle and open ticket sales.
+    ///
+    /// # Arguments
+    /// * `raffle_id` - Unique raffle identifier
+    /// * `organizer` - Address that reveals the secret and collects the fee
+    /// * `setup` - Token, ticket price, closing ledger, commitment, fee and bond
+    pub fn create_raffle(env: Env, raffle_id: u32, organizer: Address, setup: RaffleSetup) -> Result<(), Error> {
+        organizer.require_auth();
+
+        if env.storage().persistent().has(&DataKey::Raffle(raffle_id)) {
+            return Err(Error::RaffleExists);
+        }
+        let now = env.ledger().sequence();
+        if setup.ticket_price <= 0
+            || setup.close_ledger <= now
+            || setup.close_ledger > now + MAX_SALE_LEDGERS
+            || setup.fee_bp > MAX_FEE_BP
+            || setup.bond <= 0
+        {
+            return Err(Error::InvalidConfig);
+        }
+        Self::collect(&env, &setup.token, &organizer, setup.bond);
+
+        let raffle = Raffle {
+            organizer,
+            entropy: setup.commitment.clone(),
+            setup,
+            status: Status::Open,
+            tickets_sold: 0,
+            purchases: 0,
+            winner: None,
+            winning_ticket: None,
+            prize: 0,
+            refunds: 0,
+            bond_refunded: 0,
+        };
+        Self::save(&env, &DataKey::Raffle(raffle_id), &raffle);
+
+        Ok(())
+    }
+
+    /// Buy `count` tickets before the raffle closes. In token mode the price
+    /// moves into escrow now. The organizer cannot buy.
+    ///
+    /// **Auth:** the buyer authorizes `(raffle_id, cost)`.
+    ///
+    /// # Arguments
+    /// * `raffle_id` - The raffle to enter
+    /// * `buyer` - Address of the buying player
+    /// * `count` - Number of tickets
+    ///
+    /// # Returns
+    /// * `u32` - The first ticket number bought; the rest follow in order
+    pub fn buy_tickets(env: Env, raffle_id: u32, buyer: Address, count: u32) -> Result<u32, Error> {
+        let mut raffle = Self::load_raffle(&env, raffle_id)?;
+        if raffle.status != Status::Open || env.ledger().sequence() >= raffle.setup.close_ledger {
+            return Err(Error::SalesClosed);
+        }
+        if buyer == raffle.organizer {
+            return Err(Error::OrganizerCannotBuy);
+        }
+        if count == 0 {
+            return Err(Error::InvalidCount);
+        }
+        let tickets_sold = raffle.tickets_sold.checked_add(count).ok_or(Error::InvalidCount)?;
+        let cost = raffle
+            .setup
+            .ticket_price
+            .checked_mul(count as i128)
+            .ok_or(Error::InvalidCount)?;
+        buyer.require_auth_for_args(vec![&env, raffle_id.into_val(&env), cost.into_val(&env)]);
+        Self::collect(&env, &raffle.setup.token, &buyer, cost);
+
+        let first_ticket = raffle.tickets_sold;
+        let purchase = Purchase {
+            buyer: buyer.clone(),
+            first_ticket,
+            count,
+            refunded: false,
+        };
+        Self::save(&env, &DataKey::Purchase(raffle_id, raffle.purchases), &purchase);
+
+        let mut entropy_bytes = Bytes::from(raffle.entropy.clone());
+        entropy_bytes.append(&buyer.clone().to_xdr(&env));
+        entropy_bytes.append(&Bytes::from_array(&env, &count.to_be_bytes()));
+        entropy_bytes.append(&Bytes::from_array(&env, &env.ledger().sequence().to_be_bytes()));
+        raffle.entropy = env.crypto().keccak256(&entropy_bytes).into();
+        raffle.tickets_sold = tickets_sold;
+        raffle.purchases += 1;
+        Self::save(&env, &DataKey::Raffle(raffle_id), &raffle);
+
+        TicketsBought {
+            raffle_id,
+            buyer,
+            first_ticket,
+            count,
+        }
+        .publish(&env);
+
+        Ok(first_ticket)
+    }
+
+    /// Draw the winner by revealing the organizer's secret, then pay out and
+    /// return the organizer's bond. Anyone holding the secret can draw once
+    /// sales close, until the reveal window ends.
+    ///
+    /// # Arguments
+    /// * `raffle_id` - The raffle to draw
+    /// * `secret` - The 32-byte secret behind the commitment
+    ///
+    /// # Returns
+    /// * `Address` - The winner
+    pub fn draw(env: Env, raffle_id: u32, secret: BytesN<32>) -> Result<Address, Error> {
+        let mut raffle = Self::load_raffle(&env, raffle_id)?;
+        if raffle.status != Status::Open {
+            return Err(Error::AlreadyDrawn);
+        }
+        let now = env.ledger().sequence();
+        if now < raffle.setup.close_ledger {
+            return Err(Error::SalesOpen);
+        }
+        if now > raffle.setup.close_ledger + REVEAL_TIMEOUT_LEDGERS {
+            return Err(Error::RevealExpired);
+        }
+        if raffle.tickets_sold == 0 {
+            return Err(Error::NoTickets);
+        }
+
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(secret.clone())).into();
+        if hash != raffle.setup.commitment {
+            return Err(Error::InvalidReveal);
+        }
+
+        let mut seed_bytes = Bytes::from(secret);
+        seed_bytes.append(&Bytes::from(raffle.entropy.clone()));
+        env.prng().seed(env.crypto().keccak256(&seed_bytes).into());
+        let ticket = env.prng().gen_range::<u64>(0..raffle.tickets_sold as u64) as u32;
+        let winner = Self::find_purchase(&env, raffle_id, raffle.purchases, ticket)?.buyer;
+
+        // Settle: the organizer's fee comes off the pot, the winner takes the rest
+        let pot = raffle.setup.ticket_price * raffle.tickets_sold as i128;
+        let fee = pot * raffle.setup.fee_bp as i128 / MAX_FEE_BP as i128;
+        let prize = pot - fee;
+        Self::pay(&env, &raffle.setup.token, &winner, prize);
+        Self::pay(&env, &raffle.setup.token, &raffle.organizer, fee + raffle.setup.bond);
+
+        raffle.status = Status::Drawn;
+        raffle.winner = Some(winner.clone());
+        raffle.winning_ticket = Some(ticket);
+        raffle.prize = prize;
+        Self::save(&env, &DataKey::Raffle(raffle_id), &raffle);
+
+        RaffleDrawn {
+            raffle_id,
+            winner: winner.clone(),
+            ticket,
+            prize,
+        }
+        .publish(&env);
+
+        Ok(winner)
+    }
+
+    /// Refund one purchase of a raffle that was never drawn. The raffle is
+    /// cancelled once the reveal window ends without a draw; each purchase
+    /// is then refunded on its own, so refunds never iterate over buyers.
+    /// Each refund carries the purchase's share of the organizer's bond, in
+    /// proportion to its tickets; the last refund takes the rounding remainder.
+    ///
+    /// # Arguments
+    /// * `raffle_id` - The cancelled raffle
+    /// * `purchase_index` - The purchase to refund, in buying order
+    ///
+    /// # Returns
+    /// * `i128` - Amount refunded to the buyer, bond share included
+    pub fn refund(env: Env, raffle_id: u32, purchase_index: u32) -> Result<i128, Error> {
+        let mut raffle = Self::load_raffle(&env, raffle_id)?;
+        if raffle.status == Status::Open
+            && env.ledger().sequence() > raffle.setup.close_ledger + REVEAL_TIMEOUT_LEDGERS
+        {
+            raffle.status = Status::Cancelled;
+            Self::save(&env, &DataKey::Raffle(raffle_id), &raffle);
+        }
+        if raffle.status != Status::Cancelled {
+            return Err(Error::NotCancelled);
+        }
+
+        let key = DataKey::Purchase(raffle_id, purchase_index);
+        let mut purchase: Purchase = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::PurchaseNotFound)?;
+        if purchase.refunded {
+            return Err(Error::AlreadyRefunded);
+        }
+
+        raffle.refunds += 1;
+        let bond_share = if raffle.refunds == raffle.purchases {
+            raffle.setup.bond - raffle.bond_refunded
+        } else {
+            raffle.setup.bond * purchase.count as i128 / raffle.tickets_sold as i128
+        };
+        raffle.bond_refunded += bond_share;
+        Self::save(&env, &DataKey::Raffle(raffle_id), &raffle);
+
+        let amount = raffle.setup.ticket_price * purchase.count as i128 + bond_share;
+        Self::pay(&env, &raffle.setup.token, &purchase.buyer, amount);
+        purchase.refunded = true;
+        Self::save(&env, &key, &purchase);
+
+        TicketsRefunded {
+            raffle_id,
+            buyer: purchase.buyer,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(amount)
+    }
+
+    /// Cancel a raffle nobody has bought tickets for, returning the
+    /// organizer's bond.
+    ///
+    /// # Arguments
+    /// * `raffle_id` - The raffle to cancel
+    pub fn cancel_raffle(env: Env, raffle_id: u32) -> Result<(), Error> {
+        let mut raffle = Self::load_raffle(&env, raffle_id)?;
+        raffle.organizer.require_auth();
+        if raffle.status != Status::Open {
+            return Err(Error::AlreadyDrawn);
+        }
+        if raffle.tickets_sold > 0 {
+            return Err(Error::TicketsSold);
+        }
+
+        Self::pay(&env, &raffle.setup.token, &raffle.organizer, raffle.setup.bond);
+        raffle.status = Status::Cancelled;
+        Self::save(&env, &DataKey::Raffle(raffle_id), &raffle);
+
+        Ok(())
+    }
+
+    /// Get raffle information.
+    ///
+    /// # Arguments
+    /// * `raffle_id` - The raffle
+    ///
+    /// # Returns
+    /// * `Raffle` - The raffle state
+    pub fn get_raffle(env: Env, raffle_id: u32) -> Result<Raffle, Error> {
+        Self::load_raffle(&env, raffle_id)
+    }
+
+    /// Get one purchase of a raffle.
+    ///
+    /// # Arguments
+    /// * `raffle_id` - The raffle
+    /// * `purchase_index` - The purchase, in buying order
+    ///
+    /// # Returns
+    /// * `Purchase` - The buyer and their ticket range
+    pub fn get_purchase(env: Env, raffle_id: u32, purchase_index: u32) -> Result<Purchase, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Purchase(raffle_id, purchase_index))
+            .ok_or(Error::PurchaseNotFound)
+    }
+
+    /// Get a player's net points across points raffles: prizes, fees, bonds
+    /// and refunds received, less tickets bought and bonds posted.
+    ///
+    /// # Arguments
+    /// * `player` - The player
+    ///
+    /// # Returns
+    /// * `i128` - Net points, negative when the player has spent more than won
+    pub fn get_points(env: Env, player: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Points(player))
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    /// Take `amount` from `from`: into escrow in token mode, off their net
+    /// points otherwise
+    fn collect(env: &Env, token: &Option<Address>, from: &Address, amount: i128) {
+        match token {
+            Some(token) => token::Client::new(env, token).transfer(from, env.current_contract_address(), &amount),
+            None => Self::add_points(env, from, -amount),
+        }
+    }
+
+    /// Give `amount` to `to`: out of escrow in token mode, onto their net
+    /// points otherwise
+    fn pay(env: &Env, token: &Option<Address>, to: &Address, amount: i128) {
+        if amount == 0 {
+            return;
+        }
+        match token {
+            Some(token) => token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount),
+            None => Self::add_points(env, to, amount),
+        }
+    }
+
+    fn add_points(env: &Env, player: &Address, amount: i128) {
+        let key = DataKey::Points(player.clone());
+        let points: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        Self::save(env, &key, &(points + amount));
+    }
+
+    fn load_raffle(env: &Env, raffle_id: u32) -> Result<Raffle, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Raffle(raffle_id))
+            .ok_or(Error::RaffleNotFound)
+    }
+
+    fn save<T>(env: &Env, key: &DataKey, value: &T)
+    where
+        T: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+    {
+        env.storage().persistent().set(key, value);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, RAFFLE_TTL_LEDGERS, RAFFLE_TTL_LEDGERS);
+    }
+
+    /// Binary search the purchases for the one holding `ticket`.
+    /// Purchases cover consecutive ticket ranges in buying order.
+    fn find_purchase(env: &Env, raffle_id: u32, purchases: u32, ticket: u32) -> Result<Purchase, Error> {
+        let (mut low, mut high) = (0, purchases);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let purchase: Purchase = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Purchase(raffle_id, middle))
+                .ok_or(Error::PurchaseNotFound)?;
+            if ticket < purchase.first_ticket {
+                high = middle;
+            } else if ticket >= purchase.first_ticket + purchase.count {
+                low = middle + 1;
+            } else {
+                return Ok(purchase);
+            }
+        }
+        Err(Error::PurchaseNotFound)
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
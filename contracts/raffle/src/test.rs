@@ -0,0 +1,272 @@
+#![cfg(test)]
+
+// Unit tests for the raffle contract, in points mode and with a Stellar
+// Asset Contract token.
+
+use crate::{
+    Error, Purchase, RaffleContract, RaffleContractClient, RaffleSetup, Status, MAX_FEE_BP, MAX_SALE_LEDGERS,
+    REVEAL_TIMEOUT_LEDGERS,
+};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{token, Address, Bytes, BytesN, Env, Vec};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+const CLOSE_LEDGER: u32 = 1_100;
+
+fn setup_test() -> (Env, RaffleContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    // Set ledger info for time-based operations
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(RaffleContract, (&admin,));
+    let client = RaffleContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+
+    (env, client, organizer)
+}
+
+/// Assert that a Result contains a specific raffle error
+fn assert_raffle_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        _ => panic!("Expected error {:?}, got something else", expected_error),
+    }
+}
+
+fn secret(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[7; 32])
+}
+
+fn raffle_setup(env: &Env, token: Option<Address>, fee_bp: u32) -> RaffleSetup {
+    RaffleSetup {
+        token,
+        ticket_price: 10,
+        close_ledger: CLOSE_LEDGER,
+        commitment: env.crypto().keccak256(&Bytes::from(secret(env))).into(),
+        fee_bp,
+        bond: 50,
+    }
+}
+
+fn setup_token(env: &Env, players: &Vec<Address>, balance: i128) -> token::Client<'static> {
+    let issuer = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    let minter = token::StellarAssetClient::new(env, &sac.address());
+    for player in players.iter() {
+        minter.mint(&player, &balance);
+    }
+    token::Client::new(env, &sac.address())
+}
+
+fn close_sales(env: &Env) {
+    env.ledger().with_mut(|li| li.sequence_number = CLOSE_LEDGER);
+}
+
+/// The purchase holding `ticket`, found by scanning every purchase
+fn holder(client: &RaffleContractClient<'static>, raffle_id: u32, ticket: u32) -> Purchase {
+    let purchases = client.get_raffle(&raffle_id).purchases;
+    (0..purchases)
+        .map(|index| client.get_purchase(&raffle_id, &index))
+        .find(|purchase| purchase.first_ticket <= ticket && ticket < purchase.first_ticket + purchase.count)
+        .unwrap()
+}
+
+// ============================================================================
+// Draw Tests
+// ============================================================================
+
+#[test]
+fn test_points_raffle_draw() {
+    let (env, client, organizer) = setup_test();
+    client.create_raffle(&1, &organizer, &raffle_setup(&env, None, 500));
+
+    // Purchases take consecutive ticket ranges
+    let buyers: [Address; 3] = core::array::from_fn(|_| Address::generate(&env));
+    assert_raffle_error(&client.try_buy_tickets(&1, &organizer, &1), Error::OrganizerCannotBuy);
+    assert_eq!(client.buy_tickets(&1, &buyers[0], &5), 0);
+    assert_eq!(client.buy_tickets(&1, &buyers[1], &1), 5);
+    assert_eq!(client.buy_tickets(&1, &buyers[2], &14), 6);
+    assert_eq!(client.buy_tickets(&1, &buyers[0], &20), 20);
+    assert_raffle_error(&client.try_buy_tickets(&1, &buyers[1], &0), Error::InvalidCount);
+    assert_raffle_error(&client.try_draw(&1, &secret(&env)), Error::SalesOpen);
+    assert_raffle_error(&client.try_cancel_raffle(&1), Error::TicketsSold);
+
+    close_sales(&env);
+    assert_raffle_error(&client.try_buy_tickets(&1, &buyers[1], &1), Error::SalesClosed);
+    assert_raffle_error(&client.try_draw(&1, &BytesN::from_array(&env, &[8; 32])), Error::InvalidReveal);
+
+    let winner = client.draw(&1, &secret(&env));
+    let raffle = client.get_raffle(&1);
+    assert_eq!(raffle.status, Status::Drawn);
+    assert_eq!(raffle.tickets_sold, 40);
+    assert_eq!(raffle.winner, Some(winner.clone()));
+    assert_eq!(holder(&client, 1, raffle.winning_ticket.unwrap()).buyer, winner);
+
+    // 5% of the 400 pot goes to the organizer, whose bond comes back
+    assert_eq!(raffle.prize, 380);
+    assert_eq!(client.get_points(&organizer), 20);
+    let spent = |buyer: &Address| if *buyer == buyers[0] { 250 } else if *buyer == buyers[1] { 10 } else { 140 };
+    for buyer in buyers.iter() {
+        let won = if *buyer == winner { 380 } else { 0 };
+        assert_eq!(client.get_points(buyer), won - spent(buyer));
+    }
+    assert_raffle_error(&client.try_draw(&1, &secret(&env)), Error::AlreadyDrawn);
+}
+
+#[test]
+fn test_token_raffle_pays_winner_and_fee() {
+    let (env, client, organizer) = setup_test();
+    let buyers = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+    let token = setup_token(&env, &buyers, 1000);
+    token::StellarAssetClient::new(&env, &token.address).mint(&organizer, &50);
+    client.create_raffle(&1, &organizer, &raffle_setup(&env, Some(token.address.clone()), 1000));
+    assert_eq!(token.balance(&organizer), 0);
+
+    client.buy_tickets(&1, &buyers.get_unchecked(0), &30);
+    client.buy_tickets(&1, &buyers.get_unchecked(1), &70);
+    assert_eq!(token.balance(&client.address), 1050);
+
+    close_sales(&env);
+    let winner = client.draw(&1, &secret(&env));
+    let loser = if winner == buyers.get_unchecked(0) { buyers.get_unchecked(1) } else { buyers.get_unchecked(0) };
+    let spent = |buyer: &Address| if *buyer == buyers.get_unchecked(0) { 300 } else { 700 };
+
+    assert_eq!(token.balance(&winner), 1000 - spent(&winner) + 900);
+    assert_eq!(token.balance(&loser), 1000 - spent(&loser));
+    assert_eq!(token.balance(&organizer), 150);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_draw_scales_with_purchases() {
+    let (env, client, organizer) = setup_test();
+    client.create_raffle(&1, &organizer, &raffle_setup(&env, None, 0));
+
+    // A thousand purchases of varying size from a handful of buyers
+    let buyers: [Address; 7] = core::array::from_fn(|_| Address::generate(&env));
+    for i in 0..1000u32 {
+        client.buy_tickets(&1, &buyers[(i % 7) as usize], &(i % 5 + 1));
+    }
+    assert_eq!(client.get_raffle(&1).tickets_sold, 3000);
+
+    // The draw is a binary search over purchases, well inside one call's budget
+    close_sales(&env);
+    env.cost_estimate().budget().reset_default();
+    let winner = client.draw(&1, &secret(&env));
+    let raffle = client.get_raffle(&1);
+    assert_eq!(holder(&client, 1, raffle.winning_ticket.unwrap()).buyer, winner);
+    assert_eq!(raffle.prize, 30_000);
+}
+
+// ============================================================================
+// Refund Tests
+// ============================================================================
+
+#[test]
+fn test_refunds_after_missed_reveal() {
+    let (env, client, organizer) = setup_test();
+    let buyers = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+    let token = setup_token(&env, &buyers, 1000);
+    token::StellarAssetClient::new(&env, &token.address).mint(&organizer, &50);
+    client.create_raffle(&1, &organizer, &raffle_setup(&env, Some(token.address.clone()), 1000));
+
+    client.buy_tickets(&1, &buyers.get_unchecked(0), &30);
+    client.buy_tickets(&1, &buyers.get_unchecked(1), &70);
+
+    // No refunds while the organizer can still draw
+    close_sales(&env);
+    assert_raffle_error(&client.try_refund(&1, &0), Error::NotCancelled);
+
+    env.ledger().with_mut(|li| li.sequence_number += REVEAL_TIMEOUT_LEDGERS + 1);
+    assert_raffle_error(&client.try_draw(&1, &secret(&env)), Error::RevealExpired);
+
+    // Each refund carries its share of the forfeited bond
+    assert_eq!(client.refund(&1, &1), 735);
+    assert_eq!(client.get_raffle(&1).status, Status::Cancelled);
+    assert_raffle_error(&client.try_refund(&1, &1), Error::AlreadyRefunded);
+    assert_raffle_error(&client.try_refund(&1, &2), Error::PurchaseNotFound);
+    assert_eq!(client.refund(&1, &0), 315);
+
+    assert_eq!(token.balance(&buyers.get_unchecked(0)), 1015);
+    assert_eq!(token.balance(&buyers.get_unchecked(1)), 1035);
+    assert_eq!(token.balance(&organizer), 0);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+// ============================================================================
+// Setup Tests
+// ============================================================================
+
+#[test]
+fn test_invalid_setup() {
+    let (env, client, organizer) = setup_test();
+
+    let mut setup = raffle_setup(&env, None, MAX_FEE_BP + 1);
+    assert_raffle_error(&client.try_create_raffle(&1, &organizer, &setup), Error::InvalidConfig);
+
+    setup.fee_bp = 0;
+    setup.ticket_price = 0;
+    assert_raffle_error(&client.try_create_raffle(&1, &organizer, &setup), Error::InvalidConfig);
+
+    setup.ticket_price = 10;
+    setup.close_ledger = 100;
+    assert_raffle_error(&client.try_create_raffle(&1, &organizer, &setup), Error::InvalidConfig);
+
+    setup.close_ledger = 101 + MAX_SALE_LEDGERS;
+    assert_raffle_error(&client.try_create_raffle(&1, &organizer, &setup), Error::InvalidConfig);
+
+    setup.close_ledger = CLOSE_LEDGER;
+    setup.bond = 0;
+    assert_raffle_error(&client.try_create_raffle(&1, &organizer, &setup), Error::InvalidConfig);
+
+    client.create_raffle(&1, &organizer, &raffle_setup(&env, None, 0));
+    let result = client.try_create_raffle(&1, &organizer, &raffle_setup(&env, None, 0));
+    assert_raffle_error(&result, Error::RaffleExists);
+}
+
+#[test]
+fn test_draw_needs_tickets() {
+    let (env, client, organizer) = setup_test();
+    client.create_raffle(&1, &organizer, &raffle_setup(&env, None, 0));
+    close_sales(&env);
+    assert_raffle_error(&client.try_draw(&1, &secret(&env)), Error::NoTickets);
+
+    // With no tickets sold the organizer can cancel and take the bond back
+    assert_eq!(client.get_points(&organizer), -50);
+    client.cancel_raffle(&1);
+    assert_eq!(client.get_raffle(&1).status, Status::Cancelled);
+    assert_eq!(client.get_points(&organizer), 0);
+    assert_raffle_error(&client.try_cancel_raffle(&1), Error::AlreadyDrawn);
+}
+
+#[test]
+fn test_cannot_get_nonexistent_raffle() {
+    let (_env, client, _organizer) = setup_test();
+    assert_raffle_error(&client.try_get_raffle(&999), Error::RaffleNotFound);
+    assert_raffle_error(&client.try_get_purchase(&999, &0), Error::PurchaseNotFound);
+}
@@ -6,13 +6,20 @@
 //! Both players submit their move, then either calls reveal_winner to determine the outcome.
 //! Rock beats Scissors, Scissors beats Paper, Paper beats Rock. Ties go to Player 1.
 //!
+//! **Team mode** (`start_team_game`): two equal teams of 1..=4 players. Each
+//! member plays the opposing member in the same seat; the team that wins more
+//! of those duels wins. A level result resets all moves, like a tied game,
+//! up to `MAX_TEAM_REPLAYS` times before the game is drawn. A round that
+//! stalls past its deadline can be claimed by either side.
+//!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
 use soroban_sdk::{
-    Address, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl, contracttype, vec
+    Address, BytesN, Env, IntoVal, Map, Vec, contract, contractclient, contracterror, contractimpl, contracttype, vec
 };
+pub use session_keys::{SessionKey, MAX_SESSION_KEY_LEDGERS};
 
 // Import GameHub contract interface
 // This allows us to call into the GameHub contract
@@ -33,6 +40,20 @@ pub trait GameHub {
         session_id: u32,
         player1_won: bool
     );
+
+    fn start_team_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        team1: Vec<Address>,
+        team1_points: Vec<i128>,
+        team2: Vec<Address>,
+        team2_points: Vec<i128>,
+    );
+
+    fn end_team_game(env: Env, session_id: u32, team1_won: bool);
+
+    fn end_game_draw(env: Env, session_id: u32);
 }
 
 // ============================================================================
@@ -48,6 +69,20 @@ pub enum Error {
     AlreadyMoved = 3,
     BothPlayersNotMoved = 4,
     GameAlreadyEnded = 5,
+    InvalidTeams = 6,
+    NotAllMoved = 7,
+    InvalidSessionKey = 8,
+    TimeoutNotReached = 9,
+    CannotClaimTimeout = 10,
+}
+
+impl From<session_keys::GrantError> for Error {
+    fn from(error: session_keys::GrantError) -> Self {
+        match error {
+            session_keys::GrantError::NotPlayer => Error::NotPlayer,
+            session_keys::GrantError::InvalidSessionKey => Error::InvalidSessionKey,
+        }
+    }
 }
 
 // ============================================================================
@@ -75,10 +110,32 @@ pub struct Game {
     pub winner: Option<Address>,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TeamGame {
+    pub team1: Vec<Address>,
+    /// Points committed by each member of team 1, same order as `team1`
+    pub team1_points: Vec<i128>,
+    /// Same size as `team1`; seat i plays seat i
+    pub team2: Vec<Address>,
+    pub team2_points: Vec<i128>,
+    /// Moves submitted this round
+    pub moves: Map<Address, PlayerMove>,
+    /// Level rounds replayed so far
+    pub replays: u32,
+    /// Ledger after which a stalled round can be claimed
+    pub deadline: u32,
+    /// None until a team wins
+    pub team1_won: Option<bool>,
+    /// Set when the game ended without a winner
+    pub draw: bool,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
+    TeamGame(u32),
     GameHubAddress,
     Admin,
 }
@@ -93,6 +150,30 @@ pub enum DataKey {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// Most members on one side of a team game (matches the Game Hub)
+const MAX_TEAM_SIZE: u32 = 4;
+
+/// Level team rounds replayed before the game is drawn
+const MAX_TEAM_REPLAYS: u32 = 5;
+
+/// Ledgers a team round may take before it can be claimed
+/// 1 day = 24 * 60 * 60 / 5 = 17,280 ledgers
+const MOVE_TIMEOUT_LEDGERS: u32 = 17_280;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Rock beats Scissors, Scissors beats Paper, Paper beats Rock.
+fn beats(a: &PlayerMove, b: &PlayerMove) -> bool {
+    matches!(
+        (a, b),
+        (PlayerMove::Rock, PlayerMove::Scissors)
+            | (PlayerMove::Scissors, PlayerMove::Paper)
+            | (PlayerMove::Paper, PlayerMove::Rock)
+    )
+}
+
 // ============================================================================
 // Contract Definition
 // ============================================================================
@@ -127,6 +208,8 @@ impl RpsContract {
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    /// * `session_keys` - Move keys to delegate up front, each mapped to its
+    ///   player and expiry (see `delegate_session_key`); may be empty
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -134,15 +217,28 @@ impl RpsContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
+        session_keys: Map<Address, SessionKey>,
     ) -> Result<(), Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
         }
 
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+        // Both players consent to the points and to any session keys
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
+
+        session_keys::grant_all(&env, session_id, &player1, &player2, &session_keys, GAME_TTL_LEDGERS)?;
 
         // Get GameHub address
         let game_hub_addr: Address = env
@@ -195,10 +291,10 @@ impl RpsContract {
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `player` - Address of the player submitting a move
+    /// * `player` - Address of the player submitting a move, or their session key
     /// * `game_move` - The chosen move: Rock, Paper, or Scissors
     pub fn submit_move(env: Env, session_id: u32, player: Address, game_move: PlayerMove) -> Result<(), Error> {
-        player.require_auth();
+        let player = session_keys::move_player(&env, session_id, player);
 
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -272,13 +368,7 @@ impl RpsContract {
             return Ok(None);
         }
 
-        // Rock beats Scissors, Scissors beats Paper, Paper beats Rock.
-        let player1_won = matches!(
-            (&move1, &move2),
-            (PlayerMove::Rock, PlayerMove::Scissors)
-                | (PlayerMove::Scissors, PlayerMove::Paper)
-                | (PlayerMove::Paper, PlayerMove::Rock)
-        );
+        let player1_won = beats(&move1, &move2);
 
         let winner = if player1_won {
             game.player1.clone()
@@ -319,6 +409,293 @@ impl RpsContract {
             .ok_or(Error::GameNotFound)
     }
 
+    // ========================================================================
+    // Team Mode
+    // ========================================================================
+
+    /// Start a team game. Both teams must have the same number of members
+    /// (1..=4) and every member signs the session and their own points.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `team1` - Members of the first team, in seat order
+    /// * `team1_points` - Points committed by each member of team 1, same order
+    /// * `team2` - Members of the second team, in seat order
+    /// * `team2_points` - Points committed by each member of team 2, same order
+    pub fn start_team_game(
+        env: Env,
+        session_id: u32,
+        team1: Vec<Address>,
+        team1_points: Vec<i128>,
+        team2: Vec<Address>,
+        team2_points: Vec<i128>,
+    ) -> Result<(), Error> {
+        if !(1..=MAX_TEAM_SIZE).contains(&team1.len())
+            || team2.len() != team1.len()
+            || team1_points.len() != team1.len()
+            || team2_points.len() != team2.len()
+        {
+            return Err(Error::InvalidTeams);
+        }
+        let mut seen = Vec::new(&env);
+        for member in team1.iter().chain(team2.iter()) {
+            if seen.contains(&member) {
+                return Err(Error::InvalidTeams);
+            }
+            seen.push_back(member);
+        }
+
+        for (member, points) in team1.iter().zip(team1_points.iter()).chain(team2.iter().zip(team2_points.iter())) {
+            member.require_auth_for_args(vec![&env, session_id.into_val(&env), points.into_val(&env)]);
+        }
+
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Call Game Hub to start the team session and lock every stake
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.start_team_game(
+            &env.current_contract_address(),
+            &session_id,
+            &team1,
+            &team1_points,
+            &team2,
+            &team2_points,
+        );
+
+        let game = TeamGame {
+            team1,
+            team1_points,
+            team2,
+            team2_points,
+            moves: Map::new(&env),
+            replays: 0,
+            deadline: env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS,
+            team1_won: None,
+            draw: false,
+        };
+        Self::save_team_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Submit a team member's move for the current round.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the member submitting a move
+    /// * `game_move` - The chosen move: Rock, Paper, or Scissors
+    pub fn submit_team_move(env: Env, session_id: u32, player: Address, game_move: PlayerMove) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = Self::load_team_game(&env, session_id)?;
+        if game.team1_won.is_some() || game.draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !game.team1.contains(&player) && !game.team2.contains(&player) {
+            return Err(Error::NotPlayer);
+        }
+        if game.moves.contains_key(player.clone()) {
+            return Err(Error::AlreadyMoved);
+        }
+
+        game.moves.set(player, game_move);
+        Self::save_team_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Play out every seat once all members have moved.
+    /// The team with more seat wins takes the game; if both teams won the
+    /// same number of seats, moves are reset and everyone submits again.
+    /// After `MAX_TEAM_REPLAYS` replays a level result draws the game.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Option<Vec<Address>>` - Members of the winning team, or None if
+    ///   level (moves reset, or the game drawn once out of replays)
+    pub fn reveal_team_winner(env: Env, session_id: u32) -> Result<Option<Vec<Address>>, Error> {
+        let mut game = Self::load_team_game(&env, session_id)?;
+        if let Some(team1_won) = game.team1_won {
+            return Ok(Some(if team1_won { game.team1 } else { game.team2 }));
+        }
+        if game.draw {
+            return Ok(None);
+        }
+
+        let mut team1_wins = 0u32;
+        let mut team2_wins = 0u32;
+        for (member1, member2) in game.team1.iter().zip(game.team2.iter()) {
+            let move1 = game.moves.get(member1).ok_or(Error::NotAllMoved)?;
+            let move2 = game.moves.get(member2).ok_or(Error::NotAllMoved)?;
+            if beats(&move1, &move2) {
+                team1_wins += 1;
+            } else if beats(&move2, &move1) {
+                team2_wins += 1;
+            }
+        }
+
+        // Level: reset moves so the teams can go again, do NOT call end_team_game
+        if team1_wins == team2_wins {
+            if game.replays < MAX_TEAM_REPLAYS {
+                game.replays += 1;
+                game.moves = Map::new(&env);
+                game.deadline = env.ledger().sequence() + MOVE_TIMEOUT_LEDGERS;
+                Self::save_team_game(&env, session_id, &game);
+            } else {
+                Self::finish_team_game(&env, session_id, &mut game, None);
+            }
+            return Ok(None);
+        }
+
+        Ok(Self::finish_team_game(&env, session_id, &mut game, Some(team1_wins > team2_wins)))
+    }
+
+    /// End a team round that stalled past its deadline. A team whose members
+    /// have all moved wins against one that has not; if neither team is
+    /// complete, the game is drawn.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Any member of either team
+    ///
+    /// # Returns
+    /// * `Option<Vec<Address>>` - Members of the winning team, or None if drawn
+    pub fn claim_team_timeout(env: Env, session_id: u32, player: Address) -> Result<Option<Vec<Address>>, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_team_game(&env, session_id)?;
+        if game.team1_won.is_some() || game.draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !game.team1.contains(&player) && !game.team2.contains(&player) {
+            return Err(Error::NotPlayer);
+        }
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        let team1_ready = game.team1.iter().all(|member| game.moves.contains_key(member));
+        let team2_ready = game.team2.iter().all(|member| game.moves.contains_key(member));
+        let team1_won = match (team1_ready, team2_ready) {
+            // Nothing stalled: reveal instead
+            (true, true) => return Err(Error::CannotClaimTimeout),
+            (true, false) => Some(true),
+            (false, true) => Some(false),
+            (false, false) => None,
+        };
+
+        Ok(Self::finish_team_game(&env, session_id, &mut game, team1_won))
+    }
+
+    /// Get team game information.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `TeamGame` - The game state
+    pub fn get_team_game(env: Env, session_id: u32) -> Result<TeamGame, Error> {
+        Self::load_team_game(&env, session_id)
+    }
+
+    /// Record the result (None for a draw) and end the session with the Game Hub
+    fn finish_team_game(
+        env: &Env,
+        session_id: u32,
+        game: &mut TeamGame,
+        team1_won: Option<bool>,
+    ) -> Option<Vec<Address>> {
+        game.team1_won = team1_won;
+        game.draw = team1_won.is_none();
+        Self::save_team_game(env, session_id, game);
+
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // The Game Hub splits the winnings among the winning team's members,
+        // or returns every stake on a draw
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        match team1_won {
+            Some(team1_won) => {
+                game_hub.end_team_game(&session_id, &team1_won);
+                Some(if team1_won { game.team1.clone() } else { game.team2.clone() })
+            }
+            None => {
+                game_hub.end_game_draw(&session_id);
+                None
+            }
+        }
+    }
+
+    fn load_team_game(env: &Env, session_id: u32) -> Result<TeamGame, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::TeamGame(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    fn save_team_game(env: &Env, session_id: u32, game: &TeamGame) {
+        let key = DataKey::TeamGame(session_id);
+        env.storage().temporary().set(&key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    // ========================================================================
+    // Session Keys
+    // ========================================================================
+
+    /// Let a short-lived key sign `submit_move` for a player, so the main wallet is
+    /// not prompted for every move. Usually done through `start_game`; this call is
+    /// for renewing or adding a key once the game is under way.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The player delegating
+    /// * `session_key` - The key that will sign moves; also authorizes this call
+    /// * `expiration_ledger` - Last ledger the key is accepted at, at most
+    ///   `MAX_SESSION_KEY_LEDGERS` from now
+    pub fn delegate_session_key(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        session_key: Address,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if game.winner.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let delegation = SessionKey { player, expiration_ledger };
+        session_keys::grant(
+            &env,
+            session_id,
+            &game.player1,
+            &game.player2,
+            session_key,
+            delegation,
+            GAME_TTL_LEDGERS,
+        )?;
+        Ok(())
+    }
+
     // ========================================================================
     // Admin Functions
     // ========================================================================
@@ -2,9 +2,9 @@
 
 // Unit tests for the rps (Rock Paper Scissors) contract using a simple mock GameHub.
 
-use crate::{Error, PlayerMove, RpsContract, RpsContractClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use crate::{Error, PlayerMove, RpsContract, RpsContractClient, SessionKey, MAX_SESSION_KEY_LEDGERS};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, Ledger as _};
+use soroban_sdk::{contract, contractimpl, map, vec, Address, BytesN, Env, IntoVal, Map, Vec};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -28,6 +28,21 @@ impl MockGameHub {
 
     pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {}
 
+    pub fn start_team_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _team1: Vec<Address>,
+        _team1_points: Vec<i128>,
+        _team2: Vec<Address>,
+        _team2_points: Vec<i128>,
+    ) {
+    }
+
+    pub fn end_team_game(_env: Env, _session_id: u32, _team1_won: bool) {}
+
+    pub fn end_game_draw(_env: Env, _session_id: u32) {}
+
     pub fn add_game(_env: Env, _game_address: Address) {}
 }
 
@@ -89,12 +104,12 @@ fn assert_rps_error<T, E>(
 
 #[test]
 fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &Map::new(&env));
 
     let game = client.get_game(&session_id);
     assert_eq!(game.player1_move, PlayerMove::None);
@@ -119,8 +134,8 @@ fn test_multiple_sessions() {
     let player3 = Address::generate(&env);
     let player4 = Address::generate(&env);
 
-    client.start_game(&3u32, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&4u32, &player3, &player4, &50_0000000, &50_0000000);
+    client.start_game(&3u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    client.start_game(&4u32, &player3, &player4, &50_0000000, &50_0000000, &Map::new(&env));
 
     assert_eq!(client.get_game(&3u32).player1, player1);
     assert_eq!(client.get_game(&4u32).player1, player3);
@@ -132,8 +147,8 @@ fn test_multiple_sessions() {
 
 #[test]
 fn test_rock_beats_scissors() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-    client.start_game(&1u32, &player1, &player2, &100_0000000, &100_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&1u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
     client.submit_move(&1u32, &player1, &PlayerMove::Rock);
     client.submit_move(&1u32, &player2, &PlayerMove::Scissors);
     assert_eq!(client.reveal_winner(&1u32), Some(player1.clone()));
@@ -141,8 +156,8 @@ fn test_rock_beats_scissors() {
 
 #[test]
 fn test_scissors_beats_paper() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-    client.start_game(&2u32, &player1, &player2, &100_0000000, &100_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&2u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
     client.submit_move(&2u32, &player1, &PlayerMove::Scissors);
     client.submit_move(&2u32, &player2, &PlayerMove::Paper);
     assert_eq!(client.reveal_winner(&2u32), Some(player1.clone()));
@@ -150,8 +165,8 @@ fn test_scissors_beats_paper() {
 
 #[test]
 fn test_paper_beats_rock() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-    client.start_game(&3u32, &player1, &player2, &100_0000000, &100_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&3u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
     client.submit_move(&3u32, &player1, &PlayerMove::Paper);
     client.submit_move(&3u32, &player2, &PlayerMove::Rock);
     assert_eq!(client.reveal_winner(&3u32), Some(player1.clone()));
@@ -159,8 +174,8 @@ fn test_paper_beats_rock() {
 
 #[test]
 fn test_player2_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-    client.start_game(&4u32, &player1, &player2, &100_0000000, &100_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&4u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
     client.submit_move(&4u32, &player1, &PlayerMove::Scissors);
     client.submit_move(&4u32, &player2, &PlayerMove::Rock); // Rock beats Scissors
     assert_eq!(client.reveal_winner(&4u32), Some(player2.clone()));
@@ -168,8 +183,8 @@ fn test_player2_wins() {
 
 #[test]
 fn test_tie_resets_moves() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-    client.start_game(&5u32, &player1, &player2, &100_0000000, &100_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&5u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
     client.submit_move(&5u32, &player1, &PlayerMove::Rock);
     client.submit_move(&5u32, &player2, &PlayerMove::Rock);
     // Tie: returns None and resets moves
@@ -186,10 +201,10 @@ fn test_tie_resets_moves() {
 
 #[test]
 fn test_all_tie_variants_reset_moves() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     for (id, m) in [(6u32, PlayerMove::Rock), (7u32, PlayerMove::Paper), (8u32, PlayerMove::Scissors)] {
-        client.start_game(&id, &player1, &player2, &100_0000000, &100_0000000);
+        client.start_game(&id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
         client.submit_move(&id, &player1, &m.clone());
         client.submit_move(&id, &player2, &m);
         assert_eq!(client.reveal_winner(&id), None);
@@ -205,8 +220,8 @@ fn test_all_tie_variants_reset_moves() {
 
 #[test]
 fn test_cannot_move_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-    client.start_game(&10u32, &player1, &player2, &100_0000000, &100_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&10u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
     client.submit_move(&10u32, &player1, &PlayerMove::Rock);
     let result = client.try_submit_move(&10u32, &player1, &PlayerMove::Paper);
     assert_rps_error(&result, Error::AlreadyMoved);
@@ -214,8 +229,8 @@ fn test_cannot_move_twice() {
 
 #[test]
 fn test_cannot_reveal_before_both_move() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-    client.start_game(&11u32, &player1, &player2, &100_0000000, &100_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&11u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
     client.submit_move(&11u32, &player1, &PlayerMove::Rock);
     let result = client.try_reveal_winner(&11u32);
     assert_rps_error(&result, Error::BothPlayersNotMoved);
@@ -225,15 +240,15 @@ fn test_cannot_reveal_before_both_move() {
 fn test_non_player_cannot_move() {
     let (env, client, _hub, player1, player2) = setup_test();
     let outsider = Address::generate(&env);
-    client.start_game(&12u32, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&12u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
     let result = client.try_submit_move(&12u32, &outsider, &PlayerMove::Rock);
     assert_rps_error(&result, Error::NotPlayer);
 }
 
 #[test]
 fn test_cannot_move_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-    client.start_game(&13u32, &player1, &player2, &100_0000000, &100_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&13u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
     client.submit_move(&13u32, &player1, &PlayerMove::Rock);
     client.submit_move(&13u32, &player2, &PlayerMove::Scissors);
     client.reveal_winner(&13u32);
@@ -250,8 +265,8 @@ fn test_cannot_reveal_nonexistent_game() {
 
 #[test]
 fn test_reveal_twice_is_idempotent() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-    client.start_game(&14u32, &player1, &player2, &100_0000000, &100_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&14u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
     client.submit_move(&14u32, &player1, &PlayerMove::Rock);
     client.submit_move(&14u32, &player2, &PlayerMove::Scissors);
     let winner1 = client.reveal_winner(&14u32);
@@ -270,8 +285,8 @@ fn test_multiple_games_independent() {
     let player3 = Address::generate(&env);
     let player4 = Address::generate(&env);
 
-    client.start_game(&20u32, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&21u32, &player3, &player4, &50_0000000, &50_0000000);
+    client.start_game(&20u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    client.start_game(&21u32, &player3, &player4, &50_0000000, &50_0000000, &Map::new(&env));
 
     client.submit_move(&20u32, &player1, &PlayerMove::Rock);
     client.submit_move(&21u32, &player3, &PlayerMove::Paper);
@@ -287,8 +302,8 @@ fn test_multiple_games_independent() {
 
 #[test]
 fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-    client.start_game(&15u32, &player1, &player2, &200_0000000, &50_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&15u32, &player1, &player2, &200_0000000, &50_0000000, &Map::new(&env));
     let game = client.get_game(&15u32);
     assert_eq!(game.player1_points, 200_0000000);
     assert_eq!(game.player2_points, 50_0000000);
@@ -298,6 +313,259 @@ fn test_asymmetric_points() {
     assert!(client.get_game(&15u32).winner.is_some());
 }
 
+// ============================================================================
+// Team Mode Tests
+// ============================================================================
+
+fn start_2v2(env: &Env, client: &RpsContractClient<'static>, session_id: u32) -> (Vec<Address>, Vec<Address>) {
+    let team1 = vec![env, Address::generate(env), Address::generate(env)];
+    let team2 = vec![env, Address::generate(env), Address::generate(env)];
+    let points = vec![env, 50_0000000, 50_0000000];
+    client.start_team_game(&session_id, &team1, &points, &team2, &points);
+    (team1, team2)
+}
+
+fn play_seats(
+    client: &RpsContractClient<'static>,
+    session_id: u32,
+    team1: &Vec<Address>,
+    team2: &Vec<Address>,
+    moves: [(PlayerMove, PlayerMove); 2],
+) {
+    for (seat, (move1, move2)) in moves.into_iter().enumerate() {
+        client.submit_team_move(&session_id, &team1.get(seat as u32).unwrap(), &move1);
+        client.submit_team_move(&session_id, &team2.get(seat as u32).unwrap(), &move2);
+    }
+}
+
+#[test]
+fn test_team_game_more_seat_wins() {
+    let (env, client, _hub, _p1, _p2) = setup_test();
+    let (team1, team2) = start_2v2(&env, &client, 30);
+
+    // Seat 1 is a tie, seat 2 goes to team 2
+    play_seats(
+        &client,
+        30,
+        &team1,
+        &team2,
+        [(PlayerMove::Rock, PlayerMove::Rock), (PlayerMove::Scissors, PlayerMove::Rock)],
+    );
+    assert_eq!(client.reveal_team_winner(&30), Some(team2.clone()));
+    assert_eq!(client.get_team_game(&30).team1_won, Some(false));
+
+    // Idempotent, and no more moves
+    assert_eq!(client.reveal_team_winner(&30), Some(team2));
+    let result = client.try_submit_team_move(&30, &team1.get(0).unwrap(), &PlayerMove::Paper);
+    assert_rps_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_team_game_level_result_resets_moves() {
+    let (env, client, _hub, _p1, _p2) = setup_test();
+    let (team1, team2) = start_2v2(&env, &client, 31);
+
+    // One seat each
+    play_seats(
+        &client,
+        31,
+        &team1,
+        &team2,
+        [(PlayerMove::Paper, PlayerMove::Rock), (PlayerMove::Paper, PlayerMove::Scissors)],
+    );
+    assert_eq!(client.reveal_team_winner(&31), None);
+    assert!(client.get_team_game(&31).moves.is_empty());
+
+    play_seats(
+        &client,
+        31,
+        &team1,
+        &team2,
+        [(PlayerMove::Paper, PlayerMove::Rock), (PlayerMove::Rock, PlayerMove::Rock)],
+    );
+    assert_eq!(client.reveal_team_winner(&31), Some(team1));
+}
+
+#[test]
+fn test_team_game_drawn_after_max_replays() {
+    let (env, client, _hub, _p1, _p2) = setup_test();
+    let (team1, team2) = start_2v2(&env, &client, 33);
+
+    // Every round is level; the sixth one draws the game instead of replaying
+    for replays in 1..=6u32 {
+        play_seats(
+            &client,
+            33,
+            &team1,
+            &team2,
+            [(PlayerMove::Rock, PlayerMove::Rock), (PlayerMove::Paper, PlayerMove::Paper)],
+        );
+        assert_eq!(client.reveal_team_winner(&33), None);
+        assert_eq!(client.get_team_game(&33).replays, replays.min(5));
+    }
+
+    let game = client.get_team_game(&33);
+    assert!(game.draw);
+    assert_eq!(game.team1_won, None);
+    let result = client.try_submit_team_move(&33, &team1.get(0).unwrap(), &PlayerMove::Rock);
+    assert_rps_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_team_game_claim_timeout() {
+    let (env, client, _hub, _p1, _p2) = setup_test();
+    let (team1, team2) = start_2v2(&env, &client, 34);
+    let deadline = client.get_team_game(&34).deadline;
+
+    // Team 1 moves, one member of team 2 never does
+    client.submit_team_move(&34, &team1.get(0).unwrap(), &PlayerMove::Rock);
+    client.submit_team_move(&34, &team1.get(1).unwrap(), &PlayerMove::Rock);
+    client.submit_team_move(&34, &team2.get(0).unwrap(), &PlayerMove::Paper);
+    let result = client.try_claim_team_timeout(&34, &team1.get(0).unwrap());
+    assert_rps_error(&result, Error::TimeoutNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    let result = client.try_claim_team_timeout(&34, &Address::generate(&env));
+    assert_rps_error(&result, Error::NotPlayer);
+    assert_eq!(client.claim_team_timeout(&34, &team1.get(0).unwrap()), Some(team1.clone()));
+    assert_eq!(client.reveal_team_winner(&34), Some(team1.clone()));
+
+    // Nobody on either side moves: the game is drawn
+    let (team1, _team2) = start_2v2(&env, &client, 35);
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1 + 17_281);
+    assert_eq!(client.claim_team_timeout(&35, &team1.get(0).unwrap()), None);
+    assert!(client.get_team_game(&35).draw);
+    let result = client.try_claim_team_timeout(&35, &team1.get(0).unwrap());
+    assert_rps_error(&result, Error::GameAlreadyEnded);
+
+    // A complete round is revealed, not claimed
+    let (team1, team2) = start_2v2(&env, &client, 36);
+    play_seats(
+        &client,
+        36,
+        &team1,
+        &team2,
+        [(PlayerMove::Rock, PlayerMove::Rock), (PlayerMove::Paper, PlayerMove::Rock)],
+    );
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 2 + 2 * 17_281);
+    let result = client.try_claim_team_timeout(&36, &team2.get(0).unwrap());
+    assert_rps_error(&result, Error::CannotClaimTimeout);
+}
+
+#[test]
+fn test_team_game_move_rules() {
+    let (env, client, _hub, _p1, _p2) = setup_test();
+    let (team1, _team2) = start_2v2(&env, &client, 32);
+
+    let member = team1.get(0).unwrap();
+    client.submit_team_move(&32, &member, &PlayerMove::Rock);
+    let result = client.try_submit_team_move(&32, &member, &PlayerMove::Paper);
+    assert_rps_error(&result, Error::AlreadyMoved);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_submit_team_move(&32, &outsider, &PlayerMove::Paper);
+    assert_rps_error(&result, Error::NotPlayer);
+
+    let result = client.try_reveal_team_winner(&32);
+    assert_rps_error(&result, Error::NotAllMoved);
+}
+
+#[test]
+fn test_invalid_teams_rejected() {
+    let (env, client, _hub, _p1, _p2) = setup_test();
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let two_points = vec![&env, 1i128, 1];
+
+    // Unequal team sizes
+    let result = client.try_start_team_game(&33, &vec![&env, a.clone(), b.clone()], &two_points, &vec![&env, c.clone()], &vec![&env, 1]);
+    assert_rps_error(&result, Error::InvalidTeams);
+
+    // Missing points
+    let result = client.try_start_team_game(&33, &vec![&env, a.clone()], &two_points, &vec![&env, c.clone()], &vec![&env, 1]);
+    assert_rps_error(&result, Error::InvalidTeams);
+
+    // Same player on both teams
+    let result =
+        client.try_start_team_game(&33, &vec![&env, a.clone(), b.clone()], &two_points, &vec![&env, c, a], &two_points);
+    assert_rps_error(&result, Error::InvalidTeams);
+
+    let empty: Vec<Address> = Vec::new(&env);
+    let result = client.try_start_team_game(&33, &empty, &Vec::new(&env), &empty, &Vec::new(&env));
+    assert_rps_error(&result, Error::InvalidTeams);
+}
+
+// ============================================================================
+// Session Key Tests
+// ============================================================================
+
+#[test]
+fn test_session_key_moves_for_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let key = Address::generate(&env);
+    let session_keys = map![&env, (key.clone(), SessionKey { player: player1.clone(), expiration_ledger: 200 })];
+    client.start_game(&40u32, &player1, &player2, &100_0000000, &100_0000000, &session_keys);
+
+    // Player 1 signs the key together with their stake
+    let (_, invocation) = env.auths().into_iter().find(|(signer, _)| *signer == player1).unwrap();
+    let AuthorizedFunction::Contract((_, _, args)) = invocation.function else {
+        panic!("expected a contract call");
+    };
+    let expected = vec![&env, 40u32.into_val(&env), 100_0000000i128.into_val(&env), session_keys.into_val(&env)];
+    assert_eq!(args, expected);
+
+    // The key signs the move, and it counts as player 1's
+    client.submit_move(&40u32, &key, &PlayerMove::Rock);
+    assert_eq!(env.auths()[0].0, key);
+    assert_eq!(client.get_game(&40u32).player1_move, PlayerMove::Rock);
+
+    // The main wallet still works alongside the key
+    client.submit_move(&40u32, &player2, &PlayerMove::Scissors);
+    assert_eq!(client.reveal_winner(&40u32), Some(player1));
+}
+
+#[test]
+fn test_session_key_rules() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&41u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    let key = Address::generate(&env);
+
+    // Expiry must be in the future and short-lived
+    let result = client.try_delegate_session_key(&41u32, &player1, &key, &100);
+    assert_rps_error(&result, Error::InvalidSessionKey);
+    let result = client.try_delegate_session_key(&41u32, &player1, &key, &(101 + MAX_SESSION_KEY_LEDGERS));
+    assert_rps_error(&result, Error::InvalidSessionKey);
+
+    // Players cannot be keys, and a key serves one player
+    let result = client.try_delegate_session_key(&41u32, &player1, &player2, &200);
+    assert_rps_error(&result, Error::InvalidSessionKey);
+    client.delegate_session_key(&41u32, &player1, &key, &200);
+    let result = client.try_delegate_session_key(&41u32, &player2, &key, &200);
+    assert_rps_error(&result, Error::InvalidSessionKey);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_delegate_session_key(&41u32, &outsider, &Address::generate(&env), &200);
+    assert_rps_error(&result, Error::NotPlayer);
+    let result = client.try_delegate_session_key(&99u32, &player1, &key, &200);
+    assert_rps_error(&result, Error::GameNotFound);
+
+    // Keys passed to start_game follow the same rules
+    let stranger_key = map![&env, (key.clone(), SessionKey { player: outsider, expiration_ledger: 200 })];
+    let result = client.try_start_game(&43u32, &player1, &player2, &100_0000000, &100_0000000, &stranger_key);
+    assert_rps_error(&result, Error::NotPlayer);
+
+    // Keys are scoped to their session...
+    client.start_game(&42u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    let result = client.try_submit_move(&42u32, &key, &PlayerMove::Rock);
+    assert_rps_error(&result, Error::NotPlayer);
+
+    // ...and stop working once they expire
+    env.ledger().with_mut(|li| li.sequence_number = 201);
+    let result = client.try_submit_move(&41u32, &key, &PlayerMove::Rock);
+    assert_rps_error(&result, Error::NotPlayer);
+}
+
 // ============================================================================
 // Admin Function Tests
 // ============================================================================
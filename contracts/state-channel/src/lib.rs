@@ -0,0 +1,402 @@
+#![no_std]
+
+//! # State Channel
+//!
+//! Off-chain play for multi-move games such as twenty-one or best-of-N Rock
+//! Paper Scissors. Only opening and settling a channel touch the chain.
+//!
+//! Each player registers an ed25519 key when the channel opens. After every
+//! move the players co-sign a [`ChannelState`]:
+//! - session id
+//! - nonce (one higher per state)
+//! - hash of the full game state
+//! - the outcome if the channel settled on that state
+//!
+//! Either player can submit the latest co-signed state. The first submission
+//! opens a challenge period, during which the other player can submit any
+//! state with a higher nonce. Once the period is over, anyone can settle the
+//! channel, which reports the outcome of the latest state to the Game Hub.
+//!
+//! The contract never sees the game itself, only the signed states, so the
+//! game rules are enforced by the players refusing to sign invalid states.
+//! While a game is undecided, its states should carry `Outcome::Draw`.
+//!
+//! **Game Hub Integration:**
+//! The channel contract is the game as far as the Game Hub is concerned, and
+//! must be registered there like any other game.
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, vec, xdr::ToXdr, Address, BytesN, Env,
+    IntoVal,
+};
+
+// Import GameHub contract interface
+// This allows us to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(env: Env, session_id: u32, player1_won: bool);
+
+    fn end_game_draw(env: Env, session_id: u32);
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    ChannelNotFound = 1,
+    ChannelExists = 2,
+    WrongSession = 3,
+    StaleState = 4,
+    ChallengeClosed = 5,
+    ChallengeOpen = 6,
+    AlreadySettled = 7,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Player1Won,
+    Player2Won,
+    Draw,
+}
+
+/// A player's side of a channel
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Seat {
+    pub player: Address,
+    /// ed25519 public key that signs the player's channel states
+    pub key: BytesN<32>,
+    pub points: i128,
+}
+
+/// A game state both players sign off-chain
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChannelState {
+    pub session_id: u32,
+    /// Higher nonces replace lower ones; the first state has nonce 1
+    pub nonce: u64,
+    /// Hash of the full game state (hands, moves, score...)
+    pub state_hash: BytesN<32>,
+    /// Result if the channel settles on this state
+    pub outcome: Outcome,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Channel {
+    pub seat1: Seat,
+    pub seat2: Seat,
+    /// Ledger the channel opened at
+    pub opened_ledger: u32,
+    /// Nonce of the latest submitted state, 0 until the first submission
+    pub nonce: u64,
+    /// Hash of the latest submitted state
+    pub state_hash: BytesN<32>,
+    /// Outcome of the latest submitted state, a draw until the first submission
+    pub outcome: Outcome,
+    /// Last ledger a higher state can be submitted at, None until the first submission
+    pub challenge_deadline: Option<u32>,
+    pub settled: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Channel(u32),
+    GameHubAddress,
+    Admin,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) ensures channel data doesn't expire unexpectedly
+// Channels are stored in temporary storage with a minimum 30-day retention
+
+/// TTL for channel storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+const CHANNEL_TTL_LEDGERS: u32 = 518_400;
+
+/// How long the other player has to answer a submitted state (~1 hour)
+pub const CHALLENGE_LEDGERS: u32 = 720;
+
+/// How long before a channel nobody submitted a state for settles as a draw
+/// (~7 days, matching the Game Hub's session expiry)
+pub const CHANNEL_TIMEOUT_LEDGERS: u32 = 120_960;
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct StateChannelContract;
+
+#[contractimpl]
+impl StateChannelContract {
+    /// Initialize the contract with GameHub address and admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    /// * `game_hub` - Address of the GameHub contract
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &game_hub);
+    }
+
+    /// Open a channel and lock both stakes in the Game Hub.
+    /// Each player authorizes their stake and the key that signs for them.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `seat1` - Player 1, their signing key and stake
+    /// * `seat2` - Player 2, their signing key and stake
+    pub fn open_channel(env: Env, session_id: u32, seat1: Seat, seat2: Seat) -> Result<(), Error> {
+        if seat1.player == seat2.player {
+            panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
+        }
+
+        let key = DataKey::Channel(session_id);
+        if env.storage().temporary().has(&key) {
+            return Err(Error::ChannelExists);
+        }
+
+        for seat in [&seat1, &seat2] {
+            seat.player.require_auth_for_args(vec![
+                &env,
+                session_id.into_val(&env),
+                seat.points.into_val(&env),
+                seat.key.into_val(&env),
+            ]);
+        }
+
+        let game_hub = GameHubClient::new(&env, &Self::get_hub(env.clone()));
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &seat1.player,
+            &seat2.player,
+            &seat1.points,
+            &seat2.points,
+        );
+
+        let channel = Channel {
+            seat1,
+            seat2,
+            opened_ledger: env.ledger().sequence(),
+            nonce: 0,
+            state_hash: BytesN::from_array(&env, &[0; 32]),
+            outcome: Outcome::Draw,
+            challenge_deadline: None,
+            settled: false,
+        };
+        Self::save_channel(&env, session_id, &channel);
+
+        Ok(())
+    }
+
+    /// Submit a co-signed state. The first submission starts the challenge
+    /// period; until it ends, only states with a higher nonce are accepted.
+    ///
+    /// Both signatures are over [`StateChannelContract::state_message`].
+    ///
+    /// # Arguments
+    /// * `session_id` - The channel's session ID
+    /// * `state` - The state both players signed
+    /// * `signature1` - Player 1's key's signature
+    /// * `signature2` - Player 2's key's signature
+    pub fn submit_state(
+        env: Env,
+        session_id: u32,
+        state: ChannelState,
+        signature1: BytesN<64>,
+        signature2: BytesN<64>,
+    ) -> Result<(), Error> {
+        let mut channel = Self::get_channel(env.clone(), session_id)?;
+
+        if channel.settled {
+            return Err(Error::AlreadySettled);
+        }
+        if state.session_id != session_id {
+            return Err(Error::WrongSession);
+        }
+        if state.nonce <= channel.nonce {
+            return Err(Error::StaleState);
+        }
+        if let Some(deadline) = channel.challenge_deadline {
+            if env.ledger().sequence() > deadline {
+                return Err(Error::ChallengeClosed);
+            }
+        }
+
+        let message = Self::state_message(env.clone(), state.clone());
+        env.crypto()
+            .ed25519_verify(&channel.seat1.key, &message, &signature1);
+        env.crypto()
+            .ed25519_verify(&channel.seat2.key, &message, &signature2);
+
+        if channel.challenge_deadline.is_none() {
+            channel.challenge_deadline = Some(env.ledger().sequence() + CHALLENGE_LEDGERS);
+        }
+        channel.nonce = state.nonce;
+        channel.state_hash = state.state_hash;
+        channel.outcome = state.outcome;
+        Self::save_channel(&env, session_id, &channel);
+
+        Ok(())
+    }
+
+    /// Settle a channel on its latest state once the challenge period is
+    /// over, reporting the outcome to the Game Hub. A channel nobody ever
+    /// submitted a state for settles as a draw after the channel timeout.
+    ///
+    /// # Arguments
+    /// * `session_id` - The channel's session ID
+    ///
+    /// # Returns
+    /// * `Outcome` - The settled outcome
+    pub fn settle(env: Env, session_id: u32) -> Result<Outcome, Error> {
+        let mut channel = Self::get_channel(env.clone(), session_id)?;
+
+        if channel.settled {
+            return Err(Error::AlreadySettled);
+        }
+
+        let now = env.ledger().sequence();
+        let settles_at = channel
+            .challenge_deadline
+            .unwrap_or(channel.opened_ledger + CHANNEL_TIMEOUT_LEDGERS);
+        if now <= settles_at {
+            return Err(Error::ChallengeOpen);
+        }
+        let outcome = channel.outcome;
+
+        channel.settled = true;
+        Self::save_channel(&env, session_id, &channel);
+
+        let game_hub = GameHubClient::new(&env, &Self::get_hub(env.clone()));
+        match outcome {
+            Outcome::Player1Won => game_hub.end_game(&session_id, &true),
+            Outcome::Player2Won => game_hub.end_game(&session_id, &false),
+            Outcome::Draw => game_hub.end_game_draw(&session_id),
+        }
+
+        Ok(outcome)
+    }
+
+    /// Get a channel
+    ///
+    /// # Arguments
+    /// * `session_id` - The channel's session ID
+    pub fn get_channel(env: Env, session_id: u32) -> Result<Channel, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Channel(session_id))
+            .ok_or(Error::ChannelNotFound)
+    }
+
+    /// The bytes players sign for a state: this contract's address and the
+    /// state, XDR-encoded. Binding the contract address keeps a signed state
+    /// from being replayed on another channel contract.
+    ///
+    /// # Arguments
+    /// * `state` - The state to sign
+    pub fn state_message(env: Env, state: ChannelState) -> soroban_sdk::Bytes {
+        (env.current_contract_address(), state).to_xdr(&env)
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    pub fn get_hub(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set")
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    pub fn set_hub(env: Env, new_hub: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        Self::get_admin(env.clone()).require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    fn save_channel(env: &Env, session_id: u32, channel: &Channel) {
+        let key = DataKey::Channel(session_id);
+        env.storage().temporary().set(&key, channel);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, CHANNEL_TTL_LEDGERS, CHANNEL_TTL_LEDGERS);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
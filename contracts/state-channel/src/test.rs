@@ -0,0 +1,219 @@
+#![cfg(test)]
+extern crate std;
+
+// Tests play games off-chain the way a frontend would: both players sign each
+// state with their channel key, and only the states worth submitting go
+// on-chain.
+
+use crate::{
+    ChannelState, Error, Outcome, Seat, StateChannelContract, StateChannelContractClient, CHALLENGE_LEDGERS,
+    CHANNEL_TIMEOUT_LEDGERS,
+};
+use ed25519_dalek::{Signer as _, SigningKey};
+use mock_game_hub::{MockGameHub, MockGameHubClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, BytesN, Env};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+struct Player {
+    address: Address,
+    key: SigningKey,
+}
+
+impl Player {
+    fn new(env: &Env, seed: u8) -> Self {
+        Player {
+            address: Address::generate(env),
+            key: SigningKey::from_bytes(&[seed; 32]),
+        }
+    }
+
+    fn seat(&self, env: &Env, points: i128) -> Seat {
+        Seat {
+            player: self.address.clone(),
+            key: BytesN::from_array(env, &self.key.verifying_key().to_bytes()),
+            points,
+        }
+    }
+
+    fn sign(&self, env: &Env, client: &StateChannelContractClient, state: &ChannelState) -> BytesN<64> {
+        let message: std::vec::Vec<u8> = client.state_message(state).iter().collect();
+        BytesN::from_array(env, &self.key.sign(&message).to_bytes())
+    }
+}
+
+struct Setup {
+    env: Env,
+    hub: MockGameHubClient<'static>,
+    client: StateChannelContractClient<'static>,
+    player1: Player,
+    player2: Player,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Set ledger info for time-based operations
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let admin = Address::generate(&env);
+    let hub = MockGameHubClient::new(&env, &env.register(MockGameHub, (&admin,)));
+    let contract_id = env.register(StateChannelContract, (&admin, &hub.address));
+    let client = StateChannelContractClient::new(&env, &contract_id);
+
+    let player1 = Player::new(&env, 1);
+    let player2 = Player::new(&env, 2);
+
+    Setup {
+        env,
+        hub,
+        client,
+        player1,
+        player2,
+    }
+}
+
+fn open(t: &Setup, session_id: u32) {
+    t.client
+        .open_channel(&session_id, &t.player1.seat(&t.env, 100), &t.player2.seat(&t.env, 100));
+}
+
+fn state(env: &Env, session_id: u32, nonce: u64, outcome: Outcome) -> ChannelState {
+    ChannelState {
+        session_id,
+        nonce,
+        state_hash: BytesN::from_array(env, &[nonce as u8; 32]),
+        outcome,
+    }
+}
+
+/// Submit a state both players signed
+fn submit(t: &Setup, state: &ChannelState) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+    let signature1 = t.player1.sign(&t.env, &t.client, state);
+    let signature2 = t.player2.sign(&t.env, &t.client, state);
+    t.client
+        .try_submit_state(&state.session_id, state, &signature1, &signature2)
+        .map(|_| ())
+}
+
+fn advance(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+fn assert_channel_error<T>(result: &Result<T, Result<Error, soroban_sdk::InvokeError>>, expected_error: Error) {
+    match result {
+        Err(Ok(actual_error)) => assert_eq!(*actual_error, expected_error),
+        _ => panic!("Expected error {:?}, got something else", expected_error),
+    }
+}
+
+// ============================================================================
+// Settlement
+// ============================================================================
+
+#[test]
+fn test_best_of_three_settles_on_latest_state() {
+    let t = setup_test();
+    open(&t, 1);
+
+    // Three rounds of RPS played off-chain; the final state says player 2 won
+    let rounds = [Outcome::Draw, Outcome::Draw, Outcome::Player2Won];
+    let states: std::vec::Vec<ChannelState> = rounds
+        .iter()
+        .enumerate()
+        .map(|(i, outcome)| state(&t.env, 1, i as u64 + 1, *outcome))
+        .collect();
+
+    submit(&t, &states[2]).unwrap();
+    assert_channel_error(&t.client.try_settle(&1), Error::ChallengeOpen);
+
+    advance(&t.env, CHALLENGE_LEDGERS + 1);
+    assert_eq!(t.client.settle(&1), Outcome::Player2Won);
+
+    let stats = t.hub.get_player_stats(&t.player2.address, &t.client.address);
+    assert_eq!(stats.wins, 1);
+    assert_eq!(stats.net_points, 100);
+    assert!(t.client.get_channel(&1).settled);
+    assert_channel_error(&t.client.try_settle(&1), Error::AlreadySettled);
+}
+
+#[test]
+fn test_challenge_with_higher_nonce() {
+    let t = setup_test();
+    open(&t, 2);
+
+    // Player 1 submits an old state where they were ahead...
+    submit(&t, &state(&t.env, 2, 4, Outcome::Player1Won)).unwrap();
+
+    // ...and player 2 answers with the later state within the period
+    advance(&t.env, CHALLENGE_LEDGERS / 2);
+    submit(&t, &state(&t.env, 2, 9, Outcome::Player2Won)).unwrap();
+    assert_channel_error(&submit(&t, &state(&t.env, 2, 9, Outcome::Player1Won)), Error::StaleState);
+    assert_channel_error(&submit(&t, &state(&t.env, 2, 5, Outcome::Player1Won)), Error::StaleState);
+
+    // The period runs from the first submission and is not extended
+    advance(&t.env, CHALLENGE_LEDGERS / 2 + 1);
+    assert_channel_error(&submit(&t, &state(&t.env, 2, 10, Outcome::Player1Won)), Error::ChallengeClosed);
+    assert_eq!(t.client.settle(&2), Outcome::Player2Won);
+}
+
+#[test]
+fn test_abandoned_channel_settles_as_draw() {
+    let t = setup_test();
+    open(&t, 3);
+
+    advance(&t.env, CHANNEL_TIMEOUT_LEDGERS);
+    assert_channel_error(&t.client.try_settle(&3), Error::ChallengeOpen);
+
+    advance(&t.env, 1);
+    assert_eq!(t.client.settle(&3), Outcome::Draw);
+    assert_eq!(t.hub.get_player_stats(&t.player1.address, &t.client.address).draws, 1);
+}
+
+// ============================================================================
+// Signatures
+// ============================================================================
+
+#[test]
+fn test_state_needs_both_signatures() {
+    let t = setup_test();
+    open(&t, 4);
+
+    // Player 1 signs a win for themselves and forges player 2's half with their own key
+    let claim = state(&t.env, 4, 1, Outcome::Player1Won);
+    let signature = t.player1.sign(&t.env, &t.client, &claim);
+    assert!(t.client.try_submit_state(&4, &claim, &signature, &signature).is_err());
+
+    // A state signed for another session doesn't carry over
+    let other = state(&t.env, 5, 1, Outcome::Player1Won);
+    let signature1 = t.player1.sign(&t.env, &t.client, &other);
+    let signature2 = t.player2.sign(&t.env, &t.client, &other);
+    assert_channel_error(&t.client.try_submit_state(&4, &other, &signature1, &signature2), Error::WrongSession);
+
+    assert_eq!(t.client.get_channel(&4).nonce, 0);
+}
+
+#[test]
+fn test_channel_lifecycle_errors() {
+    let t = setup_test();
+    assert_channel_error(&submit(&t, &state(&t.env, 6, 1, Outcome::Draw)), Error::ChannelNotFound);
+
+    open(&t, 6);
+    let result = t
+        .client
+        .try_open_channel(&6, &t.player1.seat(&t.env, 100), &t.player2.seat(&t.env, 100));
+    assert_channel_error(&result, Error::ChannelExists);
+}
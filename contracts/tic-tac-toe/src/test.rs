@@ -0,0 +1,208 @@
+#![cfg(test)]
+
+// Unit tests for the tic-tac-toe contract using a simple mock GameHub.
+// These tests verify game logic independently of the full GameHub system.
+
+use crate::{Error, Status, TicTacToeContract, TicTacToeContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+// ============================================================================
+// Mock GameHub for Unit Testing
+// ============================================================================
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game_draw(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (
+    Env,
+    TicTacToeContractClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Set ledger info for time-based operations
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    // Deploy mock GameHub contract
+    let hub_addr = env.register(MockGameHub, ());
+
+    // Deploy tic-tac-toe with admin and GameHub address
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TicTacToeContract, (&admin, &hub_addr));
+    let client = TicTacToeContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    (env, client, player1, player2)
+}
+
+/// Assert that a Result contains a specific tic-tac-toe error
+fn assert_tic_tac_toe_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        _ => panic!("Expected error {:?}, got something else", expected_error),
+    }
+}
+
+/// Start a game and play cells in turn, player 1 first
+fn play_cells(
+    client: &TicTacToeContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    cells: &[u32],
+) -> Status {
+    client.start_game(&session_id, player1, player2, &100_0000000, &100_0000000);
+    let mut status = Status::Playing;
+    for (i, cell) in cells.iter().enumerate() {
+        let player = if i % 2 == 0 { player1 } else { player2 };
+        status = client.play(&session_id, player, cell);
+    }
+    status
+}
+
+// ============================================================================
+// Game Flow Tests
+// ============================================================================
+
+#[test]
+fn test_row_wins() {
+    let (_env, client, player1, player2) = setup_test();
+
+    // X: 0 1 2 across the top, O: 3 4
+    let status = play_cells(&client, 1, &player1, &player2, &[0, 3, 1, 4, 2]);
+    assert_eq!(status, Status::Won);
+
+    let game = client.get_game(&1);
+    assert_eq!(game.winner, Some(player1));
+    assert_eq!(game.player1_cells, 0b000_000_111);
+    assert_eq!(game.player2_cells, 0b000_011_000);
+}
+
+#[test]
+fn test_column_and_diagonal_wins() {
+    let (_env, client, player1, player2) = setup_test();
+
+    // O takes the middle column
+    let status = play_cells(&client, 1, &player1, &player2, &[0, 1, 2, 4, 8, 7]);
+    assert_eq!(status, Status::Won);
+    assert_eq!(client.get_game(&1).winner, Some(player2.clone()));
+
+    // X takes the anti-diagonal
+    let status = play_cells(&client, 2, &player1, &player2, &[2, 0, 4, 1, 6]);
+    assert_eq!(status, Status::Won);
+    assert_eq!(client.get_game(&2).winner, Some(player1));
+}
+
+#[test]
+fn test_full_board_is_draw() {
+    let (_env, client, player1, player2) = setup_test();
+
+    // X O X / X O O / O X X
+    let status = play_cells(&client, 1, &player1, &player2, &[0, 1, 2, 4, 3, 5, 7, 6, 8]);
+    assert_eq!(status, Status::Draw);
+    assert_eq!(client.get_game(&1).winner, None);
+}
+
+#[test]
+fn test_turn_enforcement() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100_0000000, &100_0000000);
+
+    assert_tic_tac_toe_error(&client.try_play(&1, &player2, &4), Error::NotYourTurn);
+    assert_tic_tac_toe_error(&client.try_play(&1, &Address::generate(&env), &4), Error::NotPlayer);
+    assert_tic_tac_toe_error(&client.try_play(&1, &player1, &9), Error::InvalidCell);
+
+    client.play(&1, &player1, &4);
+    assert_tic_tac_toe_error(&client.try_play(&1, &player1, &0), Error::NotYourTurn);
+    assert_tic_tac_toe_error(&client.try_play(&1, &player2, &4), Error::CellTaken);
+    client.play(&1, &player2, &0);
+    assert_eq!(client.get_game(&1).next_player, player1);
+}
+
+#[test]
+fn test_no_moves_after_game_ended() {
+    let (_env, client, player1, player2) = setup_test();
+    play_cells(&client, 1, &player1, &player2, &[0, 3, 1, 4, 2]);
+
+    assert_tic_tac_toe_error(&client.try_play(&1, &player2, &5), Error::GameAlreadyEnded);
+    assert_tic_tac_toe_error(&client.try_claim_timeout(&1, &player2), Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_does_not_move() {
+    let (env, client, player1, player2) = setup_test();
+    play_cells(&client, 1, &player1, &player2, &[4]);
+
+    assert_tic_tac_toe_error(&client.try_claim_timeout(&1, &player1), Error::TimeoutNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    assert_tic_tac_toe_error(&client.try_claim_timeout(&1, &player2), Error::CannotClaimTimeout);
+    assert_eq!(client.claim_timeout(&1, &player1), player1);
+
+    let game = client.get_game(&1);
+    assert_eq!(game.status, Status::Won);
+    assert_eq!(game.winner, Some(player1));
+}
+
+#[test]
+fn test_cannot_play_against_self() {
+    let (_env, client, player1, _player2) = setup_test();
+    let result = client.try_start_game(&1, &player1, &player1, &100_0000000, &100_0000000);
+    assert_tic_tac_toe_error(&result, Error::SelfPlay);
+}
+
+#[test]
+fn test_cannot_get_nonexistent_game() {
+    let (_env, client, _player1, _player2) = setup_test();
+    assert_tic_tac_toe_error(&client.try_get_game(&999), Error::GameNotFound);
+}
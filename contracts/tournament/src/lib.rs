@@ -0,0 +1,1151 @@
+#![no_std]
+
+//! # Tournament
+//!
+//! Single- or double-elimination brackets played out in any Game Hub-aware
+//! game contract (rps, dice-duel, ...).
+//!
+//! Players register for a tournament, which locks their entry fees as one
+//! multi-player Game Hub session (the prize pool). Token entry fees are
+//! escrowed by the tournament itself when each player registers, and paid out
+//! with the prize pool. Starting the tournament
+//! seeds the bracket in registration order, giving byes to the top seeds.
+//! Every match gets its own session ID and a day to start; after that it can
+//! be forfeited, advancing a player who checked in against one who did not.
+//!
+//! **Game Hub Integration:**
+//! The tournament sits between a game and the Game Hub. Deploy an instance of
+//! the game with this contract as its `game_hub`. Players start each match
+//! through the game with the match's session ID and zero points. The game then
+//! reports `start_game`/`end_game` here, and the tournament forwards them to
+//! the real Game Hub before advancing the bracket. When the final is decided,
+//! the prize pool session is ended with every player's finishing place, and
+//! the champion takes the pool.
+
+use soroban_sdk::{
+    Address, BytesN, Env, IntoVal, Map, Vec, contract, contractclient, contracterror, contractimpl,
+    contracttype, token, vec
+};
+
+// Import GameHub contract interface
+// This allows us to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(
+        env: Env,
+        session_id: u32,
+        player1_won: bool
+    );
+
+    fn end_game_draw(env: Env, session_id: u32);
+
+    fn start_multi_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+    );
+
+    fn end_multi_game(env: Env, session_id: u32, places: Vec<u32>);
+
+    fn report_jackpot(env: Env, session_id: u32, player: Address) -> i128;
+
+    fn get_jackpot_rate(env: Env, game_id: Address) -> u32;
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    TournamentNotFound = 1,
+    TournamentExists = 2,
+    InvalidConfig = 3,
+    RegistrationClosed = 4,
+    AlreadyRegistered = 5,
+    TournamentFull = 6,
+    NotEnoughPlayers = 7,
+    UnknownSession = 8,
+    WrongGame = 9,
+    WrongPlayers = 10,
+    MatchAlreadyStarted = 11,
+    MatchNotStarted = 12,
+    StakeNotAllowed = 13,
+    MatchNotFound = 14,
+    MatchNotReady = 15,
+    MatchDecided = 16,
+    DeadlinePassed = 17,
+    DeadlineNotReached = 18,
+    NotPlayer = 19,
+    NotRegistered = 20,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Format {
+    SingleElimination,
+    /// Losers drop into a losers bracket; its winner meets the winners
+    /// bracket champion in a single grand final
+    DoubleElimination,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    Registration,
+    Running,
+    Finished,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Bracket {
+    Winners,
+    Losers,
+    GrandFinal,
+}
+
+/// Tournament rules, fixed at creation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TournamentSetup {
+    /// The game contract matches are played in (deployed with this contract as its hub)
+    pub game_id: Address,
+    pub format: Format,
+    /// Points each player stakes into the prize pool
+    pub entry_fee: i128,
+    /// Token entry fees are paid in, escrowed by the tournament from
+    /// registration until the final; None for plain Game Hub points
+    pub token: Option<Address>,
+    /// Registration limit (2..=8)
+    pub max_players: u32,
+    /// Game Hub session ID for the prize pool. Matches use the session IDs
+    /// after it; the whole range is reserved when the tournament is created.
+    pub pool_session_id: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tournament {
+    pub organizer: Address,
+    pub setup: TournamentSetup,
+    /// In registration order, which is also seed order
+    pub players: Vec<Address>,
+    pub status: Status,
+    /// Session ID the next playable match will get
+    pub next_session_id: u32,
+    /// Elimination stage of every knocked-out player (later stages place higher)
+    pub eliminated: Map<Address, u32>,
+    pub champion: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match {
+    pub bracket: Bracket,
+    pub round: u32,
+    /// None for a bye, or while the slot is still waiting on an earlier match
+    pub player1: Option<Address>,
+    pub player2: Option<Address>,
+    /// Number of slots (0..=2) whose player or bye is known
+    pub slots_filled: u32,
+    /// Game Hub session for the match, set once both players are known
+    pub session_id: Option<u32>,
+    pub started: bool,
+    /// Last ledger the match can start at before it can be forfeited
+    pub deadline: u32,
+    pub player1_checked_in: bool,
+    pub player2_checked_in: bool,
+    pub done: bool,
+    /// None only if both slots were byes, or both players forfeited
+    pub winner: Option<Address>,
+    /// Match the winner moves to; None for the final
+    pub winner_next: Option<u32>,
+    pub winner_slot: u32,
+    /// Match the loser drops to; None if losing eliminates
+    pub loser_next: Option<u32>,
+    pub loser_slot: u32,
+    /// Elimination stage recorded for a loser with nowhere to go
+    pub stage: u32,
+}
+
+/// Which match a Game Hub session belongs to
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchRef {
+    pub tournament_id: u32,
+    pub match_index: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Tournament(u32),
+    Matches(u32),
+    Session(u32),
+    /// Session ID reserved by a tournament: session_id -> tournament_id
+    Reserved(u32),
+    GameHubAddress,
+    Admin,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) ensures tournament data doesn't expire unexpectedly
+// Tournaments are stored in temporary storage with a minimum 30-day retention,
+// or in persistent storage if they escrow tokens
+
+/// TTL for tournament storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Most players in a tournament: the prize pool is one multi-player Game Hub
+/// session, which allows up to 8
+const MAX_PLAYERS: u32 = 8;
+
+/// Ledgers a match has to start once both players are known
+/// 1 day = 24 * 60 * 60 / 5 = 17,280 ledgers
+const MATCH_TIMEOUT_LEDGERS: u32 = 17_280;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Seeds in bracket order for `size` slots, so that seed 1 meets seed 2 only
+/// in the final: [1, 4, 2, 3] for 4, [1, 8, 4, 5, 2, 7, 3, 6] for 8
+fn seed_order(env: &Env, size: u32) -> Vec<u32> {
+    let mut order = vec![env, 1u32];
+    while order.len() < size {
+        let mirror = order.len() * 2 + 1;
+        let mut next = Vec::new(env);
+        for seed in order.iter() {
+            next.push_back(seed);
+            next.push_back(mirror - seed);
+        }
+        order = next;
+    }
+    order
+}
+
+/// Bracket slots for `player_count` players. Double elimination needs a
+/// losers bracket, so at least 4 slots.
+fn bracket_size(format: &Format, player_count: u32) -> u32 {
+    let min_size = if *format == Format::DoubleElimination { 4 } else { 2 };
+    player_count.next_power_of_two().max(min_size)
+}
+
+fn new_match(bracket: Bracket, round: u32, stage: u32) -> Match {
+    Match {
+        bracket,
+        round,
+        player1: None,
+        player2: None,
+        slots_filled: 0,
+        session_id: None,
+        started: false,
+        deadline: 0,
+        player1_checked_in: false,
+        player2_checked_in: false,
+        done: false,
+        winner: None,
+        winner_next: None,
+        winner_slot: 0,
+        loser_next: None,
+        loser_slot: 0,
+        stage,
+    }
+}
+
+/// Lay out every match for a bracket of `size` slots (a power of two).
+/// Winners bracket rounds come first, in order; round 1 holds the first
+/// `size / 2` matches.
+///
+/// In double elimination the losers bracket has 2 * (rounds - 1) rounds:
+/// odd rounds pair up survivors, even rounds bring in the losers of the next
+/// winners round. Losers bracket stages count up from 1, so later
+/// eliminations place higher; the grand final loser has the highest stage.
+fn build_bracket(env: &Env, format: &Format, size: u32) -> Vec<Match> {
+    let rounds = size.trailing_zeros();
+    let mut matches = Vec::new(env);
+
+    // Winners bracket, identical to single elimination
+    let mut winners_offsets = Vec::new(env);
+    for round in 1..=rounds {
+        winners_offsets.push_back(matches.len());
+        for index in 0..size >> round {
+            let mut m = new_match(Bracket::Winners, round, round);
+            if round < rounds {
+                m.winner_next = Some(matches.len() - index + (size >> round) + index / 2);
+                m.winner_slot = index % 2 + 1;
+            }
+            matches.push_back(m);
+        }
+    }
+
+    if *format == Format::SingleElimination {
+        return matches;
+    }
+
+    // Losers bracket
+    let losers_rounds = 2 * (rounds - 1);
+    let mut losers_offsets = Vec::new(env);
+    for round in 1..=losers_rounds {
+        losers_offsets.push_back(matches.len());
+        let count = size >> (round.div_ceil(2) + 1);
+        for _ in 0..count {
+            matches.push_back(new_match(Bracket::Losers, round, round));
+        }
+    }
+    let grand_final = matches.len();
+    matches.push_back(new_match(Bracket::GrandFinal, 1, losers_rounds + 1));
+
+    // Winners bracket losers drop down; the winners final feeds the grand final
+    for round in 1..=rounds {
+        let offset = winners_offsets.get_unchecked(round - 1);
+        for index in 0..size >> round {
+            let mut m = matches.get_unchecked(offset + index);
+            if round == 1 {
+                m.loser_next = Some(losers_offsets.get_unchecked(0) + index / 2);
+                m.loser_slot = index % 2 + 1;
+            } else {
+                m.loser_next = Some(losers_offsets.get_unchecked(2 * (round - 1) - 1) + index);
+                m.loser_slot = 2;
+            }
+            if round == rounds {
+                m.winner_next = Some(grand_final);
+                m.winner_slot = 1;
+            }
+            matches.set(offset + index, m);
+        }
+    }
+
+    // Losers bracket winners move on; its final feeds the grand final
+    for round in 1..=losers_rounds {
+        let offset = losers_offsets.get_unchecked(round - 1);
+        let count = size >> (round.div_ceil(2) + 1);
+        for index in 0..count {
+            let mut m = matches.get_unchecked(offset + index);
+            if round == losers_rounds {
+                m.winner_next = Some(grand_final);
+                m.winner_slot = 2;
+            } else if round % 2 == 1 {
+                m.winner_next = Some(losers_offsets.get_unchecked(round) + index);
+                m.winner_slot = 1;
+            } else {
+                m.winner_next = Some(losers_offsets.get_unchecked(round) + index / 2);
+                m.winner_slot = index % 2 + 1;
+            }
+            matches.set(offset + index, m);
+        }
+    }
+
+    matches
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct TournamentContract;
+
+#[contractimpl]
+impl TournamentContract {
+    /// Initialize the contract with GameHub address and admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    /// * `game_hub` - Address of the GameHub contract
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        // Store admin and GameHub address
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &game_hub);
+    }
+
+    // ========================================================================
+    // Tournament Setup
+    // ========================================================================
+
+    /// Create a tournament and open registration. Reserves the prize pool
+    /// session ID and one session ID after it for every match a full bracket
+    /// can have; fails if another tournament holds any of them.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - Unique tournament identifier
+    /// * `organizer` - Address that starts the tournament
+    /// * `setup` - Game, format, entry fee, player limit and prize pool session
+    pub fn create_tournament(
+        env: Env,
+        tournament_id: u32,
+        organizer: Address,
+        setup: TournamentSetup,
+    ) -> Result<(), Error> {
+        organizer.require_auth();
+
+        if Self::load_tournament(&env, tournament_id).is_ok() {
+            return Err(Error::TournamentExists);
+        }
+        if setup.entry_fee < 0 || !(2..=MAX_PLAYERS).contains(&setup.max_players) {
+            return Err(Error::InvalidConfig);
+        }
+
+        let size = bracket_size(&setup.format, setup.max_players);
+        let match_count = build_bracket(&env, &setup.format, size).len();
+        let last_session_id = setup
+            .pool_session_id
+            .checked_add(match_count)
+            .ok_or(Error::InvalidConfig)?;
+        for session_id in setup.pool_session_id..=last_session_id {
+            let key = DataKey::Reserved(session_id);
+            if env.storage().temporary().has(&key) {
+                return Err(Error::InvalidConfig);
+            }
+            env.storage().temporary().set(&key, &tournament_id);
+            env.storage()
+                .temporary()
+                .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+
+        let tournament = Tournament {
+            organizer,
+            next_session_id: setup.pool_session_id + 1,
+            setup,
+            players: Vec::new(&env),
+            status: Status::Registration,
+            eliminated: Map::new(&env),
+            champion: None,
+        };
+        Self::save_tournament(&env, tournament_id, &tournament);
+
+        Ok(())
+    }
+
+    /// Register for a tournament. The player agrees to stake the entry fee,
+    /// which is locked in the Game Hub when the tournament starts. In token
+    /// mode the fee moves into the tournament's escrow now, so the player
+    /// authorizes that transfer along with the registration.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament to join
+    /// * `player` - Address of the registering player
+    pub fn register(env: Env, tournament_id: u32, player: Address) -> Result<(), Error> {
+        let mut tournament = Self::load_tournament(&env, tournament_id)?;
+        player.require_auth_for_args(vec![
+            &env,
+            tournament_id.into_val(&env),
+            tournament.setup.entry_fee.into_val(&env),
+        ]);
+
+        if tournament.status != Status::Registration {
+            return Err(Error::RegistrationClosed);
+        }
+        if tournament.players.contains(&player) {
+            return Err(Error::AlreadyRegistered);
+        }
+        if tournament.players.len() >= tournament.setup.max_players {
+            return Err(Error::TournamentFull);
+        }
+
+        if let Some(token) = &tournament.setup.token {
+            token::Client::new(&env, token).transfer(
+                &player,
+                env.current_contract_address(),
+                &tournament.setup.entry_fee,
+            );
+        }
+        tournament.players.push_back(player);
+        Self::save_tournament(&env, tournament_id, &tournament);
+
+        Ok(())
+    }
+
+    /// Leave a tournament before it starts. In token mode the entry fee is
+    /// returned.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament to leave
+    /// * `player` - Address of the registered player
+    pub fn unregister(env: Env, tournament_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut tournament = Self::load_tournament(&env, tournament_id)?;
+        if tournament.status != Status::Registration {
+            return Err(Error::RegistrationClosed);
+        }
+        let index = tournament
+            .players
+            .first_index_of(&player)
+            .ok_or(Error::NotRegistered)?;
+
+        tournament.players.remove(index);
+        Self::save_tournament(&env, tournament_id, &tournament);
+
+        if let Some(token) = &tournament.setup.token {
+            token::Client::new(&env, token).transfer(
+                &env.current_contract_address(),
+                &player,
+                &tournament.setup.entry_fee,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Close registration, lock the entry fees and seed the bracket.
+    /// Seeds follow registration order; missing players become byes for the
+    /// top seeds. Matches whose players are known get their session IDs.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament to start
+    pub fn start_tournament(env: Env, tournament_id: u32) -> Result<(), Error> {
+        let mut tournament = Self::load_tournament(&env, tournament_id)?;
+        tournament.organizer.require_auth();
+
+        if tournament.status != Status::Registration {
+            return Err(Error::RegistrationClosed);
+        }
+        let player_count = tournament.players.len();
+        if player_count < 2 {
+            return Err(Error::NotEnoughPlayers);
+        }
+
+        // Lock every entry fee in one Game Hub session: the prize pool. Token
+        // fees are already in the tournament's escrow, so the session is in
+        // points and only records the result.
+        let mut fees = Vec::new(&env);
+        for _ in tournament.players.iter() {
+            fees.push_back(tournament.setup.entry_fee);
+        }
+        Self::game_hub(&env).start_multi_game(
+            &env.current_contract_address(),
+            &tournament.setup.pool_session_id,
+            &tournament.players,
+            &fees,
+        );
+
+        let size = bracket_size(&tournament.setup.format, player_count);
+        let mut matches = build_bracket(&env, &tournament.setup.format, size);
+
+        tournament.status = Status::Running;
+        let seeds = seed_order(&env, size);
+        for (slot, seed) in seeds.iter().enumerate() {
+            let player = tournament.players.get(seed - 1);
+            let slot = slot as u32;
+            Self::fill_slot(&env, tournament_id, &mut tournament, &mut matches, slot / 2, slot % 2 + 1, player);
+        }
+
+        Self::save_matches(&env, tournament_id, &matches);
+        Self::save_tournament(&env, tournament_id, &tournament);
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Forfeits
+    // ========================================================================
+
+    /// Declare that a player is ready to play a match. Only matters if the
+    /// match is not started by its deadline.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `match_index` - Index into the match list
+    /// * `player` - One of the match's players
+    pub fn check_in(env: Env, tournament_id: u32, match_index: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        Self::load_tournament(&env, tournament_id)?;
+        let mut matches = Self::load_matches(&env, tournament_id);
+        let mut m = Self::open_match_at(&matches, match_index)?;
+
+        if env.ledger().sequence() > m.deadline {
+            return Err(Error::DeadlinePassed);
+        }
+        if m.player1 == Some(player.clone()) {
+            m.player1_checked_in = true;
+        } else if m.player2 == Some(player) {
+            m.player2_checked_in = true;
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        matches.set(match_index, m);
+        Self::save_matches(&env, tournament_id, &matches);
+
+        Ok(())
+    }
+
+    /// Settle a match nobody started before its deadline. Anyone can call it.
+    /// A player who checked in advances against one who did not; if neither
+    /// did, both are eliminated. If both checked in, the match is replayed
+    /// under a new session ID with a new deadline. A started match is settled
+    /// by the game, through its own timeout claim.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `match_index` - Index into the match list
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The player who advances, None if nobody did
+    pub fn claim_forfeit(env: Env, tournament_id: u32, match_index: u32) -> Result<Option<Address>, Error> {
+        let mut tournament = Self::load_tournament(&env, tournament_id)?;
+        let mut matches = Self::load_matches(&env, tournament_id);
+        let m = Self::open_match_at(&matches, match_index)?;
+
+        if m.started {
+            return Err(Error::MatchAlreadyStarted);
+        }
+        if env.ledger().sequence() <= m.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+        // Never started, so the Game Hub has no session to close
+        if let Some(session_id) = m.session_id {
+            env.storage().temporary().remove(&DataKey::Session(session_id));
+        }
+
+        let (winner, loser) = match (m.player1_checked_in, m.player2_checked_in) {
+            (true, true) => {
+                Self::open_match(&env, tournament_id, &mut tournament, &mut matches, match_index);
+                Self::save_matches(&env, tournament_id, &matches);
+                Self::save_tournament(&env, tournament_id, &tournament);
+                return Ok(None);
+            }
+            (true, false) => (m.player1, m.player2),
+            (false, true) => (m.player2, m.player1),
+            (false, false) => {
+                for player in [m.player1, m.player2].into_iter().flatten() {
+                    tournament.eliminated.set(player, m.stage);
+                }
+                (None, None)
+            }
+        };
+        Self::finish_match(
+            &env,
+            tournament_id,
+            &mut tournament,
+            &mut matches,
+            match_index,
+            winner.clone(),
+            loser,
+        );
+
+        Self::save_matches(&env, tournament_id, &matches);
+        Self::save_tournament(&env, tournament_id, &tournament);
+
+        Ok(winner)
+    }
+
+    // ========================================================================
+    // Game Hub Interface (called by the game contract)
+    // ========================================================================
+
+    /// Called by the game when a match starts. Checks the session belongs to
+    /// a ready match with the right players in the right order and no extra
+    /// stake, then opens it in the Game Hub.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - The match's session ID
+    /// * `player1` - The match's first player
+    /// * `player2` - The match's second player
+    /// * `player1_points` - Must be 0; the entry fee is the stake
+    /// * `player2_points` - Must be 0
+    pub fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        game_id.require_auth();
+
+        let (match_ref, tournament, mut matches) = Self::load_session(&env, session_id)?;
+        if game_id != tournament.setup.game_id {
+            return Err(Error::WrongGame);
+        }
+
+        let mut m = matches.get_unchecked(match_ref.match_index);
+        if m.started {
+            return Err(Error::MatchAlreadyStarted);
+        }
+        if m.player1 != Some(player1.clone()) || m.player2 != Some(player2.clone()) {
+            return Err(Error::WrongPlayers);
+        }
+        if player1_points != 0 || player2_points != 0 {
+            return Err(Error::StakeNotAllowed);
+        }
+
+        m.started = true;
+        matches.set(match_ref.match_index, m);
+        Self::save_matches(&env, match_ref.tournament_id, &matches);
+
+        // The tournament opens the session under its own address
+        Self::game_hub(&env).start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &0,
+            &0,
+        );
+
+        Ok(())
+    }
+
+    /// Called by the game when a match ends. Records the result in the Game
+    /// Hub and advances the bracket. The last match ends the prize pool.
+    ///
+    /// # Arguments
+    /// * `session_id` - The match's session ID
+    /// * `player1_won` - True if the match's first player won
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
+        let (match_ref, mut tournament, mut matches) = Self::load_session(&env, session_id)?;
+        tournament.setup.game_id.require_auth();
+
+        let m = matches.get_unchecked(match_ref.match_index);
+        if !m.started {
+            return Err(Error::MatchNotStarted);
+        }
+        env.storage().temporary().remove(&DataKey::Session(session_id));
+
+        Self::game_hub(&env).end_game(&session_id, &player1_won);
+
+        let (winner, loser) = if player1_won {
+            (m.player1, m.player2)
+        } else {
+            (m.player2, m.player1)
+        };
+        Self::finish_match(
+            &env,
+            match_ref.tournament_id,
+            &mut tournament,
+            &mut matches,
+            match_ref.match_index,
+            winner,
+            loser,
+        );
+
+        Self::save_matches(&env, match_ref.tournament_id, &matches);
+        Self::save_tournament(&env, match_ref.tournament_id, &tournament);
+
+        Ok(())
+    }
+
+    /// Called by games that can end level. A knockout match needs a winner,
+    /// so the match is replayed under a new session ID.
+    ///
+    /// # Arguments
+    /// * `session_id` - The match's session ID
+    pub fn end_game_draw(env: Env, session_id: u32) -> Result<(), Error> {
+        let (match_ref, mut tournament, mut matches) = Self::load_session(&env, session_id)?;
+        tournament.setup.game_id.require_auth();
+
+        let mut m = matches.get_unchecked(match_ref.match_index);
+        if !m.started {
+            return Err(Error::MatchNotStarted);
+        }
+        env.storage().temporary().remove(&DataKey::Session(session_id));
+
+        Self::game_hub(&env).end_game_draw(&session_id);
+
+        m.started = false;
+        matches.set(match_ref.match_index, m);
+        Self::open_match(&env, match_ref.tournament_id, &mut tournament, &mut matches, match_ref.match_index);
+
+        Self::save_matches(&env, match_ref.tournament_id, &matches);
+        Self::save_tournament(&env, match_ref.tournament_id, &tournament);
+
+        Ok(())
+    }
+
+    /// Called by the game before `end_game` when a match earns its game's
+    /// jackpot. Forwarded to the Game Hub, which pays the player.
+    ///
+    /// # Arguments
+    /// * `session_id` - The match's session ID
+    /// * `player` - The jackpot winner
+    pub fn report_jackpot(env: Env, session_id: u32, player: Address) -> Result<i128, Error> {
+        let (match_ref, tournament, matches) = Self::load_session(&env, session_id)?;
+        tournament.setup.game_id.require_auth();
+
+        if !matches.get_unchecked(match_ref.match_index).started {
+            return Err(Error::MatchNotStarted);
+        }
+
+        Ok(Self::game_hub(&env).report_jackpot(&session_id, &player))
+    }
+
+    /// The Game Hub's jackpot rate for this tournament's sessions, which the hub
+    /// opens under the tournament's address. Games check it before reporting a
+    /// jackpot.
+    ///
+    /// # Arguments
+    /// * `_game_id` - The calling game; the tournament has only one
+    pub fn get_jackpot_rate(env: Env, _game_id: Address) -> u32 {
+        Self::game_hub(&env).get_jackpot_rate(&env.current_contract_address())
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// Get tournament information.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament's ID
+    pub fn get_tournament(env: Env, tournament_id: u32) -> Result<Tournament, Error> {
+        Self::load_tournament(&env, tournament_id)
+    }
+
+    /// Get every match of a started tournament, for rendering the bracket.
+    /// Winners bracket rounds come first, then losers bracket rounds and the
+    /// grand final.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament's ID
+    pub fn get_matches(env: Env, tournament_id: u32) -> Result<Vec<Match>, Error> {
+        Self::load_tournament(&env, tournament_id)?;
+        Ok(Self::load_matches(&env, tournament_id))
+    }
+
+    /// Find the match a session ID belongs to.
+    ///
+    /// # Arguments
+    /// * `session_id` - A match session ID
+    pub fn get_session_match(env: Env, session_id: u32) -> Result<MatchRef, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Session(session_id))
+            .ok_or(Error::UnknownSession)
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    /// Put a player (or a bye, None) into a match slot. Once both slots are
+    /// known the match becomes playable, or is decided at once by a bye.
+    fn fill_slot(
+        env: &Env,
+        tournament_id: u32,
+        tournament: &mut Tournament,
+        matches: &mut Vec<Match>,
+        index: u32,
+        slot: u32,
+        player: Option<Address>,
+    ) {
+        let mut m = matches.get_unchecked(index);
+        if slot == 1 {
+            m.player1 = player;
+        } else {
+            m.player2 = player;
+        }
+        m.slots_filled += 1;
+        let ready = m.slots_filled == 2;
+        let players = (m.player1.clone(), m.player2.clone());
+        matches.set(index, m);
+
+        if !ready {
+            return;
+        }
+        match players {
+            (Some(_), Some(_)) => Self::open_match(env, tournament_id, tournament, matches, index),
+            (Some(player), None) | (None, Some(player)) => {
+                Self::finish_match(env, tournament_id, tournament, matches, index, Some(player), None)
+            }
+            (None, None) => Self::finish_match(env, tournament_id, tournament, matches, index, None, None),
+        }
+    }
+
+    /// Give a match with both players a fresh session ID and start deadline,
+    /// clearing any check-ins. Replayed draws can
+    /// run past the range reserved at creation; IDs there are reserved as
+    /// they are used, skipping any another tournament holds.
+    fn open_match(env: &Env, tournament_id: u32, tournament: &mut Tournament, matches: &mut Vec<Match>, index: u32) {
+        let mut session_id = tournament.next_session_id;
+        loop {
+            let key = DataKey::Reserved(session_id);
+            match env.storage().temporary().get::<_, u32>(&key) {
+                Some(holder) if holder != tournament_id => session_id += 1,
+                holder => {
+                    if holder.is_none() {
+                        env.storage().temporary().set(&key, &tournament_id);
+                        env.storage()
+                            .temporary()
+                            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+                    }
+                    break;
+                }
+            }
+        }
+        tournament.next_session_id = session_id + 1;
+
+        let mut m = matches.get_unchecked(index);
+        m.session_id = Some(session_id);
+        m.deadline = env.ledger().sequence() + MATCH_TIMEOUT_LEDGERS;
+        m.player1_checked_in = false;
+        m.player2_checked_in = false;
+        matches.set(index, m);
+
+        let key = DataKey::Session(session_id);
+        let match_ref = MatchRef {
+            tournament_id,
+            match_index: index,
+        };
+        env.storage().temporary().set(&key, &match_ref);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// Record a match result and move both players on. A loser with nowhere
+    /// to go is eliminated; the winner of the last match is the champion.
+    fn finish_match(
+        env: &Env,
+        tournament_id: u32,
+        tournament: &mut Tournament,
+        matches: &mut Vec<Match>,
+        index: u32,
+        winner: Option<Address>,
+        loser: Option<Address>,
+    ) {
+        let mut m = matches.get_unchecked(index);
+        m.done = true;
+        m.winner = winner.clone();
+        matches.set(index, m.clone());
+
+        match m.loser_next {
+            Some(next) => Self::fill_slot(env, tournament_id, tournament, matches, next, m.loser_slot, loser),
+            None => {
+                if let Some(loser) = loser {
+                    tournament.eliminated.set(loser, m.stage);
+                }
+            }
+        }
+        match m.winner_next {
+            Some(next) => Self::fill_slot(env, tournament_id, tournament, matches, next, m.winner_slot, winner),
+            None => {
+                tournament.champion = winner;
+                Self::finish_tournament(env, tournament);
+            }
+        }
+    }
+
+    /// End the prize pool session with every player's place. Players knocked
+    /// out at the same stage share a place; only the champion is first, so
+    /// the champion takes the pool. In token mode the escrowed fees are paid
+    /// to the first place, split evenly if nobody won the final.
+    fn finish_tournament(env: &Env, tournament: &mut Tournament) {
+        tournament.status = Status::Finished;
+
+        let mut stages = Vec::new(env);
+        for player in tournament.players.iter() {
+            let stage = if tournament.champion == Some(player.clone()) {
+                u32::MAX
+            } else {
+                tournament.eliminated.get(player).unwrap_or(0)
+            };
+            stages.push_back(stage);
+        }
+        let mut places = Vec::new(env);
+        for stage in stages.iter() {
+            places.push_back(stages.iter().filter(|other| *other > stage).count() as u32 + 1);
+        }
+
+        Self::game_hub(env).end_multi_game(&tournament.setup.pool_session_id, &places);
+
+        if let Some(token) = &tournament.setup.token {
+            let client = token::Client::new(env, token);
+            let pool = tournament.setup.entry_fee * tournament.players.len() as i128;
+            let first = places.iter().filter(|place| *place == 1).count() as i128;
+            let mut remainder = pool % first;
+            for (player, place) in tournament.players.iter().zip(places.iter()) {
+                if place == 1 {
+                    let share = pool / first + remainder;
+                    remainder = 0;
+                    if share > 0 {
+                        client.transfer(&env.current_contract_address(), &player, &share);
+                    }
+                }
+            }
+        }
+    }
+
+    fn game_hub(env: &Env) -> GameHubClient<'_> {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        GameHubClient::new(env, &game_hub_addr)
+    }
+
+    fn load_session(env: &Env, session_id: u32) -> Result<(MatchRef, Tournament, Vec<Match>), Error> {
+        let match_ref: MatchRef = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Session(session_id))
+            .ok_or(Error::UnknownSession)?;
+        let tournament = Self::load_tournament(env, match_ref.tournament_id)?;
+        let matches = Self::load_matches(env, match_ref.tournament_id);
+        Ok((match_ref, tournament, matches))
+    }
+
+    /// Every match of a tournament; empty until it starts
+    fn load_matches(env: &Env, tournament_id: u32) -> Vec<Match> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Matches(tournament_id))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// The match at `index`, if it has both players and is still undecided
+    fn open_match_at(matches: &Vec<Match>, index: u32) -> Result<Match, Error> {
+        let m = matches.get(index).ok_or(Error::MatchNotFound)?;
+        if m.done {
+            return Err(Error::MatchDecided);
+        }
+        if m.session_id.is_none() {
+            return Err(Error::MatchNotReady);
+        }
+        Ok(m)
+    }
+
+    /// Get a tournament from temporary (points) or persistent (token) storage
+    fn load_tournament(env: &Env, tournament_id: u32) -> Result<Tournament, Error> {
+        let key = DataKey::Tournament(tournament_id);
+        env.storage()
+            .temporary()
+            .get(&key)
+            .or_else(|| env.storage().persistent().get(&key))
+            .ok_or(Error::TournamentNotFound)
+    }
+
+    /// Save a tournament: persistent storage for tokens, so the record of
+    /// escrowed fees cannot expire
+    fn save_tournament(env: &Env, tournament_id: u32, tournament: &Tournament) {
+        let key = DataKey::Tournament(tournament_id);
+        if tournament.setup.token.is_some() {
+            env.storage().persistent().set(&key, tournament);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        } else {
+            env.storage().temporary().set(&key, tournament);
+            env.storage()
+                .temporary()
+                .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+    }
+
+    fn save_matches(env: &Env, tournament_id: u32, matches: &Vec<Match>) {
+        let key = DataKey::Matches(tournament_id);
+        env.storage().temporary().set(&key, matches);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    pub fn get_hub(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set")
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
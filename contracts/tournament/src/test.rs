@@ -0,0 +1,624 @@
+#![cfg(test)]
+
+// Unit tests for the tournament contract using a simple mock GameHub.
+// The tests call the tournament's Game Hub interface directly, standing in
+// for the game contract the matches are played in.
+
+use crate::{
+    Bracket, Error, Format, Status, TournamentContract, TournamentContractClient, TournamentSetup,
+};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, symbol_short, token, vec, Address, Env, Vec};
+
+// ============================================================================
+// Mock GameHub for Unit Testing
+// ============================================================================
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game_draw(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn report_jackpot(_env: Env, _session_id: u32, _player: Address) -> i128 {
+        // Mock implementation - the jackpot is always 25
+        25
+    }
+
+    pub fn get_jackpot_rate(_env: Env, _game_id: Address) -> u32 {
+        // Mock implementation - the rate is always 1%
+        100
+    }
+
+    pub fn start_multi_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _players: Vec<Address>,
+        _points: Vec<i128>,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    /// Keeps the places so tests can check the prize pool result
+    pub fn end_multi_game(env: Env, _session_id: u32, places: Vec<u32>) {
+        env.storage().instance().set(&symbol_short!("places"), &places);
+    }
+
+    pub fn get_places(env: Env) -> Option<Vec<u32>> {
+        env.storage().instance().get(&symbol_short!("places"))
+    }
+}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+const TOURNAMENT_ID: u32 = 1;
+const POOL_SESSION_ID: u32 = 10;
+const ENTRY_FEE: i128 = 100_0000000;
+
+fn setup_test() -> (
+    Env,
+    TournamentContractClient<'static>,
+    MockGameHubClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Set ledger info for time-based operations
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    // Deploy mock GameHub contract
+    let hub_addr = env.register(MockGameHub, ());
+    let game_hub = MockGameHubClient::new(&env, &hub_addr);
+
+    // Create admin address
+    let admin = Address::generate(&env);
+
+    // Deploy tournament with admin and GameHub address
+    let contract_id = env.register(TournamentContract, (&admin, &hub_addr));
+    let client = TournamentContractClient::new(&env, &contract_id);
+
+    // Stand-in for the game contract the matches are played in
+    let game = Address::generate(&env);
+    let organizer = Address::generate(&env);
+
+    (env, client, game_hub, game, organizer)
+}
+
+fn tournament_setup(game: &Address, format: Format, max_players: u32) -> TournamentSetup {
+    TournamentSetup {
+        game_id: game.clone(),
+        format,
+        entry_fee: ENTRY_FEE,
+        max_players,
+        pool_session_id: POOL_SESSION_ID,
+        token: None,
+    }
+}
+
+/// A token with `balance` minted to each player
+fn setup_token(env: &Env, players: &Vec<Address>, balance: i128) -> token::Client<'static> {
+    // Players authorize the escrow transfer inside `register`, not at the root
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let issuer = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    let minter = token::StellarAssetClient::new(env, &sac.address());
+    for player in players.iter() {
+        minter.mint(&player, &balance);
+    }
+    token::Client::new(env, &sac.address())
+}
+
+/// Create a tournament and register `count` players
+fn setup_tournament(
+    env: &Env,
+    client: &TournamentContractClient,
+    game: &Address,
+    organizer: &Address,
+    format: Format,
+    count: u32,
+) -> Vec<Address> {
+    client.create_tournament(&TOURNAMENT_ID, organizer, &tournament_setup(game, format, 8));
+
+    let mut players = Vec::new(env);
+    for _ in 0..count {
+        let player = Address::generate(env);
+        client.register(&TOURNAMENT_ID, &player);
+        players.push_back(player);
+    }
+    players
+}
+
+/// Play the match at `index` through the tournament's Game Hub interface
+fn play_match(client: &TournamentContractClient, game: &Address, index: u32, player1_won: bool) {
+    let m = client.get_matches(&TOURNAMENT_ID).get(index).unwrap();
+    let session_id = m.session_id.expect("match not ready");
+    client.start_game(
+        game,
+        &session_id,
+        &m.player1.unwrap(),
+        &m.player2.unwrap(),
+        &0,
+        &0,
+    );
+    client.end_game(&session_id, &player1_won);
+}
+
+/// Assert that a Result contains a specific tournament error
+fn assert_tournament_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        Err(Err(_invoke_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got invocation error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Err(_conv_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got conversion error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Ok(_)) => {
+            panic!(
+                "Expected error {:?} (code {}), but operation succeeded",
+                expected_error, expected_error as u32
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Single Elimination Tests
+// ============================================================================
+
+#[test]
+fn test_single_elimination() {
+    let (env, client, hub, game, organizer) = setup_test();
+    let players = setup_tournament(&env, &client, &game, &organizer, Format::SingleElimination, 4);
+
+    client.start_tournament(&TOURNAMENT_ID);
+    let tournament = client.get_tournament(&TOURNAMENT_ID);
+    assert_eq!(tournament.status, Status::Running);
+
+    // Seeds 1 v 4 and 2 v 3, with sessions after the prize pool's
+    let matches = client.get_matches(&TOURNAMENT_ID);
+    assert_eq!(matches.len(), 3);
+    let first = matches.get(0).unwrap();
+    assert_eq!(first.player1, players.get(0));
+    assert_eq!(first.player2, players.get(3));
+    assert_eq!(first.session_id, Some(POOL_SESSION_ID + 1));
+    let second = matches.get(1).unwrap();
+    assert_eq!(second.player1, players.get(1));
+    assert_eq!(second.player2, players.get(2));
+    assert_eq!(second.session_id, Some(POOL_SESSION_ID + 2));
+    assert!(matches.get(2).unwrap().session_id.is_none());
+
+    play_match(&client, &game, 0, true);
+    play_match(&client, &game, 1, false);
+
+    let final_match = client.get_matches(&TOURNAMENT_ID).get(2).unwrap();
+    assert_eq!(final_match.player1, players.get(0));
+    assert_eq!(final_match.player2, players.get(2));
+    assert_eq!(final_match.session_id, Some(POOL_SESSION_ID + 3));
+
+    play_match(&client, &game, 2, false);
+
+    let tournament = client.get_tournament(&TOURNAMENT_ID);
+    assert_eq!(tournament.status, Status::Finished);
+    assert_eq!(tournament.champion, players.get(2));
+
+    // Champion first, runner-up second, semi-final losers share third
+    assert_eq!(hub.get_places(), Some(vec![&env, 2, 3, 1, 3]));
+}
+
+#[test]
+fn test_byes_go_to_top_seeds() {
+    let (env, client, hub, game, organizer) = setup_test();
+    let players = setup_tournament(&env, &client, &game, &organizer, Format::SingleElimination, 3);
+
+    client.start_tournament(&TOURNAMENT_ID);
+
+    // Seed 1 has a bye and waits in the final
+    let matches = client.get_matches(&TOURNAMENT_ID);
+    let bye = matches.get(0).unwrap();
+    assert!(bye.done);
+    assert_eq!(bye.winner, players.get(0));
+    assert!(bye.session_id.is_none());
+    assert_eq!(matches.get(1).unwrap().session_id, Some(POOL_SESSION_ID + 1));
+    assert_eq!(matches.get(2).unwrap().player1, players.get(0));
+
+    play_match(&client, &game, 1, true);
+    play_match(&client, &game, 2, true);
+
+    assert_eq!(client.get_tournament(&TOURNAMENT_ID).champion, players.get(0));
+    assert_eq!(hub.get_places(), Some(vec![&env, 1, 2, 3]));
+}
+
+#[test]
+fn test_two_player_tournament() {
+    let (env, client, hub, game, organizer) = setup_test();
+    let players = setup_tournament(&env, &client, &game, &organizer, Format::SingleElimination, 2);
+
+    client.start_tournament(&TOURNAMENT_ID);
+    assert_eq!(client.get_matches(&TOURNAMENT_ID).len(), 1);
+
+    play_match(&client, &game, 0, false);
+
+    assert_eq!(client.get_tournament(&TOURNAMENT_ID).champion, players.get(1));
+    assert_eq!(hub.get_places(), Some(vec![&env, 2, 1]));
+}
+
+// ============================================================================
+// Double Elimination Tests
+// ============================================================================
+
+#[test]
+fn test_double_elimination() {
+    let (env, client, hub, game, organizer) = setup_test();
+    let players = setup_tournament(&env, &client, &game, &organizer, Format::DoubleElimination, 4);
+
+    client.start_tournament(&TOURNAMENT_ID);
+
+    // 2 + 1 winners bracket matches, 1 + 1 losers bracket matches, grand final
+    let matches = client.get_matches(&TOURNAMENT_ID);
+    assert_eq!(matches.len(), 6);
+    assert_eq!(matches.get(3).unwrap().bracket, Bracket::Losers);
+    assert_eq!(matches.get(5).unwrap().bracket, Bracket::GrandFinal);
+
+    play_match(&client, &game, 0, true); // seed 1 beats seed 4
+    play_match(&client, &game, 1, true); // seed 2 beats seed 3
+
+    // First-round losers meet in the losers bracket
+    let losers_round1 = client.get_matches(&TOURNAMENT_ID).get(3).unwrap();
+    assert_eq!(losers_round1.player1, players.get(3));
+    assert_eq!(losers_round1.player2, players.get(2));
+
+    play_match(&client, &game, 2, true); // seed 1 wins the winners bracket
+    play_match(&client, &game, 3, false); // seed 3 knocks out seed 4
+
+    // The winners final loser gets a second chance
+    let losers_final = client.get_matches(&TOURNAMENT_ID).get(4).unwrap();
+    assert_eq!(losers_final.player1, players.get(2));
+    assert_eq!(losers_final.player2, players.get(1));
+
+    play_match(&client, &game, 4, false); // seed 2 wins the losers bracket
+
+    let grand_final = client.get_matches(&TOURNAMENT_ID).get(5).unwrap();
+    assert_eq!(grand_final.player1, players.get(0));
+    assert_eq!(grand_final.player2, players.get(1));
+
+    play_match(&client, &game, 5, false);
+
+    let tournament = client.get_tournament(&TOURNAMENT_ID);
+    assert_eq!(tournament.status, Status::Finished);
+    assert_eq!(tournament.champion, players.get(1));
+    assert_eq!(hub.get_places(), Some(vec![&env, 2, 1, 3, 4]));
+}
+
+#[test]
+fn test_double_elimination_with_byes() {
+    let (env, client, hub, game, organizer) = setup_test();
+    let players = setup_tournament(&env, &client, &game, &organizer, Format::DoubleElimination, 5);
+
+    client.start_tournament(&TOURNAMENT_ID);
+
+    // 8-slot bracket: play every ready match, player 1 always winning,
+    // until the tournament is over
+    let mut played = 0;
+    while client.get_tournament(&TOURNAMENT_ID).status == Status::Running {
+        let matches = client.get_matches(&TOURNAMENT_ID);
+        let index = matches
+            .iter()
+            .position(|m| m.session_id.is_some() && !m.done)
+            .expect("no playable match") as u32;
+        play_match(&client, &game, index, true);
+        played += 1;
+    }
+
+    // Every player but the champion loses twice, byes aside: 5 players need
+    // 4 winners bracket and 3 losers bracket matches plus the grand final
+    assert_eq!(played, 8);
+    assert_eq!(client.get_tournament(&TOURNAMENT_ID).champion, players.get(0));
+    let places = hub.get_places().unwrap();
+    assert_eq!(places.get(0), Some(1));
+    assert_eq!(places.iter().filter(|place| *place == 1).count(), 1);
+}
+
+// ============================================================================
+// Match Reporting Tests
+// ============================================================================
+
+#[test]
+fn test_draw_replays_match() {
+    let (env, client, _hub, game, organizer) = setup_test();
+    let players = setup_tournament(&env, &client, &game, &organizer, Format::SingleElimination, 2);
+
+    client.start_tournament(&TOURNAMENT_ID);
+    let session_id = POOL_SESSION_ID + 1;
+    client.start_game(&game, &session_id, &players.get(0).unwrap(), &players.get(1).unwrap(), &0, &0);
+    client.end_game_draw(&session_id);
+
+    // The old session is gone; the replay gets the next ID
+    assert_tournament_error(&client.try_get_session_match(&session_id), Error::UnknownSession);
+    let m = client.get_matches(&TOURNAMENT_ID).get(0).unwrap();
+    assert!(!m.started);
+    assert_eq!(m.session_id, Some(session_id + 1));
+
+    play_match(&client, &game, 0, true);
+    assert_eq!(client.get_tournament(&TOURNAMENT_ID).champion, players.get(0));
+}
+
+#[test]
+fn test_match_checks() {
+    let (env, client, _hub, game, organizer) = setup_test();
+    let players = setup_tournament(&env, &client, &game, &organizer, Format::SingleElimination, 2);
+    client.start_tournament(&TOURNAMENT_ID);
+
+    let session_id = POOL_SESSION_ID + 1;
+    let player1 = players.get(0).unwrap();
+    let player2 = players.get(1).unwrap();
+
+    let result = client.try_end_game(&session_id, &true);
+    assert_tournament_error(&result, Error::MatchNotStarted);
+    let result = client.try_report_jackpot(&session_id, &player1);
+    assert_tournament_error(&result, Error::MatchNotStarted);
+
+    let other_game = Address::generate(&env);
+    let result = client.try_start_game(&other_game, &session_id, &player1, &player2, &0, &0);
+    assert_tournament_error(&result, Error::WrongGame);
+
+    let result = client.try_start_game(&game, &session_id, &player2, &player1, &0, &0);
+    assert_tournament_error(&result, Error::WrongPlayers);
+
+    let result = client.try_start_game(&game, &session_id, &player1, &player2, &10, &10);
+    assert_tournament_error(&result, Error::StakeNotAllowed);
+
+    let result = client.try_start_game(&game, &(session_id + 1), &player1, &player2, &0, &0);
+    assert_tournament_error(&result, Error::UnknownSession);
+
+    client.start_game(&game, &session_id, &player1, &player2, &0, &0);
+    let result = client.try_start_game(&game, &session_id, &player1, &player2, &0, &0);
+    assert_tournament_error(&result, Error::MatchAlreadyStarted);
+
+    // Jackpots are forwarded to the hub while the match is live
+    assert_eq!(client.get_jackpot_rate(&game), 100);
+    assert_eq!(client.report_jackpot(&session_id, &player1), 25);
+
+    client.end_game(&session_id, &true);
+    let result = client.try_end_game(&session_id, &true);
+    assert_tournament_error(&result, Error::UnknownSession);
+}
+
+#[test]
+fn test_claim_forfeit() {
+    let (env, client, hub, game, organizer) = setup_test();
+    let players = setup_tournament(&env, &client, &game, &organizer, Format::SingleElimination, 4);
+    client.start_tournament(&TOURNAMENT_ID);
+
+    let seed1 = players.get(0).unwrap();
+    let outsider = Address::generate(&env);
+    assert_tournament_error(&client.try_check_in(&TOURNAMENT_ID, &0, &outsider), Error::NotPlayer);
+    assert_tournament_error(&client.try_check_in(&TOURNAMENT_ID, &2, &seed1), Error::MatchNotReady);
+    assert_tournament_error(&client.try_claim_forfeit(&TOURNAMENT_ID, &9), Error::MatchNotFound);
+
+    // Seed 1 shows up for the first semi-final; nobody shows for the second
+    client.check_in(&TOURNAMENT_ID, &0, &seed1);
+    let deadline = client.get_matches(&TOURNAMENT_ID).get(0).unwrap().deadline;
+    assert_tournament_error(&client.try_claim_forfeit(&TOURNAMENT_ID, &0), Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    assert_tournament_error(
+        &client.try_check_in(&TOURNAMENT_ID, &1, &players.get(1).unwrap()),
+        Error::DeadlinePassed,
+    );
+
+    assert_eq!(client.claim_forfeit(&TOURNAMENT_ID, &0), Some(seed1.clone()));
+    assert_tournament_error(&client.try_claim_forfeit(&TOURNAMENT_ID, &0), Error::MatchDecided);
+    assert_tournament_error(&client.try_get_session_match(&(POOL_SESSION_ID + 1)), Error::UnknownSession);
+
+    // Both no-shows are out, so seed 1 gets a bye through the final
+    assert_eq!(client.claim_forfeit(&TOURNAMENT_ID, &1), None);
+    let tournament = client.get_tournament(&TOURNAMENT_ID);
+    assert_eq!(tournament.status, Status::Finished);
+    assert_eq!(tournament.champion, Some(seed1));
+    assert_eq!(hub.get_places(), Some(vec![&env, 1, 2, 2, 2]));
+}
+
+#[test]
+fn test_claim_forfeit_replays_when_both_checked_in() {
+    let (env, client, _hub, game, organizer) = setup_test();
+    let players = setup_tournament(&env, &client, &game, &organizer, Format::SingleElimination, 2);
+    client.start_tournament(&TOURNAMENT_ID);
+
+    let session_id = POOL_SESSION_ID + 1;
+    client.check_in(&TOURNAMENT_ID, &0, &players.get(0).unwrap());
+    client.check_in(&TOURNAMENT_ID, &0, &players.get(1).unwrap());
+
+    let deadline = client.get_matches(&TOURNAMENT_ID).get(0).unwrap().deadline;
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    assert_eq!(client.claim_forfeit(&TOURNAMENT_ID, &0), None);
+
+    // Fresh session, deadline and check-ins
+    let m = client.get_matches(&TOURNAMENT_ID).get(0).unwrap();
+    assert_eq!(m.session_id, Some(session_id + 1));
+    assert!(m.deadline > deadline);
+    assert!(!m.player1_checked_in && !m.player2_checked_in);
+
+    // A started match is left to the game's own timeout
+    client.start_game(&game, &(session_id + 1), &players.get(0).unwrap(), &players.get(1).unwrap(), &0, &0);
+    env.ledger().with_mut(|li| li.sequence_number = m.deadline + 1);
+    assert_tournament_error(&client.try_claim_forfeit(&TOURNAMENT_ID, &0), Error::MatchAlreadyStarted);
+}
+
+// ============================================================================
+// Registration Tests
+// ============================================================================
+
+#[test]
+fn test_registration_checks() {
+    let (env, client, _hub, game, organizer) = setup_test();
+
+    let result = client.try_create_tournament(
+        &TOURNAMENT_ID,
+        &organizer,
+        &tournament_setup(&game, Format::SingleElimination, 9),
+    );
+    assert_tournament_error(&result, Error::InvalidConfig);
+
+    client.create_tournament(
+        &TOURNAMENT_ID,
+        &organizer,
+        &tournament_setup(&game, Format::SingleElimination, 2),
+    );
+    let result = client.try_create_tournament(
+        &TOURNAMENT_ID,
+        &organizer,
+        &tournament_setup(&game, Format::SingleElimination, 2),
+    );
+    assert_tournament_error(&result, Error::TournamentExists);
+
+    let player1 = Address::generate(&env);
+    client.register(&TOURNAMENT_ID, &player1);
+    assert_tournament_error(&client.try_register(&TOURNAMENT_ID, &player1), Error::AlreadyRegistered);
+    assert_tournament_error(&client.try_start_tournament(&TOURNAMENT_ID), Error::NotEnoughPlayers);
+
+    client.register(&TOURNAMENT_ID, &Address::generate(&env));
+    let late = Address::generate(&env);
+    assert_tournament_error(&client.try_register(&TOURNAMENT_ID, &late), Error::TournamentFull);
+
+    client.start_tournament(&TOURNAMENT_ID);
+    assert_tournament_error(&client.try_register(&TOURNAMENT_ID, &late), Error::RegistrationClosed);
+    assert_tournament_error(&client.try_start_tournament(&TOURNAMENT_ID), Error::RegistrationClosed);
+    assert_tournament_error(&client.try_get_tournament(&2), Error::TournamentNotFound);
+}
+
+#[test]
+fn test_token_entry_fees_escrowed_at_register() {
+    let (env, client, hub, game, organizer) = setup_test();
+    let players = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env), Address::generate(&env)]);
+    let token = setup_token(&env, &players, ENTRY_FEE);
+
+    let mut setup = tournament_setup(&game, Format::SingleElimination, 4);
+    setup.token = Some(token.address.clone());
+    client.create_tournament(&TOURNAMENT_ID, &organizer, &setup);
+    for player in players.iter() {
+        client.register(&TOURNAMENT_ID, &player);
+        assert_eq!(token.balance(&player), 0);
+    }
+    assert_eq!(token.balance(&client.address), 3 * ENTRY_FEE);
+
+    // Leaving before the start returns the fee
+    let leaver = players.get(2).unwrap();
+    client.unregister(&TOURNAMENT_ID, &leaver);
+    assert_eq!(token.balance(&leaver), ENTRY_FEE);
+    assert_tournament_error(&client.try_unregister(&TOURNAMENT_ID, &leaver), Error::NotRegistered);
+
+    // The organizer starts without any player signing; the champion takes the escrow
+    client.start_tournament(&TOURNAMENT_ID);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, organizer);
+    assert_tournament_error(
+        &client.try_unregister(&TOURNAMENT_ID, &players.get(0).unwrap()),
+        Error::RegistrationClosed,
+    );
+    play_match(&client, &game, 0, false);
+
+    assert_eq!(hub.get_places(), Some(vec![&env, 2, 1]));
+    assert_eq!(token.balance(&players.get(1).unwrap()), 2 * ENTRY_FEE);
+    assert_eq!(token.balance(&players.get(0).unwrap()), 0);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_session_ranges_cannot_overlap() {
+    let (_env, client, _hub, game, organizer) = setup_test();
+
+    // Four single-elimination slots hold three matches: the pool and the next three IDs
+    client.create_tournament(&1, &organizer, &tournament_setup(&game, Format::SingleElimination, 4));
+
+    let mut setup = tournament_setup(&game, Format::DoubleElimination, 8);
+    setup.pool_session_id = POOL_SESSION_ID + 3;
+    assert_tournament_error(&client.try_create_tournament(&2, &organizer, &setup), Error::InvalidConfig);
+
+    // Eight double-elimination slots hold fourteen matches
+    setup.pool_session_id = POOL_SESSION_ID + 4;
+    client.create_tournament(&2, &organizer, &setup);
+    setup.pool_session_id = POOL_SESSION_ID + 18;
+    assert_tournament_error(&client.try_create_tournament(&3, &organizer, &setup), Error::InvalidConfig);
+    setup.pool_session_id = POOL_SESSION_ID + 19;
+    client.create_tournament(&3, &organizer, &setup);
+
+    setup.pool_session_id = u32::MAX;
+    assert_tournament_error(&client.try_create_tournament(&4, &organizer, &setup), Error::InvalidConfig);
+}
+
+#[test]
+fn test_replays_skip_sessions_of_other_tournaments() {
+    let (env, client, _hub, game, organizer) = setup_test();
+
+    // Two players need one match: the pool and one match session each
+    client.create_tournament(&TOURNAMENT_ID, &organizer, &tournament_setup(&game, Format::SingleElimination, 2));
+    let mut setup = tournament_setup(&game, Format::SingleElimination, 2);
+    setup.pool_session_id = POOL_SESSION_ID + 2;
+    client.create_tournament(&2, &organizer, &setup);
+
+    let players = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+    for player in players.iter() {
+        client.register(&TOURNAMENT_ID, &player);
+    }
+    client.start_tournament(&TOURNAMENT_ID);
+    let session_id = POOL_SESSION_ID + 1;
+    client.start_game(&game, &session_id, &players.get(0).unwrap(), &players.get(1).unwrap(), &0, &0);
+    client.end_game_draw(&session_id);
+
+    // The replay runs past the reserved range and over tournament 2's
+    let m = client.get_matches(&TOURNAMENT_ID).get(0).unwrap();
+    assert_eq!(m.session_id, Some(POOL_SESSION_ID + 4));
+    setup.pool_session_id = POOL_SESSION_ID + 4;
+    assert_tournament_error(&client.try_create_tournament(&3, &organizer, &setup), Error::InvalidConfig);
+}
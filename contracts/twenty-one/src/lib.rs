@@ -5,15 +5,22 @@
 //! A two-player card game where players try to get as close to 21 as possible without going over.
 //! Each player is dealt 2 cards and can choose to "hit" (draw another card) or "stick" (end their turn).
 //! Cards are valued 1-13 where Ace=1, 2-10=face value, Jack/Queen/King=10.
+//! Equal totals are resolved by a per-session `DrawPolicy` (redeal, split pot,
+//! or player 1 wins).
+//!
+//! A winner holding exactly 21 in five cards wins the Game Hub's jackpot.
+//! Hits are drawn from a deck seed the network picks when the game starts,
+//! so no player can choose a session ID that deals them five-card 21.
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror,
+    Address, Bytes, BytesN, Env, IntoVal, Map, contract, contractclient, contracterror,
     contractimpl, contracttype, vec
 };
+pub use session_keys::{SessionKey, MAX_SESSION_KEY_LEDGERS};
 
 // Import GameHub contract interface
 // This allows us to call into the GameHub contract
@@ -30,6 +37,12 @@ pub trait GameHub {
     );
 
     fn end_game(env: Env, session_id: u32, player1_won: bool);
+
+    fn end_game_draw(env: Env, session_id: u32);
+
+    fn report_jackpot(env: Env, session_id: u32, player: Address) -> i128;
+
+    fn get_jackpot_rate(env: Env, game_id: Address) -> u32;
 }
 
 // ============================================================================
@@ -47,10 +60,22 @@ pub enum Error {
     PlayerBusted = 5,
     BothPlayersNotStuck = 6,
     OpponentNotStuck = 7,
+    /// Reserved: no longer returned, since a tie is now settled by the
+    /// session's `DrawPolicy`. Kept so the other codes stay stable.
     Draw = 8,
     SelfPlay = 9,
     RoundOverflow = 10,
     InvalidHandData = 11,
+    InvalidSessionKey = 12,
+}
+
+impl From<session_keys::GrantError> for Error {
+    fn from(error: session_keys::GrantError) -> Self {
+        match error {
+            session_keys::GrantError::NotPlayer => Error::NotPlayer,
+            session_keys::GrantError::InvalidSessionKey => Error::InvalidSessionKey,
+        }
+    }
 }
 
 // ============================================================================
@@ -68,6 +93,31 @@ pub enum Error {
 // Data Types
 // ============================================================================
 
+/// How `reveal_winner` resolves equal hand totals. Chosen per session at start.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DrawPolicy {
+    /// Deal fresh hands, at most this many times. A draw after the last
+    /// redeal splits the pot.
+    Redeal(u32),
+    /// End the session as a draw and return both stakes.
+    SplitPot,
+    /// Ties go to player 1.
+    Player1Wins,
+}
+
+/// Result of a `reveal_winner` call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    /// The game ended with this winner.
+    Winner(Address),
+    /// Hands were tied and redealt. Carries the new round number.
+    Redeal(u32),
+    /// The game ended as a draw and the pot was split.
+    Split,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
@@ -81,6 +131,17 @@ pub struct Game {
     pub player2_stuck: bool,
     pub winner: Option<Address>,
     pub round: u32,
+    pub draw_policy: DrawPolicy,
+    pub split: bool,
+    /// Drawn from the network PRNG at the start and mixed into every hit
+    pub deck_seed: BytesN<32>,
+}
+
+impl Game {
+    /// True once the game has a winner or has ended in a split pot
+    fn is_over(&self) -> bool {
+        self.winner.is_some() || self.split
+    }
 }
 
 #[contracttype]
@@ -101,6 +162,9 @@ pub enum DataKey {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// Redeals allowed by `start_game`, which does not take an explicit policy
+const DEFAULT_MAX_REDEALS: u32 = 5;
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -169,6 +233,8 @@ impl TwentyOneContract {
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    /// * `session_keys` - Move keys to delegate up front, each mapped to its
+    ///   player and expiry (see `delegate_session_key`); may be empty
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -176,99 +242,93 @@ impl TwentyOneContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
+        session_keys: Map<Address, SessionKey>,
     ) -> Result<(), Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             return Err(Error::SelfPlay);
         }
 
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
-
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call the Game Hub to start the session and lock points
-        // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
-
-        // Generate deterministic seed for card dealing
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player1.to_string().to_bytes());
-        seed_bytes.append(&player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Deal initial hands (2 cards each)
-        // Use different seeds for each card to ensure variety
-        let mut player1_hand = Bytes::new(&env);
-        let mut player2_hand = Bytes::new(&env);
-
-        // Deal 2 cards to player1
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player1_hand.push_back(deal_card(&env, card_seed.into()));
-        }
+        // Both players consent to the points and to any session keys
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
+
+        Self::create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            DrawPolicy::Redeal(DEFAULT_MAX_REDEALS),
+            session_keys,
+        )
+    }
 
-        // Deal 2 cards to player2
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player2_hand.push_back(deal_card(&env, card_seed.into()));
+    /// Start a new game with an explicit draw policy.
+    /// Behaves like `start_game`, but both players also sign the policy that
+    /// decides what happens when their final hands are equal.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points amount committed by player 1
+    /// * `player2_points` - Points amount committed by player 2
+    /// * `draw_policy` - How equal totals are resolved
+    /// * `session_keys` - Move keys to delegate up front, each mapped to its
+    ///   player and expiry (see `delegate_session_key`); may be empty
+    pub fn start_game_with_policy(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        draw_policy: DrawPolicy,
+        session_keys: Map<Address, SessionKey>,
+    ) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            return Err(Error::SelfPlay);
         }
 
-        // Create game
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
+        // Both players consent to the points, the draw policy and any session keys
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            draw_policy.clone().into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            draw_policy.clone().into_val(&env),
+            session_keys.clone().into_val(&env),
+        ]);
+
+        Self::create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
             player1_points,
             player2_points,
-            player1_hand,
-            player2_hand,
-            player1_stuck: false,
-            player2_stuck: false,
-            winner: None,
-            round: 1,
-        };
-
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
-
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
-
-        // Event emitted by GameHub contract (GameStarted)
-
-        Ok(())
+            draw_policy,
+            session_keys,
+        )
     }
 
     /// Player draws another card ("hit").
@@ -276,9 +336,9 @@ impl TwentyOneContract {
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `player` - Address of the player drawing a card
+    /// * `player` - Address of the player drawing a card, or their session key
     pub fn hit(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
-        player.require_auth();
+        let player = session_keys::move_player(&env, session_id, player);
 
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
@@ -288,8 +348,8 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner or split yet)
+        if game.is_over() {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -320,8 +380,9 @@ impl TwentyOneContract {
             game.player2_hand.len()
         };
 
-        seed_bytes.append(&Bytes::from_array(&env, &(card_count as u32).to_be_bytes()));
+        seed_bytes.append(&Bytes::from_array(&env, &card_count.to_be_bytes()));
         seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
+        seed_bytes.append(&Bytes::from(game.deck_seed.clone()));
 
         let card_seed = env.crypto().keccak256(&seed_bytes);
         let new_card = deal_card(&env, card_seed.into());
@@ -335,7 +396,7 @@ impl TwentyOneContract {
             if hand_value > 21 {
                 // Player 1 busted, player 2 wins
                 // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, false)?;
+                Self::end_game_with_hub(&env, session_id, &game, false)?;
 
                 // Only set winner AFTER GameHub succeeds
                 game.winner = Some(game.player2.clone());
@@ -352,7 +413,7 @@ impl TwentyOneContract {
             if hand_value > 21 {
                 // Player 2 busted, player 1 wins
                 // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, true)?;
+                Self::end_game_with_hub(&env, session_id, &game, true)?;
 
                 // Only set winner AFTER GameHub succeeds
                 game.winner = Some(game.player1.clone());
@@ -374,9 +435,9 @@ impl TwentyOneContract {
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `player` - Address of the player sticking
+    /// * `player` - Address of the player sticking, or their session key
     pub fn stick(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
-        player.require_auth();
+        let player = session_keys::move_player(&env, session_id, player);
 
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
@@ -386,8 +447,8 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner or split yet)
+        if game.is_over() {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -414,15 +475,17 @@ impl TwentyOneContract {
 
     /// Reveal the winner of the game and submit outcome to GameHub.
     /// Can only be called after both players have stuck.
-    /// This calculates hand values, determines the winner (closest to 21),
-    /// and handles draws by dealing new hands.
+    /// This calculates hand values and determines the winner (closest to 21).
+    /// Equal totals are resolved by the session's `DrawPolicy`: a redeal is
+    /// stored and reported as `Outcome::Redeal`, so players can play the new
+    /// round without the state change being rolled back.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Outcome` - The winner, a redeal (with the new round), or a split pot
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Outcome, Error> {
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -431,9 +494,12 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
+        // Check if game already ended (has a winner or split the pot)
         if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+            return Ok(Outcome::Winner(winner.clone()));
+        }
+        if game.split {
+            return Ok(Outcome::Split);
         }
 
         // Check both players have stuck
@@ -454,58 +520,36 @@ impl TwentyOneContract {
             // Player 2 is closer to 21
             game.player2.clone()
         } else {
-            // Draw - deal new hands and continue
-            game.round = game.round.checked_add(1).ok_or(Error::RoundOverflow)?;
-
-            // Reset stuck flags
-            game.player1_stuck = false;
-            game.player2_stuck = false;
-
-            // Clear hands
-            game.player1_hand = Bytes::new(&env);
-            game.player2_hand = Bytes::new(&env);
-
-            // Deal new hands (2 cards each)
-            let mut seed_bytes = Bytes::new(&env);
-            seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-            seed_bytes.append(&game.player1.to_string().to_bytes());
-            seed_bytes.append(&game.player2.to_string().to_bytes());
-            seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
-            let base_seed = env.crypto().keccak256(&seed_bytes);
-
-            // Deal 2 cards to player1
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player1_hand.push_back(deal_card(&env, card_seed.into()));
-            }
-
-            // Deal 2 cards to player2
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player2_hand.push_back(deal_card(&env, card_seed.into()));
+            match game.draw_policy {
+                DrawPolicy::Player1Wins => game.player1.clone(),
+                // Redeal(n) allows n redeals; round 1 is the opening deal
+                DrawPolicy::Redeal(max_redeals) if game.round <= max_redeals => {
+                    Self::redeal(&env, session_id, &mut game)?;
+                    env.storage().temporary().set(&key, &game);
+
+                    return Ok(Outcome::Redeal(game.round));
+                }
+                DrawPolicy::Redeal(_) | DrawPolicy::SplitPot => {
+                    // Call GameHub FIRST (before marking the split)
+                    Self::draw_game_with_hub(&env, session_id);
+
+                    game.split = true;
+                    env.storage().temporary().set(&key, &game);
+
+                    return Ok(Outcome::Split);
+                }
             }
-
-            // Store updated game and return error to indicate draw
-            env.storage().temporary().set(&key, &game);
-
-            return Err(Error::Draw);
         };
 
         // Call GameHub FIRST (before setting winner)
         let player1_won = winner == game.player1;
-        Self::end_game_with_hub(&env, session_id, player1_won)?;
+        Self::end_game_with_hub(&env, session_id, &game, player1_won)?;
 
         // Only update game with winner AFTER GameHub succeeds
         game.winner = Some(winner.clone());
         env.storage().temporary().set(&key, &game);
 
-        Ok(winner)
+        Ok(Outcome::Winner(winner))
     }
 
     /// Get game information.
@@ -552,8 +596,166 @@ impl TwentyOneContract {
     // Internal Helper Functions
     // ========================================================================
 
+    /// Open the Game Hub session, deal the opening hands and store the game
+    fn create_game(
+        env: &Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        draw_policy: DrawPolicy,
+        session_keys: Map<Address, SessionKey>,
+    ) -> Result<(), Error> {
+        session_keys::grant_all(env, session_id, &player1, &player2, &session_keys, GAME_TTL_LEDGERS)?;
+
+        // Get GameHub address
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Create GameHub client
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        // The deck seed for hits comes from the network, before any card
+        // reseeds the PRNG, and is stored so later hits agree with it
+        let deck_seed: BytesN<32> = env.prng().gen();
+
+        // Generate deterministic seed for card dealing
+        // Seed components (all deterministic and identical between sim/submit):
+        // 1. Session ID - unique per game
+        // 2. Player addresses - both players contribute
+        //
+        // Note: We do NOT include ledger sequence or timestamp because those differ
+        // between simulation and submission.
+        let mut seed_bytes = Bytes::new(env);
+        seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        seed_bytes.append(&player1.to_string().to_bytes());
+        seed_bytes.append(&player2.to_string().to_bytes());
+        let base_seed = env.crypto().keccak256(&seed_bytes);
+
+        // Deal initial hands (2 cards each)
+        // Use different seeds for each card to ensure variety
+        let mut player1_hand = Bytes::new(env);
+        let mut player2_hand = Bytes::new(env);
+
+        // Deal 2 cards to player1
+        for i in 0..2 {
+            let mut card_seed_bytes = Bytes::new(env);
+            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
+            card_seed_bytes.append(&Bytes::from_array(env, &[i, 1])); // [card_index, player]
+            let card_seed = env.crypto().keccak256(&card_seed_bytes);
+            player1_hand.push_back(deal_card(env, card_seed.into()));
+        }
+
+        // Deal 2 cards to player2
+        for i in 0..2 {
+            let mut card_seed_bytes = Bytes::new(env);
+            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
+            card_seed_bytes.append(&Bytes::from_array(env, &[i, 2])); // [card_index, player]
+            let card_seed = env.crypto().keccak256(&card_seed_bytes);
+            player2_hand.push_back(deal_card(env, card_seed.into()));
+        }
+
+        // Create game
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            player1_hand,
+            player2_hand,
+            player1_stuck: false,
+            player2_stuck: false,
+            winner: None,
+            round: 1,
+            draw_policy,
+            split: false,
+            deck_seed,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        let game_key = DataKey::Game(session_id);
+        env.storage().temporary().set(&game_key, &game);
+
+        // Set TTL to ensure game is retained for at least 30 days
+        env.storage()
+            .temporary()
+            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
+    }
+
+    /// Start a new round after a draw: reset stuck flags and deal fresh hands
+    fn redeal(env: &Env, session_id: u32, game: &mut Game) -> Result<(), Error> {
+        game.round = game.round.checked_add(1).ok_or(Error::RoundOverflow)?;
+
+        // Reset stuck flags
+        game.player1_stuck = false;
+        game.player2_stuck = false;
+
+        // Clear hands
+        game.player1_hand = Bytes::new(env);
+        game.player2_hand = Bytes::new(env);
+
+        // Deal new hands (2 cards each), seeded by the new round number
+        let mut seed_bytes = Bytes::new(env);
+        seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        seed_bytes.append(&game.player1.to_string().to_bytes());
+        seed_bytes.append(&game.player2.to_string().to_bytes());
+        seed_bytes.append(&Bytes::from_array(env, &game.round.to_be_bytes()));
+        let base_seed = env.crypto().keccak256(&seed_bytes);
+
+        // Deal 2 cards to player1
+        for i in 0..2 {
+            let mut card_seed_bytes = Bytes::new(env);
+            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
+            card_seed_bytes.append(&Bytes::from_array(env, &[i, 1])); // [card_index, player]
+            let card_seed = env.crypto().keccak256(&card_seed_bytes);
+            game.player1_hand.push_back(deal_card(env, card_seed.into()));
+        }
+
+        // Deal 2 cards to player2
+        for i in 0..2 {
+            let mut card_seed_bytes = Bytes::new(env);
+            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
+            card_seed_bytes.append(&Bytes::from_array(env, &[i, 2])); // [card_index, player]
+            let card_seed = env.crypto().keccak256(&card_seed_bytes);
+            game.player2_hand.push_back(deal_card(env, card_seed.into()));
+        }
+
+        Ok(())
+    }
+
+    /// Helper to end game as a draw with the Game Hub (both stakes returned)
+    fn draw_game_with_hub(env: &Env, session_id: u32) {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+
+        // Event emitted by the Game Hub contract (GameDrawn)
+        GameHubClient::new(env, &game_hub_addr).end_game_draw(&session_id);
+    }
+
     /// Helper to end game with the Game Hub
-    fn end_game_with_hub(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
+    fn end_game_with_hub(env: &Env, session_id: u32, game: &Game, player1_won: bool) -> Result<(), Error> {
         // Get GameHub address
         let game_hub_addr: Address = env
             .storage()
@@ -564,6 +766,19 @@ impl TwentyOneContract {
         // Create GameHub client
         let game_hub = GameHubClient::new(env, &game_hub_addr);
 
+        // Five-card 21 wins the hub's jackpot, reported while the session is open
+        let (winner, hand) = if player1_won {
+            (&game.player1, &game.player1_hand)
+        } else {
+            (&game.player2, &game.player2_hand)
+        };
+        if hand.len() == 5
+            && calculate_hand_value(hand)? == 21
+            && game_hub.get_jackpot_rate(&env.current_contract_address()) > 0
+        {
+            game_hub.report_jackpot(&session_id, winner);
+        }
+
         // Call the Game Hub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
@@ -572,6 +787,51 @@ impl TwentyOneContract {
         Ok(())
     }
 
+    // ========================================================================
+    // Session Keys
+    // ========================================================================
+
+    /// Let a short-lived key sign `hit` and `stick` for a player, so the main
+    /// wallet is not prompted for every move. Usually done through `start_game`;
+    /// this call is for renewing or adding a key once the game is under way.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The player delegating
+    /// * `session_key` - The key that will sign moves; also authorizes this call
+    /// * `expiration_ledger` - Last ledger the key is accepted at, at most
+    ///   `MAX_SESSION_KEY_LEDGERS` from now
+    pub fn delegate_session_key(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        session_key: Address,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if game.is_over() {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let delegation = SessionKey { player, expiration_ledger };
+        session_keys::grant(
+            &env,
+            session_id,
+            &game.player1,
+            &game.player2,
+            session_key,
+            delegation,
+            GAME_TTL_LEDGERS,
+        )?;
+        Ok(())
+    }
+
     // ========================================================================
     // Admin Functions
     // ========================================================================
@@ -7,9 +7,9 @@
 // For full integration tests with the real GameHub contract, see:
 // contracts/game_hub/src/tests/twenty_one_integration.rs
 
-use crate::{Error, TwentyOneContract, TwentyOneContractClient};
+use crate::{DrawPolicy, Error, Outcome, SessionKey, TwentyOneContract, TwentyOneContractClient};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, map, symbol_short, Address, Bytes, BytesN, Env, Map};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -36,9 +36,31 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn end_game_draw(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
+
+    /// Remembers jackpot winners so tests can check the report
+    pub fn report_jackpot(env: Env, session_id: u32, player: Address) -> i128 {
+        env.storage().instance().set(&session_id, &player);
+        0
+    }
+
+    pub fn get_jackpot_winner(env: Env, session_id: u32) -> Option<Address> {
+        env.storage().instance().get(&session_id)
+    }
+
+    pub fn set_jackpot_rate(env: Env, rate_bp: u32) {
+        env.storage().instance().set(&symbol_short!("rate"), &rate_bp);
+    }
+
+    pub fn get_jackpot_rate(env: Env, _game_id: Address) -> u32 {
+        env.storage().instance().get(&symbol_short!("rate")).unwrap_or(0)
+    }
 }
 
 // ============================================================================
@@ -151,19 +173,49 @@ fn calculate_hand_value_helper(hand: &Bytes) -> u32 {
     total
 }
 
+/// Start games with the given policy until one has tied opening hands, then stick both players.
+/// Dealing is deterministic, so the same session id always ties for the same players.
+fn start_tied_game(
+    client: &TwentyOneContractClient<'static>,
+    player1: &Address,
+    player2: &Address,
+    draw_policy: &DrawPolicy,
+) -> u32 {
+    for session_id in 1000u32..1500 {
+        client.start_game_with_policy(
+            &session_id,
+            player1,
+            player2,
+            &100_0000000,
+            &100_0000000,
+            draw_policy,
+            &Map::new(&client.env),
+        );
+        let game = client.get_game(&session_id);
+        if calculate_hand_value_helper(&game.player1_hand)
+            == calculate_hand_value_helper(&game.player2_hand)
+        {
+            client.stick(&session_id, player1);
+            client.stick(&session_id, player2);
+            return session_id;
+        }
+    }
+    panic!("No tied opening hands found");
+}
+
 // ============================================================================
 // Basic Game Flow Tests
 // ============================================================================
 
 #[test]
 fn test_complete_game_simple() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.start_game(&session_id, &player1, &player2, &points, &points, &Map::new(&env));
 
     // Get game to verify initial state
     let game = client.get_game(&session_id);
@@ -181,8 +233,14 @@ fn test_complete_game_simple() {
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    // Reveal winner
-    let winner = client.reveal_winner(&session_id);
+    // Reveal winner (this session's opening hands are not tied, so no redeal)
+    assert_ne!(
+        calculate_hand_value_helper(&game.player1_hand),
+        calculate_hand_value_helper(&game.player2_hand)
+    );
+    let Outcome::Winner(winner) = client.reveal_winner(&session_id) else {
+        panic!("expected a winner");
+    };
     assert!(winner == player1 || winner == player2);
 
     // Verify game is ended
@@ -193,10 +251,10 @@ fn test_complete_game_simple() {
 
 #[test]
 fn test_initial_cards_dealt() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     let game = client.get_game(&session_id);
 
@@ -217,10 +275,10 @@ fn test_initial_cards_dealt() {
 
 #[test]
 fn test_get_hand_value() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 3u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Get hand values
     let player1_value = client.get_hand_value(&session_id, &player1);
@@ -241,10 +299,10 @@ fn test_get_hand_value() {
 
 #[test]
 fn test_hit_adds_card() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 4u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     let initial_game = client.get_game(&session_id);
     let initial_hand_size = initial_game.player1_hand.len();
@@ -258,10 +316,10 @@ fn test_hit_adds_card() {
 
 #[test]
 fn test_stick_prevents_further_hits() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Player 1 sticks
     client.stick(&session_id, &player1);
@@ -273,10 +331,10 @@ fn test_stick_prevents_further_hits() {
 
 #[test]
 fn test_multiple_hits_allowed() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     let initial_game = client.get_game(&session_id);
     let initial_hand_size = initial_game.player1_hand.len();
@@ -305,37 +363,38 @@ fn test_multiple_hits_allowed() {
 
 #[test]
 fn test_closer_to_21_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Both players stick
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    let winner = client.reveal_winner(&session_id);
-
-    // Get final hand values
+    // Get final hand values before the reveal (a redeal replaces them)
     let game = client.get_game(&session_id);
     let player1_value = calculate_hand_value_helper(&game.player1_hand);
     let player2_value = calculate_hand_value_helper(&game.player2_hand);
 
+    let outcome = client.reveal_winner(&session_id);
+
     // Winner should be closer to 21
     if player1_value > player2_value {
-        assert_eq!(winner, player1);
+        assert_eq!(outcome, Outcome::Winner(player1));
     } else if player2_value > player1_value {
-        assert_eq!(winner, player2);
+        assert_eq!(outcome, Outcome::Winner(player2));
+    } else {
+        assert_eq!(outcome, Outcome::Redeal(2));
     }
-    // If equal, test will continue (draw handling)
 }
 
 #[test]
 fn test_reveal_winner_requires_both_stuck() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Only player1 sticks
     client.stick(&session_id, &player1);
@@ -351,10 +410,10 @@ fn test_reveal_winner_requires_both_stuck() {
 
 #[test]
 fn test_bust_detection() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Keep hitting until player1 busts
     // Note: With enough hits, player will eventually bust (hand value > 21)
@@ -381,10 +440,10 @@ fn test_bust_detection() {
 
 #[test]
 fn test_cannot_hit_after_bust() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 10u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Hit until bust (game ends)
     let mut did_bust = false;
@@ -407,6 +466,45 @@ fn test_cannot_hit_after_bust() {
     assert!(did_bust, "Player should have busted after 20 hits");
 }
 
+// ============================================================================
+// Jackpot Tests
+// ============================================================================
+
+#[test]
+fn test_five_card_21_reports_jackpot() {
+    let (env, client, hub, player1, player2) = setup_test();
+    hub.set_jackpot_rate(&100);
+
+    // Player 1 draws up to five cards; only a five-card 21 is reported
+    let mut jackpot = false;
+    for session_id in 100..1000u32 {
+        client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+        while client.get_game(&session_id).player1_hand.len() < 5 && client.get_game(&session_id).winner.is_none() {
+            client.hit(&session_id, &player1);
+        }
+
+        let game = client.get_game(&session_id);
+        if game.winner.is_some() {
+            // Player 1 busted
+            assert_eq!(hub.get_jackpot_winner(&session_id), None);
+            continue;
+        }
+
+        client.stick(&session_id, &player1);
+        client.stick(&session_id, &player2);
+        let outcome = client.reveal_winner(&session_id);
+        if client.get_hand_value(&session_id, &player1) == 21 {
+            // Two cards are worth at most 20, so player 1 wins outright
+            assert_eq!(outcome, Outcome::Winner(player1.clone()));
+            assert_eq!(hub.get_jackpot_winner(&session_id), Some(player1.clone()));
+            jackpot = true;
+            break;
+        }
+        assert_eq!(hub.get_jackpot_winner(&session_id), None);
+    }
+    assert!(jackpot, "Player 1 should have drawn a five-card 21");
+}
+
 // ============================================================================
 // Draw/Tie Tests
 // ============================================================================
@@ -415,32 +513,116 @@ fn test_cannot_hit_after_bust() {
 fn test_draw_starts_new_round() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 11u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = start_tied_game(&client, &player1, &player2, &DrawPolicy::Redeal(3));
+
+    // A tie redeals instead of ending the game
+    let outcome = client.reveal_winner(&session_id);
+    assert_eq!(outcome, Outcome::Redeal(2));
+
+    // Verify new round was created
+    let game_after = client.get_game(&session_id);
+    assert_eq!(game_after.round, 2); // Round should increment
+    assert!(!game_after.player1_stuck); // Flags reset
+    assert!(!game_after.player2_stuck);
+    assert_eq!(game_after.player1_hand.len(), 2); // New cards dealt
+    assert_eq!(game_after.player2_hand.len(), 2);
+}
+
+// ============================================================================
+// Draw Policy Tests
+// ============================================================================
+
+#[test]
+fn test_default_draw_policy() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&31, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    assert_eq!(client.get_game(&31).draw_policy, DrawPolicy::Redeal(5));
+}
 
-    // Both players stick immediately
+#[test]
+fn test_redeal_is_persisted() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = start_tied_game(&client, &player1, &player2, &DrawPolicy::Redeal(3));
+    let game_before = client.get_game(&session_id);
+
+    // A redeal is a successful call, so the new round is persisted
+    assert_eq!(client.reveal_winner(&session_id), Outcome::Redeal(2));
+    let game_after = client.get_game(&session_id);
+    assert!(game_after.winner.is_none());
+    assert!(!game_after.split);
+    assert_ne!(
+        (game_before.player1_hand, game_before.player2_hand),
+        (game_after.player1_hand, game_after.player2_hand)
+    );
+
+    // Players can act in the new round
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
+}
+
+#[test]
+fn test_draw_after_last_redeal_splits_pot() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    // No redeals allowed: the first tie already exhausts the policy
+    let session_id = start_tied_game(&client, &player1, &player2, &DrawPolicy::Redeal(0));
+
+    let outcome = client.reveal_winner(&session_id);
+    assert_eq!(outcome, Outcome::Split);
 
     let game = client.get_game(&session_id);
-    let player1_value = calculate_hand_value_helper(&game.player1_hand);
-    let player2_value = calculate_hand_value_helper(&game.player2_hand);
+    assert!(game.split);
+    assert!(game.winner.is_none());
+    assert_eq!(game.round, 1);
+}
 
-    // Only test draw behavior if hands are equal
-    if player1_value == player2_value {
-        // This should return Draw error
-        let result = client.try_reveal_winner(&session_id);
-        assert_twenty_one_error(&result, Error::Draw);
-
-        // Verify new round was created
-        let game_after = client.get_game(&session_id);
-        assert_eq!(game_after.round, 2); // Round should increment
-        assert_eq!(game_after.player1_stuck, false); // Flags reset
-        assert_eq!(game_after.player2_stuck, false);
-        assert_eq!(game_after.player1_hand.len(), 2); // New cards dealt
-        assert_eq!(game_after.player2_hand.len(), 2);
-    }
-    // If not a draw, test passes (no assertion needed)
+#[test]
+fn test_draw_split_pot_policy() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = start_tied_game(&client, &player1, &player2, &DrawPolicy::SplitPot);
+
+    let outcome = client.reveal_winner(&session_id);
+    assert_eq!(outcome, Outcome::Split);
+    assert!(client.get_game(&session_id).split);
+
+    // Reveal is idempotent and the game accepts no further moves
+    assert_eq!(client.reveal_winner(&session_id), Outcome::Split);
+    let result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_stick(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_draw_player1_wins_policy() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = start_tied_game(&client, &player1, &player2, &DrawPolicy::Player1Wins);
+
+    let outcome = client.reveal_winner(&session_id);
+    assert_eq!(outcome, Outcome::Winner(player1.clone()));
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player1));
+    assert!(!game.split);
+}
+
+#[test]
+fn test_start_game_with_policy_self_play() {
+    let (env, client, _hub, player1, _player2) = setup_test();
+
+    let result = client.try_start_game_with_policy(
+        &30,
+        &player1,
+        &player1,
+        &100_0000000,
+        &100_0000000,
+        &DrawPolicy::SplitPot,
+        &Map::new(&env),
+    );
+    assert_twenty_one_error(&result, Error::SelfPlay);
 }
 
 // ============================================================================
@@ -449,10 +631,10 @@ fn test_draw_starts_new_round() {
 
 #[test]
 fn test_cannot_stick_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // First stick succeeds
     client.stick(&session_id, &player1);
@@ -468,7 +650,7 @@ fn test_non_player_cannot_hit() {
     let non_player = Address::generate(&env);
 
     let session_id = 13u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Non-player tries to hit
     let result = client.try_hit(&session_id, &non_player);
@@ -481,7 +663,7 @@ fn test_non_player_cannot_stick() {
     let non_player = Address::generate(&env);
 
     let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Non-player tries to stick
     let result = client.try_stick(&session_id, &non_player);
@@ -494,7 +676,7 @@ fn test_non_player_cannot_get_hand_value() {
     let non_player = Address::generate(&env);
 
     let session_id = 15u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Non-player tries to get hand value
     let result = client.try_get_hand_value(&session_id, &non_player);
@@ -535,18 +717,18 @@ fn test_cannot_get_nonexistent_game() {
 
 #[test]
 fn test_cannot_hit_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 16u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Both players stick
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    // Reveal winner (may panic if draw, but that's okay)
+    // Reveal winner (a draw redeals instead of ending the game)
     let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
+    if let Ok(Ok(Outcome::Winner(_))) = result {
         // Game ended successfully
         // Try to hit after game ended
         let hit_result = client.try_hit(&session_id, &player1);
@@ -556,10 +738,10 @@ fn test_cannot_hit_after_game_ended() {
 
 #[test]
 fn test_cannot_stick_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 17u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Player 1 sticks
     client.stick(&session_id, &player1);
@@ -569,7 +751,7 @@ fn test_cannot_stick_after_game_ended() {
 
     // Reveal winner
     let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
+    if let Ok(Ok(Outcome::Winner(_))) = result {
         // Game ended, verify stuck flag is set
         let game = client.get_game(&session_id);
         assert!(game.player1_stuck);
@@ -582,22 +764,20 @@ fn test_cannot_stick_after_game_ended() {
 
 #[test]
 fn test_reveal_winner_idempotent() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 18u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
     // First reveal
     let result1 = client.try_reveal_winner(&session_id);
-    if result1.is_ok() {
-        let winner1 = result1.unwrap().unwrap();
-
+    if let Ok(Ok(Outcome::Winner(winner1))) = result1 {
         // Second reveal should return same winner (idempotent)
         let winner2 = client.reveal_winner(&session_id);
-        assert_eq!(winner1, winner2);
+        assert_eq!(winner2, Outcome::Winner(winner1));
     }
 }
 
@@ -615,8 +795,8 @@ fn test_multiple_games_independent() {
     let session2 = 21u32;
 
     // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000, &Map::new(&env));
 
     // Play both games independently (use try_ methods to handle potential busts)
     let _ = client.try_hit(&session1, &player1);
@@ -653,8 +833,8 @@ fn test_multiple_sessions() {
     let session1 = 22u32;
     let session2 = 23u32;
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000, &Map::new(&env));
 
     // Verify both games exist and are independent
     let game1 = client.get_game(&session1);
@@ -668,13 +848,13 @@ fn test_multiple_sessions() {
 
 #[test]
 fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 24u32;
     let points1 = 200_0000000;
     let points2 = 50_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
+    client.start_game(&session_id, &player1, &player2, &points1, &points2, &Map::new(&env));
 
     let game = client.get_game(&session_id);
     assert_eq!(game.player1_points, points1);
@@ -683,7 +863,7 @@ fn test_asymmetric_points() {
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    // Try to reveal winner (may be draw)
+    // Try to reveal winner (may be a redeal)
     let _ = client.try_reveal_winner(&session_id);
 }
 
@@ -695,10 +875,10 @@ fn test_asymmetric_points() {
 fn test_face_cards_worth_10() {
     // This is a deterministic test of card value logic
     // We can't control what cards are dealt, but we can verify the hand value calculation
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 25u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     let game = client.get_game(&session_id);
 
@@ -714,10 +894,10 @@ fn test_face_cards_worth_10() {
 
 #[test]
 fn test_hand_value_calculation() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 26u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     // Get hand value from contract
     let contract_value = client.get_hand_value(&session_id, &player1);
@@ -729,6 +909,39 @@ fn test_hand_value_calculation() {
     assert_eq!(contract_value, expected_value);
 }
 
+// ============================================================================
+// Session Key Tests
+// ============================================================================
+
+#[test]
+fn test_session_key_plays_for_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let key = Address::generate(&env);
+    let session_keys = map![&env, (key.clone(), SessionKey { player: player1.clone(), expiration_ledger: 200 })];
+    client.start_game(&80u32, &player1, &player2, &100_0000000, &100_0000000, &session_keys);
+
+    let result = client.try_delegate_session_key(&80u32, &player1, &player1, &200);
+    assert_twenty_one_error(&result, Error::InvalidSessionKey);
+
+    // The key signs for player 1 without the main wallet
+    client.stick(&80u32, &key);
+    assert_eq!(env.auths()[0].0, key);
+    assert!(client.get_game(&80u32).player1_stuck);
+    let result = client.try_hit(&80u32, &key);
+    assert_twenty_one_error(&result, Error::AlreadyStuck);
+
+    // Another player's session does not accept the key
+    client.start_game(&81u32, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
+    let result = client.try_stick(&81u32, &key);
+    assert_twenty_one_error(&result, Error::NotPlayer);
+
+    // Keys can also come with a draw policy
+    let session_keys = map![&env, (key.clone(), SessionKey { player: player2.clone(), expiration_ledger: 200 })];
+    client.start_game_with_policy(&82u32, &player1, &player2, &100_0000000, &100_0000000, &DrawPolicy::SplitPot, &session_keys);
+    client.stick(&82u32, &key);
+    assert!(client.get_game(&82u32).player2_stuck);
+}
+
 // ============================================================================
 // Admin Function Tests
 // ============================================================================
@@ -827,17 +1040,17 @@ fn test_upgrade_function_exists() {
 
 #[test]
 fn test_deterministic_card_dealing() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 27u32;
 
     // Start first game
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
     let game1 = client.get_game(&session_id);
 
     // Start second game with same session_id in new environment (should be identical)
-    let (_env2, client2, _hub2, player1_2, player2_2) = setup_test();
-    client2.start_game(&session_id, &player1_2, &player2_2, &100_0000000, &100_0000000);
+    let (env2, client2, _hub2, player1_2, player2_2) = setup_test();
+    client2.start_game(&session_id, &player1_2, &player2_2, &100_0000000, &100_0000000, &Map::new(&env2));
     let game2 = client2.get_game(&session_id);
 
     // Note: Since we generate new addresses each time, the cards will be different
@@ -848,10 +1061,10 @@ fn test_deterministic_card_dealing() {
 
 #[test]
 fn test_round_counter() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 28u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &Map::new(&env));
 
     let game = client.get_game(&session_id);
     assert_eq!(game.round, 1); // First round
@@ -863,10 +1076,10 @@ fn test_round_counter() {
 
 #[test]
 fn test_cannot_play_against_self() {
-    let (_env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, _player2) = setup_test();
 
     let session_id = 29u32;
     // Try to start game where player1 plays against themselves
-    let result = client.try_start_game(&session_id, &player1, &player1, &100_0000000, &100_0000000);
+    let result = client.try_start_game(&session_id, &player1, &player1, &100_0000000, &100_0000000, &Map::new(&env));
     assert_twenty_one_error(&result, Error::SelfPlay);
 }
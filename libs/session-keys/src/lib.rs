@@ -0,0 +1,110 @@
+#![no_std]
+
+//! # Session Keys
+//!
+//! Shared by the two-player games (rps, number-guess, dice-duel, twenty-one).
+//! A player lets a short-lived key sign their moves in one game, so the main
+//! wallet is not prompted for every move:
+//! - keys are granted with the stake in `start_game` (and its variants), or
+//!   later through the game's `delegate_session_key`
+//! - a key only works for one session in one contract, and expires at its
+//!   `expiration_ledger`
+//! - the key also authorizes its grant, proving it is held
+//! - stakes are only ever authorized by the main wallet
+
+use soroban_sdk::{contracttype, Address, Env, Map};
+
+/// A short-lived key a player lets sign their moves in one game
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionKey {
+    /// The player the key moves for
+    pub player: Address,
+    /// Last ledger the key is accepted at
+    pub expiration_ledger: u32,
+}
+
+#[contracttype(export = false)]
+#[derive(Clone)]
+enum DataKey {
+    /// Delegated move key: (session_id, key) -> SessionKey
+    SessionKey(u32, Address),
+}
+
+/// Why a key was not granted. Each game maps these onto its own errors.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GrantError {
+    /// The key is for someone who is not in the game
+    NotPlayer,
+    /// The expiry is out of range, the key is a player, or it already serves
+    /// the other player
+    InvalidSessionKey,
+}
+
+/// Longest a session key can live (~1 day)
+pub const MAX_SESSION_KEY_LEDGERS: u32 = 17_280;
+
+/// Grant every key in `session_keys` for the game between `player1` and
+/// `player2`, keeping each for `ttl` ledgers
+pub fn grant_all(
+    env: &Env,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    session_keys: &Map<Address, SessionKey>,
+    ttl: u32,
+) -> Result<(), GrantError> {
+    for (session_key, delegation) in session_keys.iter() {
+        grant(env, session_id, player1, player2, session_key, delegation, ttl)?;
+    }
+    Ok(())
+}
+
+/// Store `session_key` as a move key for `delegation.player`
+pub fn grant(
+    env: &Env,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    session_key: Address,
+    delegation: SessionKey,
+    ttl: u32,
+) -> Result<(), GrantError> {
+    if delegation.player != *player1 && delegation.player != *player2 {
+        return Err(GrantError::NotPlayer);
+    }
+
+    let now = env.ledger().sequence();
+    let expiration_ledger = delegation.expiration_ledger;
+    if expiration_ledger <= now || expiration_ledger > now + MAX_SESSION_KEY_LEDGERS {
+        return Err(GrantError::InvalidSessionKey);
+    }
+    if session_key == *player1 || session_key == *player2 {
+        return Err(GrantError::InvalidSessionKey);
+    }
+
+    let key = DataKey::SessionKey(session_id, session_key.clone());
+    let existing: Option<SessionKey> = env.storage().temporary().get(&key);
+    if existing.is_some_and(|existing| existing.player != delegation.player) {
+        return Err(GrantError::InvalidSessionKey);
+    }
+    session_key.require_auth();
+    env.storage().temporary().set(&key, &delegation);
+    env.storage().temporary().extend_ttl(&key, ttl, ttl);
+
+    Ok(())
+}
+
+/// The player a move is for. `signer` is the player or a live session key
+/// they delegated for this session, and must authorize the call either way.
+pub fn move_player(env: &Env, session_id: u32, signer: Address) -> Address {
+    signer.require_auth();
+    let delegation: Option<SessionKey> = env
+        .storage()
+        .temporary()
+        .get(&DataKey::SessionKey(session_id, signer.clone()));
+    match delegation {
+        Some(delegation) if env.ledger().sequence() <= delegation.expiration_ledger => delegation.player,
+        _ => signer,
+    }
+}
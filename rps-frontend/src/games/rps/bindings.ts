@@ -39,6 +39,21 @@ export const networks = {
 } as const
 
 
+export const Errors = {
+  1: {message:"GameNotFound"},
+  2: {message:"NotPlayer"},
+  3: {message:"AlreadyMoved"},
+  4: {message:"BothPlayersNotMoved"},
+  5: {message:"GameAlreadyEnded"},
+  6: {message:"InvalidTeams"},
+  7: {message:"NotAllMoved"},
+  8: {message:"InvalidSessionKey"},
+  9: {message:"TimeoutNotReached"},
+  10: {message:"CannotClaimTimeout"}
+}
+
+export type PlayerMove = {tag: "None", values: void} | {tag: "Rock", values: void} | {tag: "Paper", values: void} | {tag: "Scissors", values: void};
+
 export interface Game {
   player1: string;
   player1_move: PlayerMove;
@@ -49,45 +64,100 @@ export interface Game {
   winner: Option<string>;
 }
 
-export const Errors = {
-  1: {message:"GameNotFound"},
-  2: {message:"NotPlayer"},
-  3: {message:"AlreadyMoved"},
-  4: {message:"BothPlayersNotMoved"},
-  5: {message:"GameAlreadyEnded"}
+export interface TeamGame {
+  /**
+   * Ledger after which a stalled round can be claimed
+   */
+  deadline: u32;
+  /**
+   * Set when the game ended without a winner
+   */
+  draw: boolean;
+  /**
+   * Moves submitted this round
+   */
+  moves: Map<string, PlayerMove>;
+  /**
+   * Level rounds replayed so far
+   */
+  replays: u32;
+  team1: Array<string>;
+  /**
+   * Points committed by each member of team 1, same order as `team1`
+   */
+  team1_points: Array<i128>;
+  /**
+   * None until a team wins
+   */
+  team1_won: Option<boolean>;
+  /**
+   * Same size as `team1`; seat i plays seat i
+   */
+  team2: Array<string>;
+  team2_points: Array<i128>;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "TeamGame", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
 
-export type PlayerMove = {tag: "None", values: void} | {tag: "Rock", values: void} | {tag: "Paper", values: void} | {tag: "Scissors", values: void};
+/**
+ * A short-lived key a player lets sign their moves in one game
+ */
+export interface SessionKey {
+  /**
+   * Last ledger the key is accepted at
+   */
+  expiration_ledger: u32;
+  /**
+   * The player the key moves for
+   */
+  player: string;
+}
 
 export interface Client {
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
    * 
-   * # Returns
-   * * `Address` - The GameHub contract address
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `session_keys` - Move keys to delegate up front, each mapped to its
+   * player and expiry (see `delegate_session_key`); may be empty
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  start_game: ({session_id, player1, player2, player1_points, player2_points, session_keys}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, session_keys: Map<string, SessionKey>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a submit_move transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit a Rock, Paper, or Scissors move for the current game.
+   * Each player may only submit once. The game resolves when both have moved.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player submitting a move, or their session key
+   * * `game_move` - The chosen move: Rock, Paper, or Scissors
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  submit_move: ({session_id, player, game_move}: {session_id: u32, player: string, game_move: PlayerMove}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the winner once both players have submitted their moves.
+   * Rock beats Scissors, Scissors beats Paper, Paper beats Rock.
+   * On a tie, moves are reset and players must submit again (returns Ok(None)).
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Option<Address>` - Some(winner) if decided, None if tied (moves reset)
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -101,6 +171,89 @@ export interface Client {
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a start_team_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a team game. Both teams must have the same number of members
+   * (1..=4) and every member signs the session and their own points.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `team1` - Members of the first team, in seat order
+   * * `team1_points` - Points committed by each member of team 1, same order
+   * * `team2` - Members of the second team, in seat order
+   * * `team2_points` - Points committed by each member of team 2, same order
+   */
+  start_team_game: ({session_id, team1, team1_points, team2, team2_points}: {session_id: u32, team1: Array<string>, team1_points: Array<i128>, team2: Array<string>, team2_points: Array<i128>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_team_move transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit a team member's move for the current round.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the member submitting a move
+   * * `game_move` - The chosen move: Rock, Paper, or Scissors
+   */
+  submit_team_move: ({session_id, player, game_move}: {session_id: u32, player: string, game_move: PlayerMove}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_team_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Play out every seat once all members have moved.
+   * The team with more seat wins takes the game; if both teams won the
+   * same number of seats, moves are reset and everyone submits again.
+   * After `MAX_TEAM_REPLAYS` replays a level result draws the game.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Option<Vec<Address>>` - Members of the winning team, or None if
+   * level (moves reset, or the game drawn once out of replays)
+   */
+  reveal_team_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<Array<string>>>>>
+
+  /**
+   * Construct and simulate a claim_team_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a team round that stalled past its deadline. A team whose members
+   * have all moved wins against one that has not; if neither team is
+   * complete, the game is drawn.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Any member of either team
+   * 
+   * # Returns
+   * * `Option<Vec<Address>>` - Members of the winning team, or None if drawn
+   */
+  claim_team_timeout: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<Array<string>>>>>
+
+  /**
+   * Construct and simulate a get_team_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get team game information.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `TeamGame` - The game state
+   */
+  get_team_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<TeamGame>>>
+
+  /**
+   * Construct and simulate a delegate_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Let a short-lived key sign `submit_move` for a player, so the main wallet is
+   * not prompted for every move. Usually done through `start_game`; this call is
+   * for renewing or adding a key once the game is under way.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - The player delegating
+   * * `session_key` - The key that will sign moves; also authorizes this call
+   * * `expiration_ledger` - Last ledger the key is accepted at, at most
+   * `MAX_SESSION_KEY_LEDGERS` from now
+   */
+  delegate_session_key: ({session_id, player, session_key, expiration_ledger}: {session_id: u32, player: string, session_key: string, expiration_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the current admin address
@@ -120,47 +273,31 @@ export interface Client {
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
-   * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
    * 
-   * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * # Returns
+   * * `Address` - The GameHub contract address
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a submit_move transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Submit a Rock, Paper, or Scissors move for the current game.
-   * Each player may only submit once. The game resolves when both have moved.
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
-   * * `player` - Address of the player submitting a move
-   * * `game_move` - The chosen move: Rock, Paper, or Scissors
+   * * `new_hub` - The new GameHub contract address
    */
-  submit_move: ({session_id, player, game_move}: {session_id: u32, player: string, game_move: PlayerMove}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner once both players have submitted their moves.
-   * Rock beats Scissors, Scissors beats Paper, Paper beats Rock.
-   * On a tie, moves are reset and players must submit again (returns Ok(None)).
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract)
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
-   * 
-   * # Returns
-   * * `Option<Address>` - Some(winner) if decided, None if tied (moves reset)
+   * * `new_wasm_hash` - The hash of the new WASM binary
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -182,32 +319,46 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAHAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfbW92ZQAAB9AAAAAKUGxheWVyTW92ZQAAAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAxwbGF5ZXIyX21vdmUAAAfQAAAAClBsYXllck1vdmUAAAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeU1vdmVkAAAAAwAAAAAAAAATQm90aFBsYXllcnNOb3RNb3ZlZAAAAAAEAAAAAAAAABBHYW1lQWxyZWFkeUVuZGVkAAAABQ==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACgAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeU1vdmVkAAAAAwAAAAAAAAATQm90aFBsYXllcnNOb3RNb3ZlZAAAAAAEAAAAAAAAABBHYW1lQWxyZWFkeUVuZGVkAAAABQAAAAAAAAAMSW52YWxpZFRlYW1zAAAABgAAAAAAAAALTm90QWxsTW92ZWQAAAAABwAAAAAAAAARSW52YWxpZFNlc3Npb25LZXkAAAAAAAAIAAAAAAAAABFUaW1lb3V0Tm90UmVhY2hlZAAAAAAAAAkAAAAAAAAAEkNhbm5vdENsYWltVGltZW91dAAAAAAACg==",
         "AAAAAgAAAAAAAAAAAAAAClBsYXllck1vdmUAAAAAAAQAAAAAAAAAAAAAAAROb25lAAAAAAAAAAAAAAAEUm9jawAAAAAAAAAAAAAABVBhcGVyAAAAAAAAAAAAAAAAAAAIU2Npc3NvcnM=",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAHAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfbW92ZQAAB9AAAAAKUGxheWVyTW92ZQAAAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAxwbGF5ZXIyX21vdmUAAAfQAAAAClBsYXllck1vdmUAAAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAQAAAAAAAAAAAAAACFRlYW1HYW1lAAAACQAAADFMZWRnZXIgYWZ0ZXIgd2hpY2ggYSBzdGFsbGVkIHJvdW5kIGNhbiBiZSBjbGFpbWVkAAAAAAAACGRlYWRsaW5lAAAABAAAAChTZXQgd2hlbiB0aGUgZ2FtZSBlbmRlZCB3aXRob3V0IGEgd2lubmVyAAAABGRyYXcAAAABAAAAGk1vdmVzIHN1Ym1pdHRlZCB0aGlzIHJvdW5kAAAAAAAFbW92ZXMAAAAAAAPsAAAAEwAAB9AAAAAKUGxheWVyTW92ZQAAAAAAHExldmVsIHJvdW5kcyByZXBsYXllZCBzbyBmYXIAAAAHcmVwbGF5cwAAAAAEAAAAAAAAAAV0ZWFtMQAAAAAAA+oAAAATAAAAQFBvaW50cyBjb21taXR0ZWQgYnkgZWFjaCBtZW1iZXIgb2YgdGVhbSAxLCBzYW1lIG9yZGVyIGFzIGB0ZWFtMWAAAAAMdGVhbTFfcG9pbnRzAAAD6gAAAAsAAAAWTm9uZSB1bnRpbCBhIHRlYW0gd2lucwAAAAAACXRlYW0xX3dvbgAAAAAAA+gAAAABAAAAKVNhbWUgc2l6ZSBhcyBgdGVhbTFgOyBzZWF0IGkgcGxheXMgc2VhdCBpAAAAAAAABXRlYW0yAAAAAAAD6gAAABMAAAAAAAAADHRlYW0yX3BvaW50cwAAA+oAAAAL",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABAAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAEAAAAAAAAACFRlYW1HYW1lAAAAAQAAAAQAAAAAAAAAAAAAAA5HYW1lSHViQWRkcmVzcwAAAAAAAAAAAAAAAAAFQWRtaW4AAAA=",
+        "AAAAAQAAADxBIHNob3J0LWxpdmVkIGtleSBhIHBsYXllciBsZXRzIHNpZ24gdGhlaXIgbW92ZXMgaW4gb25lIGdhbWUAAAAAAAAAClNlc3Npb25LZXkAAAAAAAIAAAAiTGFzdCBsZWRnZXIgdGhlIGtleSBpcyBhY2NlcHRlZCBhdAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAABxUaGUgcGxheWVyIHRoZSBrZXkgbW92ZXMgZm9yAAAABnBsYXllcgAAAAAAEw==",
+        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
+        "AAAAAAAAAq1TdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgoqIGBzZXNzaW9uX2tleXNgIC0gTW92ZSBrZXlzIHRvIGRlbGVnYXRlIHVwIGZyb250LCBlYWNoIG1hcHBlZCB0byBpdHMKcGxheWVyIGFuZCBleHBpcnkgKHNlZSBgZGVsZWdhdGVfc2Vzc2lvbl9rZXlgKTsgbWF5IGJlIGVtcHR5AAAAAAAACnN0YXJ0X2dhbWUAAAAAAAYAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADHNlc3Npb25fa2V5cwAAA+wAAAATAAAH0AAAAApTZXNzaW9uS2V5AAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAURTdWJtaXQgYSBSb2NrLCBQYXBlciwgb3IgU2Npc3NvcnMgbW92ZSBmb3IgdGhlIGN1cnJlbnQgZ2FtZS4KRWFjaCBwbGF5ZXIgbWF5IG9ubHkgc3VibWl0IG9uY2UuIFRoZSBnYW1lIHJlc29sdmVzIHdoZW4gYm90aCBoYXZlIG1vdmVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN1Ym1pdHRpbmcgYSBtb3ZlLCBvciB0aGVpciBzZXNzaW9uIGtleQoqIGBnYW1lX21vdmVgIC0gVGhlIGNob3NlbiBtb3ZlOiBSb2NrLCBQYXBlciwgb3IgU2Npc3NvcnMAAAALc3VibWl0X21vdmUAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAlnYW1lX21vdmUAAAAAAAfQAAAAClBsYXllck1vdmUAAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAVZSZXZlYWwgdGhlIHdpbm5lciBvbmNlIGJvdGggcGxheWVycyBoYXZlIHN1Ym1pdHRlZCB0aGVpciBtb3Zlcy4KUm9jayBiZWF0cyBTY2lzc29ycywgU2Npc3NvcnMgYmVhdHMgUGFwZXIsIFBhcGVyIGJlYXRzIFJvY2suCk9uIGEgdGllLCBtb3ZlcyBhcmUgcmVzZXQgYW5kIHBsYXllcnMgbXVzdCBzdWJtaXQgYWdhaW4gKHJldHVybnMgT2soTm9uZSkpLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYE9wdGlvbjxBZGRyZXNzPmAgLSBTb21lKHdpbm5lcikgaWYgZGVjaWRlZCwgTm9uZSBpZiB0aWVkIChtb3ZlcyByZXNldCkAAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD6AAAABMAAAAD",
         "AAAAAAAAAHNHZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlAAAAAAhnZXRfZ2FtZQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAER2FtZQAAAAM=",
+        "AAAAAAAAAb5TdGFydCBhIHRlYW0gZ2FtZS4gQm90aCB0ZWFtcyBtdXN0IGhhdmUgdGhlIHNhbWUgbnVtYmVyIG9mIG1lbWJlcnMKKDEuLj00KSBhbmQgZXZlcnkgbWVtYmVyIHNpZ25zIHRoZSBzZXNzaW9uIGFuZCB0aGVpciBvd24gcG9pbnRzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBVbmlxdWUgc2Vzc2lvbiBpZGVudGlmaWVyICh1MzIpCiogYHRlYW0xYCAtIE1lbWJlcnMgb2YgdGhlIGZpcnN0IHRlYW0sIGluIHNlYXQgb3JkZXIKKiBgdGVhbTFfcG9pbnRzYCAtIFBvaW50cyBjb21taXR0ZWQgYnkgZWFjaCBtZW1iZXIgb2YgdGVhbSAxLCBzYW1lIG9yZGVyCiogYHRlYW0yYCAtIE1lbWJlcnMgb2YgdGhlIHNlY29uZCB0ZWFtLCBpbiBzZWF0IG9yZGVyCiogYHRlYW0yX3BvaW50c2AgLSBQb2ludHMgY29tbWl0dGVkIGJ5IGVhY2ggbWVtYmVyIG9mIHRlYW0gMiwgc2FtZSBvcmRlcgAAAAAAD3N0YXJ0X3RlYW1fZ2FtZQAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAV0ZWFtMQAAAAAAA+oAAAATAAAAAAAAAAx0ZWFtMV9wb2ludHMAAAPqAAAACwAAAAAAAAAFdGVhbTIAAAAAAAPqAAAAEwAAAAAAAAAMdGVhbTJfcG9pbnRzAAAD6gAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAANpTdWJtaXQgYSB0ZWFtIG1lbWJlcidzIG1vdmUgZm9yIHRoZSBjdXJyZW50IHJvdW5kLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgbWVtYmVyIHN1Ym1pdHRpbmcgYSBtb3ZlCiogYGdhbWVfbW92ZWAgLSBUaGUgY2hvc2VuIG1vdmU6IFJvY2ssIFBhcGVyLCBvciBTY2lzc29ycwAAAAAAEHN1Ym1pdF90ZWFtX21vdmUAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACWdhbWVfbW92ZQAAAAAAB9AAAAAKUGxheWVyTW92ZQAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAbdQbGF5IG91dCBldmVyeSBzZWF0IG9uY2UgYWxsIG1lbWJlcnMgaGF2ZSBtb3ZlZC4KVGhlIHRlYW0gd2l0aCBtb3JlIHNlYXQgd2lucyB0YWtlcyB0aGUgZ2FtZTsgaWYgYm90aCB0ZWFtcyB3b24gdGhlCnNhbWUgbnVtYmVyIG9mIHNlYXRzLCBtb3ZlcyBhcmUgcmVzZXQgYW5kIGV2ZXJ5b25lIHN1Ym1pdHMgYWdhaW4uCkFmdGVyIGBNQVhfVEVBTV9SRVBMQVlTYCByZXBsYXlzIGEgbGV2ZWwgcmVzdWx0IGRyYXdzIHRoZSBnYW1lLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYE9wdGlvbjxWZWM8QWRkcmVzcz4+YCAtIE1lbWJlcnMgb2YgdGhlIHdpbm5pbmcgdGVhbSwgb3IgTm9uZSBpZgpsZXZlbCAobW92ZXMgcmVzZXQsIG9yIHRoZSBnYW1lIGRyYXduIG9uY2Ugb3V0IG9mIHJlcGxheXMpAAAAABJyZXZlYWxfdGVhbV93aW5uZXIAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+gAAAPqAAAAEwAAAAM=",
+        "AAAAAAAAAVdFbmQgYSB0ZWFtIHJvdW5kIHRoYXQgc3RhbGxlZCBwYXN0IGl0cyBkZWFkbGluZS4gQSB0ZWFtIHdob3NlIG1lbWJlcnMKaGF2ZSBhbGwgbW92ZWQgd2lucyBhZ2FpbnN0IG9uZSB0aGF0IGhhcyBub3Q7IGlmIG5laXRoZXIgdGVhbSBpcwpjb21wbGV0ZSwgdGhlIGdhbWUgaXMgZHJhd24uCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBbnkgbWVtYmVyIG9mIGVpdGhlciB0ZWFtCgojIFJldHVybnMKKiBgT3B0aW9uPFZlYzxBZGRyZXNzPj5gIC0gTWVtYmVycyBvZiB0aGUgd2lubmluZyB0ZWFtLCBvciBOb25lIGlmIGRyYXduAAAAABJjbGFpbV90ZWFtX3RpbWVvdXQAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD6AAAA+oAAAATAAAAAw==",
+        "AAAAAAAAAHxHZXQgdGVhbSBnYW1lIGluZm9ybWF0aW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYFRlYW1HYW1lYCAtIFRoZSBnYW1lIHN0YXRlAAAADWdldF90ZWFtX2dhbWUAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAACFRlYW1HYW1lAAAAAw==",
+        "AAAAAAAAAd9MZXQgYSBzaG9ydC1saXZlZCBrZXkgc2lnbiBgc3VibWl0X21vdmVgIGZvciBhIHBsYXllciwgc28gdGhlIG1haW4gd2FsbGV0IGlzCm5vdCBwcm9tcHRlZCBmb3IgZXZlcnkgbW92ZS4gVXN1YWxseSBkb25lIHRocm91Z2ggYHN0YXJ0X2dhbWVgOyB0aGlzIGNhbGwgaXMKZm9yIHJlbmV3aW5nIG9yIGFkZGluZyBhIGtleSBvbmNlIHRoZSBnYW1lIGlzIHVuZGVyIHdheS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIFRoZSBwbGF5ZXIgZGVsZWdhdGluZwoqIGBzZXNzaW9uX2tleWAgLSBUaGUga2V5IHRoYXQgd2lsbCBzaWduIG1vdmVzOyBhbHNvIGF1dGhvcml6ZXMgdGhpcyBjYWxsCiogYGV4cGlyYXRpb25fbGVkZ2VyYCAtIExhc3QgbGVkZ2VyIHRoZSBrZXkgaXMgYWNjZXB0ZWQgYXQsIGF0IG1vc3QKYE1BWF9TRVNTSU9OX0tFWV9MRURHRVJTYCBmcm9tIG5vdwAAAAAUZGVsZWdhdGVfc2Vzc2lvbl9rZXkAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC3Nlc3Npb25fa2V5AAAAABMAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
         "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAAS5TdWJtaXQgYSBSb2NrLCBQYXBlciwgb3IgU2Npc3NvcnMgbW92ZSBmb3IgdGhlIGN1cnJlbnQgZ2FtZS4KRWFjaCBwbGF5ZXIgbWF5IG9ubHkgc3VibWl0IG9uY2UuIFRoZSBnYW1lIHJlc29sdmVzIHdoZW4gYm90aCBoYXZlIG1vdmVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN1Ym1pdHRpbmcgYSBtb3ZlCiogYGdhbWVfbW92ZWAgLSBUaGUgY2hvc2VuIG1vdmU6IFJvY2ssIFBhcGVyLCBvciBTY2lzc29ycwAAAAAAC3N1Ym1pdF9tb3ZlAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAJZ2FtZV9tb3ZlAAAAAAAH0AAAAApQbGF5ZXJNb3ZlAAAAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAVZSZXZlYWwgdGhlIHdpbm5lciBvbmNlIGJvdGggcGxheWVycyBoYXZlIHN1Ym1pdHRlZCB0aGVpciBtb3Zlcy4KUm9jayBiZWF0cyBTY2lzc29ycywgU2Npc3NvcnMgYmVhdHMgUGFwZXIsIFBhcGVyIGJlYXRzIFJvY2suCk9uIGEgdGllLCBtb3ZlcyBhcmUgcmVzZXQgYW5kIHBsYXllcnMgbXVzdCBzdWJtaXQgYWdhaW4gKHJldHVybnMgT2soTm9uZSkpLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYE9wdGlvbjxBZGRyZXNzPmAgLSBTb21lKHdpbm5lcikgaWYgZGVjaWRlZCwgTm9uZSBpZiB0aWVkIChtb3ZlcyByZXNldCkAAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD6AAAABMAAAAD" ]),
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+    start_game: this.txFromJSON<Result<void>>,
+        submit_move: this.txFromJSON<Result<void>>,
+        reveal_winner: this.txFromJSON<Result<Option<string>>>,
         get_game: this.txFromJSON<Result<Game>>,
+        start_team_game: this.txFromJSON<Result<void>>,
+        submit_team_move: this.txFromJSON<Result<void>>,
+        reveal_team_winner: this.txFromJSON<Result<Option<Array<string>>>>,
+        claim_team_timeout: this.txFromJSON<Result<Option<Array<string>>>>,
+        get_team_game: this.txFromJSON<Result<TeamGame>>,
+        delegate_session_key: this.txFromJSON<Result<void>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
-        submit_move: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<Option<string>>>
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
@@ -13,8 +13,11 @@ import {
 import path from 'path';
 import { fileURLToPath } from 'url';
 
+// Contracts that can be scaffolded from: simultaneous-move and turn-based
+const TEMPLATES = ['number-guess', 'tic-tac-toe'];
+
 function usage() {
-  console.log(`\nUsage: bun run create <game-slug> [--force] [--skip-setup]\n`);
+  console.log(`\nUsage: bun run create <game-slug> [--template <${TEMPLATES.join('|')}>] [--force] [--skip-setup]\n`);
 }
 
 function isValidSlug(slug: string): boolean {
@@ -46,6 +49,24 @@ function toEnvKey(slug: string): string {
   return slug.replace(/-/g, '_').toUpperCase();
 }
 
+function slugReplacements(from: string, to: string): Record<string, string> {
+  return {
+    [from]: to,
+    [pascalFromSlug(from)]: pascalFromSlug(to) || 'Game',
+    [titleCaseFromSlug(from)]: titleCaseFromSlug(to) || 'Game',
+    [camelFromSlug(from)]: camelFromSlug(to) || 'game',
+    [toEnvKey(from)]: toEnvKey(to) || 'GAME',
+    [from.replace(/-/g, '_')]: to.replace(/-/g, '_') || 'game',
+  };
+}
+
+function optionValue(args: string[], name: string): string | undefined {
+  const index = args.indexOf(name);
+  if (index >= 0) return args[index + 1];
+  const inline = args.find((arg) => arg.startsWith(`${name}=`));
+  return inline?.slice(name.length + 1);
+}
+
 function shouldSkip(name: string): boolean {
   const skipNames = new Set([
     'node_modules',
@@ -148,6 +169,7 @@ if (args.length === 0 || args.includes('--help')) {
 const gameSlug = args[0];
 const force = args.includes('--force');
 const skipSetup = args.includes('--skip-setup') || args.includes('--no-setup');
+const template = optionValue(args, '--template') ?? 'number-guess';
 
 if (!isValidSlug(gameSlug)) {
   console.error(`\n❌ Invalid game slug: ${gameSlug}`);
@@ -155,17 +177,23 @@ if (!isValidSlug(gameSlug)) {
   process.exit(1);
 }
 
+if (!TEMPLATES.includes(template)) {
+  console.error(`\n❌ Unknown template: ${template}`);
+  console.error(`Available templates: ${TEMPLATES.join(', ')}`);
+  process.exit(1);
+}
+
 const scriptDir = path.dirname(fileURLToPath(import.meta.url));
 const repoRoot = path.resolve(scriptDir, '..');
 const contractsRoot = path.join(repoRoot, 'contracts');
-const exampleContractDir = path.join(contractsRoot, 'number-guess');
+const exampleContractDir = path.join(contractsRoot, template);
 const newContractDir = path.join(contractsRoot, gameSlug);
 const frontendTemplateDir = path.join(repoRoot, 'template_frontend');
 const frontendSlug = `${gameSlug}-frontend`;
 const newFrontendDir = path.join(repoRoot, frontendSlug);
 
 if (!existsSync(exampleContractDir)) {
-  console.error(`\n❌ Missing number-guess example contract at ${exampleContractDir}`);
+  console.error(`\n❌ Missing ${template} example contract at ${exampleContractDir}`);
   process.exit(1);
 }
 
@@ -194,7 +222,7 @@ if (existsSync(newFrontendDir)) {
 
 console.log(`\n🧩 Creating game: ${gameSlug}`);
 
-console.log('  • Copying number-guess contract...');
+console.log(`  • Copying ${template} contract...`);
 copyDir(exampleContractDir, newContractDir);
 
 const pascalName = pascalFromSlug(gameSlug);
@@ -209,7 +237,7 @@ const replacements = {
   'numberGuess': camelName || 'game',
   'NUMBER_GUESS': envKey || 'GAME',
 };
-replaceInDir(newContractDir, replacements);
+replaceInDir(newContractDir, slugReplacements(template, gameSlug));
 
 console.log('  • Registering contract in workspace...');
 updateWorkspaceMembers(repoRoot, gameSlug);
@@ -369,6 +397,10 @@ if (!skipSetup) {
   }
 }
 
+if (template !== 'number-guess') {
+  console.log(`⚠️  The frontend is the number-guess example; adapt ${frontendSlug}/src/games/${gameSlug} to the ${template} contract.`);
+}
+
 console.log('Next steps:');
 console.log(`  1) Review contracts/${gameSlug}/src/lib.rs`);
 if (skipSetup) {
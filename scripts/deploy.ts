@@ -61,6 +61,23 @@ const NETWORK = 'testnet';
 const RPC_URL = 'https://soroban-testnet.stellar.org';
 const NETWORK_PASSPHRASE = 'Test SDF Network ; September 2015';
 const EXISTING_GAME_HUB_TESTNET_CONTRACT_ID = 'CB4VZAT2U3UC6XFK3N23SKRF2NDCMP3QHJYMCHHFMZO7MRQO6DQ2EMYG';
+// Hub methods the games in this repo call beyond start_game/end_game. A hub
+// without them (such as the shared testnet hub) is not reused.
+const REQUIRED_GAME_HUB_METHODS = [
+  'end_game_draw',
+  'start_multi_game',
+  'end_multi_game',
+  'start_team_game',
+  'end_team_game',
+  'add_stake',
+  'report_jackpot',
+  'get_jackpot_rate',
+];
+// Each player deploys their own player-account, owned by their own key, so it
+// is not deployed here.
+const PER_PLAYER_CONTRACTS = ['player-account'];
+// Contracts that settle their own pots and take only `--admin`
+const HUBLESS_CONTRACTS = ['raffle'];
 
 async function testnetAccountExists(address: string): Promise<boolean> {
   const res = await fetch(`https://horizon-testnet.stellar.org/accounts/${address}`, { method: 'GET' });
@@ -99,6 +116,20 @@ async function testnetContractExists(contractId: string): Promise<boolean> {
   }
 }
 
+async function testnetHubSupportsGames(contractId: string): Promise<boolean> {
+  try {
+    const iface = await $`stellar -q contract info interface --id ${contractId} --network ${NETWORK}`.text();
+    const missing = REQUIRED_GAME_HUB_METHODS.filter((method) => !iface.includes(`fn ${method}(`));
+    if (missing.length > 0) {
+      console.warn(`⚠️  Game Hub ${contractId} lacks ${missing.join(", ")}; not reusing it`);
+      return false;
+    }
+    return true;
+  } catch {
+    return false;
+  }
+}
+
 const args = process.argv.slice(2);
 if (args.includes("--help") || args.includes("-h")) {
   usage();
@@ -123,14 +154,19 @@ if (selection.unknown.length > 0 || selection.ambiguous.length > 0) {
   process.exit(1);
 }
 
-const contracts = selection.contracts;
+const contracts = selection.contracts.filter((c) => !PER_PLAYER_CONTRACTS.includes(c.packageName));
+for (const contract of selection.contracts) {
+  if (PER_PLAYER_CONTRACTS.includes(contract.packageName)) {
+    console.log(`⏭️  Skipping ${contract.packageName}: each player deploys their own\n`);
+  }
+}
 const mock = allContracts.find((c) => c.isMockHub);
 if (!mock) {
   console.error("❌ Error: mock-game-hub contract not found in workspace members");
   process.exit(1);
 }
 
-const needsMock = contracts.some((c) => !c.isMockHub);
+const needsMock = contracts.some((c) => !c.isMockHub && !HUBLESS_CONTRACTS.includes(c.packageName));
 const deployMockRequested = contracts.some((c) => c.isMockHub);
 const shouldEnsureMock = deployMockRequested || needsMock;
 
@@ -255,8 +291,10 @@ if (shouldEnsureMock) {
     EXISTING_GAME_HUB_TESTNET_CONTRACT_ID,
   ].filter(Boolean) as string[];
 
+  // Only a candidate that is live and compatible is reused
+  mockGameHubId = "";
   for (const candidate of candidateMockIds) {
-    if (await testnetContractExists(candidate)) {
+    if (await testnetContractExists(candidate) && await testnetHubSupportsGames(candidate)) {
       mockGameHubId = candidate;
       break;
     }
@@ -273,11 +311,11 @@ if (shouldEnsureMock) {
       process.exit(1);
     }
 
-    console.warn(`⚠️  ${mock.packageName} not found on testnet (archived or reset). Deploying a new one...`);
+    console.warn(`⚠️  No compatible ${mock.packageName} found on testnet (missing, archived or outdated). Deploying a new one...`);
     console.log(`Deploying ${mock.packageName}...`);
     try {
       const result =
-        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK}`.text();
+        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress}`.text();
       mockGameHubId = result.trim();
       deployed[mock.packageName] = mockGameHubId;
       console.log(`✅ ${mock.packageName} deployed: ${mockGameHubId}\n`);
@@ -300,8 +338,11 @@ for (const contract of contracts) {
     console.log(`  WASM hash: ${wasmHash}`);
 
     console.log("  Deploying and initializing...");
+    const constructorArgs = HUBLESS_CONTRACTS.includes(contract.packageName)
+      ? ["--admin", adminAddress]
+      : ["--admin", adminAddress, "--game-hub", mockGameHubId];
     const deployResult =
-      await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --game-hub ${mockGameHubId}`.text();
+      await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- ${constructorArgs}`.text();
     const contractId = deployResult.trim();
     deployed[contract.packageName] = contractId;
     console.log(`✅ ${contract.packageName} deployed: ${contractId}\n`);
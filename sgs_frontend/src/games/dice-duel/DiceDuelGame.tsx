@@ -910,15 +910,19 @@ export function DiceDuelGame({
   const isPlayer2 = gameState && gameState.player2 === userAddress;
   const hasRolled = isPlayer1 ? !!gameState?.player1_rolled : isPlayer2 ? !!gameState?.player2_rolled : false;
 
-  const player1Dice = [gameState?.player1_die1 ?? null, gameState?.player1_die2 ?? null];
-  const player2Dice = [gameState?.player2_die1 ?? null, gameState?.player2_die2 ?? null];
+  // Dice stay empty until a player rolls; show blank faces until then
+  const diceCount = gameState?.rules.dice_count ?? 2;
+  const diceOrBlank = (dice?: number[]): (number | null)[] =>
+    dice && dice.length > 0 ? dice : Array.from({ length: diceCount }, () => null);
+  const player1Dice = diceOrBlank(gameState?.player1_dice);
+  const player2Dice = diceOrBlank(gameState?.player2_dice);
   const player1Rolling = rollingPlayer === 'player1' || rollingPlayer === 'both';
   const player2Rolling = rollingPlayer === 'player2' || rollingPlayer === 'both';
-  const player1Total = player1Dice.every((die) => die !== null)
-    ? Number(player1Dice[0]) + Number(player1Dice[1])
+  const player1Total = gameState?.player1_dice.length
+    ? gameState.player1_dice.reduce((total, die) => total + Number(die), 0)
     : null;
-  const player2Total = player2Dice.every((die) => die !== null)
-    ? Number(player2Dice[0]) + Number(player2Dice[1])
+  const player2Total = gameState?.player2_dice.length
+    ? gameState.player2_dice.reduce((total, die) => total + Number(die), 0)
     : null;
 
   return (
@@ -1362,8 +1366,9 @@ export function DiceDuelGame({
                 Points: {(Number(gameState.player1_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-4 flex items-center gap-3 dice-tray">
-                <DiceFace value={gameState.player1_die1 ?? null} tone="gold" rolling={player1Rolling} rolled={gameState.player1_rolled} />
-                <DiceFace value={gameState.player1_die2 ?? null} tone="gold" rolling={player1Rolling} rolled={gameState.player1_rolled} />
+                {player1Dice.map((die, index) => (
+                  <DiceFace key={index} value={die} tone="gold" rolling={player1Rolling} rolled={gameState.player1_rolled} />
+                ))}
                 <div className="text-xs font-bold text-gray-600">
                   {gameState.player1_rolled ? 'Rolled' : 'Waiting'}
                 </div>
@@ -1379,8 +1384,9 @@ export function DiceDuelGame({
                 Points: {(Number(gameState.player2_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-4 flex items-center gap-3 dice-tray">
-                <DiceFace value={gameState.player2_die1 ?? null} tone="red" rolling={player2Rolling} rolled={gameState.player2_rolled} />
-                <DiceFace value={gameState.player2_die2 ?? null} tone="red" rolling={player2Rolling} rolled={gameState.player2_rolled} />
+                {player2Dice.map((die, index) => (
+                  <DiceFace key={index} value={die} tone="red" rolling={player2Rolling} rolled={gameState.player2_rolled} />
+                ))}
                 <div className="text-xs font-bold text-gray-600">
                   {gameState.player2_rolled ? 'Rolled' : 'Waiting'}
                 </div>
@@ -1425,10 +1431,12 @@ export function DiceDuelGame({
               The house is ready. Reveal the dice.
             </p>
             <div className="flex items-center justify-center gap-4 mb-6 dice-tray">
-              <DiceFace value={gameState.player1_die1 ?? null} tone="gold" rolling={player1Rolling} rolled />
-              <DiceFace value={gameState.player1_die2 ?? null} tone="gold" rolling={player1Rolling} rolled />
-              <DiceFace value={gameState.player2_die1 ?? null} tone="red" rolling={player2Rolling} rolled />
-              <DiceFace value={gameState.player2_die2 ?? null} tone="red" rolling={player2Rolling} rolled />
+              {player1Dice.map((die, index) => (
+                <DiceFace key={index} value={die} tone="gold" rolling={player1Rolling} rolled />
+              ))}
+              {player2Dice.map((die, index) => (
+                <DiceFace key={index} value={die} tone="red" rolling={player2Rolling} rolled />
+              ))}
             </div>
             <button
               onClick={handleRevealWinner}
@@ -1457,8 +1465,9 @@ export function DiceDuelGame({
                   {gameState.player1.slice(0, 8)}...{gameState.player1.slice(-4)}
                 </p>
                 <div className="flex items-center justify-center gap-3 mb-3 dice-tray">
-                  <DiceFace value={gameState.player1_die1 ?? null} tone="gold" rolled />
-                  <DiceFace value={gameState.player1_die2 ?? null} tone="gold" rolled />
+                  {player1Dice.map((die, index) => (
+                    <DiceFace key={index} value={die} tone="gold" rolled />
+                  ))}
                 </div>
                 <p className="text-lg font-black text-gray-800">
                   Total: {player1Total ?? '—'}
@@ -1471,8 +1480,9 @@ export function DiceDuelGame({
                   {gameState.player2.slice(0, 8)}...{gameState.player2.slice(-4)}
                 </p>
                 <div className="flex items-center justify-center gap-3 mb-3 dice-tray">
-                  <DiceFace value={gameState.player2_die1 ?? null} tone="red" rolled />
-                  <DiceFace value={gameState.player2_die2 ?? null} tone="red" rolled />
+                  {player2Dice.map((die, index) => (
+                    <DiceFace key={index} value={die} tone="red" rolled />
+                  ))}
                 </div>
                 <p className="text-lg font-black text-gray-800">
                   Total: {player2Total ?? '—'}
@@ -39,68 +39,238 @@ export const networks = {
 } as const
 
 
+export const Errors = {
+  1: {message:"GameNotFound"},
+  2: {message:"NotPlayer"},
+  3: {message:"AlreadyRolled"},
+  4: {message:"BothPlayersNotRolled"},
+  5: {message:"GameAlreadyEnded"},
+  6: {message:"InvalidRules"},
+  7: {message:"NotRolled"},
+  8: {message:"RerollNotAllowed"},
+  9: {message:"AlreadyRerolled"},
+  10: {message:"InvalidDieIndex"},
+  11: {message:"RerollPending"},
+  12: {message:"InvalidTeams"},
+  13: {message:"NotAllRolled"},
+  14: {message:"InvalidSessionKey"},
+  15: {message:"InvalidReveal"},
+  16: {message:"TimeoutNotReached"},
+  17: {message:"CannotClaimTimeout"}
+}
+
+/**
+ * How a player's dice are turned into a score
+ */
+export type Scoring = {tag: "HighestSum", values: void} | {tag: "HighestSingle", values: void};
+
+/**
+ * Per-session rule set, fixed when the game starts
+ */
+export interface DiceRules {
+  /**
+   * Whether each player may reroll a chosen subset of their dice once
+   */
+  allow_reroll: boolean;
+  /**
+   * Dice rolled by each player (1..=MAX_DICE)
+   */
+  dice_count: u32;
+  /**
+   * Faces on each die (2..=MAX_FACES)
+   */
+  faces: u32;
+  scoring: Scoring;
+}
+
 export interface Game {
   player1: string;
-  player1_die1: Option<u32>;
-  player1_die2: Option<u32>;
+  /**
+   * Dice values, empty until the player rolls
+   */
+  player1_dice: Array<u32>;
   player1_points: i128;
+  /**
+   * keccak256(salt ‖ dice indices), set when the player locks in their reroll
+   */
+  player1_reroll_commitment: Option<Buffer>;
+  /**
+   * Revealed dice indices, applied once both players reveal
+   */
+  player1_reroll_dice: Array<u32>;
+  /**
+   * Revealed salt, mixed into both players' rerolled dice
+   */
+  player1_reroll_salt: Option<Buffer>;
+  /**
+   * Set once both players' rerolls are revealed and applied
+   */
+  player1_rerolled: boolean;
   player1_rolled: boolean;
   player2: string;
-  player2_die1: Option<u32>;
-  player2_die2: Option<u32>;
+  player2_dice: Array<u32>;
   player2_points: i128;
+  player2_reroll_commitment: Option<Buffer>;
+  player2_reroll_dice: Array<u32>;
+  player2_reroll_salt: Option<Buffer>;
+  player2_rerolled: boolean;
   player2_rolled: boolean;
+  /**
+   * Ledger after which a stalled reroll can be claimed; 0 until both
+   * players have rolled in a game with rerolls
+   */
+  reroll_deadline: u32;
+  rules: DiceRules;
   winner: Option<string>;
 }
 
-export const Errors = {
-  1: {message:"GameNotFound"},
-  2: {message:"NotPlayer"},
-  3: {message:"AlreadyRolled"},
-  4: {message:"BothPlayersNotRolled"},
-  5: {message:"GameAlreadyEnded"}
+export interface TeamGame {
+  /**
+   * Each member's dice, added as they roll
+   */
+  dice: Map<string, Array<u32>>;
+  rules: DiceRules;
+  team1: Array<string>;
+  /**
+   * Points committed by each member of team 1, same order as `team1`
+   */
+  team1_points: Array<i128>;
+  /**
+   * Team totals, set when the winner is revealed
+   */
+  team1_score: u32;
+  /**
+   * None until the winner is revealed
+   */
+  team1_won: Option<boolean>;
+  team2: Array<string>;
+  team2_points: Array<i128>;
+  team2_score: u32;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "TeamGame", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+
+/**
+ * A short-lived key a player lets sign their moves in one game
+ */
+export interface SessionKey {
+  /**
+   * Last ledger the key is accepted at
+   */
+  expiration_ledger: u32;
+  /**
+   * The player the key moves for
+   */
+  player: string;
+}
 
 export interface Client {
+  /**
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
+   * The game uses the classic rules: two six-sided dice each, highest total wins.
+   * 
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `session_keys` - Move keys to delegate up front, each mapped to its
+   * player and expiry (see `delegate_session_key`); may be empty
+   */
+  start_game: ({session_id, player1, player2, player1_points, player2_points, session_keys}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, session_keys: Map<string, SessionKey>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_game_with_rules transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game with a custom rule set.
+   * Behaves like `start_game`, but both players also sign the rules.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `rules` - Dice count, faces, reroll allowance and scoring for this session
+   * * `session_keys` - Move keys to delegate up front, each mapped to its
+   * player and expiry (see `delegate_session_key`); may be empty
+   */
+  start_game_with_rules: ({session_id, player1, player2, player1_points, player2_points, rules, session_keys}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, rules: DiceRules, session_keys: Map<string, SessionKey>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a roll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Commit a roll for the current game.
+   * Roll the player's dice for the current game.
    * Both players must roll before the winner can be revealed.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
-   * * `player` - Address of the player rolling the dice
+   * * `player` - Address of the player rolling the dice, or their session key
    */
   roll: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a reroll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lock in the player's single reroll without showing it.
+   * Only available when the session rules allow it. `commitment` is
+   * keccak256 of a random 32-byte salt followed by the zero-based indices
+   * of the dice to reroll, each as 4 big-endian bytes; committing to no
+   * indices declines the reroll.
    * 
-   * # Returns
-   * * `Address` - The GameHub contract address
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player rerolling, or their session key
+   * * `commitment` - keccak256(salt ‖ dice indices)
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  reroll: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a reveal_reroll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the player's reroll once both players have committed. The
+   * second reveal rolls the selected dice of both players, seeded with
+   * both salts, so neither reroll is known until it is applied.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player revealing, or their session key
+   * * `dice` - Zero-based indices of the dice to reroll, as committed
+   * * `salt` - The salt behind the commitment
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_reroll: ({session_id, player, dice, salt}: {session_id: u32, player: string, dice: Array<u32>, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the winner of the game and submit outcome to GameHub.
+   * Can only be called after both players have rolled (and, when rerolls
+   * are allowed, both have revealed their reroll).
+   * This scores both players' dice, determines the winner, and ends the session.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Address` - Address of the winning player
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Win a game whose opponent stalled the reroll: after the deadline, a
+   * player who has committed (or, once both committed, revealed) wins if
+   * the other has not.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the claiming player (the one waiting)
+   * 
+   * # Returns
+   * * `Address` - Address of the winning player (the claimant)
+   */
+  claim_timeout: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -114,6 +284,73 @@ export interface Client {
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a start_team_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a team game. Every member signs the session and their own points
+   * together with the rules. Rerolls are not available in team mode.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `team1` - Members of the first team (1..=4)
+   * * `team1_points` - Points committed by each member of team 1, same order
+   * * `team2` - Members of the second team (1..=4)
+   * * `team2_points` - Points committed by each member of team 2, same order
+   * * `rules` - Dice count, faces and scoring for every member
+   */
+  start_team_game: ({session_id, team1, team1_points, team2, team2_points, rules}: {session_id: u32, team1: Array<string>, team1_points: Array<i128>, team2: Array<string>, team2_points: Array<i128>, rules: DiceRules}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a roll_team transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Roll a team member's dice. Every member rolls once.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the member rolling
+   */
+  roll_team: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_team_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add up each team's scores once every member has rolled and report the
+   * winning team to the Game Hub, which splits the winnings among its
+   * members. Ties go to team 1.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Vec<Address>` - Members of the winning team
+   */
+  reveal_team_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<string>>>>
+
+  /**
+   * Construct and simulate a get_team_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get team game information.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `TeamGame` - The game state (includes team totals after the reveal)
+   */
+  get_team_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<TeamGame>>>
+
+  /**
+   * Construct and simulate a delegate_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Let a short-lived key sign `roll`, `reroll` and `reveal_reroll` for a
+   * player, so the main wallet is not prompted for every move. Usually done
+   * through `start_game`; this call is for renewing or adding a key once the
+   * game is under way.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - The player delegating
+   * * `session_key` - The key that will sign moves; also authorizes this call
+   * * `expiration_ledger` - Last ledger the key is accepted at, at most
+   * `MAX_SESSION_KEY_LEDGERS` from now
+   */
+  delegate_session_key: ({session_id, player, session_key, expiration_ledger}: {session_id: u32, player: string, session_key: string, expiration_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the current admin address
@@ -133,35 +370,31 @@ export interface Client {
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
    * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * # Returns
+   * * `Address` - The GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * * `new_hub` - The new GameHub contract address
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have rolled.
-   * This generates dice rolls for both players, determines the winner, and ends the session.
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract)
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
-   * 
-   * # Returns
-   * * `Address` - Address of the winning player
+   * * `new_wasm_hash` - The hash of the new WASM binary
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -183,31 +416,53 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAALAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIxX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjFfcm9sbGVkAAAAAAABAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADHBsYXllcjJfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIyX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcm9sbGVkAAAAAAABAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAANQWxyZWFkeVJvbGxlZAAAAAAAAAMAAAAAAAAAFEJvdGhQbGF5ZXJzTm90Um9sbGVkAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAU=",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAAMpDb21taXQgYSByb2xsIGZvciB0aGUgY3VycmVudCBnYW1lLgpCb3RoIHBsYXllcnMgbXVzdCByb2xsIGJlZm9yZSB0aGUgd2lubmVyIGNhbiBiZSByZXZlYWxlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciByb2xsaW5nIHRoZSBkaWNlAAAAAAAEcm9sbAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAEQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAANQWxyZWFkeVJvbGxlZAAAAAAAAAMAAAAAAAAAFEJvdGhQbGF5ZXJzTm90Um9sbGVkAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAUAAAAAAAAADEludmFsaWRSdWxlcwAAAAYAAAAAAAAACU5vdFJvbGxlZAAAAAAAAAcAAAAAAAAAEFJlcm9sbE5vdEFsbG93ZWQAAAAIAAAAAAAAAA9BbHJlYWR5UmVyb2xsZWQAAAAACQAAAAAAAAAPSW52YWxpZERpZUluZGV4AAAAAAoAAAAAAAAADVJlcm9sbFBlbmRpbmcAAAAAAAALAAAAAAAAAAxJbnZhbGlkVGVhbXMAAAAMAAAAAAAAAAxOb3RBbGxSb2xsZWQAAAANAAAAAAAAABFJbnZhbGlkU2Vzc2lvbktleQAAAAAAAA4AAAAAAAAADUludmFsaWRSZXZlYWwAAAAAAAAPAAAAAAAAABFUaW1lb3V0Tm90UmVhY2hlZAAAAAAAABAAAAAAAAAAEkNhbm5vdENsYWltVGltZW91dAAAAAAAEQ==",
+        "AAAAAgAAACtIb3cgYSBwbGF5ZXIncyBkaWNlIGFyZSB0dXJuZWQgaW50byBhIHNjb3JlAAAAAAAAAAAHU2NvcmluZwAAAAACAAAAAAAAAA9TdW0gb2YgYWxsIGRpY2UAAAAACkhpZ2hlc3RTdW0AAAAAAAAAAAAcVmFsdWUgb2YgdGhlIGJlc3Qgc2luZ2xlIGRpZQAAAA1IaWdoZXN0U2luZ2xlAAAA",
+        "AAAAAQAAADBQZXItc2Vzc2lvbiBydWxlIHNldCwgZml4ZWQgd2hlbiB0aGUgZ2FtZSBzdGFydHMAAAAAAAAACURpY2VSdWxlcwAAAAAAAAQAAABBV2hldGhlciBlYWNoIHBsYXllciBtYXkgcmVyb2xsIGEgY2hvc2VuIHN1YnNldCBvZiB0aGVpciBkaWNlIG9uY2UAAAAAAAAMYWxsb3dfcmVyb2xsAAAAAQAAAClEaWNlIHJvbGxlZCBieSBlYWNoIHBsYXllciAoMS4uPU1BWF9ESUNFKQAAAAAAAApkaWNlX2NvdW50AAAAAAAEAAAAIUZhY2VzIG9uIGVhY2ggZGllICgyLi49TUFYX0ZBQ0VTKQAAAAAAAAVmYWNlcwAAAAAAAAQAAAAAAAAAB3Njb3JpbmcAAAAH0AAAAAdTY29yaW5nAA==",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAATAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAApRGljZSB2YWx1ZXMsIGVtcHR5IHVudGlsIHRoZSBwbGF5ZXIgcm9sbHMAAAAAAAAMcGxheWVyMV9kaWNlAAAD6gAAAAQAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAS2tlY2NhazI1NihzYWx0IOKAliBkaWNlIGluZGljZXMpLCBzZXQgd2hlbiB0aGUgcGxheWVyIGxvY2tzIGluIHRoZWlyIHJlcm9sbAAAAAAZcGxheWVyMV9yZXJvbGxfY29tbWl0bWVudAAAAAAAA+gAAAPuAAAAIAAAADdSZXZlYWxlZCBkaWNlIGluZGljZXMsIGFwcGxpZWQgb25jZSBib3RoIHBsYXllcnMgcmV2ZWFsAAAAABNwbGF5ZXIxX3Jlcm9sbF9kaWNlAAAAA+oAAAAEAAAANVJldmVhbGVkIHNhbHQsIG1peGVkIGludG8gYm90aCBwbGF5ZXJzJyByZXJvbGxlZCBkaWNlAAAAAAAAE3BsYXllcjFfcmVyb2xsX3NhbHQAAAAD6AAAA+4AAAAgAAAAN1NldCBvbmNlIGJvdGggcGxheWVycycgcmVyb2xscyBhcmUgcmV2ZWFsZWQgYW5kIGFwcGxpZWQAAAAAEHBsYXllcjFfcmVyb2xsZWQAAAABAAAAAAAAAA5wbGF5ZXIxX3JvbGxlZAAAAAAAAQAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAxwbGF5ZXIyX2RpY2UAAAPqAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAAGXBsYXllcjJfcmVyb2xsX2NvbW1pdG1lbnQAAAAAAAPoAAAD7gAAACAAAAAAAAAAE3BsYXllcjJfcmVyb2xsX2RpY2UAAAAD6gAAAAQAAAAAAAAAE3BsYXllcjJfcmVyb2xsX3NhbHQAAAAD6AAAA+4AAAAgAAAAAAAAABBwbGF5ZXIyX3Jlcm9sbGVkAAAAAQAAAAAAAAAOcGxheWVyMl9yb2xsZWQAAAAAAAEAAABrTGVkZ2VyIGFmdGVyIHdoaWNoIGEgc3RhbGxlZCByZXJvbGwgY2FuIGJlIGNsYWltZWQ7IDAgdW50aWwgYm90aApwbGF5ZXJzIGhhdmUgcm9sbGVkIGluIGEgZ2FtZSB3aXRoIHJlcm9sbHMAAAAAD3Jlcm9sbF9kZWFkbGluZQAAAAAEAAAAAAAAAAVydWxlcwAAAAAAB9AAAAAJRGljZVJ1bGVzAAAAAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
+        "AAAAAQAAAAAAAAAAAAAACFRlYW1HYW1lAAAACQAAACZFYWNoIG1lbWJlcidzIGRpY2UsIGFkZGVkIGFzIHRoZXkgcm9sbAAAAAAABGRpY2UAAAPsAAAAEwAAA+oAAAAEAAAAAAAAAAVydWxlcwAAAAAAB9AAAAAJRGljZVJ1bGVzAAAAAAAAAAAAAAV0ZWFtMQAAAAAAA+oAAAATAAAAQFBvaW50cyBjb21taXR0ZWQgYnkgZWFjaCBtZW1iZXIgb2YgdGVhbSAxLCBzYW1lIG9yZGVyIGFzIGB0ZWFtMWAAAAAMdGVhbTFfcG9pbnRzAAAD6gAAAAsAAAAsVGVhbSB0b3RhbHMsIHNldCB3aGVuIHRoZSB3aW5uZXIgaXMgcmV2ZWFsZWQAAAALdGVhbTFfc2NvcmUAAAAABAAAACFOb25lIHVudGlsIHRoZSB3aW5uZXIgaXMgcmV2ZWFsZWQAAAAAAAAJdGVhbTFfd29uAAAAAAAD6AAAAAEAAAAAAAAABXRlYW0yAAAAAAAD6gAAABMAAAAAAAAADHRlYW0yX3BvaW50cwAAA+oAAAALAAAAAAAAAAt0ZWFtMl9zY29yZQAAAAAE",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABAAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAEAAAAAAAAACFRlYW1HYW1lAAAAAQAAAAQAAAAAAAAAAAAAAA5HYW1lSHViQWRkcmVzcwAAAAAAAAAAAAAAAAAFQWRtaW4AAAA=",
+        "AAAAAQAAADxBIHNob3J0LWxpdmVkIGtleSBhIHBsYXllciBsZXRzIHNpZ24gdGhlaXIgbW92ZXMgaW4gb25lIGdhbWUAAAAAAAAAClNlc3Npb25LZXkAAAAAAAIAAAAiTGFzdCBsZWRnZXIgdGhlIGtleSBpcyBhY2NlcHRlZCBhdAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAABxUaGUgcGxheWVyIHRoZSBrZXkgbW92ZXMgZm9yAAAABnBsYXllcgAAAAAAEw==",
+        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
+        "AAAAAAAAAvtTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpUaGUgZ2FtZSB1c2VzIHRoZSBjbGFzc2ljIHJ1bGVzOiB0d28gc2l4LXNpZGVkIGRpY2UgZWFjaCwgaGlnaGVzdCB0b3RhbCB3aW5zLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgoqIGBzZXNzaW9uX2tleXNgIC0gTW92ZSBrZXlzIHRvIGRlbGVnYXRlIHVwIGZyb250LCBlYWNoIG1hcHBlZCB0byBpdHMKcGxheWVyIGFuZCBleHBpcnkgKHNlZSBgZGVsZWdhdGVfc2Vzc2lvbl9rZXlgKTsgbWF5IGJlIGVtcHR5AAAAAApzdGFydF9nYW1lAAAAAAAGAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAAxzZXNzaW9uX2tleXMAAAPsAAAAEwAAB9AAAAAKU2Vzc2lvbktleQAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAjZTdGFydCBhIG5ldyBnYW1lIHdpdGggYSBjdXN0b20gcnVsZSBzZXQuCkJlaGF2ZXMgbGlrZSBgc3RhcnRfZ2FtZWAsIGJ1dCBib3RoIHBsYXllcnMgYWxzbyBzaWduIHRoZSBydWxlcy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVW5pcXVlIHNlc3Npb24gaWRlbnRpZmllciAodTMyKQoqIGBwbGF5ZXIxYCAtIEFkZHJlc3Mgb2YgZmlyc3QgcGxheWVyCiogYHBsYXllcjJgIC0gQWRkcmVzcyBvZiBzZWNvbmQgcGxheWVyCiogYHBsYXllcjFfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAxCiogYHBsYXllcjJfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAyCiogYHJ1bGVzYCAtIERpY2UgY291bnQsIGZhY2VzLCByZXJvbGwgYWxsb3dhbmNlIGFuZCBzY29yaW5nIGZvciB0aGlzIHNlc3Npb24KKiBgc2Vzc2lvbl9rZXlzYCAtIE1vdmUga2V5cyB0byBkZWxlZ2F0ZSB1cCBmcm9udCwgZWFjaCBtYXBwZWQgdG8gaXRzCnBsYXllciBhbmQgZXhwaXJ5IChzZWUgYGRlbGVnYXRlX3Nlc3Npb25fa2V5YCk7IG1heSBiZSBlbXB0eQAAAAAAFXN0YXJ0X2dhbWVfd2l0aF9ydWxlcwAAAAAAAAcAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAABXJ1bGVzAAAAAAAH0AAAAAlEaWNlUnVsZXMAAAAAAAAAAAAADHNlc3Npb25fa2V5cwAAA+wAAAATAAAH0AAAAApTZXNzaW9uS2V5AAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAOlSb2xsIHRoZSBwbGF5ZXIncyBkaWNlIGZvciB0aGUgY3VycmVudCBnYW1lLgpCb3RoIHBsYXllcnMgbXVzdCByb2xsIGJlZm9yZSB0aGUgd2lubmVyIGNhbiBiZSByZXZlYWxlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciByb2xsaW5nIHRoZSBkaWNlLCBvciB0aGVpciBzZXNzaW9uIGtleQAAAAAAAARyb2xsAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAActMb2NrIGluIHRoZSBwbGF5ZXIncyBzaW5nbGUgcmVyb2xsIHdpdGhvdXQgc2hvd2luZyBpdC4KT25seSBhdmFpbGFibGUgd2hlbiB0aGUgc2Vzc2lvbiBydWxlcyBhbGxvdyBpdC4gYGNvbW1pdG1lbnRgIGlzCmtlY2NhazI1NiBvZiBhIHJhbmRvbSAzMi1ieXRlIHNhbHQgZm9sbG93ZWQgYnkgdGhlIHplcm8tYmFzZWQgaW5kaWNlcwpvZiB0aGUgZGljZSB0byByZXJvbGwsIGVhY2ggYXMgNCBiaWctZW5kaWFuIGJ5dGVzOyBjb21taXR0aW5nIHRvIG5vCmluZGljZXMgZGVjbGluZXMgdGhlIHJlcm9sbC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciByZXJvbGxpbmcsIG9yIHRoZWlyIHNlc3Npb24ga2V5CiogYGNvbW1pdG1lbnRgIC0ga2VjY2FrMjU2KHNhbHQg4oCWIGRpY2UgaW5kaWNlcykAAAAABnJlcm9sbAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAApjb21taXRtZW50AAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAadSZXZlYWwgdGhlIHBsYXllcidzIHJlcm9sbCBvbmNlIGJvdGggcGxheWVycyBoYXZlIGNvbW1pdHRlZC4gVGhlCnNlY29uZCByZXZlYWwgcm9sbHMgdGhlIHNlbGVjdGVkIGRpY2Ugb2YgYm90aCBwbGF5ZXJzLCBzZWVkZWQgd2l0aApib3RoIHNhbHRzLCBzbyBuZWl0aGVyIHJlcm9sbCBpcyBrbm93biB1bnRpbCBpdCBpcyBhcHBsaWVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHJldmVhbGluZywgb3IgdGhlaXIgc2Vzc2lvbiBrZXkKKiBgZGljZWAgLSBaZXJvLWJhc2VkIGluZGljZXMgb2YgdGhlIGRpY2UgdG8gcmVyb2xsLCBhcyBjb21taXR0ZWQKKiBgc2FsdGAgLSBUaGUgc2FsdCBiZWhpbmQgdGhlIGNvbW1pdG1lbnQAAAAADXJldmVhbF9yZXJvbGwAAAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABGRpY2UAAAPqAAAABAAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAW1SZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJvbGxlZCAoYW5kLCB3aGVuIHJlcm9sbHMKYXJlIGFsbG93ZWQsIGJvdGggaGF2ZSByZXZlYWxlZCB0aGVpciByZXJvbGwpLgpUaGlzIHNjb3JlcyBib3RoIHBsYXllcnMnIGRpY2UsIGRldGVybWluZXMgdGhlIHdpbm5lciwgYW5kIGVuZHMgdGhlIHNlc3Npb24uCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBBZGRyZXNzIG9mIHRoZSB3aW5uaW5nIHBsYXllcgAAAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAVhXaW4gYSBnYW1lIHdob3NlIG9wcG9uZW50IHN0YWxsZWQgdGhlIHJlcm9sbDogYWZ0ZXIgdGhlIGRlYWRsaW5lLCBhCnBsYXllciB3aG8gaGFzIGNvbW1pdHRlZCAob3IsIG9uY2UgYm90aCBjb21taXR0ZWQsIHJldmVhbGVkKSB3aW5zIGlmCnRoZSBvdGhlciBoYXMgbm90LgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgY2xhaW1pbmcgcGxheWVyICh0aGUgb25lIHdhaXRpbmcpCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBBZGRyZXNzIG9mIHRoZSB3aW5uaW5nIHBsYXllciAodGhlIGNsYWltYW50KQAAAA1jbGFpbV90aW1lb3V0AAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAATAAAAAw==",
         "AAAAAAAAAJNHZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBkaWNlIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAAe9TdGFydCBhIHRlYW0gZ2FtZS4gRXZlcnkgbWVtYmVyIHNpZ25zIHRoZSBzZXNzaW9uIGFuZCB0aGVpciBvd24gcG9pbnRzCnRvZ2V0aGVyIHdpdGggdGhlIHJ1bGVzLiBSZXJvbGxzIGFyZSBub3QgYXZhaWxhYmxlIGluIHRlYW0gbW9kZS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVW5pcXVlIHNlc3Npb24gaWRlbnRpZmllciAodTMyKQoqIGB0ZWFtMWAgLSBNZW1iZXJzIG9mIHRoZSBmaXJzdCB0ZWFtICgxLi49NCkKKiBgdGVhbTFfcG9pbnRzYCAtIFBvaW50cyBjb21taXR0ZWQgYnkgZWFjaCBtZW1iZXIgb2YgdGVhbSAxLCBzYW1lIG9yZGVyCiogYHRlYW0yYCAtIE1lbWJlcnMgb2YgdGhlIHNlY29uZCB0ZWFtICgxLi49NCkKKiBgdGVhbTJfcG9pbnRzYCAtIFBvaW50cyBjb21taXR0ZWQgYnkgZWFjaCBtZW1iZXIgb2YgdGVhbSAyLCBzYW1lIG9yZGVyCiogYHJ1bGVzYCAtIERpY2UgY291bnQsIGZhY2VzIGFuZCBzY29yaW5nIGZvciBldmVyeSBtZW1iZXIAAAAAD3N0YXJ0X3RlYW1fZ2FtZQAAAAAGAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAV0ZWFtMQAAAAAAA+oAAAATAAAAAAAAAAx0ZWFtMV9wb2ludHMAAAPqAAAACwAAAAAAAAAFdGVhbTIAAAAAAAPqAAAAEwAAAAAAAAAMdGVhbTJfcG9pbnRzAAAD6gAAAAsAAAAAAAAABXJ1bGVzAAAAAAAH0AAAAAlEaWNlUnVsZXMAAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAJdSb2xsIGEgdGVhbSBtZW1iZXIncyBkaWNlLiBFdmVyeSBtZW1iZXIgcm9sbHMgb25jZS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIG1lbWJlciByb2xsaW5nAAAAAAlyb2xsX3RlYW0AAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAARZBZGQgdXAgZWFjaCB0ZWFtJ3Mgc2NvcmVzIG9uY2UgZXZlcnkgbWVtYmVyIGhhcyByb2xsZWQgYW5kIHJlcG9ydCB0aGUKd2lubmluZyB0ZWFtIHRvIHRoZSBHYW1lIEh1Yiwgd2hpY2ggc3BsaXRzIHRoZSB3aW5uaW5ncyBhbW9uZyBpdHMKbWVtYmVycy4gVGllcyBnbyB0byB0ZWFtIDEuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgVmVjPEFkZHJlc3M+YCAtIE1lbWJlcnMgb2YgdGhlIHdpbm5pbmcgdGVhbQAAAAAAEnJldmVhbF90ZWFtX3dpbm5lcgAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD6gAAABMAAAAD",
+        "AAAAAAAAAKRHZXQgdGVhbSBnYW1lIGluZm9ybWF0aW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYFRlYW1HYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyB0ZWFtIHRvdGFscyBhZnRlciB0aGUgcmV2ZWFsKQAAAA1nZXRfdGVhbV9nYW1lAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAAhUZWFtR2FtZQAAAAM=",
+        "AAAAAAAAAfZMZXQgYSBzaG9ydC1saXZlZCBrZXkgc2lnbiBgcm9sbGAsIGByZXJvbGxgIGFuZCBgcmV2ZWFsX3Jlcm9sbGAgZm9yIGEKcGxheWVyLCBzbyB0aGUgbWFpbiB3YWxsZXQgaXMgbm90IHByb21wdGVkIGZvciBldmVyeSBtb3ZlLiBVc3VhbGx5IGRvbmUKdGhyb3VnaCBgc3RhcnRfZ2FtZWA7IHRoaXMgY2FsbCBpcyBmb3IgcmVuZXdpbmcgb3IgYWRkaW5nIGEga2V5IG9uY2UgdGhlCmdhbWUgaXMgdW5kZXIgd2F5LgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gVGhlIHBsYXllciBkZWxlZ2F0aW5nCiogYHNlc3Npb25fa2V5YCAtIFRoZSBrZXkgdGhhdCB3aWxsIHNpZ24gbW92ZXM7IGFsc28gYXV0aG9yaXplcyB0aGlzIGNhbGwKKiBgZXhwaXJhdGlvbl9sZWRnZXJgIC0gTGFzdCBsZWRnZXIgdGhlIGtleSBpcyBhY2NlcHRlZCBhdCwgYXQgbW9zdApgTUFYX1NFU1NJT05fS0VZX0xFREdFUlNgIGZyb20gbm93AAAAAAAUZGVsZWdhdGVfc2Vzc2lvbl9rZXkAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC3Nlc3Npb25fa2V5AAAAABMAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
         "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAThSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJvbGxlZC4KVGhpcyBnZW5lcmF0ZXMgZGljZSByb2xscyBmb3IgYm90aCBwbGF5ZXJzLCBkZXRlcm1pbmVzIHRoZSB3aW5uZXIsIGFuZCBlbmRzIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAANcmV2ZWFsX3dpbm5lcgAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAABMAAAAD" ]),
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    roll: this.txFromJSON<Result<void>>,
-        get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+    start_game: this.txFromJSON<Result<void>>,
+        start_game_with_rules: this.txFromJSON<Result<void>>,
+        roll: this.txFromJSON<Result<void>>,
+        reroll: this.txFromJSON<Result<void>>,
+        reveal_reroll: this.txFromJSON<Result<void>>,
+        reveal_winner: this.txFromJSON<Result<string>>,
+        claim_timeout: this.txFromJSON<Result<string>>,
         get_game: this.txFromJSON<Result<Game>>,
+        start_team_game: this.txFromJSON<Result<void>>,
+        roll_team: this.txFromJSON<Result<void>>,
+        reveal_team_winner: this.txFromJSON<Result<Array<string>>>,
+        get_team_game: this.txFromJSON<Result<TeamGame>>,
+        delegate_session_key: this.txFromJSON<Result<void>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
-import { Client as DiceDuelClient, type Game } from './bindings';
+import { Client as DiceDuelClient, type Game, type SessionKey } from './bindings';
 import { NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS, DEFAULT_AUTH_TTL_MINUTES, MULTI_SIG_AUTH_TTL_MINUTES } from '@/utils/constants';
-import { contract, TransactionBuilder, StrKey, xdr, Address, authorizeEntry } from '@stellar/stellar-sdk';
+import { contract, TransactionBuilder, StrKey, xdr, Address, authorizeEntry, scValToNative } from '@stellar/stellar-sdk';
 import { Buffer } from 'buffer';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
@@ -77,7 +77,8 @@ export class DiceDuelService {
     player1Points: bigint,
     player2Points: bigint,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
-    authTtlMinutes?: number
+    authTtlMinutes?: number,
+    sessionKeys: Map<string, SessionKey> = new Map()
   ) {
     const client = this.createSigningClient(player1, signer);
     const tx = await client.start_game({
@@ -86,6 +87,7 @@ export class DiceDuelService {
       player2,
       player1_points: player1Points,
       player2_points: player2Points,
+      session_keys: sessionKeys,
     }, DEFAULT_METHOD_OPTIONS);
 
     const validUntilLedgerSeq = authTtlMinutes
@@ -118,7 +120,8 @@ export class DiceDuelService {
     player1Points: bigint,
     player2Points: bigint,
     player1Signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
-    authTtlMinutes?: number
+    authTtlMinutes?: number,
+    sessionKeys: Map<string, SessionKey> = new Map()
   ): Promise<string> {
     // Step 1: Build transaction with Player 2 as the source (no signing capabilities needed yet)
     const buildClient = new DiceDuelClient({
@@ -134,6 +137,7 @@ export class DiceDuelService {
       player2,
       player1_points: player1Points,
       player2_points: player2Points,
+      session_keys: sessionKeys,
     }, DEFAULT_METHOD_OPTIONS);
     // NOTE: Contract methods automatically simulate - no need to call tx.simulate() again!
     console.log('[prepareStartGame] Transaction built and simulated, extracting auth entries');
@@ -235,11 +239,13 @@ export class DiceDuelService {
    * - Player address (from credentials)
    * - Session ID (arg 0)
    * - Player's points (arg 1)
+   * - Session keys (arg 2)
    */
   parseAuthEntry(authEntryXdr: string): {
     sessionId: number;
     player1: string;
     player1Points: bigint;
+    sessionKeys: Map<string, SessionKey>;
     functionName: string;
   } {
     try {
@@ -281,15 +287,18 @@ export class DiceDuelService {
       // For start_game with require_auth_for_args, we have:
       // 0: session_id (u32)
       // 1: player_points (i128)
+      // 2: session_keys (Map<Address, SessionKey>)
       const args = contractFn.args();
       console.log('[parseAuthEntry] Number of args:', args.length);
 
-      if (args.length !== 2) {
-        throw new Error(`Expected 2 arguments for start_game auth entry, got ${args.length}`);
+      if (args.length !== 3) {
+        throw new Error(`Expected 3 arguments for start_game auth entry, got ${args.length}`);
       }
 
       const sessionId = args[0].u32();
       const player1Points = args[1].i128().lo().toBigInt();
+      // Player 2 must rebuild with exactly the keys Player 1 signed for
+      const sessionKeys = new Map(Object.entries(scValToNative(args[2]) ?? {})) as Map<string, SessionKey>;
 
       console.log('[parseAuthEntry] Extracted:', {
         sessionId,
@@ -301,6 +310,7 @@ export class DiceDuelService {
         sessionId,
         player1,
         player1Points,
+        sessionKeys,
         functionName,
       };
     } catch (err: any) {
@@ -369,6 +379,7 @@ export class DiceDuelService {
       player2: player2Address,             // Provided by Player 2
       player1_points: gameParams.player1Points, // From auth entry
       player2_points: player2Points,         // Provided by Player 2
+      session_keys: gameParams.sessionKeys,  // From auth entry
     }, DEFAULT_METHOD_OPTIONS);
     // NOTE: Contract methods automatically simulate - no need to call tx.simulate() again!
 
@@ -535,13 +546,14 @@ export class DiceDuelService {
     // 2: player2 (Address)
     // 3: player1_points (i128)
     // 4: player2_points (i128)
+    // 5: session_keys (Map<Address, SessionKey>)
 
     if (functionName !== 'start_game') {
       throw new Error(`Unexpected function: ${functionName}. Expected start_game.`);
     }
 
-    if (args.length !== 5) {
-      throw new Error(`Expected 5 arguments for start_game, got ${args.length}`);
+    if (args.length !== 6) {
+      throw new Error(`Expected 6 arguments for start_game, got ${args.length}`);
     }
 
     // Extract session_id (u32)
@@ -122,7 +122,7 @@ export function NumberGuessGame({
       setGameState(game);
 
       // Determine game phase based on state
-      if (game && game.winner !== null && game.winner !== undefined) {
+      if (game && (game.draw || (game.winner !== null && game.winner !== undefined))) {
         setGamePhase('complete');
       } else if (game && game.player1_guess !== null && game.player1_guess !== undefined &&
                  game.player2_guess !== null && game.player2_guess !== undefined) {
@@ -146,11 +146,11 @@ export function NumberGuessGame({
 
   // Auto-refresh standings when game completes (for passive player who didn't call reveal_winner)
   useEffect(() => {
-    if (gamePhase === 'complete' && gameState?.winner) {
+    if (gamePhase === 'complete' && (gameState?.winner || gameState?.draw)) {
       console.log('Game completed! Refreshing standings and dashboard data...');
       onStandingsRefresh(); // Refresh standings and available points; don't call onGameComplete() here or it will close the game!
     }
-  }, [gamePhase, gameState?.winner]);
+  }, [gamePhase, gameState?.winner, gameState?.draw]);
 
   // Handle initial values from URL deep linking or props
   // Expected URL formats:
@@ -690,7 +690,11 @@ export function NumberGuessGame({
         setLoadSessionId('');
 
         // Determine game phase based on game state
-        if (game.winner !== null && game.winner !== undefined) {
+        if (game.draw) {
+          // Game is complete - nobody won
+          setGamePhase('reveal');
+          setSuccess('Game complete. It was a draw.');
+        } else if (game.winner !== null && game.winner !== undefined) {
           // Game is complete - show reveal phase with winner
           setGamePhase('reveal');
           const isWinner = game.winner === userAddress;
@@ -794,7 +798,7 @@ export function NumberGuessGame({
   const waitForWinner = async () => {
     let updatedGame = await numberGuessService.getGame(sessionId);
     let attempts = 0;
-    while (attempts < 5 && (!updatedGame || updatedGame.winner === null || updatedGame.winner === undefined)) {
+    while (attempts < 5 && (!updatedGame || (!updatedGame.draw && (updatedGame.winner === null || updatedGame.winner === undefined)))) {
       await new Promise((resolve) => setTimeout(resolve, 1000));
       updatedGame = await numberGuessService.getGame(sessionId);
       attempts += 1;
@@ -810,15 +814,18 @@ export function NumberGuessGame({
         setSuccess(null);
 
         const signer = getContractSigner();
-        await numberGuessService.revealWinner(sessionId, userAddress, signer);
+        const winner = await numberGuessService.revealWinner(sessionId, userAddress, signer);
 
-        // Fetch updated on-chain state and derive the winner from it (avoid type mismatches from tx result decoding).
+        // Fetch updated on-chain state once the session has ended
         const updatedGame = await waitForWinner();
         setGameState(updatedGame);
         setGamePhase('complete');
 
-        const isWinner = updatedGame?.winner === userAddress;
-        setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
+        if (winner === undefined) {
+          setSuccess('Game complete! It was a draw.');
+        } else {
+          setSuccess(winner === userAddress ? '🎉 You won!' : 'Game complete! Winner revealed.');
+        }
 
         // Refresh standings immediately (without navigating away)
         onStandingsRefresh();
@@ -867,8 +874,8 @@ export function NumberGuessGame({
         </div>
         <button
           onClick={() => {
-            // If game is complete (has winner), refresh stats before going back
-            if (gameState?.winner) {
+            // If game is complete, refresh stats before going back
+            if (gameState?.winner || gameState?.draw) {
               onGameComplete();
             }
             onBack();
@@ -1374,6 +1381,14 @@ export function NumberGuessGame({
                 )}
               </div>
             )}
+            {gameState.draw && (
+              <div className="mt-6 p-5 bg-white border-2 border-gray-200 rounded-xl shadow-lg">
+                <p className="text-xs font-bold uppercase tracking-wide text-gray-600 mb-2">Draw</p>
+                <p className="text-sm font-semibold text-gray-800">
+                  Nobody won this game.
+                </p>
+              </div>
+            )}
           </div>
           <button
             onClick={onBack}
@@ -715,14 +715,21 @@ export function TwentyOneGame({
         setSuccess(null);
 
         const signer = getContractSigner();
-        const winnerResult = await twentyOneService.revealWinner(sessionId, userAddress, signer);
+        const outcome = await twentyOneService.revealWinner(sessionId, userAddress, signer);
 
-        await waitForWinner();
-        await loadGameState();
-
-        const winner = (winnerResult as any).unwrap ? (winnerResult as any).unwrap() : winnerResult;
-        const isWinner = normalizeAddress(winner) === normalizedUserAddress;
-        setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
+        if (outcome.tag === 'Winner') {
+          await waitForWinner();
+          await loadGameState();
+          const isWinner = normalizeAddress(outcome.values[0]) === normalizedUserAddress;
+          setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
+        } else {
+          await loadGameState();
+          setSuccess(
+            outcome.tag === 'Redeal'
+              ? `Tied hands! New cards dealt for round ${outcome.values[0]}.`
+              : 'Tied hands! The pot is split.'
+          );
+        }
 
         onStandingsRefresh();
       } catch (err) {
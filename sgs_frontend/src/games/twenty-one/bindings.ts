@@ -39,19 +39,6 @@ export const networks = {
 } as const
 
 
-export interface Game {
-  player1: string;
-  player1_hand: Buffer;
-  player1_points: i128;
-  player1_stuck: boolean;
-  player2: string;
-  player2_hand: Buffer;
-  player2_points: i128;
-  player2_stuck: boolean;
-  round: u32;
-  winner: Option<string>;
-}
-
 export const Errors = {
   1: {message:"GameNotFound"},
   2: {message:"NotPlayer"},
@@ -63,12 +50,94 @@ export const Errors = {
   8: {message:"Draw"},
   9: {message:"SelfPlay"},
   10: {message:"RoundOverflow"},
-  11: {message:"InvalidHandData"}
+  11: {message:"InvalidHandData"},
+  12: {message:"InvalidSessionKey"}
+}
+
+/**
+ * How `reveal_winner` resolves equal hand totals. Chosen per session at start.
+ */
+export type DrawPolicy = {tag: "Redeal", values: readonly [u32]} | {tag: "SplitPot", values: void} | {tag: "Player1Wins", values: void};
+
+/**
+ * Result of a `reveal_winner` call.
+ */
+export type Outcome = {tag: "Winner", values: readonly [string]} | {tag: "Redeal", values: readonly [u32]} | {tag: "Split", values: void};
+
+export interface Game {
+  /**
+   * Drawn from the network PRNG at the start and mixed into every hit
+   */
+  deck_seed: Buffer;
+  draw_policy: DrawPolicy;
+  player1: string;
+  player1_hand: Buffer;
+  player1_points: i128;
+  player1_stuck: boolean;
+  player2: string;
+  player2_hand: Buffer;
+  player2_points: i128;
+  player2_stuck: boolean;
+  round: u32;
+  split: boolean;
+  winner: Option<string>;
 }
 
 export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
 
+/**
+ * A short-lived key a player lets sign their moves in one game
+ */
+export interface SessionKey {
+  /**
+   * Last ledger the key is accepted at
+   */
+  expiration_ledger: u32;
+  /**
+   * The player the key moves for
+   */
+  player: string;
+}
+
 export interface Client {
+  /**
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
+   * Each player is dealt 2 cards to start.
+   * 
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `session_keys` - Move keys to delegate up front, each mapped to its
+   * player and expiry (see `delegate_session_key`); may be empty
+   */
+  start_game: ({session_id, player1, player2, player1_points, player2_points, session_keys}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, session_keys: Map<string, SessionKey>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_game_with_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game with an explicit draw policy.
+   * Behaves like `start_game`, but both players also sign the policy that
+   * decides what happens when their final hands are equal.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `draw_policy` - How equal totals are resolved
+   * * `session_keys` - Move keys to delegate up front, each mapped to its
+   * player and expiry (see `delegate_session_key`); may be empty
+   */
+  start_game_with_policy: ({session_id, player1, player2, player1_points, player2_points, draw_policy, session_keys}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, draw_policy: DrawPolicy, session_keys: Map<string, SessionKey>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a hit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Player draws another card ("hit").
@@ -76,7 +145,7 @@ export interface Client {
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
-   * * `player` - Address of the player drawing a card
+   * * `player` - Address of the player drawing a card, or their session key
    */
   hit: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
@@ -87,48 +156,66 @@ export interface Client {
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
-   * * `player` - Address of the player sticking
+   * * `player` - Address of the player sticking, or their session key
    */
   stick: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the winner of the game and submit outcome to GameHub.
+   * Can only be called after both players have stuck.
+   * This calculates hand values and determines the winner (closest to 21).
+   * Equal totals are resolved by the session's `DrawPolicy`: a redeal is
+   * stored and reported as `Outcome::Redeal`, so players can play the new
+   * round without the state change being rolled back.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
    * 
    * # Returns
-   * * `Address` - The GameHub contract address
+   * * `Outcome` - The winner, a redeal (with the new round), or a split pot
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Outcome>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get game information.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Game` - The game state (includes hands and winner after game ends)
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a get_hand_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current hand value for a player.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player
+   * 
+   * # Returns
+   * * `u32` - The total value of the player's hand
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_hand_value: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get game information.
+   * Construct and simulate a delegate_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Let a short-lived key sign `hit` and `stick` for a player, so the main
+   * wallet is not prompted for every move. Usually done through `start_game`;
+   * this call is for renewing or adding a key once the game is under way.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
-   * 
-   * # Returns
-   * * `Game` - The game state (includes hands and winner after game ends)
+   * * `player` - The player delegating
+   * * `session_key` - The key that will sign moves; also authorizes this call
+   * * `expiration_ledger` - Last ledger the key is accepted at, at most
+   * `MAX_SESSION_KEY_LEDGERS` from now
    */
-  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
+  delegate_session_key: ({session_id, player, session_key, expiration_ledger}: {session_id: u32, player: string, session_key: string, expiration_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -149,50 +236,31 @@ export interface Client {
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
-   * Each player is dealt 2 cards to start.
-   * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
    * 
-   * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * # Returns
+   * * `Address` - The GameHub contract address
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have stuck.
-   * This calculates hand values, determines the winner (closest to 21),
-   * and handles draws by dealing new hands.
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
-   * 
-   * # Returns
-   * * `Address` - Address of the winning player
+   * * `new_hub` - The new GameHub contract address
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_hand_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current hand value for a player.
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract)
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
-   * * `player` - Address of the player
-   * 
-   * # Returns
-   * * `u32` - The total value of the player's hand
+   * * `new_wasm_hash` - The hash of the new WASM binary
    */
-  get_hand_value: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -214,35 +282,42 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAKAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfaGFuZAAAAA4AAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA1wbGF5ZXIxX3N0dWNrAAAAAAAAAQAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAxwbGF5ZXIyX2hhbmQAAAAOAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMl9zdHVjawAAAAAAAAEAAAAAAAAABXJvdW5kAAAAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAADFBsYXllckJ1c3RlZAAAAAUAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAQT3Bwb25lbnROb3RTdHVjawAAAAcAAAAAAAAABERyYXcAAAAIAAAAAAAAAAhTZWxmUGxheQAAAAkAAAAAAAAADVJvdW5kT3ZlcmZsb3cAAAAAAAAKAAAAAAAAAA9JbnZhbGlkSGFuZERhdGEAAAAACw==",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADAAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAADFBsYXllckJ1c3RlZAAAAAUAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAQT3Bwb25lbnROb3RTdHVjawAAAAcAAAB8UmVzZXJ2ZWQ6IG5vIGxvbmdlciByZXR1cm5lZCwgc2luY2UgYSB0aWUgaXMgbm93IHNldHRsZWQgYnkgdGhlCnNlc3Npb24ncyBgRHJhd1BvbGljeWAuIEtlcHQgc28gdGhlIG90aGVyIGNvZGVzIHN0YXkgc3RhYmxlLgAAAAREcmF3AAAACAAAAAAAAAAIU2VsZlBsYXkAAAAJAAAAAAAAAA1Sb3VuZE92ZXJmbG93AAAAAAAACgAAAAAAAAAPSW52YWxpZEhhbmREYXRhAAAAAAsAAAAAAAAAEUludmFsaWRTZXNzaW9uS2V5AAAAAAAADA==",
+        "AAAAAgAAAExIb3cgYHJldmVhbF93aW5uZXJgIHJlc29sdmVzIGVxdWFsIGhhbmQgdG90YWxzLiBDaG9zZW4gcGVyIHNlc3Npb24gYXQgc3RhcnQuAAAAAAAAAApEcmF3UG9saWN5AAAAAAADAAAAAQAAAFdEZWFsIGZyZXNoIGhhbmRzLCBhdCBtb3N0IHRoaXMgbWFueSB0aW1lcy4gQSBkcmF3IGFmdGVyIHRoZSBsYXN0CnJlZGVhbCBzcGxpdHMgdGhlIHBvdC4AAAAABlJlZGVhbAAAAAAAAQAAAAQAAAAAAAAAMUVuZCB0aGUgc2Vzc2lvbiBhcyBhIGRyYXcgYW5kIHJldHVybiBib3RoIHN0YWtlcy4AAAAAAAAIU3BsaXRQb3QAAAAAAAAAFFRpZXMgZ28gdG8gcGxheWVyIDEuAAAAC1BsYXllcjFXaW5zAA==",
+        "AAAAAgAAACFSZXN1bHQgb2YgYSBgcmV2ZWFsX3dpbm5lcmAgY2FsbC4AAAAAAAAAAAAAB091dGNvbWUAAAAAAwAAAAEAAAAgVGhlIGdhbWUgZW5kZWQgd2l0aCB0aGlzIHdpbm5lci4AAAAGV2lubmVyAAAAAAABAAAAEwAAAAEAAAA6SGFuZHMgd2VyZSB0aWVkIGFuZCByZWRlYWx0LiBDYXJyaWVzIHRoZSBuZXcgcm91bmQgbnVtYmVyLgAAAAAABlJlZGVhbAAAAAAAAQAAAAQAAAAAAAAAL1RoZSBnYW1lIGVuZGVkIGFzIGEgZHJhdyBhbmQgdGhlIHBvdCB3YXMgc3BsaXQuAAAAAAVTcGxpdAAAAA==",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAANAAAAQURyYXduIGZyb20gdGhlIG5ldHdvcmsgUFJORyBhdCB0aGUgc3RhcnQgYW5kIG1peGVkIGludG8gZXZlcnkgaGl0AAAAAAAACWRlY2tfc2VlZAAAAAAAA+4AAAAgAAAAAAAAAAtkcmF3X3BvbGljeQAAAAfQAAAACkRyYXdQb2xpY3kAAAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAxwbGF5ZXIxX2hhbmQAAAAOAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMV9zdHVjawAAAAAAAAEAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAMcGxheWVyMl9oYW5kAAAADgAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADXBsYXllcjJfc3R1Y2sAAAAAAAABAAAAAAAAAAVyb3VuZAAAAAAAAAQAAAAAAAAABXNwbGl0AAAAAAAAAQAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
         "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAANRQbGF5ZXIgZHJhd3MgYW5vdGhlciBjYXJkICgiaGl0IikuCklmIHRoZSBwbGF5ZXIncyBoYW5kIHZhbHVlIGV4Y2VlZHMgMjEsIHRoZXkgYnVzdCBhbmQgbG9zZSBpbW1lZGlhdGVseS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBkcmF3aW5nIGEgY2FyZAAAAANoaXQAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAANZQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpJZiBib3RoIHBsYXllcnMgaGF2ZSBzdHVjaywgdGhlIGdhbWUgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN0aWNraW5nAAAAAAAFc3RpY2sAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+        "AAAAAQAAADxBIHNob3J0LWxpdmVkIGtleSBhIHBsYXllciBsZXRzIHNpZ24gdGhlaXIgbW92ZXMgaW4gb25lIGdhbWUAAAAAAAAAClNlc3Npb25LZXkAAAAAAAIAAAAiTGFzdCBsZWRnZXIgdGhlIGtleSBpcyBhY2NlcHRlZCBhdAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAABxUaGUgcGxheWVyIHRoZSBrZXkgbW92ZXMgZm9yAAAABnBsYXllcgAAAAAAEw==",
+        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
+        "AAAAAAAAAtRTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpFYWNoIHBsYXllciBpcyBkZWFsdCAyIGNhcmRzIHRvIHN0YXJ0LgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgoqIGBzZXNzaW9uX2tleXNgIC0gTW92ZSBrZXlzIHRvIGRlbGVnYXRlIHVwIGZyb250LCBlYWNoIG1hcHBlZCB0byBpdHMKcGxheWVyIGFuZCBleHBpcnkgKHNlZSBgZGVsZWdhdGVfc2Vzc2lvbl9rZXlgKTsgbWF5IGJlIGVtcHR5AAAACnN0YXJ0X2dhbWUAAAAAAAYAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADHNlc3Npb25fa2V5cwAAA+wAAAATAAAH0AAAAApTZXNzaW9uS2V5AAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAltTdGFydCBhIG5ldyBnYW1lIHdpdGggYW4gZXhwbGljaXQgZHJhdyBwb2xpY3kuCkJlaGF2ZXMgbGlrZSBgc3RhcnRfZ2FtZWAsIGJ1dCBib3RoIHBsYXllcnMgYWxzbyBzaWduIHRoZSBwb2xpY3kgdGhhdApkZWNpZGVzIHdoYXQgaGFwcGVucyB3aGVuIHRoZWlyIGZpbmFsIGhhbmRzIGFyZSBlcXVhbC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVW5pcXVlIHNlc3Npb24gaWRlbnRpZmllciAodTMyKQoqIGBwbGF5ZXIxYCAtIEFkZHJlc3Mgb2YgZmlyc3QgcGxheWVyCiogYHBsYXllcjJgIC0gQWRkcmVzcyBvZiBzZWNvbmQgcGxheWVyCiogYHBsYXllcjFfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAxCiogYHBsYXllcjJfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAyCiogYGRyYXdfcG9saWN5YCAtIEhvdyBlcXVhbCB0b3RhbHMgYXJlIHJlc29sdmVkCiogYHNlc3Npb25fa2V5c2AgLSBNb3ZlIGtleXMgdG8gZGVsZWdhdGUgdXAgZnJvbnQsIGVhY2ggbWFwcGVkIHRvIGl0cwpwbGF5ZXIgYW5kIGV4cGlyeSAoc2VlIGBkZWxlZ2F0ZV9zZXNzaW9uX2tleWApOyBtYXkgYmUgZW1wdHkAAAAAFnN0YXJ0X2dhbWVfd2l0aF9wb2xpY3kAAAAAAAcAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAAC2RyYXdfcG9saWN5AAAAB9AAAAAKRHJhd1BvbGljeQAAAAAAAAAAAAxzZXNzaW9uX2tleXMAAAPsAAAAEwAAB9AAAAAKU2Vzc2lvbktleQAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAOpQbGF5ZXIgZHJhd3MgYW5vdGhlciBjYXJkICgiaGl0IikuCklmIHRoZSBwbGF5ZXIncyBoYW5kIHZhbHVlIGV4Y2VlZHMgMjEsIHRoZXkgYnVzdCBhbmQgbG9zZSBpbW1lZGlhdGVseS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBkcmF3aW5nIGEgY2FyZCwgb3IgdGhlaXIgc2Vzc2lvbiBrZXkAAAAAAANoaXQAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAOxQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpJZiBib3RoIHBsYXllcnMgaGF2ZSBzdHVjaywgdGhlIGdhbWUgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN0aWNraW5nLCBvciB0aGVpciBzZXNzaW9uIGtleQAAAAVzdGljawAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAf5SZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHN0dWNrLgpUaGlzIGNhbGN1bGF0ZXMgaGFuZCB2YWx1ZXMgYW5kIGRldGVybWluZXMgdGhlIHdpbm5lciAoY2xvc2VzdCB0byAyMSkuCkVxdWFsIHRvdGFscyBhcmUgcmVzb2x2ZWQgYnkgdGhlIHNlc3Npb24ncyBgRHJhd1BvbGljeWA6IGEgcmVkZWFsIGlzCnN0b3JlZCBhbmQgcmVwb3J0ZWQgYXMgYE91dGNvbWU6OlJlZGVhbGAsIHNvIHBsYXllcnMgY2FuIHBsYXkgdGhlIG5ldwpyb3VuZCB3aXRob3V0IHRoZSBzdGF0ZSBjaGFuZ2UgYmVpbmcgcm9sbGVkIGJhY2suCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgT3V0Y29tZWAgLSBUaGUgd2lubmVyLCBhIHJlZGVhbCAod2l0aCB0aGUgbmV3IHJvdW5kKSwgb3IgYSBzcGxpdCBwb3QAAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAAdPdXRjb21lAAAAAAM=",
         "AAAAAAAAAJ9HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBoYW5kcyBhbmQgd2lubmVyIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAAL5HZXQgdGhlIGN1cnJlbnQgaGFuZCB2YWx1ZSBmb3IgYSBwbGF5ZXIuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHRvdGFsIHZhbHVlIG9mIHRoZSBwbGF5ZXIncyBoYW5kAAAAAAAOZ2V0X2hhbmRfdmFsdWUAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAeNMZXQgYSBzaG9ydC1saXZlZCBrZXkgc2lnbiBgaGl0YCBhbmQgYHN0aWNrYCBmb3IgYSBwbGF5ZXIsIHNvIHRoZSBtYWluCndhbGxldCBpcyBub3QgcHJvbXB0ZWQgZm9yIGV2ZXJ5IG1vdmUuIFVzdWFsbHkgZG9uZSB0aHJvdWdoIGBzdGFydF9nYW1lYDsKdGhpcyBjYWxsIGlzIGZvciByZW5ld2luZyBvciBhZGRpbmcgYSBrZXkgb25jZSB0aGUgZ2FtZSBpcyB1bmRlciB3YXkuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBUaGUgcGxheWVyIGRlbGVnYXRpbmcKKiBgc2Vzc2lvbl9rZXlgIC0gVGhlIGtleSB0aGF0IHdpbGwgc2lnbiBtb3ZlczsgYWxzbyBhdXRob3JpemVzIHRoaXMgY2FsbAoqIGBleHBpcmF0aW9uX2xlZGdlcmAgLSBMYXN0IGxlZGdlciB0aGUga2V5IGlzIGFjY2VwdGVkIGF0LCBhdCBtb3N0CmBNQVhfU0VTU0lPTl9LRVlfTEVER0VSU2AgZnJvbSBub3cAAAAAFGRlbGVnYXRlX3Nlc3Npb25fa2V5AAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAtzZXNzaW9uX2tleQAAAAATAAAAAAAAABFleHBpcmF0aW9uX2xlZGdlcgAAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
         "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAlFTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpFYWNoIHBsYXllciBpcyBkZWFsdCAyIGNhcmRzIHRvIHN0YXJ0LgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAUpSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHN0dWNrLgpUaGlzIGNhbGN1bGF0ZXMgaGFuZCB2YWx1ZXMsIGRldGVybWluZXMgdGhlIHdpbm5lciAoY2xvc2VzdCB0byAyMSksCmFuZCBoYW5kbGVzIGRyYXdzIGJ5IGRlYWxpbmcgbmV3IGhhbmRzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=",
-        "AAAAAAAAAL5HZXQgdGhlIGN1cnJlbnQgaGFuZCB2YWx1ZSBmb3IgYSBwbGF5ZXIuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHRvdGFsIHZhbHVlIG9mIHRoZSBwbGF5ZXIncyBoYW5kAAAAAAAOZ2V0X2hhbmRfdmFsdWUAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=" ]),
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    hit: this.txFromJSON<Result<void>>,
+    start_game: this.txFromJSON<Result<void>>,
+        start_game_with_policy: this.txFromJSON<Result<void>>,
+        hit: this.txFromJSON<Result<void>>,
         stick: this.txFromJSON<Result<void>>,
-        get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+        reveal_winner: this.txFromJSON<Result<Outcome>>,
         get_game: this.txFromJSON<Result<Game>>,
+        get_hand_value: this.txFromJSON<Result<u32>>,
+        delegate_session_key: this.txFromJSON<Result<void>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>,
-        get_hand_value: this.txFromJSON<Result<u32>>
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
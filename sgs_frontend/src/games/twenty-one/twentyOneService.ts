@@ -1,6 +1,6 @@
-import { Client as TwentyOneClient } from './bindings';
+import { Client as TwentyOneClient, type Outcome, type SessionKey } from './bindings';
 import { TWENTY_ONE_CONTRACT, NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS, DEFAULT_AUTH_TTL_MINUTES, MULTI_SIG_AUTH_TTL_MINUTES } from '@/utils/constants';
-import { contract, Address, authorizeEntry, xdr } from '@stellar/stellar-sdk';
+import { contract, Address, authorizeEntry, xdr, scValToNative } from '@stellar/stellar-sdk';
 import { Buffer } from 'buffer';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
@@ -97,7 +97,8 @@ export class TwentyOneService {
     player1Points: bigint,
     player2Points: bigint,
     player1Signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
-    authTtlMinutes?: number
+    authTtlMinutes?: number,
+    sessionKeys: Map<string, SessionKey> = new Map()
   ): Promise<string> {
     // Build transaction with Player 2 as the source
     const buildClient = new TwentyOneClient({
@@ -113,6 +114,7 @@ export class TwentyOneService {
       player2,
       player1_points: player1Points,
       player2_points: player2Points,
+      session_keys: sessionKeys,
     }, DEFAULT_METHOD_OPTIONS);
 
     console.log('[prepareStartGame] Transaction built and simulated');
@@ -225,6 +227,7 @@ export class TwentyOneService {
       player2,
       player1_points: gameParams.player1Points,
       player2_points: player2Points,
+      session_keys: gameParams.sessionKeys,
     }, DEFAULT_METHOD_OPTIONS);
 
     // Import Player 1's signed auth entry
@@ -266,6 +269,7 @@ export class TwentyOneService {
     sessionId: number;
     player1: string;
     player1Points: bigint;
+    sessionKeys: Map<string, SessionKey>;
   } {
     try {
       const authEntry = xdr.SorobanAuthorizationEntry.fromXDR(authEntryXDR, 'base64');
@@ -274,6 +278,7 @@ export class TwentyOneService {
       // - signer address (from credentials)
       // - session_id (arg 0)
       // - signer points (arg 1)
+      // - session keys (arg 2)
       const credentials = authEntry.credentials();
       if (credentials.switch().name !== 'sorobanCredentialsAddress') {
         throw new Error(`Unsupported credentials type: ${credentials.switch().name}`);
@@ -290,17 +295,20 @@ export class TwentyOneService {
       }
 
       const args = contractFn.args();
-      if (args.length !== 2) {
-        throw new Error(`Invalid number of arguments: ${args.length}. Expected 2`);
+      if (args.length !== 3) {
+        throw new Error(`Invalid number of arguments: ${args.length}. Expected 3`);
       }
 
       const sessionId = args[0].u32();
       const player1Points = args[1].i128().lo().toBigInt();
+      // Player 2 must rebuild with exactly the keys Player 1 signed for
+      const sessionKeys = new Map(Object.entries(scValToNative(args[2]) ?? {})) as Map<string, SessionKey>;
 
       return {
         sessionId,
         player1,
         player1Points,
+        sessionKeys,
       };
     } catch (err) {
       console.error('[parseAuthEntry] Error parsing auth entry:', err);
@@ -361,7 +369,7 @@ export class TwentyOneService {
     sessionId: number,
     player: string,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
-  ) {
+  ): Promise<Outcome> {
     const client = this.createSigningClient(player, signer);
     const tx = await client.reveal_winner({
       session_id: sessionId,
@@ -373,7 +381,8 @@ export class TwentyOneService {
       DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
       validUntilLedgerSeq
     );
-    return sentTx.result;
+    // A winner, a redeal of tied hands, or a split pot
+    return sentTx.result.unwrap();
   }
 }
 
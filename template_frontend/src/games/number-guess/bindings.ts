@@ -39,7 +39,52 @@ export const networks = {
 } as const
 
 
+export const Errors = {
+  1: {message:"GameNotFound"},
+  2: {message:"NotPlayer"},
+  3: {message:"AlreadyGuessed"},
+  4: {message:"BothPlayersNotGuessed"},
+  5: {message:"GameAlreadyEnded"},
+  6: {message:"GuessOutOfRange"},
+  7: {message:"InvalidConfig"},
+  8: {message:"NotYourTurn"},
+  9: {message:"HintPending"},
+  10: {message:"NoPendingGuess"},
+  11: {message:"AwaitingReveal"},
+  12: {message:"NotReadyToReveal"},
+  13: {message:"InvalidReveal"},
+  14: {message:"TimeoutNotReached"},
+  15: {message:"InvalidPlayers"},
+  16: {message:"NotAllGuessed"},
+  17: {message:"InvalidSessionKey"}
+}
+
+/**
+ * How guesses are compared against the winning number
+ */
+export type ScoringMode = {tag: "Closest", values: void} | {tag: "ExactOrDraw", values: void} | {tag: "ClosestWithoutOver", values: void};
+
+/**
+ * Per-session range and scoring, fixed when the game starts
+ */
+export interface GuessConfig {
+  /**
+   * Highest allowed guess (inclusive), must be above `min`
+   */
+  max: u32;
+  /**
+   * Lowest allowed guess (inclusive)
+   */
+  min: u32;
+  scoring: ScoringMode;
+}
+
 export interface Game {
+  config: GuessConfig;
+  /**
+   * Set when the game ended without a winner
+   */
+  draw: boolean;
   player1: string;
   player1_guess: Option<u32>;
   player1_points: i128;
@@ -50,43 +95,159 @@ export interface Game {
   winning_number: Option<u32>;
 }
 
-export const Errors = {
-  1: {message:"GameNotFound"},
-  2: {message:"NotPlayer"},
-  3: {message:"AlreadyGuessed"},
-  4: {message:"BothPlayersNotGuessed"},
-  5: {message:"GameAlreadyEnded"}
+/**
+ * Host's answer to a guess in hinted mode, relative to the guess
+ */
+export type Hint = {tag: "Higher", values: void} | {tag: "Lower", values: void} | {tag: "Correct", values: void};
+
+/**
+ * Setup for a hinted game, signed by both players
+ */
+export interface HintedSetup {
+  /**
+   * keccak256(secret number as 4 big-endian bytes || 32-byte salt)
+   */
+  commitment: Buffer;
+  /**
+   * Address that commits the secret and answers guesses
+   */
+  host: string;
+  max: u32;
+  /**
+   * Total guesses (both players) before the closest guess wins
+   */
+  max_turns: u32;
+  min: u32;
+}
+
+export interface HintedGame {
+  /**
+   * Ledger after which whoever is holding up the game can be timed out
+   */
+  deadline: u32;
+  draw: boolean;
+  /**
+   * All guesses in order; even indices are player 1's
+   */
+  guesses: Array<u32>;
+  /**
+   * Host answers, one per answered guess
+   */
+  hints: Array<Hint>;
+  /**
+   * False if the reveal showed a hint was wrong
+   */
+  host_honest: boolean;
+  player1: string;
+  player1_points: i128;
+  player2: string;
+  player2_points: i128;
+  /**
+   * Revealed secret, None until the host reveals it
+   */
+  secret_number: Option<u32>;
+  setup: HintedSetup;
+  winner: Option<string>;
+}
+
+export interface MultiGame {
+  config: GuessConfig;
+  guesses: Map<string, u32>;
+  /**
+   * Place of each player (1 = first, ties share a place); empty until revealed
+   */
+  places: Array<u32>;
+  players: Array<string>;
+  /**
+   * Points committed by each player, same order as `players`
+   */
+  points: Array<i128>;
+  /**
+   * Players in first place; all of them if the game is a draw
+   */
+  winners: Array<string>;
+  winning_number: Option<u32>;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "HintedGame", values: readonly [u32]} | {tag: "MultiGame", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+
+/**
+ * A short-lived key a player lets sign their moves in one game
+ */
+export interface SessionKey {
+  /**
+   * Last ledger the key is accepted at
+   */
+  expiration_ledger: u32;
+  /**
+   * The player the key moves for
+   */
+  player: string;
+}
 
 export interface Client {
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
    * 
-   * # Returns
-   * * `Address` - The GameHub contract address
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `session_keys` - Move keys to delegate up front, each mapped to its
+   * player and expiry (see `delegate_session_key`); may be empty
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  start_game: ({session_id, player1, player2, player1_points, player2_points, session_keys}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, session_keys: Map<string, SessionKey>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a start_game_with_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game with a custom range and scoring mode.
+   * Behaves like `start_game`, but both players also sign the config.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `config` - Guess range and scoring mode for this session
+   * * `session_keys` - Move keys to delegate up front, each mapped to its
+   * player and expiry (see `delegate_session_key`); may be empty
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  start_game_with_config: ({session_id, player1, player2, player1_points, player2_points, config, session_keys}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, config: GuessConfig, session_keys: Map<string, SessionKey>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a make_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Make a guess for the current game.
+   * Players can guess a number in the session's range (1-10 by default).
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player making the guess, or their session key
+   * * `guess` - The guessed number, within the session's range
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  make_guess: ({session_id, player, guess}: {session_id: u32, player: string, guess: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the winner of the game and submit outcome to GameHub.
+   * Can only be called after both players have made their guesses.
+   * This generates the winning number, scores the guesses with the session's
+   * scoring mode, and ends the session (as a draw if nobody wins).
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Option<Address>` - Some(winner), or None if the game ended in a draw
+   */
+  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -100,6 +261,156 @@ export interface Client {
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a start_hinted_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a hinted game: the host commits a secret number and answers each
+   * guess with Higher/Lower/Correct. Player 1 guesses first. The host
+   * cannot be one of the players.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `setup` - Host, range, turn limit and the host's commitment
+   */
+  start_hinted_game: ({session_id, player1, player2, player1_points, player2_points, setup}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, setup: HintedSetup}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Guess in a hinted game. Players alternate, starting with player 1, and
+   * each guess must be answered by the host before the next one.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the guessing player (must be their turn)
+   * * `guess` - The guessed number, within the game's range
+   */
+  submit_guess: ({session_id, player, guess}: {session_id: u32, player: string, guess: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a answer_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Host answers the latest guess.
+   * After a `Correct` answer, or once the turn limit is reached, the game
+   * waits for the host to reveal the secret.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `hint` - Whether the secret is higher than, lower than, or equal to the guess
+   */
+  answer_guess: ({session_id, hint}: {session_id: u32, hint: Hint}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_number transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Host reveals the secret and the game is settled with the Game Hub.
+   * The result comes from the revealed number, not from the hints: the
+   * first exact guess wins, otherwise the closest guess (earliest on ties).
+   * Any wrong hint is recorded by clearing `host_honest`.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `secret_number` - The committed number
+   * * `salt` - The 32-byte salt used in the commitment
+   * 
+   * # Returns
+   * * `Option<Address>` - Some(winner), or None if the game ended in a draw
+   */
+  reveal_number: ({session_id, secret_number, salt}: {session_id: u32, secret_number: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
+
+  /**
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a stalled hinted game after its deadline.
+   * If a player failed to guess, the other player wins. If the host failed
+   * to answer or reveal, the game ends as a draw.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player claiming the timeout
+   * 
+   * # Returns
+   * * `Option<Address>` - Some(winner), or None if the game ended in a draw
+   */
+  claim_timeout: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
+
+  /**
+   * Construct and simulate a get_hinted_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get hinted game information.
+   * The secret number stays hidden (None) until the host reveals it.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `HintedGame` - The game state
+   */
+  get_hinted_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<HintedGame>>>
+
+  /**
+   * Construct and simulate a start_multi_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a game for 2..=8 players, each with their own stake.
+   * Every player signs the session, their own points and the config.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `players` - Participant addresses, all different
+   * * `points` - Points committed by each player, same order as `players`
+   * * `config` - Guess range and scoring mode for this session
+   */
+  start_multi_game: ({session_id, players, points, config}: {session_id: u32, players: Array<string>, points: Array<i128>, config: GuessConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a make_multi_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Make a guess in a multi-player game. Each player guesses once.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player making the guess
+   * * `guess` - The guessed number, within the session's range
+   */
+  make_multi_guess: ({session_id, player, guess}: {session_id: u32, player: string, guess: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_multi_winners transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rank a multi-player game once everyone has guessed and report the
+   * ranking to the Game Hub. Unlike the two-player game, equal results
+   * share a place, so several players can win.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Vec<Address>` - The players in first place (all of them on a draw)
+   */
+  reveal_multi_winners: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<string>>>>
+
+  /**
+   * Construct and simulate a get_multi_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get multi-player game information.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `MultiGame` - The game state (includes places after the reveal)
+   */
+  get_multi_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<MultiGame>>>
+
+  /**
+   * Construct and simulate a delegate_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Let a short-lived key sign `make_guess` for a player, so the main wallet is
+   * not prompted for every move. Usually done through `start_game`; this call is
+   * for renewing or adding a key once the game is under way.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - The player delegating
+   * * `session_key` - The key that will sign moves; also authorizes this call
+   * * `expiration_ledger` - Last ledger the key is accepted at, at most
+   * `MAX_SESSION_KEY_LEDGERS` from now
+   */
+  delegate_session_key: ({session_id, player, session_key, expiration_ledger}: {session_id: u32, player: string, session_key: string, expiration_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the current admin address
@@ -119,47 +430,31 @@ export interface Client {
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a make_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Make a guess for the current game.
-   * Players can guess a number between 1 and 10.
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
    * 
-   * # Arguments
-   * * `session_id` - The session ID of the game
-   * * `player` - Address of the player making the guess
-   * * `guess` - The guessed number (1-10)
+   * # Returns
+   * * `Address` - The GameHub contract address
    */
-  make_guess: ({session_id, player, guess}: {session_id: u32, player: string, guess: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
-   * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * * `new_hub` - The new GameHub contract address
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have made their guesses.
-   * This generates the winning number, determines the winner, and ends the session.
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract)
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
-   * 
-   * # Returns
-   * * `Address` - Address of the winning player
+   * * `new_wasm_hash` - The hash of the new WASM binary
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -181,31 +476,62 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAIAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADXBsYXllcjFfZ3Vlc3MAAAAAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAANcGxheWVyMl9ndWVzcwAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEwAAAAAAAAAOd2lubmluZ19udW1iZXIAAAAAA+gAAAAE",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAFUJvdGhQbGF5ZXJzTm90R3Vlc3NlZAAAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAF",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAEQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAFUJvdGhQbGF5ZXJzTm90R3Vlc3NlZAAAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAAA9HdWVzc091dE9mUmFuZ2UAAAAABgAAAAAAAAANSW52YWxpZENvbmZpZwAAAAAAAAcAAAAAAAAAC05vdFlvdXJUdXJuAAAAAAgAAAAAAAAAC0hpbnRQZW5kaW5nAAAAAAkAAAAAAAAADk5vUGVuZGluZ0d1ZXNzAAAAAAAKAAAAAAAAAA5Bd2FpdGluZ1JldmVhbAAAAAAACwAAAAAAAAAQTm90UmVhZHlUb1JldmVhbAAAAAwAAAAAAAAADUludmFsaWRSZXZlYWwAAAAAAAANAAAAAAAAABFUaW1lb3V0Tm90UmVhY2hlZAAAAAAAAA4AAAAAAAAADkludmFsaWRQbGF5ZXJzAAAAAAAPAAAAAAAAAA1Ob3RBbGxHdWVzc2VkAAAAAAAAEAAAAAAAAAARSW52YWxpZFNlc3Npb25LZXkAAAAAAAAR",
+        "AAAAAgAAADNIb3cgZ3Vlc3NlcyBhcmUgY29tcGFyZWQgYWdhaW5zdCB0aGUgd2lubmluZyBudW1iZXIAAAAAAAAAAAtTY29yaW5nTW9kZQAAAAADAAAAAAAAADJDbG9zZXN0IGd1ZXNzIHdpbnM7IGVxdWFsIGRpc3RhbmNlcyBnbyB0byBwbGF5ZXIgMQAAAAAAB0Nsb3Nlc3QAAAAAAAAAADtPbmx5IGFuIGV4YWN0IGd1ZXNzIHdpbnM7IG5vIGV4YWN0IGd1ZXNzIChvciB0d28pIGlzIGEgZHJhdwAAAAALRXhhY3RPckRyYXcAAAAAAAAAAGpDbG9zZXN0IGd1ZXNzIHRoYXQgaXMgbm90IGFib3ZlIHRoZSBudW1iZXIgd2lucyAoInByaWNlIGlzIHJpZ2h0Iik7CmJvdGggb3Zlciwgb3IgZXF1YWwgZ3Vlc3NlcywgaXMgYSBkcmF3AAAAAAASQ2xvc2VzdFdpdGhvdXRPdmVyAAA=",
+        "AAAAAQAAADlQZXItc2Vzc2lvbiByYW5nZSBhbmQgc2NvcmluZywgZml4ZWQgd2hlbiB0aGUgZ2FtZSBzdGFydHMAAAAAAAAAAAAAC0d1ZXNzQ29uZmlnAAAAAAMAAAA2SGlnaGVzdCBhbGxvd2VkIGd1ZXNzIChpbmNsdXNpdmUpLCBtdXN0IGJlIGFib3ZlIGBtaW5gAAAAAAADbWF4AAAAAAQAAAAgTG93ZXN0IGFsbG93ZWQgZ3Vlc3MgKGluY2x1c2l2ZSkAAAADbWluAAAAAAQAAAAAAAAAB3Njb3JpbmcAAAAH0AAAAAtTY29yaW5nTW9kZQA=",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAKAAAAAAAAAAZjb25maWcAAAAAB9AAAAALR3Vlc3NDb25maWcAAAAAKFNldCB3aGVuIHRoZSBnYW1lIGVuZGVkIHdpdGhvdXQgYSB3aW5uZXIAAAAEZHJhdwAAAAEAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAANcGxheWVyMV9ndWVzcwAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA1wbGF5ZXIyX2d1ZXNzAAAAAAAD6AAAAAQAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAATAAAAAAAAAA53aW5uaW5nX251bWJlcgAAAAAD6AAAAAQ=",
+        "AAAAAgAAAD5Ib3N0J3MgYW5zd2VyIHRvIGEgZ3Vlc3MgaW4gaGludGVkIG1vZGUsIHJlbGF0aXZlIHRvIHRoZSBndWVzcwAAAAAAAAAAAARIaW50AAAAAwAAAAAAAAAqVGhlIHNlY3JldCBudW1iZXIgaXMgaGlnaGVyIHRoYW4gdGhlIGd1ZXNzAAAAAAAGSGlnaGVyAAAAAAAAAAAAKVRoZSBzZWNyZXQgbnVtYmVyIGlzIGxvd2VyIHRoYW4gdGhlIGd1ZXNzAAAAAAAABUxvd2VyAAAAAAAAAAAAAAAAAAAHQ29ycmVjdAA=",
+        "AAAAAQAAAC9TZXR1cCBmb3IgYSBoaW50ZWQgZ2FtZSwgc2lnbmVkIGJ5IGJvdGggcGxheWVycwAAAAAAAAAAC0hpbnRlZFNldHVwAAAAAAUAAAA+a2VjY2FrMjU2KHNlY3JldCBudW1iZXIgYXMgNCBiaWctZW5kaWFuIGJ5dGVzIHx8IDMyLWJ5dGUgc2FsdCkAAAAAAApjb21taXRtZW50AAAAAAPuAAAAIAAAADNBZGRyZXNzIHRoYXQgY29tbWl0cyB0aGUgc2VjcmV0IGFuZCBhbnN3ZXJzIGd1ZXNzZXMAAAAABGhvc3QAAAATAAAAAAAAAANtYXgAAAAABAAAADpUb3RhbCBndWVzc2VzIChib3RoIHBsYXllcnMpIGJlZm9yZSB0aGUgY2xvc2VzdCBndWVzcyB3aW5zAAAAAAAJbWF4X3R1cm5zAAAAAAAABAAAAAAAAAADbWluAAAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAACkhpbnRlZEdhbWUAAAAAAAwAAABCTGVkZ2VyIGFmdGVyIHdoaWNoIHdob2V2ZXIgaXMgaG9sZGluZyB1cCB0aGUgZ2FtZSBjYW4gYmUgdGltZWQgb3V0AAAAAAAIZGVhZGxpbmUAAAAEAAAAAAAAAARkcmF3AAAAAQAAADFBbGwgZ3Vlc3NlcyBpbiBvcmRlcjsgZXZlbiBpbmRpY2VzIGFyZSBwbGF5ZXIgMSdzAAAAAAAAB2d1ZXNzZXMAAAAD6gAAAAQAAAAkSG9zdCBhbnN3ZXJzLCBvbmUgcGVyIGFuc3dlcmVkIGd1ZXNzAAAABWhpbnRzAAAAAAAD6gAAB9AAAAAESGludAAAACtGYWxzZSBpZiB0aGUgcmV2ZWFsIHNob3dlZCBhIGhpbnQgd2FzIHdyb25nAAAAAAtob3N0X2hvbmVzdAAAAAABAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAL1JldmVhbGVkIHNlY3JldCwgTm9uZSB1bnRpbCB0aGUgaG9zdCByZXZlYWxzIGl0AAAAAA1zZWNyZXRfbnVtYmVyAAAAAAAD6AAAAAQAAAAAAAAABXNldHVwAAAAAAAH0AAAAAtIaW50ZWRTZXR1cAAAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAQAAAAAAAAAAAAAACU11bHRpR2FtZQAAAAAAAAcAAAAAAAAABmNvbmZpZwAAAAAH0AAAAAtHdWVzc0NvbmZpZwAAAAAAAAAAB2d1ZXNzZXMAAAAD7AAAABMAAAAEAAAASlBsYWNlIG9mIGVhY2ggcGxheWVyICgxID0gZmlyc3QsIHRpZXMgc2hhcmUgYSBwbGFjZSk7IGVtcHR5IHVudGlsIHJldmVhbGVkAAAAAAAGcGxhY2VzAAAAAAPqAAAABAAAAAAAAAAHcGxheWVycwAAAAPqAAAAEwAAADhQb2ludHMgY29tbWl0dGVkIGJ5IGVhY2ggcGxheWVyLCBzYW1lIG9yZGVyIGFzIGBwbGF5ZXJzYAAAAAZwb2ludHMAAAAAA+oAAAALAAAAOVBsYXllcnMgaW4gZmlyc3QgcGxhY2U7IGFsbCBvZiB0aGVtIGlmIHRoZSBnYW1lIGlzIGEgZHJhdwAAAAAAAAd3aW5uZXJzAAAAA+oAAAATAAAAAAAAAA53aW5uaW5nX251bWJlcgAAAAAD6AAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABQAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAEAAAAAAAAACkhpbnRlZEdhbWUAAAAAAAEAAAAEAAAAAQAAAAAAAAAJTXVsdGlHYW1lAAAAAAAAAQAAAAQAAAAAAAAAAAAAAA5HYW1lSHViQWRkcmVzcwAAAAAAAAAAAAAAAAAFQWRtaW4AAAA=",
+        "AAAAAQAAADxBIHNob3J0LWxpdmVkIGtleSBhIHBsYXllciBsZXRzIHNpZ24gdGhlaXIgbW92ZXMgaW4gb25lIGdhbWUAAAAAAAAAClNlc3Npb25LZXkAAAAAAAIAAAAiTGFzdCBsZWRnZXIgdGhlIGtleSBpcyBhY2NlcHRlZCBhdAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAABxUaGUgcGxheWVyIHRoZSBrZXkgbW92ZXMgZm9yAAAABnBsYXllcgAAAAAAEw==",
+        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
+        "AAAAAAAAAq1TdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgoqIGBzZXNzaW9uX2tleXNgIC0gTW92ZSBrZXlzIHRvIGRlbGVnYXRlIHVwIGZyb250LCBlYWNoIG1hcHBlZCB0byBpdHMKcGxheWVyIGFuZCBleHBpcnkgKHNlZSBgZGVsZWdhdGVfc2Vzc2lvbl9rZXlgKTsgbWF5IGJlIGVtcHR5AAAAAAAACnN0YXJ0X2dhbWUAAAAAAAYAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADHNlc3Npb25fa2V5cwAAA+wAAAATAAAH0AAAAApTZXNzaW9uS2V5AAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAjNTdGFydCBhIG5ldyBnYW1lIHdpdGggYSBjdXN0b20gcmFuZ2UgYW5kIHNjb3JpbmcgbW9kZS4KQmVoYXZlcyBsaWtlIGBzdGFydF9nYW1lYCwgYnV0IGJvdGggcGxheWVycyBhbHNvIHNpZ24gdGhlIGNvbmZpZy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVW5pcXVlIHNlc3Npb24gaWRlbnRpZmllciAodTMyKQoqIGBwbGF5ZXIxYCAtIEFkZHJlc3Mgb2YgZmlyc3QgcGxheWVyCiogYHBsYXllcjJgIC0gQWRkcmVzcyBvZiBzZWNvbmQgcGxheWVyCiogYHBsYXllcjFfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAxCiogYHBsYXllcjJfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAyCiogYGNvbmZpZ2AgLSBHdWVzcyByYW5nZSBhbmQgc2NvcmluZyBtb2RlIGZvciB0aGlzIHNlc3Npb24KKiBgc2Vzc2lvbl9rZXlzYCAtIE1vdmUga2V5cyB0byBkZWxlZ2F0ZSB1cCBmcm9udCwgZWFjaCBtYXBwZWQgdG8gaXRzCnBsYXllciBhbmQgZXhwaXJ5IChzZWUgYGRlbGVnYXRlX3Nlc3Npb25fa2V5YCk7IG1heSBiZSBlbXB0eQAAAAAWc3RhcnRfZ2FtZV93aXRoX2NvbmZpZwAAAAAABwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAGY29uZmlnAAAAAAfQAAAAC0d1ZXNzQ29uZmlnAAAAAAAAAAAMc2Vzc2lvbl9rZXlzAAAD7AAAABMAAAfQAAAAClNlc3Npb25LZXkAAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAASVNYWtlIGEgZ3Vlc3MgZm9yIHRoZSBjdXJyZW50IGdhbWUuClBsYXllcnMgY2FuIGd1ZXNzIGEgbnVtYmVyIGluIHRoZSBzZXNzaW9uJ3MgcmFuZ2UgKDEtMTAgYnkgZGVmYXVsdCkuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgbWFraW5nIHRoZSBndWVzcywgb3IgdGhlaXIgc2Vzc2lvbiBrZXkKKiBgZ3Vlc3NgIC0gVGhlIGd1ZXNzZWQgbnVtYmVyLCB3aXRoaW4gdGhlIHNlc3Npb24ncyByYW5nZQAAAAAAAAptYWtlX2d1ZXNzAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABWd1ZXNzAAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAY9SZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIG1hZGUgdGhlaXIgZ3Vlc3Nlcy4KVGhpcyBnZW5lcmF0ZXMgdGhlIHdpbm5pbmcgbnVtYmVyLCBzY29yZXMgdGhlIGd1ZXNzZXMgd2l0aCB0aGUgc2Vzc2lvbidzCnNjb3JpbmcgbW9kZSwgYW5kIGVuZHMgdGhlIHNlc3Npb24gKGFzIGEgZHJhdyBpZiBub2JvZHkgd2lucykuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgT3B0aW9uPEFkZHJlc3M+YCAtIFNvbWUod2lubmVyKSwgb3IgTm9uZSBpZiB0aGUgZ2FtZSBlbmRlZCBpbiBhIGRyYXcAAAAADXJldmVhbF93aW5uZXIAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPoAAAAEwAAAAM=",
         "AAAAAAAAAJ1HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyB3aW5uaW5nIG51bWJlciBhZnRlciBnYW1lIGVuZHMpAAAAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAAeFTdGFydCBhIGhpbnRlZCBnYW1lOiB0aGUgaG9zdCBjb21taXRzIGEgc2VjcmV0IG51bWJlciBhbmQgYW5zd2VycyBlYWNoCmd1ZXNzIHdpdGggSGlnaGVyL0xvd2VyL0NvcnJlY3QuIFBsYXllciAxIGd1ZXNzZXMgZmlyc3QuIFRoZSBob3N0CmNhbm5vdCBiZSBvbmUgb2YgdGhlIHBsYXllcnMuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgoqIGBzZXR1cGAgLSBIb3N0LCByYW5nZSwgdHVybiBsaW1pdCBhbmQgdGhlIGhvc3QncyBjb21taXRtZW50AAAAAAAAEXN0YXJ0X2hpbnRlZF9nYW1lAAAAAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAFc2V0dXAAAAAAAAfQAAAAC0hpbnRlZFNldHVwAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAATVHdWVzcyBpbiBhIGhpbnRlZCBnYW1lLiBQbGF5ZXJzIGFsdGVybmF0ZSwgc3RhcnRpbmcgd2l0aCBwbGF5ZXIgMSwgYW5kCmVhY2ggZ3Vlc3MgbXVzdCBiZSBhbnN3ZXJlZCBieSB0aGUgaG9zdCBiZWZvcmUgdGhlIG5leHQgb25lLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgZ3Vlc3NpbmcgcGxheWVyIChtdXN0IGJlIHRoZWlyIHR1cm4pCiogYGd1ZXNzYCAtIFRoZSBndWVzc2VkIG51bWJlciwgd2l0aGluIHRoZSBnYW1lJ3MgcmFuZ2UAAAAAAAAMc3VibWl0X2d1ZXNzAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVndWVzcwAAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAARZIb3N0IGFuc3dlcnMgdGhlIGxhdGVzdCBndWVzcy4KQWZ0ZXIgYSBgQ29ycmVjdGAgYW5zd2VyLCBvciBvbmNlIHRoZSB0dXJuIGxpbWl0IGlzIHJlYWNoZWQsIHRoZSBnYW1lCndhaXRzIGZvciB0aGUgaG9zdCB0byByZXZlYWwgdGhlIHNlY3JldC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgaGludGAgLSBXaGV0aGVyIHRoZSBzZWNyZXQgaXMgaGlnaGVyIHRoYW4sIGxvd2VyIHRoYW4sIG9yIGVxdWFsIHRvIHRoZSBndWVzcwAAAAAADGFuc3dlcl9ndWVzcwAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABGhpbnQAAAfQAAAABEhpbnQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAetIb3N0IHJldmVhbHMgdGhlIHNlY3JldCBhbmQgdGhlIGdhbWUgaXMgc2V0dGxlZCB3aXRoIHRoZSBHYW1lIEh1Yi4KVGhlIHJlc3VsdCBjb21lcyBmcm9tIHRoZSByZXZlYWxlZCBudW1iZXIsIG5vdCBmcm9tIHRoZSBoaW50czogdGhlCmZpcnN0IGV4YWN0IGd1ZXNzIHdpbnMsIG90aGVyd2lzZSB0aGUgY2xvc2VzdCBndWVzcyAoZWFybGllc3Qgb24gdGllcykuCkFueSB3cm9uZyBoaW50IGlzIHJlY29yZGVkIGJ5IGNsZWFyaW5nIGBob3N0X2hvbmVzdGAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHNlY3JldF9udW1iZXJgIC0gVGhlIGNvbW1pdHRlZCBudW1iZXIKKiBgc2FsdGAgLSBUaGUgMzItYnl0ZSBzYWx0IHVzZWQgaW4gdGhlIGNvbW1pdG1lbnQKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gU29tZSh3aW5uZXIpLCBvciBOb25lIGlmIHRoZSBnYW1lIGVuZGVkIGluIGEgZHJhdwAAAAANcmV2ZWFsX251bWJlcgAAAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAADXNlY3JldF9udW1iZXIAAAAAAAAEAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAD6QAAA+gAAAATAAAAAw==",
+        "AAAAAAAAAWZFbmQgYSBzdGFsbGVkIGhpbnRlZCBnYW1lIGFmdGVyIGl0cyBkZWFkbGluZS4KSWYgYSBwbGF5ZXIgZmFpbGVkIHRvIGd1ZXNzLCB0aGUgb3RoZXIgcGxheWVyIHdpbnMuIElmIHRoZSBob3N0IGZhaWxlZAp0byBhbnN3ZXIgb3IgcmV2ZWFsLCB0aGUgZ2FtZSBlbmRzIGFzIGEgZHJhdy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBjbGFpbWluZyB0aGUgdGltZW91dAoKIyBSZXR1cm5zCiogYE9wdGlvbjxBZGRyZXNzPmAgLSBTb21lKHdpbm5lciksIG9yIE5vbmUgaWYgdGhlIGdhbWUgZW5kZWQgaW4gYSBkcmF3AAAAAAANY2xhaW1fdGltZW91dAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD6AAAABMAAAAD",
+        "AAAAAAAAAMFHZXQgaGludGVkIGdhbWUgaW5mb3JtYXRpb24uClRoZSBzZWNyZXQgbnVtYmVyIHN0YXlzIGhpZGRlbiAoTm9uZSkgdW50aWwgdGhlIGhvc3QgcmV2ZWFscyBpdC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBIaW50ZWRHYW1lYCAtIFRoZSBnYW1lIHN0YXRlAAAAAAAAD2dldF9oaW50ZWRfZ2FtZQAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAACkhpbnRlZEdhbWUAAAAAAAM=",
+        "AAAAAAAAAW1TdGFydCBhIGdhbWUgZm9yIDIuLj04IHBsYXllcnMsIGVhY2ggd2l0aCB0aGVpciBvd24gc3Rha2UuCkV2ZXJ5IHBsYXllciBzaWducyB0aGUgc2Vzc2lvbiwgdGhlaXIgb3duIHBvaW50cyBhbmQgdGhlIGNvbmZpZy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVW5pcXVlIHNlc3Npb24gaWRlbnRpZmllciAodTMyKQoqIGBwbGF5ZXJzYCAtIFBhcnRpY2lwYW50IGFkZHJlc3NlcywgYWxsIGRpZmZlcmVudAoqIGBwb2ludHNgIC0gUG9pbnRzIGNvbW1pdHRlZCBieSBlYWNoIHBsYXllciwgc2FtZSBvcmRlciBhcyBgcGxheWVyc2AKKiBgY29uZmlnYCAtIEd1ZXNzIHJhbmdlIGFuZCBzY29yaW5nIG1vZGUgZm9yIHRoaXMgc2Vzc2lvbgAAAAAAABBzdGFydF9tdWx0aV9nYW1lAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVycwAAAAPqAAAAEwAAAAAAAAAGcG9pbnRzAAAAAAPqAAAACwAAAAAAAAAGY29uZmlnAAAAAAfQAAAAC0d1ZXNzQ29uZmlnAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAOZNYWtlIGEgZ3Vlc3MgaW4gYSBtdWx0aS1wbGF5ZXIgZ2FtZS4gRWFjaCBwbGF5ZXIgZ3Vlc3NlcyBvbmNlLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIG1ha2luZyB0aGUgZ3Vlc3MKKiBgZ3Vlc3NgIC0gVGhlIGd1ZXNzZWQgbnVtYmVyLCB3aXRoaW4gdGhlIHNlc3Npb24ncyByYW5nZQAAAAAAEG1ha2VfbXVsdGlfZ3Vlc3MAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABWd1ZXNzAAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAATlSYW5rIGEgbXVsdGktcGxheWVyIGdhbWUgb25jZSBldmVyeW9uZSBoYXMgZ3Vlc3NlZCBhbmQgcmVwb3J0IHRoZQpyYW5raW5nIHRvIHRoZSBHYW1lIEh1Yi4gVW5saWtlIHRoZSB0d28tcGxheWVyIGdhbWUsIGVxdWFsIHJlc3VsdHMKc2hhcmUgYSBwbGFjZSwgc28gc2V2ZXJhbCBwbGF5ZXJzIGNhbiB3aW4uCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgVmVjPEFkZHJlc3M+YCAtIFRoZSBwbGF5ZXJzIGluIGZpcnN0IHBsYWNlIChhbGwgb2YgdGhlbSBvbiBhIGRyYXcpAAAAAAAAFHJldmVhbF9tdWx0aV93aW5uZXJzAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD6gAAABMAAAAD",
+        "AAAAAAAAAKhHZXQgbXVsdGktcGxheWVyIGdhbWUgaW5mb3JtYXRpb24uCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgTXVsdGlHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBwbGFjZXMgYWZ0ZXIgdGhlIHJldmVhbCkAAAAOZ2V0X211bHRpX2dhbWUAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAJTXVsdGlHYW1lAAAAAAAAAw==",
+        "AAAAAAAAAd5MZXQgYSBzaG9ydC1saXZlZCBrZXkgc2lnbiBgbWFrZV9ndWVzc2AgZm9yIGEgcGxheWVyLCBzbyB0aGUgbWFpbiB3YWxsZXQgaXMKbm90IHByb21wdGVkIGZvciBldmVyeSBtb3ZlLiBVc3VhbGx5IGRvbmUgdGhyb3VnaCBgc3RhcnRfZ2FtZWA7IHRoaXMgY2FsbCBpcwpmb3IgcmVuZXdpbmcgb3IgYWRkaW5nIGEga2V5IG9uY2UgdGhlIGdhbWUgaXMgdW5kZXIgd2F5LgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gVGhlIHBsYXllciBkZWxlZ2F0aW5nCiogYHNlc3Npb25fa2V5YCAtIFRoZSBrZXkgdGhhdCB3aWxsIHNpZ24gbW92ZXM7IGFsc28gYXV0aG9yaXplcyB0aGlzIGNhbGwKKiBgZXhwaXJhdGlvbl9sZWRnZXJgIC0gTGFzdCBsZWRnZXIgdGhlIGtleSBpcyBhY2NlcHRlZCBhdCwgYXQgbW9zdApgTUFYX1NFU1NJT05fS0VZX0xFREdFUlNgIGZyb20gbm93AAAAAAAUZGVsZWdhdGVfc2Vzc2lvbl9rZXkAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC3Nlc3Npb25fa2V5AAAAABMAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
         "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAOJNYWtlIGEgZ3Vlc3MgZm9yIHRoZSBjdXJyZW50IGdhbWUuClBsYXllcnMgY2FuIGd1ZXNzIGEgbnVtYmVyIGJldHdlZW4gMSBhbmQgMTAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgbWFraW5nIHRoZSBndWVzcwoqIGBndWVzc2AgLSBUaGUgZ3Vlc3NlZCBudW1iZXIgKDEtMTApAAAAAAAKbWFrZV9ndWVzcwAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVndWVzcwAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAATtSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIG1hZGUgdGhlaXIgZ3Vlc3Nlcy4KVGhpcyBnZW5lcmF0ZXMgdGhlIHdpbm5pbmcgbnVtYmVyLCBkZXRlcm1pbmVzIHRoZSB3aW5uZXIsIGFuZCBlbmRzIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAAADXJldmVhbF93aW5uZXIAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==" ]),
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+    start_game: this.txFromJSON<Result<void>>,
+        start_game_with_config: this.txFromJSON<Result<void>>,
+        make_guess: this.txFromJSON<Result<void>>,
+        reveal_winner: this.txFromJSON<Result<Option<string>>>,
         get_game: this.txFromJSON<Result<Game>>,
+        start_hinted_game: this.txFromJSON<Result<void>>,
+        submit_guess: this.txFromJSON<Result<void>>,
+        answer_guess: this.txFromJSON<Result<void>>,
+        reveal_number: this.txFromJSON<Result<Option<string>>>,
+        claim_timeout: this.txFromJSON<Result<Option<string>>>,
+        get_hinted_game: this.txFromJSON<Result<HintedGame>>,
+        start_multi_game: this.txFromJSON<Result<void>>,
+        make_multi_guess: this.txFromJSON<Result<void>>,
+        reveal_multi_winners: this.txFromJSON<Result<Array<string>>>,
+        get_multi_game: this.txFromJSON<Result<MultiGame>>,
+        delegate_session_key: this.txFromJSON<Result<void>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
-        make_guess: this.txFromJSON<Result<void>>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file